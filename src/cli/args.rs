@@ -1,5 +1,6 @@
 /// CLI argument parsing using clap
-use clap::{Parser, Subcommand};
+use crate::config::CliOverrides;
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -14,40 +15,64 @@ use std::path::PathBuf;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Disable colored output (also disabled by NO_COLOR or when stdout is not a terminal)
+    #[arg(long, global = true)]
+    pub no_color: bool,
 }
 
+// Parsed once per run, so the size of the `Scan` variant doesn't matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum Commands {
     /// Scan a directory for PII
     Scan {
         /// Directory to scan
-        #[arg(value_name = "PATH")]
-        directory: PathBuf,
+        #[arg(
+            value_name = "PATH",
+            required_unless_present_any = ["paths_from", "print_config"]
+        )]
+        directory: Option<PathBuf>,
 
-        /// Output format
-        #[arg(short, long, value_name = "FORMAT", default_value = "terminal")]
-        format: OutputFormat,
+        /// Output format, repeatable or comma-separated (terminal,json,html) [default: auto,
+        /// from the --output extension]
+        #[arg(short, long, value_name = "FORMAT", value_delimiter = ',')]
+        format: Vec<OutputFormat>,
 
-        /// Output file (for json/csv formats)
+        /// Output file; with several formats, each gets this path with its own extension.
+        /// JSON, NDJSON and CSV files ending in `.gz` are gzip-compressed
         #[arg(short, long, value_name = "FILE")]
         output: Option<PathBuf>,
 
+        /// Gzip JSON, NDJSON and CSV report files (appends `.gz` to their paths)
+        #[arg(long)]
+        compress: bool,
+
         /// Filter by country codes (comma-separated: nl,de,gb)
         #[arg(short, long, value_name = "CODES")]
         countries: Option<String>,
 
-        /// Minimum confidence level to report
-        #[arg(long, value_name = "LEVEL", default_value = "high")]
-        min_confidence: ConfidenceLevel,
+        /// Minimum confidence level to report [default: high]
+        #[arg(long, value_name = "LEVEL")]
+        min_confidence: Option<ConfidenceLevel>,
+
+        /// False-positive tolerance: lenient (checksum-valid High only), balanced, or
+        /// paranoid (also pattern-only Low matches)
+        #[arg(long, value_enum, value_name = "LEVEL", default_value = "balanced")]
+        strictness: Strictness,
 
         /// Disable context analysis (GDPR Art. 9)
         #[arg(long)]
         no_context: bool,
 
-        /// Extract text from documents (PDF, DOCX, XLSX)
+        /// Extract text from documents (PDF, DOCX, XLSX, XML/SVG, VCF/ICS, IPYNB, HAR, SQL)
         #[arg(long)]
         extract_documents: bool,
 
+        /// Abort extracting a PDF after SECS seconds [default: 120]
+        #[arg(long, value_name = "SECS")]
+        pdf_timeout: Option<u64>,
+
         /// Disable progress bar
         #[arg(long)]
         no_progress: bool,
@@ -64,13 +89,138 @@ pub enum Commands {
         #[arg(short = 'j', long, value_name = "N")]
         threads: Option<usize>,
 
-        /// Maximum file size to scan in MB
-        #[arg(long, value_name = "SIZE", default_value = "100")]
-        max_filesize: u64,
+        /// Maximum file size to scan in MB [default: 100]
+        #[arg(long, value_name = "SIZE")]
+        max_filesize: Option<u64>,
+
+        /// Stop after scanning N files and report partial results
+        #[arg(long, value_name = "N")]
+        max_files: Option<usize>,
+
+        /// Stop after scanning BYTES of file content and report partial results
+        #[arg(long, value_name = "BYTES")]
+        max_bytes: Option<u64>,
 
         /// Load custom detector plugins from directory
         #[arg(long, value_name = "DIR")]
         plugins: Option<PathBuf>,
+
+        /// A plugin reusing a detector id: keep the first detector and warn, or fail
+        #[arg(long, value_enum, default_value = "keep-first")]
+        duplicate_ids: DuplicateIds,
+
+        /// Scan files listed in FILE (newline-delimited, `-` for stdin) instead of walking PATH
+        #[arg(long, value_name = "FILE", conflicts_with = "directory")]
+        paths_from: Option<PathBuf>,
+
+        /// Phone number regions (comma-separated: de,at); the first is used for national-format numbers
+        #[arg(long, value_name = "CODES")]
+        phone_region: Option<String>,
+
+        /// Also flag probable person names next to a name label or another finding (low confidence)
+        #[arg(long)]
+        detect_names: bool,
+
+        /// Exit with code 2 when files could not be scanned, even if no PII was found
+        #[arg(long)]
+        strict_exit: bool,
+
+        /// Exit with code 1 only for matches of this severity or higher (default: any match)
+        #[arg(long, value_enum, value_name = "SEVERITY")]
+        fail_on: Option<SeverityLevel>,
+
+        /// CSV output: write a summary grouped by this field instead of per-match rows
+        #[arg(long, value_name = "FIELD")]
+        csv_group_by: Option<CsvGroupBy>,
+
+        /// CSV output: keep the per-match rows and write the grouped summary to FILE.summary.csv
+        #[arg(long, requires = "csv_group_by")]
+        csv_with_rows: bool,
+
+        /// JSON output: group matches by file, by detector, or as one flat array
+        #[arg(long, value_enum, value_name = "SHAPE", default_value = "by-file")]
+        json_shape: JsonShape,
+
+        /// Strip zero-width characters and map Unicode homoglyphs to ASCII before detection
+        #[arg(long)]
+        normalize: bool,
+
+        /// Detector preset: pii (all detectors) or secrets (credentials and card data only)
+        #[arg(long, value_enum, default_value = "pii")]
+        preset: Preset,
+
+        /// Parallelism strategy: per-file, intra-file (chunks of one file at a time) or hybrid
+        #[arg(long, value_enum, default_value = "per-file")]
+        parallelism: Parallelism,
+
+        /// Write a JSON manifest of every discovered file (status, size, hash, match count),
+        /// including files left out by the size limit, hidden-file rule or ignore files
+        #[arg(long, value_name = "FILE")]
+        manifest: Option<PathBuf>,
+
+        /// Write the reports, manifest and log summary into a new BASE/YYYYMMDD-HHMMSS/ folder
+        #[arg(long, value_name = "BASE", conflicts_with_all = ["output", "manifest"])]
+        run_dir: Option<PathBuf>,
+
+        /// Only scan files matching this glob, relative to PATH (repeatable, e.g. '**/*.sql')
+        #[arg(long, value_name = "GLOB")]
+        include: Vec<String>,
+
+        /// Incremental cache file: unchanged files reuse their previous matches
+        #[arg(long, value_name = "FILE")]
+        cache: Option<PathBuf>,
+
+        /// Verbose summary: list the top GDPR context keywords per special category
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Print Low/Medium/High match counts per detector after scanning
+        #[arg(long)]
+        confidence_histogram: bool,
+
+        /// Custom Tera template for the HTML report (default: built-in layout)
+        #[arg(long, value_name = "FILE")]
+        html_template: Option<PathBuf>,
+
+        /// Locale for date order and number separators (e.g. en-GB, en-US, de-DE) [default: en-GB]
+        #[arg(long, value_name = "TAG")]
+        locale: Option<String>,
+
+        /// Print a one-line JSON run summary to stderr after the scan
+        #[arg(long)]
+        log_summary: bool,
+
+        /// Scan identical files once and reuse their matches for the copies
+        #[arg(long)]
+        dedup_files: bool,
+
+        /// Record each file's SHA-256 in JSON (`content_hash`) and CSV (`SHA-256`) output
+        #[arg(long)]
+        hash_files: bool,
+
+        /// Scan a random percentage (1-100) of the discovered files and estimate totals
+        #[arg(long, value_name = "PERCENT", value_parser = clap::value_parser!(u8).range(1..=100))]
+        sample_percent: Option<u8>,
+
+        /// Seed for --sample-percent, to scan the same files again [default: random]
+        #[arg(long, value_name = "N", requires = "sample_percent")]
+        seed: Option<u64>,
+
+        /// Report expected countries that had no matches (comma-separated: nl,gb)
+        #[arg(long, value_name = "CODES")]
+        expect_countries: Option<String>,
+
+        /// Exit with code 4 when an --expect-countries country had no matches
+        #[arg(long, alias = "strict", requires = "expect_countries")]
+        strict_coverage: bool,
+
+        /// Print the effective configuration (file, PII_RADAR_* env vars and flags) as TOML and exit
+        #[arg(long)]
+        print_config: bool,
+
+        /// With --print-config: note where each setting came from
+        #[arg(long, requires = "print_config")]
+        explain: bool,
     },
 
     /// Scan a database for PII
@@ -112,12 +262,17 @@ pub enum Commands {
         #[arg(long, value_name = "N")]
         row_limit: Option<usize>,
 
+        /// Only scan text-like columns (varchar, text, char, json, ...), skipping
+        /// numeric, date/time and binary columns (Postgres, SQLite)
+        #[arg(long)]
+        text_columns_only: bool,
+
         /// Pool size for database connections
         #[arg(long, value_name = "N", default_value = "4")]
         pool_size: u32,
 
         /// Output format
-        #[arg(short = 'f', long, value_name = "FORMAT", default_value = "terminal")]
+        #[arg(short = 'f', long, value_name = "FORMAT", default_value = "auto")]
         format: OutputFormat,
 
         /// Output file
@@ -131,6 +286,60 @@ pub enum Commands {
         /// Disable progress bar
         #[arg(long)]
         no_progress: bool,
+
+        /// List the tables, columns and estimated row counts in scope without reading row data
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Benchmark detector throughput over a directory
+    Bench {
+        /// Directory to scan
+        #[arg(value_name = "PATH")]
+        directory: PathBuf,
+
+        /// Number of scan iterations
+        #[arg(short = 'n', long, value_name = "N", default_value = "3")]
+        iterations: usize,
+    },
+
+    /// Rescan a directory periodically and report new findings to a webhook
+    Watch {
+        /// Directory to watch
+        #[arg(value_name = "PATH")]
+        directory: PathBuf,
+
+        /// Seconds between checks for changed files
+        #[arg(long, value_name = "SECONDS", default_value = "60")]
+        interval: u64,
+
+        /// URL that receives new findings as a JSON POST
+        #[arg(long, value_name = "URL")]
+        webhook: Option<String>,
+
+        /// Seconds the tree must stay unchanged before it is rescanned
+        #[arg(long, value_name = "SECONDS", default_value = "5")]
+        debounce: u64,
+    },
+
+    /// Merge JSON reports from several scan shards into one report
+    Merge {
+        /// JSON reports to merge (from `scan -f json`; `.json.gz` is decompressed)
+        #[arg(value_name = "REPORT", required = true)]
+        inputs: Vec<PathBuf>,
+
+        /// Output format, repeatable or comma-separated [default: auto, from the --output extension]
+        #[arg(short, long, value_name = "FORMAT", value_delimiter = ',')]
+        format: Vec<OutputFormat>,
+
+        /// Output file; with several formats, each gets this path with its own extension.
+        /// JSON, NDJSON and CSV files ending in `.gz` are gzip-compressed
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+
+        /// Gzip JSON, NDJSON and CSV report files (appends `.gz` to their paths)
+        #[arg(long)]
+        compress: bool,
     },
 
     /// List all available detectors
@@ -138,8 +347,18 @@ pub enum Commands {
         /// Show detailed information
         #[arg(short, long)]
         verbose: bool,
+
+        /// Output the detector list as JSON
+        #[arg(long)]
+        json: bool,
     },
 
+    /// Check every detector against its built-in valid and invalid examples
+    SelfTest,
+
+    /// List the built-in detector presets
+    Presets,
+
     /// Scan API endpoints for PII
     Api {
         /// API endpoint URL(s) to scan
@@ -167,7 +386,7 @@ pub enum Commands {
         no_redirects: bool,
 
         /// Output format
-        #[arg(short, long, value_name = "FORMAT", default_value = "terminal")]
+        #[arg(short, long, value_name = "FORMAT", default_value = "auto")]
         format: OutputFormat,
 
         /// Output file (for json/csv formats)
@@ -184,18 +403,208 @@ pub enum Commands {
     },
 }
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum OutputFormat {
-    /// Colored terminal output (default)
+    /// Pick the format from the output file extension, terminal without a known one (default)
+    Auto,
+    /// Colored terminal output
     Terminal,
     /// JSON format
     Json,
     /// Compact JSON (single line)
     JsonCompact,
+    /// Newline-delimited JSON, one match per line
+    Ndjson,
     /// HTML report
     Html,
     /// CSV (Comma-Separated Values)
     Csv,
+    /// SARIF 2.1.0 (for code scanning platforms)
+    Sarif,
+}
+
+impl Commands {
+    /// Config settings given as `scan` flags, or `None` for other commands
+    pub fn cli_overrides(&self) -> Option<CliOverrides> {
+        let Commands::Scan {
+            format,
+            output,
+            countries,
+            min_confidence,
+            no_context,
+            extract_documents,
+            pdf_timeout,
+            no_progress,
+            full_paths,
+            max_depth,
+            threads,
+            max_filesize,
+            phone_region,
+            include,
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        Some(CliOverrides {
+            countries: countries.clone(),
+            min_confidence: min_confidence.map(value_name),
+            extract_documents: *extract_documents,
+            pdf_timeout: *pdf_timeout,
+            no_context: *no_context,
+            threads: *threads,
+            format: (!format.is_empty()).then(|| {
+                format
+                    .iter()
+                    .map(|&f| value_name(f))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+            output: output.clone(),
+            no_progress: *no_progress,
+            full_paths: *full_paths,
+            max_filesize: *max_filesize,
+            max_depth: *max_depth,
+            phone_region: phone_region.clone(),
+            include: include.clone(),
+        })
+    }
+}
+
+/// CLI name of a value enum variant (e.g. `json-compact`)
+fn value_name<T: ValueEnum>(value: T) -> String {
+    value
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum CsvGroupBy {
+    Detector,
+    Country,
+    Severity,
+    File,
+}
+
+impl From<CsvGroupBy> for crate::reporter::GroupBy {
+    fn from(group_by: CsvGroupBy) -> Self {
+        match group_by {
+            CsvGroupBy::Detector => crate::reporter::GroupBy::Detector,
+            CsvGroupBy::Country => crate::reporter::GroupBy::Country,
+            CsvGroupBy::Severity => crate::reporter::GroupBy::Severity,
+            CsvGroupBy::File => crate::reporter::GroupBy::File,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum JsonShape {
+    ByFile,
+    ByDetector,
+    Flat,
+}
+
+impl From<JsonShape> for crate::reporter::JsonShape {
+    fn from(shape: JsonShape) -> Self {
+        match shape {
+            JsonShape::ByFile => crate::reporter::JsonShape::ByFile,
+            JsonShape::ByDetector => crate::reporter::JsonShape::ByDetector,
+            JsonShape::Flat => crate::reporter::JsonShape::Flat,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Preset {
+    Pii,
+    Secrets,
+}
+
+impl From<Preset> for crate::Preset {
+    fn from(preset: Preset) -> Self {
+        match preset {
+            Preset::Pii => crate::Preset::Pii,
+            Preset::Secrets => crate::Preset::Secrets,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum Parallelism {
+    /// One file per thread
+    PerFile,
+    /// One file at a time, split into chunks scanned in parallel
+    IntraFile,
+    /// Per-file, but files above 8 MiB are chunked
+    Hybrid,
+}
+
+impl From<Parallelism> for crate::ParallelismMode {
+    fn from(mode: Parallelism) -> Self {
+        match mode {
+            Parallelism::PerFile => crate::ParallelismMode::PerFile,
+            Parallelism::IntraFile => crate::ParallelismMode::IntraFile,
+            Parallelism::Hybrid => crate::ParallelismMode::Hybrid,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Strictness {
+    /// Only checksum-valid, High-confidence matches
+    Lenient,
+    /// Honour --min-confidence
+    Balanced,
+    /// Also pattern-only Low matches (failed checksums, missing keywords)
+    Paranoid,
+}
+
+impl From<Strictness> for crate::Strictness {
+    fn from(strictness: Strictness) -> Self {
+        match strictness {
+            Strictness::Lenient => crate::Strictness::Lenient,
+            Strictness::Balanced => crate::Strictness::Balanced,
+            Strictness::Paranoid => crate::Strictness::Paranoid,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DuplicateIds {
+    /// Keep the first detector with an id and warn about the others
+    KeepFirst,
+    /// Exit with an error
+    Reject,
+}
+
+impl From<DuplicateIds> for crate::DuplicatePolicy {
+    fn from(policy: DuplicateIds) -> Self {
+        match policy {
+            DuplicateIds::KeepFirst => crate::DuplicatePolicy::KeepFirst,
+            DuplicateIds::Reject => crate::DuplicatePolicy::Reject,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SeverityLevel {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl From<SeverityLevel> for crate::Severity {
+    fn from(level: SeverityLevel) -> Self {
+        match level {
+            SeverityLevel::Low => crate::Severity::Low,
+            SeverityLevel::Medium => crate::Severity::Medium,
+            SeverityLevel::High => crate::Severity::High,
+            SeverityLevel::Critical => crate::Severity::Critical,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
@@ -255,6 +664,21 @@ mod tests {
         assert!(cli.is_ok());
     }
 
+    #[test]
+    fn test_detectors_json_and_self_test() {
+        let cli = Cli::try_parse_from(vec!["pii-radar", "detectors", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Detectors {
+                json: true,
+                verbose: false
+            }
+        ));
+
+        let cli = Cli::try_parse_from(vec!["pii-radar", "self-test"]).unwrap();
+        assert!(matches!(cli.command, Commands::SelfTest));
+    }
+
     #[test]
     fn test_scan_command_with_extract_documents() {
         let args = vec!["pii-radar", "scan", "/tmp/test", "--extract-documents"];
@@ -265,6 +689,7 @@ mod tests {
             command: Commands::Scan {
                 extract_documents, ..
             },
+            ..
         }) = cli
         {
             assert!(extract_documents);
@@ -273,6 +698,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scan_command_with_paths_from() {
+        let args = vec!["pii-radar", "scan", "--paths-from", "-"];
+        let cli = Cli::try_parse_from(args);
+        assert!(cli.is_ok());
+
+        if let Ok(Cli {
+            command:
+                Commands::Scan {
+                    directory,
+                    paths_from,
+                    ..
+                },
+            ..
+        }) = cli
+        {
+            assert!(directory.is_none());
+            assert_eq!(paths_from, Some(PathBuf::from("-")));
+        } else {
+            panic!("Expected Scan command");
+        }
+
+        // Either a directory or a path list is required
+        assert!(Cli::try_parse_from(vec!["pii-radar", "scan"]).is_err());
+    }
+
     #[test]
     fn test_scan_command_with_all_options() {
         let args = vec![
@@ -296,8 +747,305 @@ mod tests {
             "4",
             "--max-filesize",
             "50",
+            "--phone-region",
+            "de",
         ];
         let cli = Cli::try_parse_from(args);
         assert!(cli.is_ok());
     }
+
+    #[test]
+    fn test_scan_preset_and_presets_command() {
+        let cli = Cli::try_parse_from(vec!["pii-radar", "scan", "/tmp", "--preset", "secrets"]);
+        assert!(matches!(
+            cli,
+            Ok(Cli {
+                command: Commands::Scan {
+                    preset: Preset::Secrets,
+                    ..
+                },
+                ..
+            })
+        ));
+
+        let cli = Cli::try_parse_from(vec!["pii-radar", "presets"]).unwrap();
+        assert!(matches!(cli.command, Commands::Presets));
+    }
+
+    #[test]
+    fn test_scan_expect_countries_strict() {
+        let cli = Cli::try_parse_from(vec![
+            "pii-radar",
+            "scan",
+            "/tmp",
+            "--expect-countries",
+            "nl,gb",
+            "--strict",
+        ]);
+        assert!(matches!(
+            cli,
+            Ok(Cli {
+                command: Commands::Scan {
+                    expect_countries: Some(ref codes),
+                    strict_coverage: true,
+                    ..
+                },
+                ..
+            }) if codes == "nl,gb"
+        ));
+
+        // Strict coverage needs expected countries
+        let cli = Cli::try_parse_from(vec!["pii-radar", "scan", "/tmp", "--strict-coverage"]);
+        assert!(cli.is_err());
+    }
+
+    #[test]
+    fn test_scan_fail_on() {
+        let cli = Cli::try_parse_from(vec![
+            "pii-radar",
+            "scan",
+            "/tmp",
+            "--fail-on",
+            "high",
+            "--strict-exit",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Scan {
+                fail_on: Some(SeverityLevel::High),
+                strict_exit: true,
+                ..
+            }
+        ));
+
+        let cli = Cli::try_parse_from(vec!["pii-radar", "scan", "/tmp", "--fail-on", "severe"]);
+        assert!(cli.is_err());
+    }
+
+    #[test]
+    fn test_scan_locale() {
+        let cli =
+            Cli::try_parse_from(vec!["pii-radar", "scan", "/tmp", "--locale", "en-US"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Scan {
+                locale: Some(ref tag),
+                ..
+            } if tag == "en-US"
+        ));
+    }
+
+    #[test]
+    fn test_scan_log_summary() {
+        let cli = Cli::try_parse_from(vec!["pii-radar", "scan", "/tmp", "--log-summary"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Scan {
+                log_summary: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_scan_dedup_files() {
+        let cli = Cli::try_parse_from(vec!["pii-radar", "scan", "/tmp", "--dedup-files"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Scan {
+                dedup_files: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_scan_include_globs() {
+        let cli = Cli::try_parse_from(vec![
+            "pii-radar",
+            "scan",
+            "/tmp/test",
+            "--include",
+            "**/*.sql",
+            "--include",
+            "**/config/**",
+        ]);
+
+        if let Ok(Cli {
+            command: Commands::Scan { include, .. },
+            ..
+        }) = cli
+        {
+            assert_eq!(include, vec!["**/*.sql", "**/config/**"]);
+        } else {
+            panic!("Expected Scan command");
+        }
+    }
+
+    #[test]
+    fn test_merge_command() {
+        let cli = Cli::try_parse_from(vec![
+            "pii-radar",
+            "merge",
+            "shard1.json",
+            "shard2.json",
+            "-f",
+            "json",
+            "-o",
+            "merged.json",
+        ]);
+
+        if let Ok(Cli {
+            command: Commands::Merge { inputs, format, .. },
+            ..
+        }) = cli
+        {
+            assert_eq!(inputs.len(), 2);
+            assert_eq!(format, vec![OutputFormat::Json]);
+        } else {
+            panic!("Expected Merge command");
+        }
+
+        assert!(Cli::try_parse_from(vec!["pii-radar", "merge"]).is_err());
+    }
+
+    #[test]
+    fn test_watch_command() {
+        let cli = Cli::try_parse_from(vec![
+            "pii-radar",
+            "watch",
+            "/srv/data",
+            "--interval",
+            "30",
+            "--webhook",
+            "https://hooks.example.com/pii",
+        ]);
+
+        if let Ok(Cli {
+            command:
+                Commands::Watch {
+                    interval,
+                    webhook,
+                    debounce,
+                    ..
+                },
+            ..
+        }) = cli
+        {
+            assert_eq!(interval, 30);
+            assert_eq!(webhook.as_deref(), Some("https://hooks.example.com/pii"));
+            assert_eq!(debounce, 5);
+        } else {
+            panic!("Expected Watch command");
+        }
+    }
+
+    #[test]
+    fn test_sample_percent_flags() {
+        let cli = Cli::parse_from(vec![
+            "pii-radar",
+            "scan",
+            "/data",
+            "--sample-percent",
+            "10",
+            "--seed",
+            "42",
+        ]);
+        assert!(matches!(
+            cli.command,
+            Commands::Scan {
+                sample_percent: Some(10),
+                seed: Some(42),
+                ..
+            }
+        ));
+
+        assert!(
+            Cli::try_parse_from(vec!["pii-radar", "scan", "/data", "--sample-percent", "0"])
+                .is_err()
+        );
+        assert!(Cli::try_parse_from(vec!["pii-radar", "scan", "/data", "--seed", "42"]).is_err());
+    }
+
+    #[test]
+    fn test_bench_command() {
+        let cli = Cli::try_parse_from(vec!["pii-radar", "bench", "/tmp/test", "-n", "2"]);
+
+        if let Ok(Cli {
+            command: Commands::Bench { iterations, .. },
+            ..
+        }) = cli
+        {
+            assert_eq!(iterations, 2);
+        } else {
+            panic!("Expected Bench command");
+        }
+    }
+
+    #[test]
+    fn test_scan_command_multiple_formats() {
+        let cli = Cli::try_parse_from(vec![
+            "pii-radar",
+            "scan",
+            "/tmp/test",
+            "-f",
+            "terminal",
+            "--format",
+            "json,html",
+        ]);
+
+        if let Ok(Cli {
+            command: Commands::Scan { format, .. },
+            ..
+        }) = cli
+        {
+            assert_eq!(
+                format,
+                vec![
+                    OutputFormat::Terminal,
+                    OutputFormat::Json,
+                    OutputFormat::Html
+                ]
+            );
+        } else {
+            panic!("Expected Scan command");
+        }
+    }
+
+    #[test]
+    fn test_scan_flags_explained_as_cli() {
+        use crate::config::{Config, ConfigSources};
+
+        let cli = Cli::try_parse_from(vec![
+            "pii-radar",
+            "scan",
+            "--threads",
+            "4",
+            "--no-progress",
+            "--print-config",
+            "--explain",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Scan {
+                print_config: true,
+                explain: true,
+                ..
+            }
+        ));
+
+        let overrides = cli.command.cli_overrides().unwrap();
+        let mut sources = ConfigSources::default();
+        let config = Config::default().merge_with_cli_tracked(overrides, &mut sources);
+        let toml = config.to_toml(Some(&sources)).unwrap();
+
+        assert!(toml.contains("max_threads = 4  # cli (--threads)\n"));
+        assert!(toml.contains("no_progress = true  # cli (--no-progress)\n"));
+        assert!(toml.contains("max_filesize_mb = 100  # default\n"));
+
+        let cli = Cli::try_parse_from(vec!["pii-radar", "detectors"]).unwrap();
+        assert!(cli.command.cli_overrides().is_none());
+    }
 }
@@ -0,0 +1,66 @@
+/// Terminal color control
+///
+/// Colors are disabled by `--no-color`, by a non-empty `NO_COLOR`
+/// environment variable (see <https://no-color.org>), or when stdout is not
+/// a terminal, so redirected output and CI logs carry no ANSI escape codes.
+use std::ffi::OsStr;
+use std::io::IsTerminal;
+
+/// Whether output should be colored
+pub fn colors_enabled(
+    no_color_flag: bool,
+    no_color_env: Option<&OsStr>,
+    stdout_is_tty: bool,
+) -> bool {
+    let no_color_env = no_color_env.is_some_and(|v| !v.is_empty());
+    !no_color_flag && !no_color_env && stdout_is_tty
+}
+
+/// Enable or disable colors globally for this process
+pub fn configure_colors(no_color_flag: bool) {
+    let enabled = colors_enabled(
+        no_color_flag,
+        std::env::var_os("NO_COLOR").as_deref(),
+        std::io::stdout().is_terminal(),
+    );
+    colored::control::set_override(enabled);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileResult, ScanResults};
+    use crate::reporter::TerminalReporter;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_colors_enabled() {
+        assert!(colors_enabled(false, None, true));
+        assert!(!colors_enabled(true, None, true));
+        assert!(!colors_enabled(false, None, false));
+        assert!(!colors_enabled(false, Some(OsStr::new("1")), true));
+        // An empty NO_COLOR is ignored
+        assert!(colors_enabled(false, Some(OsStr::new("")), true));
+    }
+
+    #[test]
+    fn test_disabled_colors_emit_no_ansi_codes() {
+        colored::control::set_override(false);
+
+        let mut file = FileResult::new(PathBuf::from("test.txt"));
+        file.matches = crate::default_registry()
+            .get("nl_bsn")
+            .unwrap()
+            .detect("BSN: 111222333", &PathBuf::from("test.txt"));
+        let results = ScanResults::aggregate(vec![file]);
+
+        let reporter = TerminalReporter::new();
+        let mut out = Vec::new();
+        reporter.write_summary(&results, &mut out).unwrap();
+        reporter.write_detailed_results(&results, &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("SCAN COMPLETE"));
+        assert!(!text.contains('\u{1b}'));
+    }
+}
@@ -0,0 +1,189 @@
+/// Process exit codes for CI/CD integration
+///
+/// - `0`: clean, no PII found
+/// - `1`: PII found
+/// - `2`: scan completed, but some files could not be scanned
+///   (only with `--strict-exit`)
+/// - `3`: fatal error (bad arguments, unreadable root, output failure)
+/// - `4`: an `--expect-countries` country had no matches
+///   (only with `--strict-coverage`)
+/// - `130`: interrupted with Ctrl-C; reports cover the files scanned so far
+///
+/// When several apply, the highest-priority one wins: fatal errors first,
+/// then coverage gaps, then PII findings, then file-level errors. A scan that finds PII and
+/// also hit unreadable files therefore exits with `1`.
+///
+/// `--fail-on <severity>` decides which findings count as PII found: matches
+/// below it leave the scan clean, so with `--strict-exit` file-level errors
+/// still exit with `2`.
+use crate::core::{ScanResults, Severity};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanExitCode {
+    /// No PII found
+    Clean,
+    /// PII found
+    PiiFound,
+    /// Scan completed with file-level errors
+    ScanErrors,
+    /// Fatal error, scan did not complete
+    Fatal,
+    /// Expected countries without matches
+    CoverageGap,
+    /// Interrupted by Ctrl-C, results are partial
+    Interrupted,
+}
+
+impl ScanExitCode {
+    /// Determine the exit code for a completed scan
+    ///
+    /// File-level errors only affect the exit code in strict mode.
+    pub fn from_results(results: &ScanResults, strict: bool) -> Self {
+        Self::from_results_failing_on(results, Severity::Low, strict)
+    }
+
+    /// Determine the exit code, counting only matches of `fail_on` severity or higher as PII
+    pub fn from_results_failing_on(results: &ScanResults, fail_on: Severity, strict: bool) -> Self {
+        let failing = results
+            .files
+            .iter()
+            .flat_map(|f| &f.matches)
+            .any(|m| m.severity >= fail_on);
+
+        if failing {
+            Self::PiiFound
+        } else if strict && !results.errors.is_empty() {
+            Self::ScanErrors
+        } else {
+            Self::Clean
+        }
+    }
+
+    /// Escalate to [`ScanExitCode::CoverageGap`] when expected countries had no matches
+    ///
+    /// Coverage gaps only affect the exit code in strict mode.
+    pub fn with_coverage_gaps(self, gaps: &[String], strict: bool) -> Self {
+        match self {
+            Self::Fatal | Self::Interrupted => self,
+            _ if strict && !gaps.is_empty() => Self::CoverageGap,
+            _ => self,
+        }
+    }
+
+    /// Short name of the outcome, for structured logs
+    pub fn intent(self) -> &'static str {
+        match self {
+            Self::Clean => "clean",
+            Self::PiiFound => "pii_found",
+            Self::ScanErrors => "scan_errors",
+            Self::Fatal => "fatal",
+            Self::CoverageGap => "coverage_gap",
+            Self::Interrupted => "interrupted",
+        }
+    }
+
+    /// Numeric process exit code
+    pub fn code(self) -> i32 {
+        match self {
+            Self::Clean => 0,
+            Self::PiiFound => 1,
+            Self::ScanErrors => 2,
+            Self::Fatal => 3,
+            Self::CoverageGap => 4,
+            Self::Interrupted => 130,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{default_registry, ScanEngine};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn scan(tmp: &TempDir) -> ScanResults {
+        ScanEngine::new(default_registry())
+            .show_progress(false)
+            .scan_directory(tmp.path())
+    }
+
+    #[test]
+    fn test_clean_scan() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("notes.txt"), "nothing to see here\n").unwrap();
+
+        let results = scan(&tmp);
+        assert_eq!(ScanExitCode::from_results(&results, false).code(), 0);
+        assert_eq!(ScanExitCode::from_results(&results, true).code(), 0);
+    }
+
+    #[test]
+    fn test_unreadable_file_strict() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("notes.txt"), "nothing to see here\n").unwrap();
+        fs::write(tmp.path().join("binary.dat"), [0xff, 0xfe, 0x00, 0xc3]).unwrap();
+
+        let results = scan(&tmp);
+        assert!(!results.errors.is_empty());
+        assert_eq!(
+            ScanExitCode::from_results(&results, false),
+            ScanExitCode::Clean
+        );
+        assert_eq!(ScanExitCode::from_results(&results, true).code(), 2);
+    }
+
+    #[test]
+    fn test_pii_found() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("data.txt"), "BSN: 111222333\n").unwrap();
+        fs::write(tmp.path().join("binary.dat"), [0xff, 0xfe, 0x00, 0xc3]).unwrap();
+
+        // PII takes precedence over file-level errors
+        let results = scan(&tmp);
+        assert_eq!(ScanExitCode::from_results(&results, true).code(), 1);
+        assert_eq!(ScanExitCode::Fatal.code(), 3);
+    }
+
+    #[test]
+    fn test_fail_on_severity() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("contact.txt"), "Mail jan@example.com\n").unwrap();
+        fs::write(tmp.path().join("binary.dat"), [0xff, 0xfe, 0x00, 0xc3]).unwrap();
+
+        // A Medium email is below --fail-on high
+        let results = scan(&tmp);
+        assert_eq!(
+            ScanExitCode::from_results_failing_on(&results, Severity::Medium, false),
+            ScanExitCode::PiiFound
+        );
+        assert_eq!(
+            ScanExitCode::from_results_failing_on(&results, Severity::High, false),
+            ScanExitCode::Clean
+        );
+        assert_eq!(
+            ScanExitCode::from_results_failing_on(&results, Severity::High, true),
+            ScanExitCode::ScanErrors
+        );
+    }
+
+    #[test]
+    fn test_expected_country_coverage_gap() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("data.txt"), "BSN: 111222333\n").unwrap();
+
+        let results = scan(&tmp);
+        let expected = vec!["nl".to_string(), "GB".to_string()];
+        let gaps = results.coverage_gaps(&expected);
+        assert_eq!(gaps, vec!["gb".to_string()]);
+
+        // Only strict coverage turns a gap into a failure
+        let exit = ScanExitCode::from_results(&results, false);
+        assert_eq!(
+            exit.with_coverage_gaps(&gaps, false),
+            ScanExitCode::PiiFound
+        );
+        assert_eq!(exit.with_coverage_gaps(&gaps, true).code(), 4);
+        assert_eq!(exit.with_coverage_gaps(&[], true), ScanExitCode::PiiFound);
+    }
+}
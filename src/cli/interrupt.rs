@@ -0,0 +1,27 @@
+/// Ctrl-C handling
+///
+/// The first Ctrl-C sets a cancellation flag: the scan engine stops starting
+/// new files, and the CLI reports the files completed so far before exiting
+/// with the interrupted exit code. A second Ctrl-C exits immediately.
+use super::ScanExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Install the SIGINT handler and return the cancellation flag it sets
+pub fn install_interrupt_handler() -> Arc<AtomicBool> {
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = cancel.clone();
+
+    let installed = ctrlc::set_handler(move || {
+        if flag.swap(true, Ordering::SeqCst) {
+            std::process::exit(ScanExitCode::Interrupted.code());
+        }
+        eprintln!("\n⚠️  Interrupted: finishing files in progress (Ctrl-C again to abort)");
+    });
+
+    if let Err(e) = installed {
+        eprintln!("⚠️  Warning: Failed to install Ctrl-C handler: {}", e);
+    }
+
+    cancel
+}
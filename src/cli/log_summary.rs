@@ -0,0 +1,96 @@
+/// One-line JSON scan summary for structured logging (`--log-summary`)
+///
+/// Printed to stderr after every scan, whatever the report format, so log
+/// pipelines can index scan runs without parsing the full report.
+use super::ScanExitCode;
+use crate::core::{ScanResults, SeverityCounts};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogSummary {
+    /// pii-radar version
+    pub version: &'static str,
+
+    /// Scanned root directory
+    pub root: PathBuf,
+
+    /// Scan duration in milliseconds
+    pub duration_ms: u64,
+
+    /// Files scanned
+    pub files: usize,
+
+    /// Matches reported (after `--min-confidence`)
+    pub matches: usize,
+
+    /// Matches per severity
+    pub severity: SeverityCounts,
+
+    /// Exit code the scan ends with
+    pub exit_code: i32,
+
+    /// What the exit code means (`clean`, `pii_found`, ...)
+    pub exit_intent: &'static str,
+}
+
+impl LogSummary {
+    pub fn new(root: &Path, results: &ScanResults, exit_code: ScanExitCode) -> Self {
+        Self {
+            version: crate::VERSION,
+            root: root.to_path_buf(),
+            duration_ms: results.total_time_ms,
+            files: results.total_files,
+            matches: results.total_matches,
+            severity: results.by_severity.clone(),
+            exit_code: exit_code.code(),
+            exit_intent: exit_code.intent(),
+        }
+    }
+
+    /// Compact single-line JSON
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("log summary is always serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{default_registry, ScanEngine};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_log_summary_is_one_json_line() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("data.txt"), "BSN: 111222333\n").unwrap();
+        let results = ScanEngine::new(default_registry())
+            .show_progress(false)
+            .scan_directory(tmp.path());
+
+        let exit_code = ScanExitCode::from_results(&results, false);
+        let line = LogSummary::new(tmp.path(), &results, exit_code).to_json_line();
+        assert!(!line.contains('\n'));
+
+        let json: serde_json::Value = serde_json::from_str(&line).unwrap();
+        for key in [
+            "version",
+            "root",
+            "duration_ms",
+            "files",
+            "matches",
+            "severity",
+            "exit_code",
+            "exit_intent",
+        ] {
+            assert!(json.get(key).is_some(), "missing key {}", key);
+        }
+        assert_eq!(json["version"], crate::VERSION);
+        assert_eq!(json["files"], 1);
+        assert_eq!(json["matches"], 1);
+        assert_eq!(json["severity"]["critical"], 1);
+        assert_eq!(json["exit_code"], 1);
+        assert_eq!(json["exit_intent"], "pii_found");
+    }
+}
@@ -1,4 +1,16 @@
 /// CLI module for command-line interface
 pub mod args;
+pub mod color;
+pub mod exit;
+pub mod interrupt;
+pub mod log_summary;
+pub mod output;
+pub mod run_dir;
 
-pub use args::{Cli, Commands, ConfidenceLevel, OutputFormat};
+pub use args::{Cli, Commands, ConfidenceLevel, CsvGroupBy, OutputFormat};
+pub use color::configure_colors;
+pub use exit::ScanExitCode;
+pub use interrupt::install_interrupt_handler;
+pub use log_summary::LogSummary;
+pub use output::{auto_fallback_warning, resolve_formats, write_reports, ReportOptions};
+pub use run_dir::RunDir;
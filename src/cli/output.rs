@@ -0,0 +1,524 @@
+/// Writing one scan's results in one or more output formats
+use crate::cli::OutputFormat;
+use crate::core::ScanResults;
+use crate::reporter::{
+    is_gzip_path, CsvReporter, GroupBy, HtmlReporter, JsonReporter, JsonShape, SarifReporter,
+    TerminalReporter,
+};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Base name used for report files when no `--output` is given
+const DEFAULT_REPORT_NAME: &str = "pii-radar-report";
+
+/// Reporter options shared by all formats
+#[derive(Debug, Clone, Default)]
+pub struct ReportOptions {
+    /// Show full file paths in terminal output
+    pub full_paths: bool,
+    /// Include context (terminal and CSV output)
+    pub show_context: bool,
+    /// Group CSV output into a summary
+    pub csv_group_by: Option<GroupBy>,
+    /// Keep per-match CSV rows, with the grouped summary in a separate file
+    pub csv_with_rows: bool,
+    /// Verbose terminal summary (top GDPR context keywords)
+    pub verbose: bool,
+    /// Per-detector confidence histogram in the terminal summary
+    pub confidence_histogram: bool,
+    /// Custom Tera template for the HTML report
+    pub html_template: Option<PathBuf>,
+    /// Grouping of matches in JSON output
+    pub json_shape: JsonShape,
+    /// Gzip JSON, NDJSON and CSV report files (adds `.gz` to their paths)
+    pub compress: bool,
+}
+
+impl OutputFormat {
+    /// Format implied by a report path's extension (`.json`, `.csv.gz`, `.html`, ...)
+    pub fn from_path(path: &Path) -> Option<OutputFormat> {
+        let path = if is_gzip_path(path) {
+            path.with_extension("")
+        } else {
+            path.to_path_buf()
+        };
+        let extension = path.extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "json" => Some(OutputFormat::Json),
+            "ndjson" | "jsonl" => Some(OutputFormat::Ndjson),
+            "csv" => Some(OutputFormat::Csv),
+            "html" | "htm" => Some(OutputFormat::Html),
+            "sarif" => Some(OutputFormat::Sarif),
+            _ => None,
+        }
+    }
+
+    /// Resolve `Auto` against `--output`: the format its extension implies,
+    /// or terminal when writing to stdout or to an unknown extension
+    pub fn resolve(self, output: Option<&Path>) -> OutputFormat {
+        match self {
+            OutputFormat::Auto => output
+                .and_then(Self::from_path)
+                .unwrap_or(OutputFormat::Terminal),
+            format => format,
+        }
+    }
+
+    /// File extension used when deriving per-format output paths
+    pub fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Auto | OutputFormat::Terminal => "txt",
+            OutputFormat::Json => "json",
+            OutputFormat::JsonCompact => "min.json",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Html => "html",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Sarif => "sarif",
+        }
+    }
+
+    /// Whether report files in this format can be gzip-compressed
+    pub fn compressible(self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Json
+                | OutputFormat::JsonCompact
+                | OutputFormat::Ndjson
+                | OutputFormat::Csv
+        )
+    }
+
+    /// Human-readable label for status messages
+    pub fn label(self) -> &'static str {
+        match self {
+            OutputFormat::Auto | OutputFormat::Terminal => "Terminal report",
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => "Results",
+            OutputFormat::Html => "HTML report",
+            OutputFormat::Csv => "CSV report",
+            OutputFormat::Sarif => "SARIF report",
+        }
+    }
+}
+
+/// Determine where a format is written
+///
+/// With a single format, `--output` is used as-is and formats other than
+/// HTML print to stdout when it is absent. With several formats, every file
+/// format gets its own path derived from `--output` (or
+/// `pii-radar-report`) by swapping the extension, e.g. `scan.json` and
+/// `scan.html`. Terminal output always goes to stdout.
+///
+/// A `.gz` suffix on `--output` carries over to the JSON and CSV paths
+/// (`scan.json.gz` gives `scan.csv.gz` and `scan.html`).
+pub fn output_path_for(
+    format: OutputFormat,
+    output: Option<&Path>,
+    multiple: bool,
+) -> Option<PathBuf> {
+    if matches!(format, OutputFormat::Auto | OutputFormat::Terminal) {
+        return None;
+    }
+
+    match (output, multiple) {
+        (Some(path), false) => Some(path.to_path_buf()),
+        (Some(path), true) if is_gzip_path(path) => {
+            let derived = path.with_extension("").with_extension(format.extension());
+            Some(if format.compressible() {
+                with_gzip_extension(&derived)
+            } else {
+                derived
+            })
+        }
+        (Some(path), true) => Some(path.with_extension(format.extension())),
+        (None, true) => Some(PathBuf::from(DEFAULT_REPORT_NAME).with_extension(format.extension())),
+        (None, false) if matches!(format, OutputFormat::Html) => {
+            Some(PathBuf::from(DEFAULT_REPORT_NAME).with_extension("html"))
+        }
+        (None, false) => None,
+    }
+}
+
+/// `path` with `.gz` appended
+fn with_gzip_extension(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+/// Resolve `auto` in the requested formats against `--output`
+///
+/// No formats at all means `auto`, so `--output report.csv` alone writes CSV.
+/// Formats that resolve to the same one are only written once.
+pub fn resolve_formats(formats: &[OutputFormat], output: Option<&Path>) -> Vec<OutputFormat> {
+    let requested = if formats.is_empty() {
+        &[OutputFormat::Auto][..]
+    } else {
+        formats
+    };
+
+    let mut resolved: Vec<OutputFormat> = Vec::with_capacity(requested.len());
+    for format in requested {
+        let format = format.resolve(output);
+        if !resolved.contains(&format) {
+            resolved.push(format);
+        }
+    }
+    resolved
+}
+
+/// Warning for `auto` falling back to terminal output because no format is
+/// known for the `--output` extension, e.g. `report.txt`
+pub fn auto_fallback_warning(formats: &[OutputFormat], output: Option<&Path>) -> Option<String> {
+    let auto = formats.is_empty() || formats.contains(&OutputFormat::Auto);
+    let path = output.filter(|path| auto && OutputFormat::from_path(path).is_none())?;
+    let extension = match path.extension() {
+        Some(extension) => format!("extension '.{}'", extension.to_string_lossy()),
+        None => "missing extension".to_string(),
+    };
+
+    Some(format!(
+        "Unknown output {} in {}; writing the terminal report to stdout \
+         (use --format, or .json, .ndjson, .csv, .html or .sarif)",
+        extension,
+        path.display()
+    ))
+}
+
+/// Write `results` in every requested format
+///
+/// `auto` is resolved first (see [`resolve_formats`]). Terminal output goes
+/// to `terminal_out`; formats without a file path print to stdout. Returns
+/// the files that were written.
+pub fn write_reports(
+    results: &ScanResults,
+    formats: &[OutputFormat],
+    output: Option<&Path>,
+    options: ReportOptions,
+    terminal_out: &mut dyn Write,
+) -> Result<Vec<(OutputFormat, PathBuf)>, String> {
+    let formats = resolve_formats(formats, output);
+    let multiple = formats.len() > 1;
+    let mut written = Vec::new();
+
+    for format in formats {
+        let path = output_path_for(format, output, multiple).map(|path| {
+            // JSON and CSV reporters gzip files ending in `.gz`
+            if options.compress && format.compressible() && !is_gzip_path(&path) {
+                with_gzip_extension(&path)
+            } else {
+                path
+            }
+        });
+
+        match format {
+            OutputFormat::Auto | OutputFormat::Terminal => {
+                TerminalReporter::new()
+                    .full_paths(options.full_paths)
+                    .show_context(options.show_context)
+                    .verbose(options.verbose)
+                    .confidence_histogram(options.confidence_histogram)
+                    .write_report(results, terminal_out)
+                    .map_err(|e| format!("Failed to write terminal report: {}", e))?;
+            }
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+                let reporter = JsonReporter::new()
+                    .pretty(matches!(format, OutputFormat::Json))
+                    .lines(matches!(format, OutputFormat::Ndjson))
+                    .shape(options.json_shape);
+                match &path {
+                    Some(path) => reporter.write_to_file(results, path)?,
+                    None => reporter.print(results)?,
+                }
+            }
+            OutputFormat::Html => {
+                let path = path.unwrap_or_else(|| PathBuf::from(DEFAULT_REPORT_NAME));
+                let reporter = match &options.html_template {
+                    Some(template) => HtmlReporter::with_template_file(template)?,
+                    None => HtmlReporter::new(),
+                };
+                reporter
+                    .write_to_file(results, &path)
+                    .map_err(|e| format!("Failed to write HTML report: {}", e))?;
+                written.push((format, path));
+                continue;
+            }
+            OutputFormat::Csv => {
+                let mut reporter = CsvReporter::new()
+                    .with_context(options.show_context)
+                    .include_rows(options.csv_with_rows);
+                if let Some(group_by) = options.csv_group_by {
+                    reporter = reporter.group_by(group_by);
+                }
+                match &path {
+                    Some(path) => {
+                        reporter.write_to_file(results, path)?;
+                        written.push((format, path.clone()));
+                        if options.csv_group_by.is_some() && options.csv_with_rows {
+                            written.push((format, CsvReporter::summary_path(path)));
+                        }
+                        continue;
+                    }
+                    None => reporter.print(results)?,
+                }
+            }
+            OutputFormat::Sarif => {
+                let reporter = SarifReporter::new();
+                match &path {
+                    Some(path) => reporter.write_to_file(results, path)?,
+                    None => reporter.print(results)?,
+                }
+            }
+        }
+
+        if let Some(path) = path {
+            written.push((format, path));
+        }
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{default_registry, ScanEngine};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_output_paths() {
+        let base = Path::new("out/scan.json");
+
+        assert_eq!(
+            output_path_for(OutputFormat::Json, Some(base), false),
+            Some(PathBuf::from("out/scan.json"))
+        );
+        assert_eq!(
+            output_path_for(OutputFormat::Html, Some(base), true),
+            Some(PathBuf::from("out/scan.html"))
+        );
+        assert_eq!(output_path_for(OutputFormat::Json, None, false), None);
+        assert_eq!(
+            output_path_for(OutputFormat::Terminal, Some(base), true),
+            None
+        );
+
+        let gzipped = Path::new("out/scan.json.gz");
+        assert_eq!(
+            output_path_for(OutputFormat::Csv, Some(gzipped), true),
+            Some(PathBuf::from("out/scan.csv.gz"))
+        );
+        assert_eq!(
+            output_path_for(OutputFormat::Html, Some(gzipped), true),
+            Some(PathBuf::from("out/scan.html"))
+        );
+    }
+
+    #[test]
+    fn test_compressed_json_report() {
+        let tmp = TempDir::new().unwrap();
+        let data = tmp.path().join("data");
+        fs::create_dir(&data).unwrap();
+        fs::write(data.join("customers.txt"), "BSN: 111222333\n").unwrap();
+
+        let results = ScanEngine::new(default_registry())
+            .show_progress(false)
+            .scan_directory(&data);
+
+        let plain = tmp.path().join("report.json");
+        let written = write_reports(
+            &results,
+            &[OutputFormat::Json],
+            Some(&plain),
+            ReportOptions {
+                compress: true,
+                ..Default::default()
+            },
+            &mut Vec::new(),
+        )
+        .unwrap();
+        let gzipped = tmp.path().join("report.json.gz");
+        assert_eq!(written, vec![(OutputFormat::Json, gzipped.clone())]);
+
+        // gzip magic bytes, and the same JSON as an uncompressed report
+        assert_eq!(fs::read(&gzipped).unwrap()[..2], [0x1f, 0x8b]);
+        JsonReporter::new().write_to_file(&results, &plain).unwrap();
+        let decompressed = crate::reporter::read_report_file(&gzipped).unwrap();
+        assert_eq!(decompressed, fs::read_to_string(&plain).unwrap());
+
+        let json: serde_json::Value = serde_json::from_str(&decompressed).unwrap();
+        assert_eq!(json["total_matches"], 1);
+
+        // NDJSON: one compressed match per line
+        let ndjson = tmp.path().join("report.ndjson.gz");
+        write_reports(
+            &results,
+            &[],
+            Some(&ndjson),
+            ReportOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        let decompressed = crate::reporter::read_report_file(&ndjson).unwrap();
+        assert_eq!(decompressed.lines().count(), 1);
+        let first: serde_json::Value = serde_json::from_str(&decompressed).unwrap();
+        assert_eq!(first["detector_id"], "nl_bsn");
+    }
+
+    #[test]
+    fn test_single_scan_multiple_formats() {
+        let tmp = TempDir::new().unwrap();
+        let data = tmp.path().join("data");
+        fs::create_dir(&data).unwrap();
+        fs::write(data.join("customers.txt"), "BSN: 111222333\n").unwrap();
+
+        let results = ScanEngine::new(default_registry())
+            .show_progress(false)
+            .scan_directory(&data);
+
+        let mut terminal = Vec::new();
+        let written = write_reports(
+            &results,
+            &[
+                OutputFormat::Terminal,
+                OutputFormat::Json,
+                OutputFormat::Html,
+            ],
+            Some(&tmp.path().join("report")),
+            ReportOptions::default(),
+            &mut terminal,
+        )
+        .unwrap();
+
+        let terminal = String::from_utf8(terminal).unwrap();
+        assert!(terminal.contains("SCAN COMPLETE"));
+
+        let json_path = tmp.path().join("report.json");
+        let html_path = tmp.path().join("report.html");
+        assert_eq!(
+            written,
+            vec![
+                (OutputFormat::Json, json_path.clone()),
+                (OutputFormat::Html, html_path.clone())
+            ]
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(json["total_matches"], 1);
+        assert!(fs::read_to_string(&html_path).unwrap().contains("<html"));
+    }
+
+    #[test]
+    fn test_format_inferred_from_output_extension() {
+        let tmp = TempDir::new().unwrap();
+        let data = tmp.path().join("data");
+        fs::create_dir(&data).unwrap();
+        fs::write(data.join("customers.txt"), "BSN: 111222333\n").unwrap();
+
+        let results = ScanEngine::new(default_registry())
+            .show_progress(false)
+            .scan_directory(&data);
+
+        // `--output x.csv` without `--format`
+        let csv_path = tmp.path().join("x.csv");
+        let written = write_reports(
+            &results,
+            &[],
+            Some(&csv_path),
+            ReportOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(written, vec![(OutputFormat::Csv, csv_path.clone())]);
+        assert!(fs::read_to_string(&csv_path)
+            .unwrap()
+            .starts_with("File,Line,Column,Detector"));
+
+        // `--format auto --output x.html`
+        let html_path = tmp.path().join("x.html");
+        let written = write_reports(
+            &results,
+            &[OutputFormat::Auto],
+            Some(&html_path),
+            ReportOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(written, vec![(OutputFormat::Html, html_path.clone())]);
+        assert!(fs::read_to_string(&html_path).unwrap().contains("<html"));
+
+        // `--output x.ndjson`: one match per line
+        let ndjson_path = tmp.path().join("x.ndjson");
+        write_reports(
+            &results,
+            &[],
+            Some(&ndjson_path),
+            ReportOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        let ndjson = fs::read_to_string(&ndjson_path).unwrap();
+        assert_eq!(ndjson.lines().count(), results.total_matches);
+        let first: serde_json::Value =
+            serde_json::from_str(ndjson.lines().next().unwrap()).unwrap();
+        assert_eq!(first["detector_id"], "nl_bsn");
+
+        // `--output report.txt`: unknown extension, so the default terminal report
+        let txt_path = tmp.path().join("report.txt");
+        let mut terminal = Vec::new();
+        let written = write_reports(
+            &results,
+            &[],
+            Some(&txt_path),
+            ReportOptions::default(),
+            &mut terminal,
+        )
+        .unwrap();
+        assert!(written.is_empty());
+        assert!(!terminal.is_empty());
+        assert!(!txt_path.exists());
+    }
+
+    #[test]
+    fn test_resolve_auto() {
+        assert_eq!(resolve_formats(&[], None), vec![OutputFormat::Terminal]);
+        assert_eq!(
+            resolve_formats(&[OutputFormat::Auto], Some(Path::new("scan.sarif"))),
+            vec![OutputFormat::Sarif]
+        );
+        assert_eq!(
+            resolve_formats(
+                &[OutputFormat::Auto, OutputFormat::Json],
+                Some(Path::new("scan.JSON.gz"))
+            ),
+            vec![OutputFormat::Json]
+        );
+        // Explicit formats are kept as they are
+        assert_eq!(
+            resolve_formats(&[OutputFormat::Terminal], Some(Path::new("scan.csv"))),
+            vec![OutputFormat::Terminal]
+        );
+        assert_eq!(
+            resolve_formats(&[], Some(Path::new("scan.jsonl"))),
+            vec![OutputFormat::Ndjson]
+        );
+        // Unknown extensions fall back to terminal output
+        assert_eq!(
+            resolve_formats(&[], Some(Path::new("report.txt"))),
+            vec![OutputFormat::Terminal]
+        );
+        let warning = auto_fallback_warning(&[], Some(Path::new("report.txt"))).unwrap();
+        assert!(warning.contains("'.txt'"));
+        assert!(
+            auto_fallback_warning(&[OutputFormat::Csv], Some(Path::new("report.txt"))).is_none()
+        );
+        assert!(auto_fallback_warning(&[], Some(Path::new("report.csv.gz"))).is_none());
+        assert!(auto_fallback_warning(&[], None).is_none());
+        assert_eq!(
+            resolve_formats(&[], Some(Path::new("scan.xlsx"))),
+            vec![OutputFormat::Terminal]
+        );
+    }
+}
@@ -0,0 +1,135 @@
+/// Timestamped per-run artifact folders (`--run-dir`)
+///
+/// Each scan gets `<base>/<YYYYMMDD-HHMMSS>/` holding its reports, manifest
+/// and log summary, so an evidence archive keeps every run side by side. Two
+/// runs started in the same second get a `-2`, `-3`, ... suffix.
+use crate::cli::OutputFormat;
+use std::path::{Path, PathBuf};
+
+/// Base name of the report files inside a run directory
+const REPORT_NAME: &str = "report";
+
+/// File name of the manifest inside a run directory
+const MANIFEST_NAME: &str = "manifest.json";
+
+/// File name of the log summary inside a run directory
+const LOG_SUMMARY_NAME: &str = "log-summary.json";
+
+#[derive(Debug, Clone)]
+pub struct RunDir {
+    path: PathBuf,
+}
+
+impl RunDir {
+    /// Create a new run directory under `base`, named by the current local time
+    pub fn create(base: &Path) -> Result<Self, String> {
+        Self::create_named(
+            base,
+            &chrono::Local::now().format("%Y%m%d-%H%M%S").to_string(),
+        )
+    }
+
+    /// Create `base/name`, or the first free `base/name-N`
+    fn create_named(base: &Path, name: &str) -> Result<Self, String> {
+        std::fs::create_dir_all(base)
+            .map_err(|e| format!("Failed to create {}: {}", base.display(), e))?;
+
+        let mut path = base.join(name);
+        for n in 2.. {
+            match std::fs::create_dir(&path) {
+                Ok(()) => break,
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    path = base.join(format!("{}-{}", name, n));
+                }
+                Err(e) => {
+                    return Err(format!(
+                        "Failed to create run directory {}: {}",
+                        path.display(),
+                        e
+                    ))
+                }
+            }
+        }
+
+        Ok(Self { path })
+    }
+
+    /// The created directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// `--output` for reports in this directory when `format` is the first format
+    ///
+    /// Further formats swap the extension (see
+    /// [`output_path_for`](crate::cli::output::output_path_for)).
+    pub fn report_path(&self, format: OutputFormat) -> PathBuf {
+        self.path
+            .join(REPORT_NAME)
+            .with_extension(format.extension())
+    }
+
+    /// Where the scan manifest is written
+    pub fn manifest_path(&self) -> PathBuf {
+        self.path.join(MANIFEST_NAME)
+    }
+
+    /// Where the one-line JSON log summary is written
+    pub fn log_summary_path(&self) -> PathBuf {
+        self.path.join(LOG_SUMMARY_NAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{write_reports, ReportOptions};
+    use crate::{default_registry, ScanEngine};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_dir_holds_reports() {
+        let tmp = TempDir::new().unwrap();
+        let data = tmp.path().join("data");
+        fs::create_dir(&data).unwrap();
+        fs::write(data.join("customers.txt"), "BSN: 111222333\n").unwrap();
+
+        let base = tmp.path().join("runs");
+        let run = RunDir::create(&base).unwrap();
+        assert_eq!(run.path().parent(), Some(base.as_path()));
+
+        // YYYYMMDD-HHMMSS
+        let name = run.path().file_name().unwrap().to_str().unwrap();
+        assert!(chrono::NaiveDateTime::parse_from_str(name, "%Y%m%d-%H%M%S").is_ok());
+
+        let results = ScanEngine::new(default_registry())
+            .show_progress(false)
+            .scan_directory(&data);
+        let written = write_reports(
+            &results,
+            &[OutputFormat::Json, OutputFormat::Csv],
+            Some(&run.report_path(OutputFormat::Json)),
+            ReportOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        let report = run.path().join("report.json");
+        assert_eq!(written[0], (OutputFormat::Json, report.clone()));
+        assert!(run.path().join("report.csv").is_file());
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&report).unwrap()).unwrap();
+        assert_eq!(json["total_matches"], 1);
+    }
+
+    #[test]
+    fn test_run_dir_same_second() {
+        let tmp = TempDir::new().unwrap();
+
+        let first = RunDir::create_named(tmp.path(), "20240315-103215").unwrap();
+        let second = RunDir::create_named(tmp.path(), "20240315-103215").unwrap();
+        assert_eq!(first.path(), tmp.path().join("20240315-103215"));
+        assert_eq!(second.path(), tmp.path().join("20240315-103215-2"));
+    }
+}
@@ -1,7 +1,9 @@
-use anyhow::{Context, Result};
 /// Configuration file support for PII-Radar
 /// Supports TOML files at ~/.pii-radar/config.toml or ./.pii-radar.toml
+use crate::core::{RiskWeights, Severity, SeverityOverrides, SPLIT_DETECTOR_IDS};
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -25,6 +27,47 @@ pub struct Config {
 
     #[serde(default)]
     pub plugins: Option<PluginConfig>,
+
+    /// Base severity per detector ID (e.g. `email = "high"`)
+    #[serde(default)]
+    pub severity_overrides: SeverityOverrides,
+
+    /// Maximum severity per path glob (e.g. `"docs/**" = "medium"`)
+    #[serde(default)]
+    pub severity_ceilings: BTreeMap<String, Severity>,
+
+    /// Path globs per detector ID; a scoped detector only runs on matching
+    /// files (e.g. `entropy_secret = ["*.env", "config/**"]`)
+    #[serde(default)]
+    pub detector_path_scope: BTreeMap<String, Vec<String>>,
+
+    /// IP + timestamp correlation for access logs
+    #[serde(default)]
+    pub access_log: AccessLogConfig,
+
+    /// Check digit validation of German pre-IBAN accounts
+    #[serde(default)]
+    pub legacy_bank_account: LegacyBankAccountConfig,
+
+    /// Weights for the scan risk score
+    #[serde(default)]
+    pub risk: RiskWeights,
+}
+
+/// Access-log correlation settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AccessLogConfig {
+    /// Timestamp regexes replacing the built-in log formats
+    #[serde(default)]
+    pub timestamp_patterns: Vec<String>,
+}
+
+/// German legacy bank account settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LegacyBankAccountConfig {
+    /// Bundesbank BLZ file (`blz-aktuell`) with the check method of each bank
+    #[serde(default)]
+    pub blz_file: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +80,10 @@ pub struct ScanConfig {
     #[serde(default)]
     pub extract_documents: bool,
 
+    /// Seconds before extracting a single PDF is aborted
+    #[serde(default = "default_pdf_timeout")]
+    pub pdf_timeout_secs: u64,
+
     /// Maximum number of threads to use
     #[serde(default)]
     pub max_threads: Option<usize>,
@@ -48,6 +95,15 @@ pub struct ScanConfig {
     /// Disable context analysis
     #[serde(default)]
     pub no_context: bool,
+
+    /// Phone number regions (e.g., ["de", "at"]); the first entry is used to
+    /// parse national-format numbers, all entries form the allowlist
+    #[serde(default)]
+    pub phone_regions: Vec<String>,
+
+    /// Detector IDs to disable (e.g., ["email", "phone"])
+    #[serde(default)]
+    pub disabled_detectors: Vec<String>,
 }
 
 impl Default for ScanConfig {
@@ -55,16 +111,19 @@ impl Default for ScanConfig {
         Self {
             min_confidence: "high".to_string(),
             extract_documents: false,
+            pdf_timeout_secs: default_pdf_timeout(),
             max_threads: None,
             countries: Vec::new(),
             no_context: false,
+            phone_regions: Vec::new(),
+            disabled_detectors: Vec::new(),
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputConfig {
-    /// Output format (terminal, json, json-compact, html)
+    /// Output format (auto, terminal, json, json-compact, html, csv, sarif)
     #[serde(default = "default_format")]
     pub format: String,
 
@@ -84,7 +143,7 @@ pub struct OutputConfig {
 impl Default for OutputConfig {
     fn default() -> Self {
         Self {
-            format: "terminal".to_string(),
+            format: default_format(),
             output_path: None,
             full_paths: false,
             no_progress: false,
@@ -101,6 +160,10 @@ pub struct FilterConfig {
     /// Maximum directory recursion depth
     #[serde(default)]
     pub max_depth: Option<usize>,
+
+    /// Only scan files matching at least one of these globs (relative to the scan root)
+    #[serde(default)]
+    pub include: Vec<String>,
 }
 
 impl Default for FilterConfig {
@@ -108,6 +171,7 @@ impl Default for FilterConfig {
         Self {
             max_filesize_mb: 100,
             max_depth: None,
+            include: Vec::new(),
         }
     }
 }
@@ -200,13 +264,17 @@ fn default_confidence() -> String {
 }
 
 fn default_format() -> String {
-    "terminal".to_string()
+    "auto".to_string()
 }
 
 fn default_max_filesize() -> u64 {
     100
 }
 
+fn default_pdf_timeout() -> u64 {
+    crate::extractors::pdf::DEFAULT_PDF_TIMEOUT.as_secs()
+}
+
 fn default_db_timeout() -> u64 {
     30
 }
@@ -238,6 +306,7 @@ pub struct CliOverrides {
     pub countries: Option<String>,
     pub min_confidence: Option<String>,
     pub extract_documents: bool,
+    pub pdf_timeout: Option<u64>,
     pub no_context: bool,
     pub threads: Option<usize>,
     pub format: Option<String>,
@@ -246,6 +315,77 @@ pub struct CliOverrides {
     pub full_paths: bool,
     pub max_filesize: Option<u64>,
     pub max_depth: Option<usize>,
+    pub phone_region: Option<String>,
+    pub include: Vec<String>,
+}
+
+/// Where each effective setting came from, keyed by dotted path (`scan.countries`)
+///
+/// Settings without an entry have their built-in default.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources(BTreeMap<String, String>);
+
+impl ConfigSources {
+    /// Attribute every setting that `config` changes from the default to the file at `path`
+    pub fn from_file(config: &Config, path: &Path) -> Self {
+        let mut sources = Self::default();
+        let (Ok(loaded), Ok(default)) = (
+            toml::Value::try_from(config),
+            toml::Value::try_from(Config::default()),
+        ) else {
+            return sources;
+        };
+
+        let mut loaded_leaves = BTreeMap::new();
+        let mut default_leaves = BTreeMap::new();
+        flatten_toml("", &loaded, &mut loaded_leaves);
+        flatten_toml("", &default, &mut default_leaves);
+
+        let source = format!("file ({})", path.display());
+        for (key, value) in loaded_leaves {
+            if default_leaves.get(&key) != Some(&value) {
+                sources.0.insert(key, source.clone());
+            }
+        }
+        sources
+    }
+
+    /// Source of a setting, or of the closest enclosing table
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let mut key = key;
+        loop {
+            if let Some(source) = self.0.get(key) {
+                return Some(source);
+            }
+            key = &key[..key.rfind('.')?];
+        }
+    }
+
+    fn set(&mut self, key: &str, source: String) {
+        // A replaced table (e.g. a list) drops what its old entries came from
+        let prefix = format!("{}.", key);
+        self.0.retain(|k, _| !k.starts_with(&prefix));
+        self.0.insert(key.to_string(), source);
+    }
+}
+
+/// Collect the non-table values of a TOML tree under their dotted paths
+fn flatten_toml(prefix: &str, value: &toml::Value, leaves: &mut BTreeMap<String, toml::Value>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_toml(&path, value, leaves);
+            }
+        }
+        _ => {
+            leaves.insert(prefix.to_string(), value.clone());
+        }
+    }
 }
 
 impl Config {
@@ -254,31 +394,90 @@ impl Config {
         let contents = fs::read_to_string(path.as_ref())
             .with_context(|| format!("Failed to read config file: {:?}", path.as_ref()))?;
 
-        let config: Config =
+        let mut config: Config =
             toml::from_str(&contents).with_context(|| "Failed to parse TOML configuration")?;
+        config.migrate_split_detectors();
 
         Ok(config)
     }
 
+    /// Extend settings for a split detector's old ID to its new ID
+    ///
+    /// See [`SPLIT_DETECTOR_IDS`]; settings given for the new ID are kept.
+    pub fn migrate_split_detectors(&mut self) {
+        for &(old, new) in SPLIT_DETECTOR_IDS {
+            let disabled = &mut self.scan.disabled_detectors;
+            if disabled.iter().any(|id| id == old) && !disabled.iter().any(|id| id == new) {
+                disabled.push(new.to_string());
+            }
+
+            if let (Some(severity), None) = (
+                self.severity_overrides.get(old),
+                self.severity_overrides.get(new),
+            ) {
+                self.severity_overrides =
+                    std::mem::take(&mut self.severity_overrides).set(new, severity);
+            }
+
+            if let Some(globs) = self.detector_path_scope.get(old).cloned() {
+                self.detector_path_scope
+                    .entry(new.to_string())
+                    .or_insert(globs);
+            }
+        }
+    }
+
     /// Try to load configuration from standard locations
     /// Priority: ./.pii-radar.toml > ~/.pii-radar/config.toml
     pub fn load_default() -> Result<Option<Self>> {
+        Self::default_path().map(Self::load_from_file).transpose()
+    }
+
+    /// Path of the config file [`load_default`](Self::load_default) reads, if any exists
+    pub fn default_path() -> Option<PathBuf> {
         // Try local config first
         let local_config = PathBuf::from("./.pii-radar.toml");
         if local_config.exists() {
-            return Ok(Some(Self::load_from_file(local_config)?));
+            return Some(local_config);
         }
 
         // Try user config
-        if let Some(home_dir) = dirs::home_dir() {
-            let user_config = home_dir.join(".pii-radar/config.toml");
-            if user_config.exists() {
-                return Ok(Some(Self::load_from_file(user_config)?));
+        dirs::home_dir()
+            .map(|home_dir| home_dir.join(".pii-radar/config.toml"))
+            .filter(|user_config| user_config.exists())
+    }
+
+    /// Render the configuration as TOML
+    ///
+    /// With `sources`, every setting line ends with a comment naming where
+    /// the value came from (`# cli (--countries)`, `# env (PII_RADAR_FORMAT)`,
+    /// `# file (./.pii-radar.toml)` or `# default`).
+    pub fn to_toml(&self, sources: Option<&ConfigSources>) -> Result<String> {
+        let toml = toml::to_string(self).context("Failed to serialize configuration")?;
+        let Some(sources) = sources else {
+            return Ok(toml);
+        };
+
+        let mut table = String::new();
+        let mut annotated = String::new();
+        for line in toml.lines() {
+            if line.starts_with('[') {
+                table = line.trim_matches(['[', ']']).to_string();
+                annotated.push_str(line);
+            } else if let Some((key, _)) = line.split_once(" = ") {
+                let key = format!("{}.{}", table, key.trim_matches('"'));
+                let key = key.trim_start_matches('.');
+                annotated.push_str(&format!(
+                    "{}  # {}",
+                    line,
+                    sources.get(key).unwrap_or("default")
+                ));
+            } else {
+                annotated.push_str(line);
             }
+            annotated.push('\n');
         }
-
-        // No config file found
-        Ok(None)
+        Ok(annotated)
     }
 
     /// Expand environment variables in connection strings
@@ -305,60 +504,173 @@ impl Config {
         }
     }
 
-    /// Merge CLI arguments with config file (CLI takes precedence)
-    pub fn merge_with_cli(mut self, overrides: CliOverrides) -> Self {
-        // CLI overrides config file
+    /// Apply `PII_RADAR_*` environment variables on top of the config file
+    ///
+    /// Supported variables:
+    /// - `PII_RADAR_COUNTRIES`: comma-separated country codes
+    /// - `PII_RADAR_MIN_CONFIDENCE`: low, medium or high
+    /// - `PII_RADAR_FORMAT`: output format
+    /// - `PII_RADAR_DISABLE`: comma-separated detector IDs to disable
+    ///
+    /// Empty variables are ignored.
+    pub fn apply_env(self) -> Self {
+        self.apply_env_tracked(process_env, &mut ConfigSources::default())
+    }
+
+    /// Apply the `PII_RADAR_*` variables returned by `lookup`
+    fn apply_env_tracked(
+        mut self,
+        lookup: impl Fn(&str) -> Option<String>,
+        sources: &mut ConfigSources,
+    ) -> Self {
+        let var = |name: &str| lookup(name).filter(|v| !v.trim().is_empty());
+        let env = |name: &str| format!("env ({})", name);
+
+        if let Some(countries) = var("PII_RADAR_COUNTRIES") {
+            self.scan.countries = split_list(&countries);
+            sources.set("scan.countries", env("PII_RADAR_COUNTRIES"));
+        }
+
+        if let Some(confidence) = var("PII_RADAR_MIN_CONFIDENCE") {
+            self.scan.min_confidence = confidence.trim().to_lowercase();
+            sources.set("scan.min_confidence", env("PII_RADAR_MIN_CONFIDENCE"));
+        }
+
+        if let Some(format) = var("PII_RADAR_FORMAT") {
+            self.output.format = format.trim().to_lowercase();
+            sources.set("output.format", env("PII_RADAR_FORMAT"));
+        }
+
+        if let Some(disabled) = var("PII_RADAR_DISABLE") {
+            self.scan.disabled_detectors = split_list(&disabled);
+            sources.set("scan.disabled_detectors", env("PII_RADAR_DISABLE"));
+        }
+
+        self
+    }
+
+    /// Merge environment variables and CLI arguments with config file
+    ///
+    /// Precedence: CLI flags > `PII_RADAR_*` environment variables > config file
+    pub fn merge_with_cli(self, overrides: CliOverrides) -> Self {
+        self.merge_with_cli_tracked(overrides, &mut ConfigSources::default())
+    }
+
+    /// [`merge_with_cli`](Self::merge_with_cli), recording which settings the
+    /// environment and CLI flags replaced in `sources`
+    pub fn merge_with_cli_tracked(
+        self,
+        overrides: CliOverrides,
+        sources: &mut ConfigSources,
+    ) -> Self {
+        self.merge_tracked(overrides, process_env, sources)
+    }
+
+    /// Merge the variables returned by `env` and the CLI arguments
+    fn merge_tracked(
+        mut self,
+        overrides: CliOverrides,
+        env: impl Fn(&str) -> Option<String>,
+        sources: &mut ConfigSources,
+    ) -> Self {
+        self = self.apply_env_tracked(env, sources);
+        let cli = |flag: &str| format!("cli ({})", flag);
+
+        // CLI overrides environment and config file
         if let Some(countries_str) = overrides.countries {
             self.scan.countries = countries_str
                 .split(',')
                 .map(|s| s.trim().to_string())
                 .collect();
+            sources.set("scan.countries", cli("--countries"));
         }
 
         if let Some(confidence) = overrides.min_confidence {
             self.scan.min_confidence = confidence;
+            sources.set("scan.min_confidence", cli("--min-confidence"));
         }
 
         if overrides.extract_documents {
             self.scan.extract_documents = true;
+            sources.set("scan.extract_documents", cli("--extract-documents"));
+        }
+
+        if let Some(secs) = overrides.pdf_timeout {
+            self.scan.pdf_timeout_secs = secs;
+            sources.set("scan.pdf_timeout_secs", cli("--pdf-timeout"));
         }
 
         if overrides.no_context {
             self.scan.no_context = true;
+            sources.set("scan.no_context", cli("--no-context"));
         }
 
         if let Some(t) = overrides.threads {
             self.scan.max_threads = Some(t);
+            sources.set("scan.max_threads", cli("--threads"));
         }
 
         if let Some(fmt) = overrides.format {
             self.output.format = fmt;
+            sources.set("output.format", cli("--format"));
         }
 
         if let Some(out) = overrides.output {
             self.output.output_path = Some(out);
+            sources.set("output.output_path", cli("--output"));
         }
 
         if overrides.no_progress {
             self.output.no_progress = true;
+            sources.set("output.no_progress", cli("--no-progress"));
         }
 
         if overrides.full_paths {
             self.output.full_paths = true;
+            sources.set("output.full_paths", cli("--full-paths"));
         }
 
         if let Some(size) = overrides.max_filesize {
             self.filters.max_filesize_mb = size;
+            sources.set("filters.max_filesize_mb", cli("--max-filesize"));
         }
 
         if let Some(depth) = overrides.max_depth {
             self.filters.max_depth = Some(depth);
+            sources.set("filters.max_depth", cli("--max-depth"));
         }
 
+        if let Some(regions) = overrides.phone_region {
+            self.scan.phone_regions = regions
+                .split(',')
+                .map(|s| s.trim().to_lowercase())
+                .collect();
+            sources.set("scan.phone_regions", cli("--phone-region"));
+        }
+
+        if !overrides.include.is_empty() {
+            self.filters.include = overrides.include;
+            sources.set("filters.include", cli("--include"));
+        }
+
+        self.migrate_split_detectors();
         self
     }
 }
 
+/// Split a comma-separated list into trimmed, lowercase, non-empty entries
+fn split_list(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Look up a variable in the process environment
+fn process_env(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
 /// Expand environment variables in strings
 /// Supports ${VAR_NAME} syntax
 fn expand_env_string(s: &str) -> String {
@@ -383,13 +695,15 @@ fn expand_env_string(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_default_config() {
         let config = Config::default();
         assert_eq!(config.scan.min_confidence, "high");
         assert!(!config.scan.extract_documents);
-        assert_eq!(config.output.format, "terminal");
+        assert_eq!(config.scan.pdf_timeout_secs, 120);
+        assert_eq!(config.output.format, "auto");
         assert_eq!(config.filters.max_filesize_mb, 100);
     }
 
@@ -414,6 +728,8 @@ min_confidence = "medium"
 extract_documents = true
 countries = ["nl", "de"]
 
+phone_regions = ["de", "at"]
+
 [output]
 format = "json"
 full_paths = true
@@ -423,6 +739,7 @@ full_paths = true
         assert_eq!(config.scan.min_confidence, "medium");
         assert!(config.scan.extract_documents);
         assert_eq!(config.scan.countries, vec!["nl", "de"]);
+        assert_eq!(config.scan.phone_regions, vec!["de", "at"]);
         assert_eq!(config.output.format, "json");
         assert!(config.output.full_paths);
     }
@@ -434,6 +751,7 @@ full_paths = true
             countries: Some("gb,fr".to_string()),
             min_confidence: Some("low".to_string()),
             extract_documents: true,
+            pdf_timeout: Some(30),
             no_context: true,
             threads: Some(8),
             format: Some("html".to_string()),
@@ -442,11 +760,14 @@ full_paths = true
             full_paths: true,
             max_filesize: Some(200),
             max_depth: Some(5),
+            phone_region: Some("de".to_string()),
+            include: vec!["**/*.sql".to_string()],
         });
 
         assert_eq!(config.scan.countries, vec!["gb", "fr"]);
         assert_eq!(config.scan.min_confidence, "low");
         assert!(config.scan.extract_documents);
+        assert_eq!(config.scan.pdf_timeout_secs, 30);
         assert!(config.scan.no_context);
         assert_eq!(config.scan.max_threads, Some(8));
         assert_eq!(config.output.format, "html");
@@ -454,6 +775,83 @@ full_paths = true
         assert!(config.output.full_paths);
         assert_eq!(config.filters.max_filesize_mb, 200);
         assert_eq!(config.filters.max_depth, Some(5));
+        assert_eq!(config.filters.include, vec!["**/*.sql"]);
+        assert_eq!(config.scan.phone_regions, vec!["de"]);
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        // Injected instead of set in the process environment, which other
+        // tests running in parallel read
+        let vars: HashMap<&str, &str> = HashMap::from([
+            ("PII_RADAR_COUNTRIES", "nl, de"),
+            ("PII_RADAR_MIN_CONFIDENCE", "medium"),
+            ("PII_RADAR_FORMAT", "json"),
+            ("PII_RADAR_DISABLE", "email,phone"),
+        ]);
+        let env = |name: &str| vars.get(name).map(|v| v.to_string());
+
+        let mut file_config = Config::default();
+        file_config.scan.countries = vec!["gb".to_string()];
+        file_config.output.format = "html".to_string();
+
+        // Environment wins over the config file
+        let config = file_config.clone().merge_tracked(
+            CliOverrides::default(),
+            env,
+            &mut ConfigSources::default(),
+        );
+        assert_eq!(config.scan.countries, vec!["nl", "de"]);
+        assert_eq!(config.scan.min_confidence, "medium");
+        assert_eq!(config.output.format, "json");
+        assert_eq!(config.scan.disabled_detectors, vec!["email", "phone"]);
+
+        // CLI flags win over the environment
+        let config = file_config.merge_tracked(
+            CliOverrides {
+                countries: Some("fr".to_string()),
+                format: Some("csv".to_string()),
+                ..Default::default()
+            },
+            env,
+            &mut ConfigSources::default(),
+        );
+        assert_eq!(config.scan.countries, vec!["fr"]);
+        assert_eq!(config.scan.min_confidence, "medium");
+        assert_eq!(config.output.format, "csv");
+    }
+
+    #[test]
+    fn test_effective_config_explained() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join(".pii-radar.toml");
+        fs::write(
+            &path,
+            "[scan]\nmin_confidence = \"medium\"\n\n[filters]\nmax_depth = 3\n",
+        )
+        .unwrap();
+
+        let file_config = Config::load_from_file(&path).unwrap();
+        let mut sources = ConfigSources::from_file(&file_config, &path);
+        let config = file_config.merge_with_cli_tracked(
+            CliOverrides {
+                min_confidence: Some("low".to_string()),
+                ..Default::default()
+            },
+            &mut sources,
+        );
+
+        let toml = config.to_toml(Some(&sources)).unwrap();
+        let file_source = format!("# file ({})", path.display());
+        assert!(toml.contains("min_confidence = \"low\"  # cli (--min-confidence)\n"));
+        assert!(!toml.contains("\"medium\""));
+        assert!(toml.contains(&format!("max_depth = 3  {}\n", file_source)));
+        assert!(toml.contains("max_filesize_mb = 100  # default\n"));
+
+        // Without sources the output reads back as the same configuration
+        let plain: Config = toml::from_str(&config.to_toml(None).unwrap()).unwrap();
+        assert_eq!(plain.scan.min_confidence, "low");
+        assert_eq!(plain.filters.max_depth, Some(3));
     }
 
     #[test]
@@ -489,4 +887,105 @@ endpoints = [
         assert_eq!(api.endpoints[0].name, "user_api");
         assert_eq!(api.endpoints[0].method, "GET");
     }
+
+    #[test]
+    fn test_severity_overrides_parsing() {
+        let toml_str = r#"
+[severity_overrides]
+email = "high"
+phone = "low"
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.severity_overrides.get("email"), Some(Severity::High));
+        assert_eq!(config.severity_overrides.get("phone"), Some(Severity::Low));
+        assert_eq!(config.severity_overrides.get("nl_bsn"), None);
+    }
+
+    #[test]
+    fn test_split_detector_settings_migrated() {
+        let toml_str = r#"
+[scan]
+disabled_detectors = ["api_key"]
+
+[severity_overrides]
+api_key = "critical"
+
+[detector_path_scope]
+api_key = ["*.env"]
+entropy_secret = ["config/**"]
+"#;
+
+        let mut config: Config = toml::from_str(toml_str).unwrap();
+        config.migrate_split_detectors();
+        assert_eq!(
+            config.scan.disabled_detectors,
+            vec!["api_key", "entropy_secret"]
+        );
+        assert_eq!(
+            config.severity_overrides.get("entropy_secret"),
+            Some(Severity::Critical)
+        );
+        // Settings given for the new ID win
+        assert_eq!(
+            config.detector_path_scope["entropy_secret"],
+            vec!["config/**"]
+        );
+    }
+
+    #[test]
+    fn test_severity_ceilings_parsing() {
+        let toml_str = r#"
+[severity_ceilings]
+"docs/**" = "medium"
+"examples/**" = "low"
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.severity_ceilings.len(), 2);
+        assert_eq!(config.severity_ceilings["docs/**"], Severity::Medium);
+        assert!(Config::default().severity_ceilings.is_empty());
+    }
+
+    #[test]
+    fn test_detector_path_scope_parsing() {
+        let toml_str = r#"
+[detector_path_scope]
+entropy_secret = ["*.env", "config/**"]
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.detector_path_scope["entropy_secret"],
+            vec!["*.env", "config/**"]
+        );
+        assert!(Config::default().detector_path_scope.is_empty());
+    }
+
+    #[test]
+    fn test_access_log_parsing() {
+        let toml_str = r#"
+[access_log]
+timestamp_patterns = ['\bts=\d{10}\b']
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.access_log.timestamp_patterns, vec![r"\bts=\d{10}\b"]);
+        assert!(Config::default().access_log.timestamp_patterns.is_empty());
+    }
+
+    #[test]
+    fn test_legacy_bank_account_parsing() {
+        let toml_str = r#"
+[legacy_bank_account]
+blz_file = "/data/blz-aktuell.txt"
+"#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.legacy_bank_account.blz_file,
+            Some(PathBuf::from("/data/blz-aktuell.txt"))
+        );
+        assert!(Config::default().legacy_bank_account.blz_file.is_none());
+    }
 }
@@ -1,5 +1,10 @@
 /// Detector trait that all PII detectors must implement
+use crate::core::locale::ScanContext;
 use crate::core::types::{Match, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
 
 /// Trait for PII detectors
 ///
@@ -46,6 +51,56 @@ pub trait Detector: Send + Sync {
     /// Vector of matches found. Empty vector if no matches.
     fn detect(&self, text: &str, file_path: &std::path::Path) -> Vec<Match>;
 
+    /// Detect PII using scan-wide settings such as the `--locale`
+    ///
+    /// The scan engine calls this instead of `detect()`. Detectors whose
+    /// results depend on the locale (e.g. date order) override it; the
+    /// default ignores the context.
+    fn detect_in_context(
+        &self,
+        text: &str,
+        file_path: &std::path::Path,
+        context: &ScanContext,
+    ) -> Vec<Match> {
+        let _ = context;
+        self.detect(text, file_path)
+    }
+
+    /// Optional: Whether matches can span line breaks
+    ///
+    /// Detectors returning `true` run their patterns over the whole document
+    /// (see [`Location::in_text`](crate::core::Location::in_text)) and are
+    /// never handed long-line windows or intra-file chunks, so a record such
+    /// as a postal address is not cut in two.
+    fn multiline(&self) -> bool {
+        false
+    }
+
+    /// Optional: IDs of more generic detectors this one replaces on the same text
+    ///
+    /// The scan engine drops a match of a listed detector when a match of
+    /// this detector overlaps it, e.g. `dk_phone` supersedes `phone`, so a
+    /// value is reported once, by the most specific detector.
+    fn supersedes(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Optional: Settings that change what this detector reports
+    ///
+    /// Part of the incremental cache key, so a cached file is rescanned when
+    /// e.g. a severity override or `--phone-region` changes. Detectors
+    /// without settings return an empty string.
+    fn config_key(&self) -> String {
+        String::new()
+    }
+
+    /// Optional: Compile patterns the sample document doesn't reach
+    ///
+    /// [`DetectorRegistry::warmup`] runs every detector on a sample document
+    /// and then calls this. Detectors that only run a pattern after a keyword
+    /// or a cheap pre-check force it here.
+    fn warmup(&self) {}
+
     /// Optional: Validate a specific value
     ///
     /// This is called internally by detect() but can also be used
@@ -61,23 +116,183 @@ pub trait Detector: Send + Sync {
     fn description(&self) -> Option<String> {
         None
     }
+
+    /// Optional: A sample value that passes `validate()`
+    ///
+    /// Used by `pii-radar self-test` and `pii-radar detectors --json`.
+    fn example_valid(&self) -> Option<&str> {
+        None
+    }
+
+    /// Optional: A well-formed sample value that fails `validate()`
+    fn example_invalid(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Detector IDs split off an existing detector, as (old ID, new ID)
+///
+/// The new detector reports findings the old one used to, e.g. high-entropy
+/// strings moved from `api_key` to `entropy_secret`. Settings and inline
+/// suppressions naming the old ID also apply to the new one.
+pub const SPLIT_DETECTOR_IDS: &[(&str, &str)] = &[("api_key", "entropy_secret")];
+
+/// Sample document for [`DetectorRegistry::warmup`]
+///
+/// Many detectors only run their pattern on lines with a keyword, so the
+/// sample carries the common ones; the detectors' own examples are added.
+const WARMUP_TEXT: &str = "name: Jan Jansen, email jan@example.com, phone +31 6 12345678\n\
+                           passport NX1234567, BSN 111222333, IBAN NL91ABNA0417164301\n\
+                           password=hunter2 token: abc123 DOB 01-02-1990 lat=52.3702&lng=4.8952\n";
+
+/// What [`DetectorRegistry::try_register`] does with a detector whose ID is taken
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Keep the first detector and record the conflict
+    #[default]
+    KeepFirst,
+    /// Keep the first detector and return the conflict as an error
+    Reject,
+}
+
+/// A detector that was not registered because its ID was already taken
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectorConflict {
+    /// The shared detector ID
+    pub id: String,
+    /// Name of the registered detector
+    pub kept: String,
+    /// Name of the detector that was not registered
+    pub ignored: String,
+}
+
+impl std::fmt::Display for DetectorConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Duplicate detector id '{}': keeping '{}', ignoring '{}'",
+            self.id, self.kept, self.ignored
+        )
+    }
 }
 
 /// Registry for managing all available detectors
+///
+/// Detector IDs are unique: a second detector with a registered ID (e.g. a
+/// plugin reusing `email`) is not added, so matches aren't reported twice.
+/// Such conflicts are listed by [`conflicts`](Self::conflicts).
 pub struct DetectorRegistry {
     detectors: Vec<Box<dyn Detector>>,
+    duplicate_policy: DuplicatePolicy,
+    conflicts: Vec<DetectorConflict>,
 }
 
 impl DetectorRegistry {
     pub fn new() -> Self {
         Self {
             detectors: Vec::new(),
+            duplicate_policy: DuplicatePolicy::default(),
+            conflicts: Vec::new(),
         }
     }
 
-    /// Register a detector
+    /// Set how [`try_register`](Self::try_register) handles duplicate IDs
+    pub fn set_duplicate_policy(&mut self, policy: DuplicatePolicy) {
+        self.duplicate_policy = policy;
+    }
+
+    /// Register a detector, keeping the first one when its ID is already registered
     pub fn register(&mut self, detector: Box<dyn Detector>) {
-        self.detectors.push(detector);
+        let _ = self.try_register(detector);
+    }
+
+    /// Register a detector, or report the conflict if its ID is already registered
+    ///
+    /// The first detector is always kept and the conflict recorded; with
+    /// [`DuplicatePolicy::Reject`] it is also returned as an error.
+    pub fn try_register(&mut self, detector: Box<dyn Detector>) -> Result<(), DetectorConflict> {
+        let Some(existing) = self.get(detector.id()) else {
+            self.detectors.push(detector);
+            return Ok(());
+        };
+
+        let conflict = DetectorConflict {
+            id: detector.id().to_string(),
+            kept: existing.name().to_string(),
+            ignored: detector.name().to_string(),
+        };
+        self.conflicts.push(conflict.clone());
+
+        match self.duplicate_policy {
+            DuplicatePolicy::KeepFirst => Ok(()),
+            DuplicatePolicy::Reject => Err(conflict),
+        }
+    }
+
+    /// Detectors that were not registered because of a duplicate ID
+    pub fn conflicts(&self) -> &[DetectorConflict] {
+        &self.conflicts
+    }
+
+    /// Remove a detector by ID, returning it if it was registered
+    pub fn unregister(&mut self, id: &str) -> Option<Box<dyn Detector>> {
+        let index = self.detectors.iter().position(|d| d.id() == id)?;
+        Some(self.detectors.remove(index))
+    }
+
+    /// A registry running the same detector instances as this one
+    ///
+    /// Used for nested detectors such as the base64 detector, which re-runs
+    /// the final, configured detector set on decoded text. Detectors
+    /// registered or removed afterwards only affect this registry.
+    pub fn share(&mut self) -> DetectorRegistry {
+        let shared: Vec<Arc<dyn Detector>> = std::mem::take(&mut self.detectors)
+            .into_iter()
+            .map(Arc::from)
+            .collect();
+        self.detectors = shared
+            .iter()
+            .map(|d| Box::new(d.clone()) as Box<dyn Detector>)
+            .collect();
+
+        let mut copy = DetectorRegistry::new();
+        copy.detectors = shared
+            .into_iter()
+            .map(|d| Box::new(d) as Box<dyn Detector>)
+            .collect();
+        copy
+    }
+
+    /// Replace the base severity of every registered detector listed in `overrides`
+    pub fn apply_severity_overrides(&mut self, overrides: &SeverityOverrides) {
+        if overrides.is_empty() {
+            return;
+        }
+
+        self.detectors = std::mem::take(&mut self.detectors)
+            .into_iter()
+            .map(|d| overrides.wrap(d))
+            .collect();
+    }
+
+    /// Run every detector once on a small sample document
+    ///
+    /// Detector patterns are compiled on first use; this moves that one-time
+    /// cost out of the first scanned file.
+    pub fn warmup(&self) {
+        let mut sample = String::from(WARMUP_TEXT);
+        for detector in &self.detectors {
+            if let Some(example) = detector.example_valid() {
+                sample.push_str(example);
+                sample.push('\n');
+            }
+        }
+
+        let path = Path::new("warmup.txt");
+        for detector in &self.detectors {
+            detector.detect(&sample, path);
+            detector.warmup();
+        }
     }
 
     /// Get all registered detectors
@@ -162,3 +377,188 @@ impl Default for DetectorRegistry {
         Self::new()
     }
 }
+
+/// A shared detector, see [`DetectorRegistry::share`]
+impl<D: Detector + ?Sized> Detector for Arc<D> {
+    fn id(&self) -> &str {
+        (**self).id()
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn country(&self) -> &str {
+        (**self).country()
+    }
+
+    fn base_severity(&self) -> Severity {
+        (**self).base_severity()
+    }
+
+    fn detect(&self, text: &str, file_path: &std::path::Path) -> Vec<Match> {
+        (**self).detect(text, file_path)
+    }
+
+    fn detect_in_context(
+        &self,
+        text: &str,
+        file_path: &std::path::Path,
+        context: &ScanContext,
+    ) -> Vec<Match> {
+        (**self).detect_in_context(text, file_path, context)
+    }
+
+    fn multiline(&self) -> bool {
+        (**self).multiline()
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        (**self).supersedes()
+    }
+
+    fn config_key(&self) -> String {
+        (**self).config_key()
+    }
+
+    fn warmup(&self) {
+        (**self).warmup()
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        (**self).validate(value)
+    }
+
+    fn description(&self) -> Option<String> {
+        (**self).description()
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        (**self).example_valid()
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        (**self).example_invalid()
+    }
+}
+
+/// Per-detector base severity overrides, keyed by detector ID
+///
+/// Loaded from the `[severity_overrides]` config table, e.g. `email = "high"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SeverityOverrides(BTreeMap<String, Severity>);
+
+impl SeverityOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the severity of detector `id`
+    pub fn set(mut self, id: &str, severity: Severity) -> Self {
+        self.0.insert(id.to_string(), severity);
+        self
+    }
+
+    /// Severity override for detector `id`, if any
+    pub fn get(&self, id: &str) -> Option<Severity> {
+        self.0.get(id).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Detector IDs with an override
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Wrap `detector` so its matches are at least the overridden severity, if one is set
+    pub fn wrap(&self, detector: Box<dyn Detector>) -> Box<dyn Detector> {
+        match self.get(detector.id()) {
+            Some(severity) => Box::new(SeverityOverride {
+                inner: detector,
+                severity,
+            }),
+            None => detector,
+        }
+    }
+}
+
+/// Detector wrapper that raises the severity of another detector to a floor
+///
+/// Matches the inner detector already rates higher keep their severity, so
+/// an override cannot downgrade a finding. Context analysis can still
+/// upgrade the severity afterwards.
+struct SeverityOverride {
+    inner: Box<dyn Detector>,
+    severity: Severity,
+}
+
+impl Detector for SeverityOverride {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn country(&self) -> &str {
+        self.inner.country()
+    }
+
+    fn base_severity(&self) -> Severity {
+        self.inner.base_severity().max(self.severity)
+    }
+
+    fn detect(&self, text: &str, file_path: &std::path::Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    fn detect_in_context(
+        &self,
+        text: &str,
+        file_path: &std::path::Path,
+        context: &ScanContext,
+    ) -> Vec<Match> {
+        let mut matches = self.inner.detect_in_context(text, file_path, context);
+        for m in &mut matches {
+            m.severity = m.severity.max(self.severity);
+        }
+        matches
+    }
+
+    fn multiline(&self) -> bool {
+        self.inner.multiline()
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        self.inner.supersedes()
+    }
+
+    fn config_key(&self) -> String {
+        format!("{} severity>={:?}", self.inner.config_key(), self.severity)
+    }
+
+    fn warmup(&self) {
+        self.inner.warmup()
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        self.inner.validate(value)
+    }
+
+    fn description(&self) -> Option<String> {
+        self.inner.description()
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        self.inner.example_valid()
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        self.inner.example_invalid()
+    }
+}
@@ -0,0 +1,265 @@
+/// Locale-dependent parsing settings shared with detectors
+///
+/// A [`ScanContext`] is handed to every detector through
+/// [`Detector::detect_in_context`](crate::core::Detector::detect_in_context).
+/// Its [`Locale`] decides how ambiguous dates like `03/04/2020` are read
+/// (day-month-year in `en-GB`, month-day-year in `en-US`) and which
+/// thousands and decimal separators numbers use. Its [`Strictness`] decides
+/// how much false-positive risk a scan accepts.
+use crate::core::types::Confidence;
+use chrono::NaiveDate;
+
+/// Field order of numeric dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// Day, month, year (03/04/2020 is 3 April)
+    Dmy,
+    /// Month, day, year (03/04/2020 is 4 March)
+    Mdy,
+    /// Year, month, day (2020/04/03)
+    Ymd,
+}
+
+/// Date order and number separators of a locale tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    /// BCP 47 tag, e.g. `en-GB`
+    pub tag: String,
+    pub date_order: DateOrder,
+    pub thousands_separator: char,
+    pub decimal_separator: char,
+}
+
+/// Supported tags with their date order and thousands/decimal separators
+const LOCALES: &[(&str, DateOrder, char, char)] = &[
+    ("en-GB", DateOrder::Dmy, ',', '.'),
+    ("en-IE", DateOrder::Dmy, ',', '.'),
+    ("en-US", DateOrder::Mdy, ',', '.'),
+    ("nl-NL", DateOrder::Dmy, '.', ','),
+    ("nl-BE", DateOrder::Dmy, '.', ','),
+    ("de-DE", DateOrder::Dmy, '.', ','),
+    ("de-AT", DateOrder::Dmy, '.', ','),
+    ("de-CH", DateOrder::Dmy, '\'', '.'),
+    ("fr-FR", DateOrder::Dmy, ' ', ','),
+    ("fr-BE", DateOrder::Dmy, ' ', ','),
+    ("es-ES", DateOrder::Dmy, '.', ','),
+    ("it-IT", DateOrder::Dmy, '.', ','),
+    ("pt-PT", DateOrder::Dmy, ' ', ','),
+    ("pl-PL", DateOrder::Dmy, ' ', ','),
+    ("sv-SE", DateOrder::Ymd, ' ', ','),
+    ("da-DK", DateOrder::Dmy, '.', ','),
+    ("nb-NO", DateOrder::Dmy, ' ', ','),
+    ("fi-FI", DateOrder::Dmy, ' ', ','),
+];
+
+impl Locale {
+    /// Look up a locale tag (case-insensitive, `_` accepted for `-`)
+    pub fn parse(tag: &str) -> Result<Self, String> {
+        let wanted = tag.trim().replace('_', "-");
+
+        LOCALES
+            .iter()
+            .find(|(known, ..)| known.eq_ignore_ascii_case(&wanted))
+            .map(|&(known, date_order, thousands, decimal)| Self {
+                tag: known.to_string(),
+                date_order,
+                thousands_separator: thousands,
+                decimal_separator: decimal,
+            })
+            .ok_or_else(|| {
+                let tags: Vec<&str> = LOCALES.iter().map(|(t, ..)| *t).collect();
+                format!("Unknown locale '{}' (supported: {})", tag, tags.join(", "))
+            })
+    }
+
+    /// Parse a numeric date (`03/04/2020`, `3-4-20`, `2020.04.03`) in this locale's order
+    ///
+    /// Four-digit leading fields are always read as year-month-day. Two-digit
+    /// years are placed in 1930-2029.
+    pub fn parse_date(&self, value: &str) -> Option<NaiveDate> {
+        let fields: Vec<&str> = value.split(['/', '-', '.']).collect();
+        let [a, b, c] = fields.as_slice() else {
+            return None;
+        };
+
+        let order = if a.len() == 4 {
+            DateOrder::Ymd
+        } else {
+            self.date_order
+        };
+        let (year, month, day) = match order {
+            DateOrder::Dmy => (c, b, a),
+            DateOrder::Mdy => (c, a, b),
+            DateOrder::Ymd => (a, b, c),
+        };
+
+        let mut year: i32 = year.parse().ok()?;
+        if year < 100 {
+            year += if year < 30 { 2000 } else { 1900 };
+        }
+        NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Rewrite a locale-formatted number to plain digits with a `.` decimal point
+    ///
+    /// `1.234,56` becomes `1234.56` in `de-DE`. Returns `None` when `value`
+    /// is not a number in this locale, e.g. `1,234.56` in `de-DE` or a
+    /// thousands separator that does not group three digits.
+    pub fn normalize_number(&self, value: &str) -> Option<String> {
+        let (sign, unsigned) = match value.strip_prefix(['-', '+']) {
+            Some(rest) => (&value[..1], rest),
+            None => ("", value),
+        };
+        let (whole, fraction) = match unsigned.split_once(self.decimal_separator) {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let groups: Vec<&str> = whole.split(self.thousands_separator).collect();
+        let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        let grouped = groups.len() == 1
+            || (groups[0].len() <= 3 && groups[1..].iter().all(|group| group.len() == 3));
+        if !groups.iter().all(|group| digits(group)) || !grouped {
+            return None;
+        }
+
+        let mut normalized = format!("{}{}", sign, groups.concat());
+        if let Some(fraction) = fraction {
+            if !digits(fraction) {
+                return None;
+            }
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+        Some(normalized)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::parse("en-GB").expect("en-GB is a supported locale")
+    }
+}
+
+/// False-positive tolerance of a scan
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strictness {
+    /// Only checksum-valid, High-confidence matches
+    Lenient,
+    /// The configured `--min-confidence`; pattern-only matches are dropped
+    #[default]
+    Balanced,
+    /// Everything, including pattern-only Low matches: values failing their
+    /// checksum and keyword-gated candidates without a keyword
+    Paranoid,
+}
+
+impl Strictness {
+    /// Lowest confidence to report, given the configured minimum
+    pub fn min_confidence(self, configured: Confidence) -> Confidence {
+        match self {
+            Strictness::Lenient => Confidence::High,
+            Strictness::Balanced => configured,
+            Strictness::Paranoid => Confidence::Low,
+        }
+    }
+
+    /// Whether detectors should report well-formed values that fail their
+    /// checksum or lack the keyword their gate requires
+    pub fn reports_pattern_only(self) -> bool {
+        self == Strictness::Paranoid
+    }
+}
+
+/// Scan-wide settings available to detectors
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanContext {
+    pub locale: Locale,
+    pub strictness: Strictness,
+}
+
+impl ScanContext {
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            strictness: Strictness::default(),
+        }
+    }
+
+    /// Set the false-positive tolerance
+    pub fn strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strictness_min_confidence() {
+        assert_eq!(
+            Strictness::Lenient.min_confidence(Confidence::Low),
+            Confidence::High
+        );
+        assert_eq!(
+            Strictness::Balanced.min_confidence(Confidence::Medium),
+            Confidence::Medium
+        );
+        assert_eq!(
+            Strictness::Paranoid.min_confidence(Confidence::High),
+            Confidence::Low
+        );
+    }
+
+    #[test]
+    fn test_parse_locale_tags() {
+        assert_eq!(Locale::parse("en_us").unwrap().tag, "en-US");
+        assert_eq!(Locale::default().date_order, DateOrder::Dmy);
+        assert!(Locale::parse("xx-XX").unwrap_err().contains("en-GB"));
+    }
+
+    #[test]
+    fn test_parse_date_order() {
+        let gb = Locale::parse("en-GB").unwrap();
+        let us = Locale::parse("en-US").unwrap();
+
+        assert_eq!(
+            gb.parse_date("03/04/2020"),
+            NaiveDate::from_ymd_opt(2020, 4, 3)
+        );
+        assert_eq!(
+            us.parse_date("03/04/2020"),
+            NaiveDate::from_ymd_opt(2020, 3, 4)
+        );
+        assert_eq!(
+            us.parse_date("2020-04-03"),
+            NaiveDate::from_ymd_opt(2020, 4, 3)
+        );
+        assert_eq!(
+            gb.parse_date("25.12.85"),
+            NaiveDate::from_ymd_opt(1985, 12, 25)
+        );
+        assert_eq!(us.parse_date("25/12/1985"), None);
+    }
+
+    #[test]
+    fn test_normalize_number() {
+        let de = Locale::parse("de-DE").unwrap();
+        let us = Locale::parse("en-US").unwrap();
+        let fr = Locale::parse("fr-FR").unwrap();
+
+        assert_eq!(de.normalize_number("1.234,56").as_deref(), Some("1234.56"));
+        assert_eq!(us.normalize_number("1,234.56").as_deref(), Some("1234.56"));
+        assert_eq!(fr.normalize_number("1 234,5").as_deref(), Some("1234.5"));
+        assert_eq!(de.normalize_number("-52,3702").as_deref(), Some("-52.3702"));
+        assert_eq!(us.normalize_number("52.3702").as_deref(), Some("52.3702"));
+
+        // The other convention's separators are not numbers in this locale
+        assert_eq!(de.normalize_number("1,234.56"), None);
+        assert_eq!(us.normalize_number("1.234,56"), None);
+        assert_eq!(us.normalize_number("12,34"), None);
+        assert_eq!(de.normalize_number("1,2,3"), None);
+    }
+}
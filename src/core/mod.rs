@@ -1,10 +1,20 @@
 pub mod context;
 pub mod detector;
+pub mod locale;
 pub mod plugin;
+pub mod risk;
 /// Core types and traits for PII-Radar
 pub mod types;
+/// Sandboxed WebAssembly plugin validators
+#[cfg(feature = "plugins-wasm")]
+pub mod wasm;
 
 pub use context::*;
-pub use detector::{Detector, DetectorRegistry};
+pub use detector::{
+    Detector, DetectorConflict, DetectorRegistry, DuplicatePolicy, SeverityOverrides,
+    SPLIT_DETECTOR_IDS,
+};
+pub use locale::{DateOrder, Locale, ScanContext, Strictness};
 pub use plugin::*;
+pub use risk::{RiskLevel, RiskWeights};
 pub use types::*;
@@ -12,14 +12,20 @@
 /// pattern = "\\b\\d{3}-\\d{2}-\\d{4}\\b"
 /// severity = "critical"
 /// confidence = "medium"
+/// # Optional: tag every match as GDPR Art. 9/10 data and show an icon in reports
+/// gdpr_special_category = "medical"
+/// icon = "🏥"
 ///
 /// [validation]
 /// # Optional: Validation rules
 /// min_length = 11
 /// max_length = 11
 /// checksum = "none"
+/// # Optional (`plugins-wasm` feature): module exporting `validate(ptr, len) -> i32`,
+/// # relative to the plugin file
+/// wasm = "my_detector.wasm"
 /// ```
-use crate::core::{Confidence, Detector, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity, SpecialCategory};
 use regex::Regex;
 use serde::Deserialize;
 use std::fs;
@@ -45,6 +51,12 @@ pub struct DetectorConfig {
     pub confidence: ConfidenceLevel,
     #[serde(default)]
     pub description: Option<String>,
+    /// Special category every match is tagged with (e.g. "medical")
+    #[serde(default)]
+    pub gdpr_special_category: Option<SpecialCategory>,
+    /// Icon shown next to matches in terminal and HTML reports
+    #[serde(default)]
+    pub icon: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -57,6 +69,9 @@ pub struct ValidationConfig {
     pub checksum: ChecksumType,
     #[serde(default)]
     pub allowed_chars: Option<String>,
+    /// WebAssembly module that accepts or rejects each candidate
+    #[serde(default)]
+    pub wasm: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize)]
@@ -92,6 +107,7 @@ impl Default for ValidationConfig {
             max_length: None,
             checksum: ChecksumType::None,
             allowed_chars: None,
+            wasm: None,
         }
     }
 }
@@ -133,15 +149,45 @@ impl From<ConfidenceLevel> for Confidence {
 pub struct PluginDetector {
     config: PluginConfig,
     pattern: Regex,
+    #[cfg(feature = "plugins-wasm")]
+    wasm_validator: Option<crate::core::wasm::WasmValidator>,
 }
 
 impl PluginDetector {
     /// Create a new plugin detector from configuration
+    ///
+    /// A relative `validation.wasm` path is resolved against the working directory.
     pub fn new(config: PluginConfig) -> Result<Self, String> {
+        Self::with_base_dir(config, Path::new("."))
+    }
+
+    /// Create a plugin detector, resolving a relative `validation.wasm` path against `base_dir`
+    fn with_base_dir(config: PluginConfig, base_dir: &Path) -> Result<Self, String> {
         let pattern = Regex::new(&config.detector.pattern)
             .map_err(|e| format!("Invalid regex pattern: {}", e))?;
 
-        Ok(Self { config, pattern })
+        #[cfg(feature = "plugins-wasm")]
+        let wasm_validator = config
+            .validation
+            .wasm
+            .as_ref()
+            .map(|wasm| crate::core::wasm::WasmValidator::from_file(&base_dir.join(wasm)))
+            .transpose()?;
+
+        #[cfg(not(feature = "plugins-wasm"))]
+        if let Some(wasm) = &config.validation.wasm {
+            return Err(format!(
+                "WASM validator {} requires pii-radar built with the plugins-wasm feature",
+                base_dir.join(wasm).display()
+            ));
+        }
+
+        Ok(Self {
+            config,
+            pattern,
+            #[cfg(feature = "plugins-wasm")]
+            wasm_validator,
+        })
     }
 
     /// Load a plugin from a TOML file
@@ -152,7 +198,7 @@ impl PluginDetector {
         let config: PluginConfig =
             toml::from_str(&contents).map_err(|e| format!("Failed to parse plugin TOML: {}", e))?;
 
-        Self::new(config)
+        Self::with_base_dir(config, path.parent().unwrap_or(Path::new(".")))
     }
 
     /// Validate a value according to the plugin's validation rules
@@ -179,12 +225,20 @@ impl PluginDetector {
         }
 
         // Checksum validation
-        match validation.checksum {
+        let checksum_ok = match validation.checksum {
             ChecksumType::None => true,
             ChecksumType::Luhn => self.validate_luhn(value),
             ChecksumType::Mod97 => self.validate_mod97(value),
             ChecksumType::Mod11 => self.validate_mod11(value),
+        };
+
+        // The WASM validator runs last, only on otherwise valid candidates
+        #[cfg(feature = "plugins-wasm")]
+        if let Some(validator) = &self.wasm_validator {
+            return checksum_ok && validator.validate(value);
         }
+
+        checksum_ok
     }
 
     fn validate_luhn(&self, value: &str) -> bool {
@@ -295,7 +349,22 @@ impl Detector for PluginDetector {
                         confidence: self.config.detector.confidence.into(),
                         severity: self.base_severity(),
                         context: None,
-                        gdpr_category: crate::core::types::GdprCategory::Regular,
+                        gdpr_category: match self.config.detector.gdpr_special_category {
+                            Some(category) => GdprCategory::Special {
+                                category,
+                                detected_keywords: Vec::new(),
+                            },
+                            None => GdprCategory::Regular,
+                        },
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: self.config.detector.icon.clone(),
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -305,6 +374,10 @@ impl Detector for PluginDetector {
         matches
     }
 
+    fn config_key(&self) -> String {
+        format!("{:?}", self.config)
+    }
+
     fn validate(&self, value: &str) -> bool {
         self.pattern.is_match(value) && self.validate_value(value)
     }
@@ -396,6 +469,8 @@ max_length = 11
                 severity: SeverityLevel::High,
                 confidence: ConfidenceLevel::Medium,
                 description: None,
+                gdpr_special_category: None,
+                icon: None,
             },
             validation: ValidationConfig::default(),
         };
@@ -416,6 +491,8 @@ max_length = 11
                 severity: SeverityLevel::High,
                 confidence: ConfidenceLevel::High,
                 description: None,
+                gdpr_special_category: None,
+                icon: None,
             },
             validation: ValidationConfig::default(),
         };
@@ -439,6 +516,8 @@ max_length = 11
                 severity: SeverityLevel::Critical,
                 confidence: ConfidenceLevel::High,
                 description: None,
+                gdpr_special_category: None,
+                icon: None,
             },
             validation: ValidationConfig {
                 checksum: ChecksumType::Luhn,
@@ -489,6 +568,8 @@ confidence = "high"
                 severity: SeverityLevel::High,
                 confidence: ConfidenceLevel::High,
                 description: None,
+                gdpr_special_category: None,
+                icon: None,
             },
             validation: ValidationConfig {
                 min_length: Some(5),
@@ -509,4 +590,107 @@ confidence = "high"
         // Too long
         assert!(!detector.validate("12345678901"));
     }
+
+    #[test]
+    fn test_plugin_special_category_and_icon() {
+        let toml_str = r#"
+[detector]
+id = "health_record"
+name = "Health Record Number"
+country = "nl"
+pattern = "\\bHRN-\\d{6}\\b"
+gdpr_special_category = "medical"
+icon = "🏥"
+"#;
+
+        let config: PluginConfig = toml::from_str(toml_str).unwrap();
+        let detector = PluginDetector::new(config).unwrap();
+        let matches = detector.detect("Record HRN-123456", Path::new("export.csv"));
+
+        assert_eq!(matches.len(), 1);
+        assert!(matches!(
+            matches[0].gdpr_category,
+            GdprCategory::Special {
+                category: SpecialCategory::Medical,
+                ..
+            }
+        ));
+        assert_eq!(matches[0].icon.as_deref(), Some("🏥"));
+    }
+
+    #[test]
+    fn test_plugin_invalid_special_category() {
+        let temp_dir = TempDir::new().unwrap();
+        let plugin_path = temp_dir.path().join("bad.toml");
+        fs::write(
+            &plugin_path,
+            "[detector]\nid = \"x\"\nname = \"X\"\ncountry = \"xx\"\npattern = \"X\\\\d+\"\ngdpr_special_category = \"health\"\n",
+        )
+        .unwrap();
+
+        let err = PluginDetector::from_file(&plugin_path).err().unwrap();
+        assert!(err.contains("unknown variant `health`"), "{}", err);
+        assert!(err.contains("`medical`"), "{}", err);
+    }
+
+    #[cfg(feature = "plugins-wasm")]
+    #[test]
+    fn test_plugin_wasm_validator() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // Accepts candidates starting with "OK"
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "validate") (param $ptr i32) (param $len i32) (result i32)
+                    (if (result i32) (i32.lt_u (local.get $len) (i32.const 2))
+                        (then (i32.const 0))
+                        (else
+                            (i32.and
+                                (i32.eq (i32.load8_u (local.get $ptr)) (i32.const 79))
+                                (i32.eq (i32.load8_u offset=1 (local.get $ptr)) (i32.const 75)))))))"#,
+        )
+        .unwrap();
+        fs::create_dir(temp_dir.path().join("validators")).unwrap();
+        fs::write(temp_dir.path().join("validators").join("ok.wasm"), wasm).unwrap();
+
+        let plugin_path = temp_dir.path().join("ticket.toml");
+        fs::write(
+            &plugin_path,
+            r#"
+[detector]
+id = "ticket_code"
+name = "Ticket Code"
+country = "xx"
+pattern = '\b[A-Z]{2}-\d{4}\b'
+
+[validation]
+wasm = "validators/ok.wasm"
+"#,
+        )
+        .unwrap();
+
+        let detector = PluginDetector::from_file(&plugin_path).unwrap();
+        let matches = detector.detect("codes: OK-1234, NO-5678, OK-9999", Path::new("t.txt"));
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].location.column, 7);
+        assert_eq!(matches[1].location.column, 25);
+        assert!(detector.validate("OK-1234"));
+        assert!(!detector.validate("NO-5678"));
+    }
+
+    #[cfg(not(feature = "plugins-wasm"))]
+    #[test]
+    fn test_plugin_wasm_requires_feature() {
+        let temp_dir = TempDir::new().unwrap();
+        let plugin_path = temp_dir.path().join("ticket.toml");
+        fs::write(
+            &plugin_path,
+            "[detector]\nid = \"x\"\nname = \"X\"\ncountry = \"xx\"\npattern = \"X\\\\d+\"\n\n[validation]\nwasm = \"ok.wasm\"\n",
+        )
+        .unwrap();
+
+        let err = PluginDetector::from_file(&plugin_path).err().unwrap();
+        assert!(err.contains("plugins-wasm"), "{}", err);
+    }
 }
@@ -0,0 +1,207 @@
+/// Confidence-weighted risk score for a scan
+///
+/// Every match contributes `severity weight × confidence weight`, multiplied
+/// by `special_category` for GDPR Art. 9/10 data. The sum is mapped onto
+/// 0-100 with `100 × (1 - e^(-sum / saturation))`, so the score grows
+/// quickly for the first serious findings and approaches 100 for large
+/// exposures instead of growing without bound.
+use super::{Confidence, GdprCategory, Match, Severity};
+use serde::{Deserialize, Serialize};
+
+/// Weights used to compute the risk score (`[risk]` config section)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RiskWeights {
+    /// Weight of a Low severity match
+    pub low: f64,
+    /// Weight of a Medium severity match
+    pub medium: f64,
+    /// Weight of a High severity match
+    pub high: f64,
+    /// Weight of a Critical severity match
+    pub critical: f64,
+    /// Multiplier for Low confidence matches
+    pub confidence_low: f64,
+    /// Multiplier for Medium confidence matches
+    pub confidence_medium: f64,
+    /// Multiplier for High confidence matches
+    pub confidence_high: f64,
+    /// Extra multiplier for special category (Art. 9/10) matches
+    pub special_category: f64,
+    /// Weighted sum at which the score reaches ~63
+    pub saturation: f64,
+}
+
+impl Default for RiskWeights {
+    fn default() -> Self {
+        Self {
+            low: 1.0,
+            medium: 3.0,
+            high: 7.0,
+            critical: 10.0,
+            confidence_low: 0.3,
+            confidence_medium: 0.6,
+            confidence_high: 1.0,
+            special_category: 2.0,
+            saturation: 50.0,
+        }
+    }
+}
+
+impl RiskWeights {
+    /// Weighted contribution of a single match
+    pub fn weight(&self, m: &Match) -> f64 {
+        let severity = match m.severity {
+            Severity::Low => self.low,
+            Severity::Medium => self.medium,
+            Severity::High => self.high,
+            Severity::Critical => self.critical,
+        };
+        let confidence = match m.confidence {
+            Confidence::Low => self.confidence_low,
+            Confidence::Medium => self.confidence_medium,
+            Confidence::High => self.confidence_high,
+        };
+        let special = match m.gdpr_category {
+            GdprCategory::Special { .. } => self.special_category,
+            GdprCategory::Regular => 1.0,
+        };
+
+        severity * confidence * special
+    }
+
+    /// Risk score (0-100) for `matches`
+    pub fn score<'a>(&self, matches: impl IntoIterator<Item = &'a Match>) -> f64 {
+        let sum: f64 = matches.into_iter().map(|m| self.weight(m)).sum();
+        if sum <= 0.0 || self.saturation <= 0.0 {
+            return 0.0;
+        }
+
+        let score = 100.0 * (1.0 - (-sum / self.saturation).exp());
+        (score * 10.0).round() / 10.0
+    }
+}
+
+/// Risk level label for a score
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    /// No matches
+    #[default]
+    None,
+    /// Score below 25
+    Low,
+    /// Score 25-50
+    Medium,
+    /// Score 50-75
+    High,
+    /// Score 75 and above
+    Critical,
+}
+
+impl RiskLevel {
+    pub fn from_score(score: f64) -> Self {
+        match score {
+            s if s <= 0.0 => RiskLevel::None,
+            s if s < 25.0 => RiskLevel::Low,
+            s if s < 50.0 => RiskLevel::Medium,
+            s if s < 75.0 => RiskLevel::High,
+            _ => RiskLevel::Critical,
+        }
+    }
+}
+
+impl std::fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            RiskLevel::None => "none",
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+            RiskLevel::Critical => "critical",
+        };
+        f.write_str(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileResult, Location, ScanResults, SpecialCategory};
+    use std::path::PathBuf;
+
+    fn test_match(severity: Severity, gdpr_category: GdprCategory) -> Match {
+        Match {
+            detector_id: "test".to_string(),
+            detector_name: "Test".to_string(),
+            country: "nl".to_string(),
+            value_masked: "***".to_string(),
+            location: Location {
+                file_path: PathBuf::from("test.txt"),
+                line: 1,
+                column: 0,
+                start_byte: 0,
+                end_byte: 3,
+            },
+            confidence: Confidence::High,
+            severity,
+            context: None,
+            gdpr_category,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        }
+    }
+
+    fn results_with(m: Match) -> ScanResults {
+        let mut file = FileResult::new(PathBuf::from("test.txt"));
+        file.matches.push(m);
+        ScanResults::aggregate(vec![file])
+    }
+
+    #[test]
+    fn test_art9_critical_outscores_lone_low() {
+        let critical = results_with(test_match(
+            Severity::Critical,
+            GdprCategory::Special {
+                category: SpecialCategory::Medical,
+                detected_keywords: vec!["diagnose".to_string()],
+            },
+        ));
+        let low = results_with(test_match(Severity::Low, GdprCategory::Regular));
+
+        assert!(critical.risk_score > low.risk_score);
+        assert!(low.risk_score > 0.0);
+        assert!(critical.risk_level > low.risk_level);
+        assert!(critical.risk_score <= 100.0);
+    }
+
+    #[test]
+    fn test_empty_scan_has_no_risk() {
+        let results = ScanResults::aggregate(Vec::new());
+        assert_eq!(results.risk_score, 0.0);
+        assert_eq!(results.risk_level, RiskLevel::None);
+    }
+
+    #[test]
+    fn test_custom_weights() {
+        let mut results = results_with(test_match(Severity::Low, GdprCategory::Regular));
+        let default_score = results.risk_score;
+
+        results.compute_risk(&RiskWeights {
+            low: 100.0,
+            ..RiskWeights::default()
+        });
+        assert!(results.risk_score > default_score);
+        assert_eq!(
+            RiskLevel::from_score(results.risk_score),
+            results.risk_level
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::risk::{RiskLevel, RiskWeights};
 /// Core type definitions for PII detection results
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -31,6 +32,88 @@ pub struct Match {
 
     /// GDPR categorization
     pub gdpr_category: GdprCategory,
+
+    /// Location-independent fingerprint for tracking findings across scans
+    #[serde(default)]
+    pub fingerprint: String,
+
+    /// Whether the value was found inside a base64-encoded blob
+    #[serde(default)]
+    pub decoded_from_base64: bool,
+
+    /// Whether the value was already partially masked in the source (e.g. `XXX-XX-6789`)
+    #[serde(default)]
+    pub partially_masked: bool,
+
+    /// Holder nationality (ICAO country code) read from a travel document MRZ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<String>,
+
+    /// Issuing state (ICAO country code) read from a travel document MRZ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuing_country: Option<String>,
+
+    /// Card network for payment card matches (e.g. "Visa", "Maestro")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+
+    /// Display icon (e.g. an emoji) set by plugin detectors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// Signals co-occurring with the value that raised its severity (e.g.
+    /// `timestamp` for an IP address on an access-log line)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub correlated_with: Vec<String>,
+
+    /// Property or column the value was extracted from (e.g. `TEL` in a
+    /// vCard, `users.email` in a SQL dump)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_path: Option<String>,
+}
+
+impl Match {
+    /// Compute a stable fingerprint for this match within `text`
+    ///
+    /// Hashes the detector id, the surrounding line (with the matched value
+    /// removed and whitespace collapsed) and the masked value. Line numbers
+    /// and byte offsets are not part of the hash, so edits above the match
+    /// do not change the fingerprint.
+    pub fn compute_fingerprint(&self, text: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let start = self.location.start_byte.min(text.len());
+        let end = self.location.end_byte.clamp(start, text.len());
+
+        let line_start = text
+            .get(..start)
+            .and_then(|before| before.rfind('\n'))
+            .map_or(0, |i| i + 1);
+        let line_end = text
+            .get(end..)
+            .and_then(|after| after.find('\n'))
+            .map_or(text.len(), |i| end + i);
+
+        let before = text.get(line_start..start).unwrap_or("");
+        let after = text.get(end..line_end).unwrap_or("");
+        let context = before
+            .split_whitespace()
+            .chain(after.split_whitespace())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.detector_id.as_bytes());
+        hasher.update([0]);
+        hasher.update(context.as_bytes());
+        hasher.update([0]);
+        hasher.update(self.value_masked.as_bytes());
+
+        hasher.finalize()[..16]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
 }
 
 /// Location of a match within a file
@@ -52,6 +135,20 @@ pub struct Location {
     pub end_byte: usize,
 }
 
+impl Location {
+    /// Location of `start..end` in a whole document, counting lines from its newlines
+    pub fn in_text(file_path: &std::path::Path, text: &str, start: usize, end: usize) -> Self {
+        let before = &text[..start];
+        Self {
+            file_path: file_path.to_path_buf(),
+            line: before.matches('\n').count() + 1,
+            column: start - before.rfind('\n').map_or(0, |i| i + 1),
+            start_byte: start,
+            end_byte: end,
+        }
+    }
+}
+
 /// Confidence level of a PII detection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -134,7 +231,7 @@ pub enum GdprCategory {
 }
 
 /// Types of GDPR special category data
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SpecialCategory {
     /// Health and medical data (GDPR Art. 9(1))
@@ -214,6 +311,18 @@ pub struct FileResult {
 
     /// Error message if scan failed
     pub error: Option<String>,
+
+    /// Category of `error`, set together with it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_category: Option<ScanErrorCategory>,
+
+    /// SHA-256 of the file content as hex (set with `--hash-files`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+
+    /// Matches dropped by `pii-radar:ignore` comments
+    #[serde(default)]
+    pub suppressed: usize,
 }
 
 impl FileResult {
@@ -224,20 +333,122 @@ impl FileResult {
             size_bytes: 0,
             scan_time_ms: 0,
             error: None,
+            error_category: None,
+            content_hash: None,
+            suppressed: 0,
         }
     }
 
-    pub fn with_error(path: PathBuf, error: String) -> Self {
-        Self {
-            path,
-            matches: Vec::new(),
-            size_bytes: 0,
-            scan_time_ms: 0,
-            error: Some(error),
+    /// Hash of the scan outcome (size, error and match fingerprints), ignoring timing
+    pub fn result_hash(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut fingerprints: Vec<String> = self
+            .matches
+            .iter()
+            .map(|m| {
+                format!(
+                    "{}:{}:{}:{}",
+                    m.detector_id, m.location.start_byte, m.location.end_byte, m.fingerprint
+                )
+            })
+            .collect();
+        fingerprints.sort();
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.size_bytes.to_le_bytes());
+        hasher.update(self.error.as_deref().unwrap_or("").as_bytes());
+        for fingerprint in &fingerprints {
+            hasher.update([0]);
+            hasher.update(fingerprint.as_bytes());
+        }
+
+        hasher.finalize()[..16]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    pub fn with_error(path: PathBuf, category: ScanErrorCategory, error: String) -> Self {
+        let mut result = Self::new(path);
+        result.set_error(category, error);
+        result
+    }
+
+    /// Record a scan failure and its category
+    pub fn set_error(&mut self, category: ScanErrorCategory, error: String) {
+        self.error = Some(error);
+        self.error_category = Some(category);
+    }
+}
+
+/// Category of a per-file scan failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScanErrorCategory {
+    /// File could not be read (I/O error, network error, ...)
+    Read,
+
+    /// File content could not be decoded as text
+    Decode,
+
+    /// Document text extraction failed (PDF, DOCX, XLSX)
+    Extraction,
+
+    /// Access to the file was denied
+    Permission,
+}
+
+impl ScanErrorCategory {
+    /// Category of an I/O error while reading a file
+    pub fn from_io(error: &std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::PermissionDenied => ScanErrorCategory::Permission,
+            std::io::ErrorKind::InvalidData => ScanErrorCategory::Decode,
+            _ => ScanErrorCategory::Read,
+        }
+    }
+}
+
+impl std::fmt::Display for ScanErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScanErrorCategory::Read => write!(f, "read"),
+            ScanErrorCategory::Decode => write!(f, "decode"),
+            ScanErrorCategory::Extraction => write!(f, "extraction"),
+            ScanErrorCategory::Permission => write!(f, "permission"),
         }
     }
 }
 
+/// A file that failed to scan, collected for machine-readable reporting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanError {
+    /// Path of the file that failed
+    pub path: PathBuf,
+
+    /// Failure category
+    pub category: ScanErrorCategory,
+
+    /// Error message
+    pub message: String,
+}
+
+impl ScanError {
+    /// Build a scan error from a failed file result
+    pub fn from_file_result(file: &FileResult) -> Option<Self> {
+        file.error.as_ref().map(|message| Self {
+            path: file.path.clone(),
+            category: file.error_category.unwrap_or(ScanErrorCategory::Read),
+            message: message.clone(),
+        })
+    }
+}
+
+/// Keyword frequencies per special category, see [`ScanResults::gdpr_keyword_summary`]
+pub type GdprKeywordSummary =
+    std::collections::BTreeMap<SpecialCategory, std::collections::BTreeMap<String, usize>>;
+
 /// Aggregated scan results for entire directory tree
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanResults {
@@ -267,6 +478,54 @@ pub struct ScanResults {
 
     /// Number of extraction failures
     pub extraction_failures: usize,
+
+    /// Files that failed to scan (read, decode, extraction, permission)
+    #[serde(default)]
+    pub errors: Vec<ScanError>,
+
+    /// Confidence-weighted risk score (0-100)
+    #[serde(default)]
+    pub risk_score: f64,
+
+    /// Label for `risk_score`
+    #[serde(default)]
+    pub risk_level: RiskLevel,
+
+    /// Matches per detector ID and confidence, as `[low, medium, high]`
+    ///
+    /// Counted before `filter_by_confidence`, so it shows how many matches
+    /// each `--min-confidence` threshold removes.
+    #[serde(default)]
+    pub confidence_breakdown: std::collections::HashMap<String, [usize; 3]>,
+
+    /// Files whose matches were copied from an identical file (`--dedup-files`)
+    #[serde(default)]
+    pub duplicate_files: usize,
+
+    /// Matches dropped by inline `pii-radar:ignore` comments
+    #[serde(default)]
+    pub suppressed_matches: usize,
+
+    /// Set when only a random sample of the discovered files was scanned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample: Option<SampleInfo>,
+
+    /// Set when `--max-files` or `--max-bytes` stopped the scan early
+    #[serde(default)]
+    pub limit_truncated: bool,
+}
+
+/// How a sampled scan (`--sample-percent`) picked its files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SampleInfo {
+    /// Percentage of discovered files that was scanned
+    pub percent: u8,
+
+    /// RNG seed; the same seed over the same file list picks the same files
+    pub seed: u64,
+
+    /// Files discovered before sampling
+    pub discovered_files: usize,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -289,6 +548,14 @@ impl ScanResults {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         }
     }
 
@@ -298,9 +565,15 @@ impl ScanResults {
         let total_bytes = files.iter().map(|f| f.size_bytes).sum();
         let total_time_ms = files.iter().map(|f| f.scan_time_ms).sum();
         let total_matches = files.iter().map(|f| f.matches.len()).sum();
+        let suppressed_matches = files.iter().map(|f| f.suppressed).sum();
 
         let mut by_severity = SeverityCounts::default();
         let mut by_country = std::collections::HashMap::new();
+        let mut confidence_breakdown = std::collections::HashMap::new();
+        let errors = files
+            .iter()
+            .filter_map(ScanError::from_file_result)
+            .collect();
 
         for file in &files {
             for m in &file.matches {
@@ -312,10 +585,19 @@ impl ScanResults {
                 }
 
                 *by_country.entry(m.country.clone()).or_insert(0) += 1;
+
+                let bucket = match m.confidence {
+                    Confidence::Low => 0,
+                    Confidence::Medium => 1,
+                    Confidence::High => 2,
+                };
+                confidence_breakdown
+                    .entry(m.detector_id.clone())
+                    .or_insert([0; 3])[bucket] += 1;
             }
         }
 
-        Self {
+        let mut results = Self {
             files,
             total_files,
             total_bytes,
@@ -325,13 +607,30 @@ impl ScanResults {
             by_country,
             extracted_files: 0,     // Will be calculated in scan_directory
             extraction_failures: 0, // Will be calculated in scan_directory
-        }
+            errors,
+            risk_score: 0.0,
+            risk_level: RiskLevel::None,
+            confidence_breakdown,
+            duplicate_files: 0,
+            suppressed_matches,
+            sample: None,
+            limit_truncated: false,
+        };
+        results.compute_risk(&RiskWeights::default());
+        results
+    }
+
+    /// Recompute `risk_score` and `risk_level` with `weights`
+    pub fn compute_risk(&mut self, weights: &RiskWeights) {
+        self.risk_score = weights.score(self.files.iter().flat_map(|f| &f.matches));
+        self.risk_level = RiskLevel::from_score(self.risk_score);
     }
 
     /// Filter matches by minimum confidence level
     ///
     /// Returns a new ScanResults with only matches >= min_confidence.
-    /// Statistics are recalculated based on filtered matches.
+    /// Statistics are recalculated based on filtered matches, except
+    /// `confidence_breakdown`, which keeps the counts from before filtering.
     ///
     /// # Example
     ///
@@ -356,7 +655,93 @@ impl ScanResults {
             .collect();
 
         // Re-aggregate with filtered matches
-        Self::aggregate(filtered_files)
+        let mut filtered = Self::aggregate(filtered_files);
+        filtered.confidence_breakdown = self.confidence_breakdown;
+        filtered.duplicate_files = self.duplicate_files;
+        filtered.sample = self.sample;
+        filtered.limit_truncated = self.limit_truncated;
+        filtered
+    }
+
+    /// Frequency of each context keyword behind special category (Art. 9/10)
+    /// classifications, per category
+    pub fn gdpr_keyword_summary(&self) -> GdprKeywordSummary {
+        let mut summary = GdprKeywordSummary::new();
+
+        for m in self.files.iter().flat_map(|f| &f.matches) {
+            if let GdprCategory::Special {
+                category,
+                detected_keywords,
+            } = &m.gdpr_category
+            {
+                let keywords = summary.entry(*category).or_default();
+                for keyword in detected_keywords {
+                    *keywords.entry(keyword.to_lowercase()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        summary
+    }
+
+    /// Extrapolate a count from a sampled scan to all discovered files
+    ///
+    /// Returns `None` for full scans (and samples without scanned files).
+    pub fn estimated_total(&self, sampled_count: usize) -> Option<usize> {
+        let sample = self.sample?;
+        if self.total_files == 0 {
+            return None;
+        }
+
+        let estimate =
+            sampled_count as f64 * sample.discovered_files as f64 / self.total_files as f64;
+        Some(estimate.round() as usize)
+    }
+
+    /// Expected countries (lowercase codes) without a single match
+    ///
+    /// A gap usually means the data format changed and a country's
+    /// detectors silently stopped matching.
+    pub fn coverage_gaps(&self, expected: &[String]) -> Vec<String> {
+        expected
+            .iter()
+            .map(|country| country.trim().to_lowercase())
+            .filter(|country| !country.is_empty())
+            .filter(|country| self.by_country.get(country).copied().unwrap_or(0) == 0)
+            .collect()
+    }
+
+    /// Merge results from several scan shards into one report
+    ///
+    /// Files are concatenated and totals recomputed. A file reported by more
+    /// than one shard with the same path and result hash is kept only once.
+    pub fn merge(shards: Vec<ScanResults>) -> Self {
+        let mut seen = std::collections::HashSet::new();
+        let mut files = Vec::new();
+        let mut extracted_files = 0;
+        let mut extraction_failures = 0;
+        let mut duplicate_files = 0;
+        let mut limit_truncated = false;
+
+        for shard in shards {
+            extracted_files += shard.extracted_files;
+            extraction_failures += shard.extraction_failures;
+            duplicate_files += shard.duplicate_files;
+            limit_truncated |= shard.limit_truncated;
+
+            for file in shard.files {
+                if seen.insert((file.path.clone(), file.result_hash())) {
+                    files.push(file);
+                }
+            }
+        }
+
+        let mut merged = Self::aggregate(files);
+        merged.extracted_files = extracted_files;
+        merged.extraction_failures = extraction_failures;
+        merged.duplicate_files = duplicate_files;
+        merged.limit_truncated = limit_truncated;
+        merged
     }
 }
 
@@ -387,6 +772,15 @@ mod tests {
             severity,
             context: None,
             gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
         }
     }
 
@@ -416,6 +810,34 @@ mod tests {
         assert_eq!(filtered.by_severity.medium, 0);
     }
 
+    #[test]
+    fn test_confidence_breakdown_per_detector() {
+        let mut file1 = FileResult::new(PathBuf::from("file1.txt"));
+        for confidence in [
+            Confidence::High,
+            Confidence::Low,
+            Confidence::Medium,
+            Confidence::Low,
+        ] {
+            file1
+                .matches
+                .push(create_test_match(confidence, Severity::High, "nl"));
+        }
+        let mut email = create_test_match(Confidence::High, Severity::Low, "universal");
+        email.detector_id = "email".to_string();
+        file1.matches.push(email);
+
+        let results = ScanResults::aggregate(vec![file1]);
+        assert_eq!(results.confidence_breakdown.len(), 2);
+        assert_eq!(results.confidence_breakdown["test"], [2, 1, 1]);
+        assert_eq!(results.confidence_breakdown["email"], [0, 0, 1]);
+
+        // Filtering keeps the pre-filter histogram
+        let filtered = results.filter_by_confidence(Confidence::High);
+        assert_eq!(filtered.total_matches, 2);
+        assert_eq!(filtered.confidence_breakdown["test"], [2, 1, 1]);
+    }
+
     #[test]
     fn test_filter_by_confidence_medium() {
         let mut file1 = FileResult::new(PathBuf::from("file1.txt"));
@@ -535,4 +957,137 @@ mod tests {
         assert_eq!(*filtered.by_country.get("gb").unwrap(), 1);
         assert_eq!(filtered.by_country.get("es"), None);
     }
+
+    #[test]
+    fn test_aggregate_collects_errors() {
+        let ok = FileResult::new(PathBuf::from("ok.txt"));
+        let failed = FileResult::with_error(
+            PathBuf::from("broken.pdf"),
+            ScanErrorCategory::Extraction,
+            "Extraction failed: corrupted file".to_string(),
+        );
+
+        let results = ScanResults::aggregate(vec![ok, failed]);
+        assert_eq!(results.errors.len(), 1);
+        assert_eq!(results.errors[0].path, PathBuf::from("broken.pdf"));
+        assert_eq!(results.errors[0].category, ScanErrorCategory::Extraction);
+
+        // Errors survive confidence filtering
+        let filtered = results.filter_by_confidence(Confidence::High);
+        assert_eq!(filtered.errors.len(), 1);
+    }
+
+    #[test]
+    fn test_scan_error_category_from_io() {
+        use std::io::{Error, ErrorKind};
+
+        assert_eq!(
+            ScanErrorCategory::from_io(&Error::from(ErrorKind::PermissionDenied)),
+            ScanErrorCategory::Permission
+        );
+        assert_eq!(
+            ScanErrorCategory::from_io(&Error::new(ErrorKind::InvalidData, "invalid UTF-8")),
+            ScanErrorCategory::Decode
+        );
+        assert_eq!(
+            ScanErrorCategory::from_io(&Error::from(ErrorKind::NotFound)),
+            ScanErrorCategory::Read
+        );
+
+        // The category is recorded with the error, not read back from its text
+        let file = FileResult::with_error(
+            PathBuf::from("a.txt"),
+            ScanErrorCategory::Permission,
+            "Failed to decode file".to_string(),
+        );
+        assert_eq!(
+            ScanError::from_file_result(&file).unwrap().category,
+            ScanErrorCategory::Permission
+        );
+    }
+
+    #[test]
+    fn test_merge_shards() {
+        let mut a = FileResult::new(PathBuf::from("a.txt"));
+        a.size_bytes = 100;
+        a.matches.push(create_test_match(
+            Confidence::High,
+            Severity::Critical,
+            "nl",
+        ));
+        let mut b = FileResult::new(PathBuf::from("b.txt"));
+        b.size_bytes = 50;
+        b.matches
+            .push(create_test_match(Confidence::High, Severity::High, "gb"));
+        b.matches
+            .push(create_test_match(Confidence::High, Severity::High, "gb"));
+        let c = FileResult::with_error(
+            PathBuf::from("c.pdf"),
+            ScanErrorCategory::Extraction,
+            "Extraction failed: corrupted".to_string(),
+        );
+
+        let mut shard1 = ScanResults::aggregate(vec![a.clone(), b]);
+        shard1.extracted_files = 1;
+        let mut shard2 = ScanResults::aggregate(vec![c]);
+        shard2.extracted_files = 2;
+        shard2.extraction_failures = 1;
+
+        let merged = ScanResults::merge(vec![shard1.clone(), shard2.clone()]);
+        assert_eq!(merged.total_files, shard1.total_files + shard2.total_files);
+        assert_eq!(merged.total_files, 3);
+        assert_eq!(merged.total_matches, 3);
+        assert_eq!(merged.total_bytes, 150);
+        assert_eq!(merged.by_severity.critical, 1);
+        assert_eq!(merged.by_severity.high, 2);
+        assert_eq!(*merged.by_country.get("gb").unwrap(), 2);
+        assert_eq!(merged.errors.len(), 1);
+        assert_eq!(merged.extracted_files, 3);
+        assert_eq!(merged.extraction_failures, 1);
+
+        // The same file scanned by two shards is counted once
+        let mut rescanned = a.clone();
+        rescanned.scan_time_ms = 99;
+        let shard3 = ScanResults::aggregate(vec![rescanned]);
+        let merged = ScanResults::merge(vec![shard1, shard2, shard3]);
+        assert_eq!(merged.total_files, 3);
+        assert_eq!(merged.total_matches, 3);
+
+        // A changed file with the same path is kept
+        let mut changed = a;
+        changed.size_bytes = 120;
+        let merged = ScanResults::merge(vec![merged, ScanResults::aggregate(vec![changed])]);
+        assert_eq!(merged.total_files, 4);
+    }
+
+    #[test]
+    fn test_gdpr_keyword_summary() {
+        let special = |keywords: &[&str]| {
+            let mut m = create_test_match(Confidence::High, Severity::Critical, "nl");
+            m.gdpr_category = GdprCategory::Special {
+                category: SpecialCategory::Medical,
+                detected_keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            };
+            m
+        };
+
+        let mut file1 = FileResult::new(PathBuf::from("file1.txt"));
+        file1.matches.push(special(&["patient", "diagnose"]));
+        file1.matches.push(special(&["Patient"]));
+        file1
+            .matches
+            .push(create_test_match(Confidence::High, Severity::High, "nl"));
+        let mut file2 = FileResult::new(PathBuf::from("file2.txt"));
+        file2.matches.push(special(&["patient"]));
+
+        let summary = ScanResults::aggregate(vec![file1, file2]).gdpr_keyword_summary();
+        assert_eq!(summary.len(), 1);
+
+        let medical = &summary[&SpecialCategory::Medical];
+        assert_eq!(medical.get("patient"), Some(&3));
+        assert_eq!(medical.get("diagnose"), Some(&1));
+        assert_eq!(medical.len(), 2);
+
+        assert!(ScanResults::new().gdpr_keyword_summary().is_empty());
+    }
 }
@@ -0,0 +1,183 @@
+/// Sandboxed WebAssembly validators for plugin detectors
+///
+/// A plugin can hand its candidates to a `.wasm` module for checks that a
+/// pattern and the built-in checksums can't express (a proprietary check
+/// digit, a lookup table). The module runs inside wasmtime without any
+/// imports, so it has no file system, network or clock access, and a fuel
+/// budget stops runaway loops.
+///
+/// The module must export its `memory` and `validate(ptr: i32, len: i32) ->
+/// i32`; a non-zero result accepts the candidate. If it also exports
+/// `alloc(len: i32) -> i32`, the candidate's UTF-8 bytes are written to the
+/// returned pointer, otherwise to the start of memory.
+///
+/// Requires the `plugins-wasm` feature.
+use std::path::Path;
+use std::sync::Mutex;
+use wasmtime::{Config, Engine, Instance, Memory, Module, Store, TypedFunc};
+
+/// Fuel per candidate, roughly the number of executed instructions
+const FUEL_PER_CALL: u64 = 1_000_000;
+
+/// An instance of the validator module with its own store
+struct Loaded {
+    store: Store<()>,
+    memory: Memory,
+    alloc: Option<TypedFunc<i32, i32>>,
+    validate: TypedFunc<(i32, i32), i32>,
+}
+
+impl Loaded {
+    /// Instantiate `module` without imports: it gets nothing from the host
+    fn new(engine: &Engine, module: &Module) -> Result<Self, String> {
+        let mut store = Store::new(engine, ());
+        let instance = Instance::new(&mut store, module, &[]).map_err(|e| e.to_string())?;
+
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or("module does not export `memory`")?;
+        let validate = instance
+            .get_typed_func::<(i32, i32), i32>(&mut store, "validate")
+            .map_err(|e| format!("`validate(ptr: i32, len: i32) -> i32` export: {}", e))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, "alloc")
+            .ok();
+
+        Ok(Self {
+            store,
+            memory,
+            alloc,
+            validate,
+        })
+    }
+
+    /// Run `validate` on `value`, or `None` when the module traps or runs out of fuel
+    fn call(&mut self, value: &str) -> Option<bool> {
+        let len = i32::try_from(value.len()).ok()?;
+        self.store.set_fuel(FUEL_PER_CALL).ok()?;
+
+        let ptr = match &self.alloc {
+            Some(alloc) => alloc.call(&mut self.store, len).ok()?,
+            None => 0,
+        };
+        self.memory
+            .write(&mut self.store, ptr as u32 as usize, value.as_bytes())
+            .ok()?;
+
+        self.validate
+            .call(&mut self.store, (ptr, len))
+            .ok()
+            .map(|accepted| accepted != 0)
+    }
+}
+
+/// Validator module, compiled once and shared by the scan threads
+///
+/// Each call takes an idle instance from a pool, or instantiates a new one
+/// when all are busy, so threads validate in parallel. An instance that
+/// trapped is dropped instead of being reused.
+pub struct WasmValidator {
+    engine: Engine,
+    module: Module,
+    idle: Mutex<Vec<Loaded>>,
+}
+
+impl WasmValidator {
+    /// Compile and instantiate the module at `path`
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| format!("Failed to read WASM validator {}: {}", path.display(), e))?;
+        Self::from_bytes(&bytes)
+            .map_err(|e| format!("Invalid WASM validator {}: {}", path.display(), e))
+    }
+
+    /// Compile and instantiate a module from its binary
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| e.to_string())?;
+        let module = Module::new(&engine, bytes).map_err(|e| e.to_string())?;
+
+        // Instantiate once up front so missing exports fail at load time
+        let loaded = Loaded::new(&engine, &module)?;
+
+        Ok(Self {
+            engine,
+            module,
+            idle: Mutex::new(vec![loaded]),
+        })
+    }
+
+    /// Whether the module accepts `value`; traps and exhausted fuel reject it
+    pub fn validate(&self, value: &str) -> bool {
+        let idle = self.idle.lock().ok().and_then(|mut idle| idle.pop());
+        let mut loaded = match idle {
+            Some(loaded) => loaded,
+            None => match Loaded::new(&self.engine, &self.module) {
+                Ok(loaded) => loaded,
+                Err(_) => return false,
+            },
+        };
+
+        let Some(accepted) = loaded.call(value) else {
+            return false;
+        };
+        if let Ok(mut idle) = self.idle.lock() {
+            idle.push(loaded);
+        }
+        accepted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runaway_validator_rejects() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "validate") (param i32 i32) (result i32)
+                    (loop $spin (br $spin))
+                    (i32.const 1)))"#,
+        )
+        .unwrap();
+        let validator = WasmValidator::from_bytes(&wasm).unwrap();
+
+        // Out of fuel instead of hanging the scan
+        assert!(!validator.validate("anything"));
+    }
+
+    #[test]
+    fn test_validates_on_parallel_threads() {
+        // Accepts candidates of exactly four bytes
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "validate") (param i32 i32) (result i32)
+                    (i32.eq (local.get 1) (i32.const 4))))"#,
+        )
+        .unwrap();
+        let validator = WasmValidator::from_bytes(&wasm).unwrap();
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..50 {
+                        assert!(validator.validate("1234"));
+                        assert!(!validator.validate("12345"));
+                    }
+                });
+            }
+        });
+        assert!(validator.idle.lock().unwrap().len() <= 8);
+    }
+
+    #[test]
+    fn test_missing_exports() {
+        let wasm = wat::parse_str(r#"(module (memory (export "memory") 1))"#).unwrap();
+        let err = WasmValidator::from_bytes(&wasm).err().unwrap();
+        assert!(err.contains("validate"));
+    }
+}
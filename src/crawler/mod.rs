@@ -1,6 +1,8 @@
 pub mod filter;
+pub mod paths;
 /// File system crawler module
 pub mod walker;
 
 pub use filter::FileFilter;
-pub use walker::Walker;
+pub use paths::{read_path_list, PathList};
+pub use walker::{SkippedFile, Walker};
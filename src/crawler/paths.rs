@@ -0,0 +1,69 @@
+/// Path list reader for scanning an explicit set of files
+/// Consumes newline-delimited output from tools like `fd` or `git ls-files`
+use std::io::BufRead;
+use std::path::PathBuf;
+
+/// Files read from a path list, split by whether they exist
+#[derive(Debug, Default)]
+pub struct PathList {
+    /// Paths that point to existing files
+    pub files: Vec<PathBuf>,
+    /// Paths that do not exist or are not regular files
+    pub missing: Vec<PathBuf>,
+}
+
+/// Read newline-delimited file paths from a reader
+///
+/// Blank lines are skipped and surrounding whitespace is trimmed.
+pub fn read_path_list<R: BufRead>(reader: R) -> std::io::Result<PathList> {
+    let mut list = PathList::default();
+
+    for line in reader.lines() {
+        let line = line?;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let path = PathBuf::from(trimmed);
+        if path.is_file() {
+            list.files.push(path);
+        } else {
+            list.missing.push(path);
+        }
+    }
+
+    Ok(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_path_list_filters_missing() {
+        let tmp = TempDir::new().unwrap();
+        let existing = tmp.path().join("a.txt");
+        fs::write(&existing, "hello").unwrap();
+        let missing = tmp.path().join("gone.txt");
+
+        let input = format!("{}\n\n  {}  \r\n", existing.display(), missing.display());
+        let list = read_path_list(input.as_bytes()).unwrap();
+
+        assert_eq!(list.files, vec![existing]);
+        assert_eq!(list.missing, vec![missing]);
+    }
+
+    #[test]
+    fn test_read_path_list_skips_directories() {
+        let tmp = TempDir::new().unwrap();
+        let input = format!("{}\n", tmp.path().display());
+        let list = read_path_list(input.as_bytes()).unwrap();
+
+        assert!(list.files.is_empty());
+        assert_eq!(list.missing.len(), 1);
+    }
+}
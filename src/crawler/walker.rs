@@ -1,15 +1,27 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::{DirEntry, WalkBuilder};
 /// High-performance parallel file walker using the `ignore` crate
 /// Respects .pii-ignore, .gitignore, and other ignore files
 /// Optimized for network drives and fragmented filesystems
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+/// A file under the root that the walk leaves out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFile {
+    pub path: PathBuf,
+    pub size_bytes: u64,
+    /// Why the file is not walked
+    pub reason: String,
+}
+
 pub struct Walker {
     root: PathBuf,
     hidden: bool,
     max_depth: Option<usize>,
     threads: usize,
     max_filesize: u64,
+    include: Option<GlobSet>,
 }
 
 impl Walker {
@@ -20,6 +32,7 @@ impl Walker {
             max_depth: None,
             threads: num_cpus::get(),
             max_filesize: 100 * 1024 * 1024, // 100MB default
+            include: None,
         }
     }
 
@@ -47,19 +60,45 @@ impl Walker {
         self
     }
 
+    /// Only walk files matching at least one glob, relative to the root
+    ///
+    /// Ignore files (.pii-ignore, .gitignore) still apply on top of the
+    /// includes: walked files are filtered by the globs, so an include never
+    /// brings back an ignored file.
+    pub fn include(mut self, globs: &[String]) -> Result<Self, globset::Error> {
+        if globs.is_empty() {
+            self.include = None;
+            return Ok(self);
+        }
+
+        let mut builder = GlobSetBuilder::new();
+        for glob in globs {
+            builder.add(Glob::new(glob)?);
+        }
+        self.include = Some(builder.build()?);
+        Ok(self)
+    }
+
+    /// Walk builder with the shared settings applied
+    fn builder(&self, threads: usize) -> WalkBuilder {
+        let mut builder = WalkBuilder::new(&self.root);
+        builder
+            .hidden(self.hidden)
+            .max_depth(self.max_depth)
+            .threads(threads)
+            .add_custom_ignore_filename(".pii-ignore");
+
+        builder
+    }
+
     /// Walk directory and return files as Vec
     pub fn walk(&self) -> Vec<PathBuf> {
         let mut files = Vec::new();
 
-        let walker = WalkBuilder::new(&self.root)
-            .hidden(self.hidden)
-            .max_depth(self.max_depth)
-            .threads(1) // Single-threaded for walk()
-            .add_custom_ignore_filename(".pii-ignore")
-            .build();
+        let walker = self.builder(1).build(); // Single-threaded for walk()
 
         for entry in walker {
-            if let Some(Ok(p)) = self.process_entry(entry) {
+            if let Some(Ok((p, _))) = self.process_entry(entry) {
                 files.push(p);
             }
         }
@@ -69,28 +108,77 @@ impl Walker {
 
     /// Walk directory in parallel (returns files as Vec)
     pub fn walk_parallel(&self) -> Vec<PathBuf> {
+        self.walk_parallel_with_size().0
+    }
+
+    /// Walk directory in parallel, also returning the total size of the files in bytes
+    pub fn walk_parallel_with_size(&self) -> (Vec<PathBuf>, u64) {
         let mut files = Vec::new();
+        let mut total_bytes = 0;
 
-        let walker = WalkBuilder::new(&self.root)
-            .hidden(self.hidden)
-            .max_depth(self.max_depth)
-            .threads(self.threads)
-            .add_custom_ignore_filename(".pii-ignore")
-            .build();
+        let walker = self.builder(self.threads).build();
 
         for entry in walker {
-            if let Some(Ok(p)) = self.process_entry(entry) {
+            if let Some(Ok((p, size))) = self.process_entry(entry) {
                 files.push(p);
+                total_bytes += size;
             }
         }
 
-        files
+        (files, total_bytes)
+    }
+
+    /// Files under the root that [`walk`](Self::walk) leaves out, with the reason
+    ///
+    /// Walks the tree a second time without the size limit, hidden-file
+    /// rule, ignore files and `include` globs (only `max_depth` applies, and
+    /// `.git` directories are not entered), so it is meant for coverage
+    /// records such as the scan manifest, not for every scan.
+    pub fn skipped(&self) -> Vec<SkippedFile> {
+        let walked: HashSet<PathBuf> = self.walk().into_iter().collect();
+
+        let mut builder = WalkBuilder::new(&self.root);
+        builder
+            .standard_filters(false)
+            .max_depth(self.max_depth)
+            .filter_entry(|entry| entry.file_name() != ".git");
+
+        let mut skipped = Vec::new();
+        for entry in builder.build().flatten() {
+            if entry.file_type().is_none_or(|ft| ft.is_dir()) || walked.contains(entry.path()) {
+                continue;
+            }
+
+            let path = entry.path();
+            let size_bytes = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            let hidden = path
+                .strip_prefix(&self.root)
+                .unwrap_or(path)
+                .components()
+                .any(|c| c.as_os_str().to_string_lossy().starts_with('.'));
+
+            let reason = if size_bytes > self.max_filesize {
+                "larger than the maximum file size"
+            } else if hidden && self.hidden {
+                "hidden file"
+            } else {
+                "excluded by an ignore file or --include"
+            };
+
+            skipped.push(SkippedFile {
+                path: path.to_path_buf(),
+                size_bytes,
+                reason: reason.to_string(),
+            });
+        }
+
+        skipped
     }
 
     fn process_entry(
         &self,
         entry: Result<DirEntry, ignore::Error>,
-    ) -> Option<Result<PathBuf, String>> {
+    ) -> Option<Result<(PathBuf, u64), String>> {
         match entry {
             Ok(entry) => {
                 // Skip directories
@@ -100,14 +188,20 @@ impl Walker {
 
                 let path = entry.path();
 
-                // Check file size
-                if let Ok(metadata) = std::fs::metadata(path) {
-                    if metadata.len() > self.max_filesize {
-                        return None; // Skip files that are too large
+                // Check include globs
+                if let Some(ref include) = self.include {
+                    if !include.is_match(path.strip_prefix(&self.root).unwrap_or(path)) {
+                        return None;
                     }
                 }
 
-                Some(Ok(path.to_path_buf()))
+                // Check file size
+                let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                if size > self.max_filesize {
+                    return None; // Skip files that are too large
+                }
+
+                Some(Ok((path.to_path_buf(), size)))
             }
             Err(err) => Some(Err(format!("Walker error: {}", err))),
         }
@@ -169,4 +263,85 @@ mod tests {
         assert_eq!(files.len(), 1);
         assert!(files[0].to_string_lossy().contains("root.txt"));
     }
+
+    #[test]
+    fn test_walker_skipped_files() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".pii-ignore"), "*.secret\n").unwrap();
+        fs::write(tmp.path().join("normal.txt"), "content").unwrap();
+        fs::write(tmp.path().join("keys.secret"), "secret").unwrap();
+        fs::write(tmp.path().join("large.txt"), "x".repeat(64)).unwrap();
+
+        let walker = Walker::new(tmp.path()).max_filesize(32);
+        let mut skipped = walker.skipped();
+        skipped.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let reasons: Vec<(&str, &str)> = skipped
+            .iter()
+            .map(|f| {
+                (
+                    f.path.file_name().unwrap().to_str().unwrap(),
+                    f.reason.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                (".pii-ignore", "hidden file"),
+                ("keys.secret", "excluded by an ignore file or --include"),
+                ("large.txt", "larger than the maximum file size"),
+            ]
+        );
+        assert_eq!(skipped[2].size_bytes, 64);
+    }
+
+    #[test]
+    fn test_walker_include_globs() {
+        let tmp = TempDir::new().unwrap();
+        let config = tmp.path().join("app").join("config");
+        fs::create_dir_all(&config).unwrap();
+        fs::write(tmp.path().join("dump.sql"), "sql").unwrap();
+        fs::write(tmp.path().join("app").join("users.sql"), "sql").unwrap();
+        fs::write(tmp.path().join("app").join("main.rs"), "rs").unwrap();
+        fs::write(config.join("settings.toml"), "toml").unwrap();
+        fs::write(tmp.path().join("notes.txt"), "txt").unwrap();
+
+        let walker = Walker::new(tmp.path())
+            .include(&["**/*.sql".to_string()])
+            .unwrap();
+        let mut names: Vec<String> = walker
+            .walk()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["dump.sql", "users.sql"]);
+
+        let walker = Walker::new(tmp.path())
+            .include(&["**/*.sql".to_string(), "**/config/**".to_string()])
+            .unwrap();
+        assert_eq!(walker.walk().len(), 3);
+
+        assert!(Walker::new(tmp.path())
+            .include(&["[invalid".to_string()])
+            .is_err());
+    }
+
+    #[test]
+    fn test_walker_include_keeps_ignore_files() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".pii-ignore"), "fixtures.sql\n").unwrap();
+        fs::write(tmp.path().join("dump.sql"), "sql").unwrap();
+        fs::write(tmp.path().join("fixtures.sql"), "sql").unwrap();
+
+        let walker = Walker::new(tmp.path())
+            .include(&["**/*.sql".to_string()])
+            .unwrap();
+        let files = walker.walk();
+
+        // Included and ignored: the ignore file wins
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("dump.sql"));
+    }
 }
@@ -18,11 +18,17 @@ pub mod postgres;
 #[cfg(feature = "database")]
 pub mod mongodb;
 
+#[cfg(feature = "database")]
+pub mod sqlite;
+
 #[cfg(feature = "database")]
 pub mod scanner;
 
 #[cfg(feature = "database")]
-pub use types::{DatabaseConfig, DatabaseScanResults, DatabaseType, ScanOptions, TableScanResult};
+pub use types::{
+    DatabaseConfig, DatabaseScanResults, DatabaseType, ScanOptions, ScanPlan, TablePlan,
+    TableScanResult,
+};
 
 #[cfg(feature = "database")]
 pub use scanner::DatabaseScanner;
@@ -1,6 +1,6 @@
 /// MongoDB database scanner
 use crate::core::{DetectorRegistry, Match};
-use crate::database::types::{DatabaseConfig, ScanOptions, TableScanResult};
+use crate::database::types::{DatabaseConfig, ScanOptions, TablePlan, TableScanResult};
 use anyhow::{Context, Result};
 use futures::stream::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
@@ -146,6 +146,7 @@ impl MongoScanner {
                             // Add database-specific metadata to matches
                             for mut m in detector_matches {
                                 m.location.line = doc_num;
+                                m.fingerprint = m.compute_fingerprint(text);
                                 matches.push(m);
                             }
                         }
@@ -178,6 +179,7 @@ impl MongoScanner {
 
                                         for mut m in detector_matches {
                                             m.location.line = doc_num;
+                                            m.fingerprint = m.compute_fingerprint(text);
                                             matches.push(m);
                                         }
                                     }
@@ -202,6 +204,36 @@ impl MongoScanner {
         }
     }
 
+    /// Plan a scan without reading documents
+    ///
+    /// Row counts come from the collection metadata
+    /// (`estimatedDocumentCount`). Collections are schemaless, so only the
+    /// `--columns` filter (if any) is listed as the fields in scope.
+    pub async fn plan_database(&self, options: &ScanOptions) -> Result<Vec<TablePlan>> {
+        let mut plans = Vec::new();
+
+        for collection in self.get_collections().await? {
+            if !options.should_include_table(&collection) {
+                continue;
+            }
+
+            let estimated_rows = self
+                .db
+                .collection::<Document>(&collection)
+                .estimated_document_count()
+                .await
+                .context(format!("Failed to count documents in {}", collection))?;
+
+            plans.push(TablePlan {
+                name: collection,
+                columns: options.include_columns.clone().unwrap_or_default(),
+                estimated_rows: Some(estimated_rows),
+            });
+        }
+
+        Ok(plans)
+    }
+
     /// Scan all collections in the database
     pub async fn scan_database(&self, options: &ScanOptions) -> Result<Vec<TableScanResult>> {
         let all_collections = self.get_collections().await?;
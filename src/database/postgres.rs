@@ -1,6 +1,6 @@
 /// PostgreSQL database scanner
 use crate::core::{DetectorRegistry, Match};
-use crate::database::types::{DatabaseConfig, ScanOptions, TableScanResult};
+use crate::database::types::{DatabaseConfig, ScanOptions, TablePlan, TableScanResult};
 use anyhow::{Context, Result};
 use futures::stream::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
@@ -50,23 +50,32 @@ impl PostgresScanner {
         Ok(rows.into_iter().map(|(name,)| name).collect())
     }
 
-    /// Get column names for a table
-    async fn get_columns(&self, table: &str) -> Result<Vec<String>> {
+    /// Get (name, data type) of the columns of a table
+    async fn get_columns(&self, table: &str) -> Result<Vec<(String, String)>> {
         let query = r#"
-            SELECT column_name
+            SELECT column_name, data_type
             FROM information_schema.columns
             WHERE table_schema = 'public'
             AND table_name = $1
             ORDER BY ordinal_position
         "#;
 
-        let rows: Vec<(String,)> = sqlx::query_as(query)
+        sqlx::query_as(query)
             .bind(table)
             .fetch_all(&self.pool)
             .await
-            .context(format!("Failed to fetch columns for table {}", table))?;
+            .context(format!("Failed to fetch columns for table {}", table))
+    }
 
-        Ok(rows.into_iter().map(|(name,)| name).collect())
+    /// Names of the columns of a table that `options` scans
+    async fn columns_in_scope(&self, table: &str, options: &ScanOptions) -> Result<Vec<String>> {
+        Ok(self
+            .get_columns(table)
+            .await?
+            .into_iter()
+            .filter(|(name, data_type)| options.should_scan_column(name, data_type))
+            .map(|(name, _)| name)
+            .collect())
     }
 
     /// Scan a single table for PII
@@ -75,11 +84,7 @@ impl PostgresScanner {
         let mut result = TableScanResult::new(table.to_string());
 
         // Get columns
-        let all_columns = self.get_columns(table).await?;
-        let columns: Vec<String> = all_columns
-            .into_iter()
-            .filter(|col| options.should_include_column(col))
-            .collect();
+        let columns = self.columns_in_scope(table, options).await?;
 
         if columns.is_empty() {
             result.duration = start_time.elapsed();
@@ -161,6 +166,7 @@ impl PostgresScanner {
                 for mut m in detector_matches {
                     // Update location to include database context
                     m.location.line = row_num;
+                    m.fingerprint = m.compute_fingerprint(&text);
                     matches.push(m);
                 }
             }
@@ -225,6 +231,42 @@ impl PostgresScanner {
         Ok(results)
     }
 
+    /// Plan a scan without reading row data
+    ///
+    /// Row counts are estimated from `pg_class.reltuples`, which is unknown
+    /// (`-1`) for tables that were never vacuumed or analyzed.
+    pub async fn plan_database(&self, options: &ScanOptions) -> Result<Vec<TablePlan>> {
+        let mut plans = Vec::new();
+
+        for table in self.get_tables().await? {
+            if !options.should_include_table(&table) {
+                continue;
+            }
+
+            let columns = self.columns_in_scope(&table, options).await?;
+
+            let estimate: Option<(f32,)> = sqlx::query_as(
+                "SELECT c.reltuples FROM pg_class c \
+                 JOIN pg_namespace n ON n.oid = c.relnamespace \
+                 WHERE n.nspname = 'public' AND c.relname = $1",
+            )
+            .bind(&table)
+            .fetch_optional(&self.pool)
+            .await
+            .context(format!("Failed to estimate rows for table {}", table))?;
+
+            plans.push(TablePlan {
+                name: table,
+                columns,
+                estimated_rows: estimate
+                    .filter(|(rows,)| *rows >= 0.0)
+                    .map(|(rows,)| rows as u64),
+            });
+        }
+
+        Ok(plans)
+    }
+
     /// Close the database connection
     pub async fn close(self) {
         self.pool.close().await;
@@ -2,7 +2,10 @@
 use crate::core::DetectorRegistry;
 use crate::database::mongodb::MongoScanner;
 use crate::database::postgres::PostgresScanner;
-use crate::database::types::{DatabaseConfig, DatabaseScanResults, DatabaseType, ScanOptions};
+use crate::database::sqlite::SqliteScanner;
+use crate::database::types::{
+    DatabaseConfig, DatabaseScanResults, DatabaseType, ScanOptions, ScanPlan,
+};
 use anyhow::{Context, Result};
 use std::time::Instant;
 
@@ -10,6 +13,7 @@ use std::time::Instant;
 pub enum DatabaseScanner {
     PostgreSQL(PostgresScanner),
     MongoDB(MongoScanner),
+    SQLite(SqliteScanner),
 }
 
 impl DatabaseScanner {
@@ -30,7 +34,8 @@ impl DatabaseScanner {
                 Ok(DatabaseScanner::MongoDB(scanner))
             }
             DatabaseType::SQLite => {
-                anyhow::bail!("SQLite support not yet implemented")
+                let scanner = SqliteScanner::new(&config).await?;
+                Ok(DatabaseScanner::SQLite(scanner))
             }
         }
     }
@@ -47,6 +52,9 @@ impl DatabaseScanner {
         let table_results = match self {
             DatabaseScanner::PostgreSQL(scanner) => scanner.scan_database(options).await?,
             DatabaseScanner::MongoDB(scanner) => scanner.scan_database(options).await?,
+            DatabaseScanner::SQLite(_) => {
+                anyhow::bail!("SQLite scanning not yet implemented (only --dry-run is supported)")
+            }
         };
 
         let mut results = DatabaseScanResults::new(db_type, database_name.to_string());
@@ -60,11 +68,30 @@ impl DatabaseScanner {
         Ok(results)
     }
 
+    /// List the tables, columns and estimated row counts a scan would cover
+    ///
+    /// Applies the include/exclude filters of `options` but reads no row
+    /// data and runs no detectors.
+    pub async fn plan(&self, database_name: &str, options: &ScanOptions) -> Result<ScanPlan> {
+        let tables = match self {
+            DatabaseScanner::PostgreSQL(scanner) => scanner.plan_database(options).await?,
+            DatabaseScanner::MongoDB(scanner) => scanner.plan_database(options).await?,
+            DatabaseScanner::SQLite(scanner) => scanner.plan_database(options).await?,
+        };
+
+        Ok(ScanPlan {
+            database_type: self.database_type(),
+            database_name: database_name.to_string(),
+            tables,
+        })
+    }
+
     /// Get the database type
     pub fn database_type(&self) -> DatabaseType {
         match self {
             DatabaseScanner::PostgreSQL(_) => DatabaseType::PostgreSQL,
             DatabaseScanner::MongoDB(_) => DatabaseType::MongoDB,
+            DatabaseScanner::SQLite(_) => DatabaseType::SQLite,
         }
     }
 }
@@ -0,0 +1,212 @@
+/// SQLite database scanner
+///
+/// Only scan planning (`--dry-run`) is supported so far; row scanning is
+/// not yet implemented for SQLite.
+use crate::database::types::{DatabaseConfig, ScanOptions, TablePlan};
+use anyhow::{Context, Result};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+
+/// SQLite scanner
+pub struct SqliteScanner {
+    pool: SqlitePool,
+}
+
+impl SqliteScanner {
+    /// Open a SQLite database (e.g. `sqlite://path/to/database.db`)
+    pub async fn new(config: &DatabaseConfig) -> Result<Self> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(config.pool_size)
+            .acquire_timeout(config.timeout)
+            .connect(&config.connection_string)
+            .await
+            .context("Failed to open SQLite database")?;
+
+        Ok(Self { pool })
+    }
+
+    /// Get list of all user tables in the database
+    async fn get_tables(&self) -> Result<Vec<String>> {
+        let query = r#"
+            SELECT name
+            FROM sqlite_master
+            WHERE type = 'table'
+            AND name NOT LIKE 'sqlite_%'
+            ORDER BY name
+        "#;
+
+        let rows: Vec<(String,)> = sqlx::query_as(query)
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch table list")?;
+
+        Ok(rows.into_iter().map(|(name,)| name).collect())
+    }
+
+    /// Get (name, declared type) of the columns of a table
+    async fn get_columns(&self, table: &str) -> Result<Vec<(String, String)>> {
+        sqlx::query_as("SELECT name, type FROM pragma_table_info(?) ORDER BY cid")
+            .bind(table)
+            .fetch_all(&self.pool)
+            .await
+            .context(format!("Failed to fetch columns for table {}", table))
+    }
+
+    /// Plan a scan without reading row data
+    ///
+    /// SQLite keeps no row statistics, so rows are counted with `COUNT(*)`,
+    /// which does not read column values.
+    pub async fn plan_database(&self, options: &ScanOptions) -> Result<Vec<TablePlan>> {
+        let mut plans = Vec::new();
+
+        for table in self.get_tables().await? {
+            if !options.should_include_table(&table) {
+                continue;
+            }
+
+            // SQLite has no information_schema; the declared type is used.
+            // Columns without one can hold any value, so they are kept.
+            let columns = self
+                .get_columns(&table)
+                .await?
+                .into_iter()
+                .filter(|(name, data_type)| {
+                    options.should_scan_column(name, data_type)
+                        || (data_type.is_empty() && options.should_include_column(name))
+                })
+                .map(|(name, _)| name)
+                .collect();
+
+            let (count,): (i64,) = sqlx::query_as(&format!(
+                "SELECT COUNT(*) FROM \"{}\"",
+                table.replace('"', "\"\"")
+            ))
+            .fetch_one(&self.pool)
+            .await
+            .context(format!("Failed to count rows in table {}", table))?;
+
+            plans.push(TablePlan {
+                name: table,
+                columns,
+                estimated_rows: Some(count as u64),
+            });
+        }
+
+        Ok(plans)
+    }
+
+    /// Close the database connection
+    pub async fn close(self) {
+        self.pool.close().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Detector, DetectorRegistry, Match, Severity};
+    use crate::database::{DatabaseScanner, DatabaseType};
+    use std::path::Path;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    /// Detector that records every call, to prove no row data is scanned
+    struct CountingDetector(Arc<AtomicUsize>);
+
+    impl Detector for CountingDetector {
+        fn id(&self) -> &str {
+            "counting"
+        }
+
+        fn name(&self) -> &str {
+            "Counting"
+        }
+
+        fn country(&self) -> &str {
+            "universal"
+        }
+
+        fn base_severity(&self) -> Severity {
+            Severity::Low
+        }
+
+        fn detect(&self, _text: &str, _file_path: &Path) -> Vec<Match> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Vec::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_dry_run_lists_filtered_tables() {
+        let tmp = TempDir::new().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", tmp.path().join("crm.db").display());
+        let config = DatabaseConfig::new(DatabaseType::SQLite, url);
+
+        let setup = SqliteScanner::new(&config).await.unwrap();
+        for statement in [
+            "CREATE TABLE customers (id INTEGER, name TEXT, email TEXT, bsn TEXT)",
+            "CREATE TABLE orders (id INTEGER, customer_id INTEGER, note TEXT)",
+            "CREATE TABLE audit_log (id INTEGER, message TEXT)",
+            "INSERT INTO customers VALUES (1, 'Jan', 'jan@example.com', '111222333'), (2, 'Piet', 'piet@example.nl', '123456782')",
+            "INSERT INTO orders VALUES (1, 1, 'call back')",
+            "INSERT INTO audit_log VALUES (1, 'login')",
+        ] {
+            sqlx::query(statement).execute(&setup.pool).await.unwrap();
+        }
+        setup.close().await;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(CountingDetector(calls.clone())));
+
+        let mut options = ScanOptions::new();
+        options.exclude_tables = vec!["audit_log".to_string()];
+        options.exclude_columns = vec!["id".to_string()];
+
+        let scanner = DatabaseScanner::new(config, Some("crm.db"), registry)
+            .await
+            .unwrap();
+        let plan = scanner.plan("crm.db", &options).await.unwrap();
+
+        let names: Vec<&str> = plan.tables.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["customers", "orders"]);
+        assert_eq!(plan.tables[0].columns, vec!["name", "email", "bsn"]);
+        assert_eq!(plan.tables[0].estimated_rows, Some(2));
+        assert_eq!(plan.tables[1].columns, vec!["customer_id", "note"]);
+        assert_eq!(plan.estimated_rows(), 3);
+
+        // No detector ever saw row data
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_text_columns_only() {
+        let tmp = TempDir::new().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", tmp.path().join("hr.db").display());
+        let config = DatabaseConfig::new(DatabaseType::SQLite, url);
+
+        let setup = SqliteScanner::new(&config).await.unwrap();
+        sqlx::query(
+            "CREATE TABLE employees (id INTEGER, name VARCHAR(100), born DATE, \
+             salary NUMERIC(10,2), photo BLOB, notes TEXT, prefs JSON, \
+             hired_at TIMESTAMP, legacy)",
+        )
+        .execute(&setup.pool)
+        .await
+        .unwrap();
+
+        let mut options = ScanOptions::new();
+        let plan = setup.plan_database(&options).await.unwrap();
+        assert_eq!(plan[0].columns.len(), 9);
+
+        options.text_columns_only = true;
+        let plan = setup.plan_database(&options).await.unwrap();
+        assert_eq!(plan[0].columns, vec!["name", "notes", "prefs", "legacy"]);
+
+        // Column filters still apply
+        options.exclude_columns = vec!["notes".to_string()];
+        let plan = setup.plan_database(&options).await.unwrap();
+        assert_eq!(plan[0].columns, vec!["name", "prefs", "legacy"]);
+        setup.close().await;
+    }
+}
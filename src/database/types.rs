@@ -98,6 +98,10 @@ pub struct ScanOptions {
 
     /// Show progress during scanning
     pub show_progress: bool,
+
+    /// Only scan text-like columns (varchar, text, char, json, ...), skipping
+    /// numeric, date/time and binary columns
+    pub text_columns_only: bool,
 }
 
 impl ScanOptions {
@@ -122,6 +126,14 @@ impl ScanOptions {
         }
     }
 
+    /// Whether a column of SQL type `data_type` is scanned
+    ///
+    /// Applies the column filters and, with `text_columns_only`, the type check.
+    pub fn should_scan_column(&self, column_name: &str, data_type: &str) -> bool {
+        self.should_include_column(column_name)
+            && (!self.text_columns_only || is_text_type(data_type))
+    }
+
     pub fn should_include_column(&self, column_name: &str) -> bool {
         // Check exclusions first
         if self.exclude_columns.iter().any(|c| c == column_name) {
@@ -137,6 +149,17 @@ impl ScanOptions {
     }
 }
 
+/// Whether a SQL column type holds text (`character varying`, `VARCHAR(255)`,
+/// `text`, `CLOB`, `jsonb`, `xml`, ...)
+///
+/// Numeric, date/time, boolean and binary types cannot contain text PII.
+pub fn is_text_type(data_type: &str) -> bool {
+    let data_type = data_type.to_lowercase();
+    ["char", "text", "clob", "json", "xml", "string"]
+        .iter()
+        .any(|kind| data_type.contains(kind))
+}
+
 /// Results from scanning a single table/collection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TableScanResult {
@@ -208,3 +231,49 @@ impl DatabaseScanResults {
         self.tables_scanned.push(result);
     }
 }
+
+/// Plan for a single table/collection in a dry run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TablePlan {
+    /// Table or collection name
+    pub name: String,
+
+    /// Columns/fields in scope after filtering (empty for schemaless
+    /// collections without a column filter: all fields are scanned)
+    pub columns: Vec<String>,
+
+    /// Estimated row/document count, if the backend provides one
+    pub estimated_rows: Option<u64>,
+}
+
+/// Dry-run scan plan: what a scan would read, without reading row data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanPlan {
+    /// Database type
+    pub database_type: DatabaseType,
+
+    /// Database name
+    pub database_name: String,
+
+    /// Tables/collections in scope after filtering
+    pub tables: Vec<TablePlan>,
+}
+
+impl ScanPlan {
+    /// Sum of the known row estimates
+    pub fn estimated_rows(&self) -> u64 {
+        self.tables.iter().filter_map(|t| t.estimated_rows).sum()
+    }
+
+    /// Rows a scan would read from `table`, given the row limit and sampling
+    pub fn rows_to_scan(table: &TablePlan, options: &ScanOptions) -> Option<u64> {
+        let mut rows = table.estimated_rows?;
+        if let Some(percent) = options.sample_percent {
+            rows = rows * u64::from(percent.min(100)) / 100;
+        }
+        if let Some(limit) = options.row_limit {
+            rows = rows.min(limit as u64);
+        }
+        Some(rows)
+    }
+}
@@ -1,4 +1,6 @@
 /// Belgium PII Detectors
+pub mod phone;
 pub mod rrn;
 
+pub use phone::PhoneDetector;
 pub use rrn::RrnDetector;
@@ -0,0 +1,103 @@
+/// Belgium phone number detector
+///
+/// Finds Belgian numbers in international (`+32`, `0032`) or national
+/// format with a trunk `0` (`02 555 12 12`, `0470 12 34 56`). Extensions written as
+/// `ext 42`, `x42`, `toestel 42` or `poste 42` are split off: the base number is validated with
+/// libphonenumber metadata and the extension is noted in the match.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::personal::phone::{detect_extension_phones, is_region_phone_with_extension};
+use phonenumber::country;
+use std::path::Path;
+
+pub struct PhoneDetector;
+
+impl PhoneDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PhoneDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PhoneDetector {
+    fn id(&self) -> &str {
+        "be_phone"
+    }
+
+    fn name(&self) -> &str {
+        "Belgian Phone Number"
+    }
+
+    fn country(&self) -> &str {
+        "be"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_extension_phones(self, country::Id::BE, text, file_path)
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        &["phone"]
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        is_region_phone_with_extension(country::Id::BE, value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Belgian phone numbers (+32, 0032 or national format with a \
+             trunk 0), including numbers with an extension, validated against \
+             libphonenumber metadata."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("+32 2 555 12 12")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("02 123")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Confidence;
+
+    #[test]
+    fn test_extension_syntaxes() {
+        let detector = PhoneDetector::new();
+        let path = Path::new("support.csv");
+
+        for text in [
+            "Tel +32 2 555 12 12 ext 42",
+            "Tel 02 555 12 12 x 42",
+            "Tel 02 555 12 12 toestel 42",
+        ] {
+            let matches = detector.detect(text, path);
+            assert_eq!(matches.len(), 1, "{}", text);
+            assert_eq!(matches[0].detector_name, "Belgian Phone Number (ext. 42)");
+        }
+    }
+
+    #[test]
+    fn test_number_without_extension() {
+        let detector = PhoneDetector::new();
+        let matches = detector.detect("GSM 0470 12 34 56", Path::new("klanten.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Belgian Phone Number");
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+}
@@ -8,7 +8,7 @@
 ///
 /// Can also appear without separators: YYMMDDXXXCC
 /// Example: 85.07.30-001-60 or 85073000160
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::{mask_value, validate_belgian_rrn};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -54,6 +54,12 @@ impl Detector for RrnDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Values failing their checksum are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -76,8 +82,8 @@ impl Detector for RrnDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches (strict mode)
-                if confidence == Confidence::High {
+                // Checksum failures are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -94,6 +100,15 @@ impl Detector for RrnDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -103,6 +118,18 @@ impl Detector for RrnDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_belgian_rrn(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("85.07.30-001-60")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("85073000199")
+    }
 }
 
 #[cfg(test)]
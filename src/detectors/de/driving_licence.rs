@@ -0,0 +1,119 @@
+/// German driving licence number (Führerscheinnummer) detector
+///
+/// German card licences carry an 11-character number: a 4-character
+/// issuing authority code, a 5-character serial, a check character (digit
+/// or X) and the issue counter. As the check algorithm is not public, a
+/// number is only reported when "Führerschein" appears on the same line.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::driving_licence::detect_on_keyword_lines;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for Führerscheinnummer detection
+/// Matches: 9 letters/digits, a check digit or X, and 1 letter/digit (e.g., B072RRE2I55)
+static LICENCE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b[A-Z0-9]{9}[0-9X][A-Z0-9]\b").expect("Failed to compile driving licence regex")
+});
+
+/// Keyword that must appear on the line
+static LICENCE_KEYWORD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)f(?:ü|ue)hrerschein").expect("Failed to compile driving licence keyword regex")
+});
+
+pub struct DrivingLicenceDetector;
+
+impl DrivingLicenceDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DrivingLicenceDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for DrivingLicenceDetector {
+    fn id(&self) -> &str {
+        "de_driving_licence"
+    }
+
+    fn name(&self) -> &str {
+        "German Driving Licence Number (Führerscheinnummer)"
+    }
+
+    fn country(&self) -> &str {
+        "de"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn warmup(&self) {
+        Lazy::force(&LICENCE_PATTERN);
+        Lazy::force(&LICENCE_KEYWORD);
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_on_keyword_lines(self, &LICENCE_PATTERN, &LICENCE_KEYWORD, text, file_path)
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        value.len() == 11
+            && value
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && value.chars().any(|c| c.is_ascii_digit())
+            && value.chars().any(|c| c.is_ascii_uppercase())
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("B072RRE2I55")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("B072RRE2I5")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects German driving licence numbers (Führerscheinnummer) on lines mentioning \"Führerschein\". \
+             Format: 11 letters/digits"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_licence_with_keyword() {
+        let detector = DrivingLicenceDetector::new();
+        let text = "Führerscheinnummer: B072RRE2I55";
+        let matches = detector.detect(text, &PathBuf::from("mietvertrag.csv"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "de_driving_licence");
+        assert_eq!(matches[0].severity, Severity::High);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "B072RRE2I55"
+        );
+    }
+
+    #[test]
+    fn test_licence_without_keyword() {
+        let detector = DrivingLicenceDetector::new();
+        let matches = detector.detect(
+            "Kundennummer: B072RRE2I55",
+            &PathBuf::from("mietvertrag.csv"),
+        );
+        assert!(matches.is_empty());
+    }
+}
@@ -0,0 +1,333 @@
+/// German legacy bank account detector (Kontonummer + Bankleitzahl)
+///
+/// Before IBAN, German accounts were identified by an 8-digit bank code
+/// (Bankleitzahl, BLZ) and an account number of up to 10 digits. Old records
+/// still hold such pairs, e.g. `Kto. 532013000, BLZ 370 400 44`. Both numbers
+/// look like any other number, so only labeled pairs on the same line are
+/// reported.
+///
+/// The check digit method of an account depends on its bank. When a BLZ
+/// file is configured (`[legacy_bank_account] blz_file`, see
+/// [`load_blz_file`]), accounts at banks using method 00 or 01 are checked;
+/// other accounts are reported unchecked.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+/// Labeled account number and BLZ in either order, up to 20 characters apart
+static LEGACY_ACCOUNT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    let account = r"(?:Kontonummer|Kontonr\.?|Konto-?Nr\.?|Kto\.?-?Nr\.?|Konto|Kto\.?)[:\s]*";
+    let blz = r"(?:Bankleitzahl|BLZ)[:\s]*";
+    Regex::new(&format!(
+        r"(?i)\b(?:{account}(?P<account1>\d{{1,10}})\b[^\d\n]{{1,20}}?{blz}(?P<blz1>\d{{3}} ?\d{{3}} ?\d{{2}})|{blz}(?P<blz2>\d{{3}} ?\d{{3}} ?\d{{2}})\b[^\d\n]{{1,20}}?{account}(?P<account2>\d{{1,10}}))\b"
+    ))
+    .expect("Failed to compile legacy bank account regex")
+});
+
+/// Prüfziffer (check digit) calculation method of a bank
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CheckMethod {
+    /// Method 00: weights 2, 1, 2, 1, ... from the right, cross sums of the products, modulo 10
+    Method00,
+    /// Method 01: weights 3, 7, 1, 3, 7, 1, ... from the right, modulo 10
+    Method01,
+}
+
+impl CheckMethod {
+    /// Parse a two-digit method code from the Bundesbank BLZ file
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "00" => Some(CheckMethod::Method00),
+            "01" => Some(CheckMethod::Method01),
+            _ => None,
+        }
+    }
+
+    /// Check an account number (the last of its 10 zero-padded digits is the check digit)
+    pub fn check(self, account: &str) -> bool {
+        if account.is_empty() || account.len() > 10 {
+            return false;
+        }
+        let padded = format!("{:0>10}", account);
+        let Some(digits) = padded
+            .chars()
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+        else {
+            return false;
+        };
+
+        let sum: u32 = digits[..9]
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, d)| match self {
+                CheckMethod::Method00 => {
+                    let product = d * if i % 2 == 0 { 2 } else { 1 };
+                    product / 10 + product % 10
+                }
+                CheckMethod::Method01 => d * [3, 7, 1][i % 3],
+            })
+            .sum();
+
+        (10 - sum % 10) % 10 == digits[9]
+    }
+}
+
+/// Parse the Bundesbank BLZ file (fixed-width `blz-aktuell` format)
+///
+/// The BLZ is in columns 1-8 and the check method code in columns 151-152.
+/// Banks with an unsupported method and lines that do not parse are skipped.
+pub fn parse_blz_table(text: &str) -> HashMap<String, CheckMethod> {
+    text.lines()
+        .filter_map(|line| {
+            let columns: Vec<char> = line.chars().collect();
+            let blz: String = columns.get(..8)?.iter().collect();
+            let code: String = columns.get(150..152)?.iter().collect();
+            let method = CheckMethod::from_code(&code)?;
+            blz.chars()
+                .all(|c| c.is_ascii_digit())
+                .then_some((blz, method))
+        })
+        .collect()
+}
+
+/// Read and parse a Bundesbank BLZ file
+///
+/// The Bundesbank publishes the file in ISO 8859-1; UTF-8 copies work too.
+pub fn load_blz_file(path: &Path) -> io::Result<HashMap<String, CheckMethod>> {
+    let bytes = std::fs::read(path)?;
+    let text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => e.into_bytes().into_iter().map(char::from).collect(),
+    };
+    Ok(parse_blz_table(&text))
+}
+
+pub struct LegacyBankAccountDetector {
+    check_methods: HashMap<String, CheckMethod>,
+}
+
+impl LegacyBankAccountDetector {
+    pub fn new() -> Self {
+        Self {
+            check_methods: HashMap::new(),
+        }
+    }
+
+    /// Check accounts with the method of their bank (BLZ -> method)
+    pub fn check_methods(mut self, methods: HashMap<String, CheckMethod>) -> Self {
+        self.check_methods = methods;
+        self
+    }
+
+    /// Run the bank's check digit method
+    ///
+    /// Returns `None` when the BLZ has no known method.
+    fn account_check(&self, blz: &str, account: &str) -> Option<bool> {
+        self.check_methods
+            .get(blz)
+            .map(|method| method.check(account))
+    }
+
+    /// (BLZ without spaces, account) of a pattern match
+    fn pair(caps: &regex::Captures) -> Option<(String, String)> {
+        let blz = caps.name("blz1").or_else(|| caps.name("blz2"))?;
+        let account = caps.name("account1").or_else(|| caps.name("account2"))?;
+        Some((blz.as_str().replace(' ', ""), account.as_str().to_string()))
+    }
+}
+
+impl Default for LegacyBankAccountDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for LegacyBankAccountDetector {
+    fn id(&self) -> &str {
+        "de_legacy_bank_account"
+    }
+
+    fn name(&self) -> &str {
+        "German Bank Account (Kontonummer + BLZ)"
+    }
+
+    fn country(&self) -> &str {
+        "de"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn config_key(&self) -> String {
+        let mut methods: Vec<_> = self.check_methods.iter().collect();
+        methods.sort();
+        methods
+            .iter()
+            .map(|(blz, method)| format!("{}={:?}", blz, method))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for caps in LEGACY_ACCOUNT_PATTERN.captures_iter(line) {
+                let (Some(full), Some((blz, account))) = (caps.get(0), Self::pair(&caps)) else {
+                    continue;
+                };
+
+                let confidence = match self.account_check(&blz, &account) {
+                    Some(true) => Confidence::High,
+                    Some(false) => continue,
+                    None => Confidence::Medium,
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    // The BLZ identifies the bank, not the holder
+                    value_masked: format!("{} {}", mask_value(&account), blz),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: full.start(),
+                        start_byte: byte_offset + full.start(),
+                        end_byte: byte_offset + full.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        let Some((blz, account)) = LEGACY_ACCOUNT_PATTERN
+            .captures(value)
+            .and_then(|caps| Self::pair(&caps))
+        else {
+            return false;
+        };
+
+        self.account_check(&blz, &account) != Some(false)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("Kto. 532013000, BLZ 370 400 44")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("Kto. 532013000")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects pre-IBAN German Kontonummer + Bankleitzahl pairs. \
+             Optionally validated with the bank's check digit method (00, 01) \
+             from the Bundesbank BLZ file."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn method_00_table() -> HashMap<String, CheckMethod> {
+        let line = format!("{:<150}00", "37040044");
+        parse_blz_table(&line)
+    }
+
+    #[test]
+    fn test_pair_passing_method_00() {
+        let detector = LegacyBankAccountDetector::new().check_methods(method_00_table());
+        let path = PathBuf::from("kunden_1998.txt");
+
+        let matches = detector.detect("Bankverbindung: Kto. 532013000, BLZ 370 400 44", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::High);
+        assert_eq!(matches[0].severity, Severity::High);
+        assert_eq!(matches[0].country, "de");
+        assert_eq!(matches[0].value_masked, "532****00 37040044");
+
+        // BLZ first, and a failing check digit
+        assert_eq!(
+            detector
+                .detect("BLZ: 37040044 Kontonummer: 532013000", &path)
+                .len(),
+            1
+        );
+        assert!(detector
+            .detect("BLZ: 37040044 Kontonummer: 532013001", &path)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_lone_account_not_detected() {
+        let detector = LegacyBankAccountDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        assert!(detector.detect("Kontonummer: 532013000", &path).is_empty());
+        assert!(detector.detect("BLZ 37040044", &path).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_bank_reported_unchecked() {
+        let detector = LegacyBankAccountDetector::new().check_methods(method_00_table());
+        let matches = detector.detect(
+            "Konto 1234567 bei BLZ 100 200 30",
+            &PathBuf::from("test.txt"),
+        );
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_blz_file_in_latin1() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("blz-aktuell.txt");
+        // Bank names hold umlauts as single ISO 8859-1 bytes
+        let mut line = format!("{:<150}00", "37040044").into_bytes();
+        line[20] = 0xFC;
+        std::fs::write(&path, line).unwrap();
+
+        let methods = load_blz_file(&path).unwrap();
+        assert_eq!(methods.get("37040044"), Some(&CheckMethod::Method00));
+    }
+
+    #[test]
+    fn test_check_methods() {
+        assert!(CheckMethod::Method00.check("9290701"));
+        assert!(!CheckMethod::Method00.check("9290702"));
+        assert!(CheckMethod::Method01.check("1234567899"));
+        assert!(!CheckMethod::Method01.check("1234567890"));
+    }
+}
@@ -1,4 +1,12 @@
 /// Germany PII Detectors
+pub mod driving_licence;
+pub mod legacy_bank_account;
+pub mod phone;
 pub mod steuer_id;
+pub mod steuernummer;
 
+pub use driving_licence::DrivingLicenceDetector;
+pub use legacy_bank_account::LegacyBankAccountDetector;
+pub use phone::PhoneDetector;
 pub use steuer_id::SteuerIdDetector;
+pub use steuernummer::SteuernummerDetector;
@@ -0,0 +1,102 @@
+/// Germany phone number detector
+///
+/// Finds German numbers in international (`+49`, `0049`) or national
+/// format with a trunk `0` (`030 12345678`, `0151 23456789`). Extensions written as
+/// `ext 42`, `x42` or `Durchwahl 42` are split off: the base number is validated with
+/// libphonenumber metadata and the extension is noted in the match.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::personal::phone::{detect_extension_phones, is_region_phone_with_extension};
+use phonenumber::country;
+use std::path::Path;
+
+pub struct PhoneDetector;
+
+impl PhoneDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PhoneDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PhoneDetector {
+    fn id(&self) -> &str {
+        "de_phone"
+    }
+
+    fn name(&self) -> &str {
+        "German Phone Number"
+    }
+
+    fn country(&self) -> &str {
+        "de"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_extension_phones(self, country::Id::DE, text, file_path)
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        &["phone"]
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        is_region_phone_with_extension(country::Id::DE, value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects German phone numbers (+49, 0049 or national format with a \
+             trunk 0), including numbers with an extension, validated against \
+             libphonenumber metadata."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("+49 30 12345678")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("030 12")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Confidence;
+
+    #[test]
+    fn test_extension_syntaxes() {
+        let detector = PhoneDetector::new();
+        let path = Path::new("support.csv");
+
+        for text in [
+            "Tel. +49 30 12345678 ext. 42",
+            "Tel. 030 12345678 Durchwahl: 42",
+        ] {
+            let matches = detector.detect(text, path);
+            assert_eq!(matches.len(), 1, "{}", text);
+            assert_eq!(matches[0].detector_name, "German Phone Number (ext. 42)");
+        }
+    }
+
+    #[test]
+    fn test_number_without_extension() {
+        let detector = PhoneDetector::new();
+        let matches = detector.detect("Mobil: +49 151 23456789", Path::new("kunden.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "German Phone Number");
+        assert_eq!(matches[0].confidence, Confidence::High);
+    }
+}
@@ -8,7 +8,7 @@
 /// - One digit must appear 2-3 times
 /// - Not all digits can be the same
 /// - Uses modified modulus 11 algorithm
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::{mask_value, validate_steuer_id};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -54,6 +54,12 @@ impl Detector for SteuerIdDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Values failing their checksum are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -76,8 +82,8 @@ impl Detector for SteuerIdDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches (strict mode)
-                if confidence == Confidence::High {
+                // Checksum failures are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -94,6 +100,15 @@ impl Detector for SteuerIdDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -103,6 +118,18 @@ impl Detector for SteuerIdDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_steuer_id(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("860 957 427 19")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("86095742710")
+    }
 }
 
 #[cfg(test)]
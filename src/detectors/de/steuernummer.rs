@@ -0,0 +1,264 @@
+/// Germany Steuernummer (tax number) detector
+///
+/// The Steuernummer is issued by the local tax office (Finanzamt), unlike the
+/// nationwide Steuer-ID. Each federal state writes it differently:
+/// - 2/3/5 digits (e.g. Berlin 21/815/08150, Hamburg, Niedersachsen)
+/// - 3/3/5 digits (e.g. Bayern 181/815/08155, Sachsen, Thüringen)
+/// - 3/4/4 digits (Nordrhein-Westfalen 133/8150/8159)
+/// - 5/5 digits (Baden-Württemberg 93815/08152)
+/// - 2/3/4/1 digits (Rheinland-Pfalz 22/815/0815/4)
+/// - spaced forms (Bremen 75 815 08152, Hessen 013 815 08153)
+///
+/// The 13-digit unified federal format (e.g. 2893081508152) starts with a
+/// state prefix and has a `0` after the tax office number.
+///
+/// There is no public check digit algorithm, so the slash formats are Low
+/// confidence without a keyword; spaced and unified forms require one.
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
+use crate::utils::{has_keyword_before, keyword_gate, mask_value};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for the federal-state slash formats
+/// Examples: 21/815/08150, 181/815/08155, 133/8150/8159, 93815/08152, 22/815/0815/4
+static STATE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:\d{2,3}/\d{3}/\d{5}|\d{3}/\d{4}/\d{4}|\d{5}/\d{5}|\d{2}/\d{3}/\d{4}/\d)\b")
+        .expect("Failed to compile Steuernummer regex")
+});
+
+/// Regex pattern for the spaced state formats and the unified 13-digit format
+/// Examples: 75 815 08152, 013 815 08153, 2893081508152
+static KEYWORD_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:\d{13}|0?\d{2} \d{3} \d{5})\b")
+        .expect("Failed to compile Steuernummer keyword regex")
+});
+
+/// State prefixes of the unified 13-digit format
+const UNIFIED_PREFIXES: &[&str] = &[
+    "10", "11", "21", "22", "23", "24", "26", "27", "28", "30", "31", "32", "40", "41", "5", "9",
+];
+
+/// Keywords (lowercase) that raise a match to High confidence
+const STEUERNUMMER_KEYWORDS: &[&str] = &["steuernummer", "steuer-nr", "st.-nr", "stnr", "st-nr"];
+
+pub struct SteuernummerDetector;
+
+impl SteuernummerDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a 13-digit number against the unified federal format
+    fn is_unified(digits: &str) -> bool {
+        if digits.len() != 13 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+
+        // State prefix and tax office number fill the first four digits
+        // (e.g. `28` + `93` for BW, `9` + `181` for Bayern), followed by `0`
+        UNIFIED_PREFIXES.iter().any(|p| digits.starts_with(p)) && digits.as_bytes()[4] == b'0'
+    }
+
+    /// Check a value against the recognized formats
+    fn is_steuernummer(value: &str) -> bool {
+        let value = value.trim();
+        let full_match = |re: &Regex| re.find(value).is_some_and(|m| m.as_str() == value);
+
+        if full_match(&STATE_PATTERN) {
+            return true;
+        }
+
+        full_match(&KEYWORD_PATTERN) && (value.contains(' ') || Self::is_unified(value))
+    }
+
+    fn build_match(
+        &self,
+        matched: regex::Match,
+        line_num: usize,
+        byte_offset: usize,
+        confidence: Confidence,
+        file_path: &Path,
+    ) -> Match {
+        let digits: String = matched
+            .as_str()
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+
+        Match {
+            detector_id: self.id().to_string(),
+            detector_name: self.name().to_string(),
+            country: self.country().to_string(),
+            value_masked: mask_value(&digits),
+            location: crate::core::types::Location {
+                file_path: file_path.to_path_buf(),
+                line: line_num + 1,
+                column: matched.start(),
+                start_byte: byte_offset + matched.start(),
+                end_byte: byte_offset + matched.end(),
+            },
+            confidence,
+            severity: self.base_severity(),
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        }
+    }
+}
+
+impl Default for SteuernummerDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for SteuernummerDetector {
+    fn id(&self) -> &str {
+        "de_steuernummer"
+    }
+
+    fn name(&self) -> &str {
+        "Germany Tax Number (Steuernummer)"
+    }
+
+    fn country(&self) -> &str {
+        "de"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Unformatted numbers without a keyword are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in STATE_PATTERN.find_iter(line) {
+                let confidence = if has_keyword_before(line, capture.start(), STEUERNUMMER_KEYWORDS)
+                {
+                    Confidence::High
+                } else {
+                    Confidence::Low
+                };
+
+                matches.push(self.build_match(
+                    capture,
+                    line_num,
+                    byte_offset,
+                    confidence,
+                    file_path,
+                ));
+            }
+
+            for capture in KEYWORD_PATTERN.find_iter(line) {
+                if !Self::is_steuernummer(capture.as_str()) {
+                    continue;
+                }
+                let Some(confidence) = keyword_gate(
+                    line,
+                    capture.start(),
+                    STEUERNUMMER_KEYWORDS,
+                    Confidence::High,
+                    context.strictness,
+                ) else {
+                    continue;
+                };
+
+                matches.push(self.build_match(
+                    capture,
+                    line_num,
+                    byte_offset,
+                    confidence,
+                    file_path,
+                ));
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::is_steuernummer(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("21/815/08150")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("1893081508152")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects German Steuernummer (tax office number) in the federal-state formats \
+             (e.g. 21/815/08150, 133/8150/8159, 93815/08152) and the 13-digit unified format. \
+             Spaced and unified forms require a keyword such as 'Steuernummer' or 'St.-Nr.'"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_steuernummer_state_formats() {
+        let detector = SteuernummerDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        for value in [
+            "21/815/08150",
+            "181/815/08155",
+            "133/8150/8159",
+            "93815/08152",
+            "22/815/0815/4",
+        ] {
+            let text = format!("Steuernummer: {}", value);
+            let matches = detector.detect(&text, &path);
+            assert_eq!(matches.len(), 1, "{}", value);
+            assert_eq!(matches[0].confidence, Confidence::High);
+            assert_eq!(matches[0].severity, Severity::High);
+            assert_eq!(matches[0].location.start_byte, 14);
+        }
+
+        let matches = detector.detect("Ref 181/815/08155", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+    }
+
+    #[test]
+    fn test_steuernummer_unified_and_spaced_need_keyword() {
+        let detector = SteuernummerDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        assert_eq!(detector.detect("St.-Nr. 2893081508152", &path).len(), 1);
+        assert_eq!(
+            detector.detect("Steuernummer 013 815 08153", &path).len(),
+            1
+        );
+        assert!(detector.detect("EAN 2893081508152", &path).is_empty());
+        // Unknown state prefix
+        assert!(detector.detect("St.-Nr. 1893081508152", &path).is_empty());
+    }
+}
@@ -133,6 +133,15 @@ impl Detector for CprDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: crate::core::GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -141,6 +150,18 @@ impl Detector for CprDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::validate_date(value) && Self::validate_cpr(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("070985-1004")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("070985-1456")
+    }
 }
 
 impl Default for CprDetector {
@@ -1,4 +1,6 @@
 /// Denmark detectors module
 pub mod cpr;
+pub mod phone;
 
 pub use cpr::CprDetector;
+pub use phone::PhoneDetector;
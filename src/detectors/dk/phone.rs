@@ -0,0 +1,103 @@
+/// Denmark phone number detector
+///
+/// Finds Danish numbers in international (`+45`, `0045`) or national format:
+/// 8 digits without a trunk prefix (`20123456`, `20 12 34 56`).
+/// Candidates are validated as assignable Danish numbers with libphonenumber
+/// metadata, so numbers of other countries and too-short numbers are rejected.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::personal::phone::{detect_region_phones, is_region_phone};
+use once_cell::sync::Lazy;
+use phonenumber::country;
+use regex::Regex;
+use std::path::Path;
+
+/// National-format candidate: 8 digits, bare or grouped as `xx xx xx xx` / `xxx xx xxx`
+static NATIONAL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:\d{8}|\d{2} \d{2} \d{2} \d{2}|\d{3} \d{2} \d{3})\b")
+        .expect("Failed to compile Danish phone regex")
+});
+
+pub struct PhoneDetector;
+
+impl PhoneDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PhoneDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PhoneDetector {
+    fn id(&self) -> &str {
+        "dk_phone"
+    }
+
+    fn name(&self) -> &str {
+        "Danish Phone Number"
+    }
+
+    fn country(&self) -> &str {
+        "dk"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_region_phones(self, country::Id::DK, &NATIONAL_PATTERN, text, file_path)
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        &["phone"]
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        is_region_phone(country::Id::DK, &NATIONAL_PATTERN, value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Danish phone numbers (+45, 0045 or 8-digit national format) \
+             validated against libphonenumber metadata."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("+45 20 12 34 56")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("20 12 34")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Confidence;
+
+    #[test]
+    fn test_danish_formats() {
+        let detector = PhoneDetector::new();
+        let text = "Tlf. 20 12 34 56 eller +45 32 12 34 56";
+        let matches = detector.detect(text, Path::new("kunder.txt"));
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+        assert_eq!(matches[1].confidence, Confidence::High);
+    }
+
+    #[test]
+    fn test_cpr_is_not_a_phone_number() {
+        let detector = PhoneDetector::new();
+        assert!(detector
+            .detect("CPR 010190-1234", Path::new("kunder.txt"))
+            .is_empty());
+    }
+}
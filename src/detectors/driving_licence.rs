@@ -0,0 +1,65 @@
+//! Shared scanning for keyword-gated driving licence numbers
+//!
+//! Most national licence numbers have no public checksum, so the country
+//! detectors only report a number on a line that also names the licence.
+
+use crate::core::types::Location;
+use crate::core::{Confidence, Detector, GdprCategory, Match};
+use crate::utils::mask_value;
+use regex::Regex;
+use std::path::Path;
+
+/// Report every `pattern` match that `detector` validates on lines matching `keyword`
+pub(crate) fn detect_on_keyword_lines(
+    detector: &dyn Detector,
+    pattern: &Regex,
+    keyword: &Regex,
+    text: &str,
+    file_path: &Path,
+) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let mut byte_offset = 0;
+
+    for (line_num, line) in text.lines().enumerate() {
+        if keyword.is_match(line) {
+            for capture in pattern.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !detector.validate(matched_text) {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: detector.id().to_string(),
+                    detector_name: detector.name().to_string(),
+                    country: detector.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence: Confidence::High,
+                    severity: detector.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+        }
+
+        byte_offset += line.len() + 1;
+    }
+
+    matches
+}
@@ -3,7 +3,7 @@
 /// DNI is the Spanish national ID card number for Spanish citizens.
 /// Format: 8 digits followed by a letter (e.g., 12345678Z)
 /// The letter is calculated using modulus 23 algorithm.
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::{mask_value, validate_spain_id};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -47,6 +47,12 @@ impl Detector for DniDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Values failing their checksum are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -63,8 +69,8 @@ impl Detector for DniDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches (strict mode)
-                if confidence == Confidence::High {
+                // Checksum failures are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -81,6 +87,15 @@ impl Detector for DniDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -90,6 +105,18 @@ impl Detector for DniDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_spain_id(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("12345678Z")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("12345678A")
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 /// Spain PII Detectors
 pub mod dni;
 pub mod nie;
+pub mod social_security;
 
 pub use dni::DniDetector;
 pub use nie::NieDetector;
+pub use social_security::SocialSecurityDetector;
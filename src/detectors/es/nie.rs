@@ -4,7 +4,7 @@
 /// Format: X/Y/Z followed by 7 digits and a letter (e.g., X1234567L)
 /// The letter is calculated using modulus 23 algorithm (same as DNI).
 /// X=0, Y=1, Z=2 for calculation purposes.
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::{mask_value, validate_spain_id};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -48,6 +48,12 @@ impl Detector for NieDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Values failing their checksum are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -65,8 +71,8 @@ impl Detector for NieDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches (strict mode)
-                if confidence == Confidence::High {
+                // Checksum failures are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -83,6 +89,15 @@ impl Detector for NieDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -92,6 +107,18 @@ impl Detector for NieDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_spain_id(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("X1234567L")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("X1234567A")
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,155 @@
+/// Spain Seguridad Social number (NAF/NUSS) detector
+///
+/// The NAF (Número de Afiliación) appears on Spanish payslips and contracts.
+/// Format: 2-digit province + 8-digit serial + 2 check digits, often written
+/// with separators (e.g., 28/12345678/40). The check digits are the
+/// province and serial modulo 97.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::{mask_value, validate_spain_naf};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for NAF detection
+/// Matches: 12 digits, optionally separated as PP SSSSSSSS CC
+/// Examples: 281234567840, 28/12345678/40, 28-12345678-40
+static NAF_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b\d{2}[ /-]?\d{8}[ /-]?\d{2}\b").expect("Failed to compile NAF regex")
+});
+
+pub struct SocialSecurityDetector;
+
+impl SocialSecurityDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SocialSecurityDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for SocialSecurityDetector {
+    fn id(&self) -> &str {
+        "es_naf"
+    }
+
+    fn name(&self) -> &str {
+        "Spain Social Security Number (NAF)"
+    }
+
+    fn country(&self) -> &str {
+        "es"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in NAF_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                // Only report numbers with valid modulus 97 check digits
+                if !validate_spain_naf(matched_text) {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence: Confidence::High,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_spain_naf(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("281234567840")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("281234567841")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Spanish social security numbers (NAF/NUSS). \
+             Uses modulus 97 check digit validation. \
+             Format: 12 digits (PP/SSSSSSSS/CC)"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_naf_detect_valid() {
+        let detector = SocialSecurityDetector::new();
+        let text = "Nº afiliación S.S.: 28/12345678/40";
+        let path = PathBuf::from("nomina.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "es_naf");
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "28/12345678/40"
+        );
+    }
+
+    #[test]
+    fn test_naf_reject_invalid_check_digits() {
+        let detector = SocialSecurityDetector::new();
+        let matches = detector.detect("NAF: 281234567841", &PathBuf::from("test.txt"));
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_naf_reject_wrong_length() {
+        let detector = SocialSecurityDetector::new();
+        let matches = detector.detect("NAF: 2812345678", &PathBuf::from("test.txt"));
+        assert!(matches.is_empty());
+        assert!(!detector.validate("28123456784"));
+    }
+}
@@ -0,0 +1,201 @@
+/// EU EORI (Economic Operators Registration and Identification) number detector
+///
+/// EORI numbers identify traders in customs declarations: a 2-letter country
+/// code followed by up to 15 alphanumerics. Many member states derive the
+/// number from the trader's VAT number; when the body has a known national
+/// VAT format its check digits decide the confidence (valid: High, invalid:
+/// Low). Other bodies are reported with Medium confidence. Only numbers on a
+/// line that mentions EORI are reported.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::checksum::validate_vat;
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// EU member state codes plus `XI` (Northern Ireland)
+static EORI_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"\b(?:AT|BE|BG|CY|CZ|DE|DK|EE|ES|FI|FR|GR|HR|HU|IE|IT|LT|LU|LV|MT|NL|PL|PT|RO|SE|SI|SK|XI)[0-9A-Z]{1,15}\b",
+    )
+    .expect("Failed to compile EORI regex")
+});
+
+/// Keyword (lowercase) that must appear on the same line
+const EORI_KEYWORD: &str = "eori";
+
+pub struct EoriDetector;
+
+impl EoriDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Confidence for a well-formed EORI, from its VAT check digits if any
+    fn confidence(value: &str) -> Confidence {
+        match validate_vat(value) {
+            Some(true) => Confidence::High,
+            Some(false) => Confidence::Low,
+            None => Confidence::Medium,
+        }
+    }
+
+    /// Country code followed by 1-15 alphanumerics, with at least one digit
+    fn is_eori_format(value: &str) -> bool {
+        EORI_PATTERN
+            .find(value)
+            .is_some_and(|m| m.start() == 0 && m.end() == value.len())
+            && value[2..].bytes().any(|b| b.is_ascii_digit())
+    }
+}
+
+impl Default for EoriDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for EoriDetector {
+    fn id(&self) -> &str {
+        "eu_eori"
+    }
+
+    fn name(&self) -> &str {
+        "EU EORI Number"
+    }
+
+    fn country(&self) -> &str {
+        "eu"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn warmup(&self) {
+        Lazy::force(&EORI_PATTERN);
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            if !line.to_lowercase().contains(EORI_KEYWORD) {
+                byte_offset += line.len() + 1;
+                continue;
+            }
+
+            for capture in EORI_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !Self::is_eori_format(matched_text) {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence: Self::confidence(matched_text),
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::is_eori_format(value) && validate_vat(value) != Some(false)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("DE136695976")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("DE136695977")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects EU EORI numbers (country code + up to 15 alphanumerics) on lines \
+             mentioning EORI. VAT-based numbers are checked with the national VAT check digits."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eori_from_valid_de_vat() {
+        let detector = EoriDetector::new();
+        let matches = detector.detect("EORI: DE136695976", Path::new("customs.csv"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::High);
+        assert_eq!(matches[0].severity, Severity::Medium);
+        assert_eq!(matches[0].location.start_byte, 6);
+    }
+
+    #[test]
+    fn test_eori_with_invalid_vat_body() {
+        let detector = EoriDetector::new();
+        let matches = detector.detect("EORI: DE136695977", Path::new("customs.csv"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+        assert!(!detector.validate("DE136695977"));
+    }
+
+    #[test]
+    fn test_eori_non_vat_body_and_keyword() {
+        let detector = EoriDetector::new();
+        let matches = detector.detect(
+            "Exporter EORI number IE1234567XYZ",
+            Path::new("customs.csv"),
+        );
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+
+        // Without the keyword, VAT-like codes are not reported
+        assert!(detector
+            .detect("VAT: DE136695976", Path::new("invoice.txt"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_warmup_compiles_keyword_gated_pattern() {
+        let mut registry = crate::core::DetectorRegistry::new();
+        registry.register(Box::new(EoriDetector::new()));
+        registry.warmup();
+
+        // The warmup sample has no EORI keyword, so detect() alone skips it
+        assert!(Lazy::get(&EORI_PATTERN).is_some());
+    }
+}
@@ -2,8 +2,14 @@
 ///
 /// Detects IBANs for all EU countries using modulo-97 validation.
 /// Supports all SEPA countries and additional European countries.
+/// IBANs whose length doesn't match the ISO 13616 registry length for their
+/// country code are reported with Low confidence, and IBANs whose national
+/// BBAN check digits fail (e.g. the Italian CIN or French RIB key) are
+/// reported with Medium confidence.
 use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
-use crate::utils::{mask_iban, validate_iban};
+use crate::utils::{
+    mask_preserving, validate_iban, validate_iban_length, validate_iban_national, MaskKind,
+};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::path::Path;
@@ -15,11 +21,37 @@ static IBAN_PATTERN: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"\b[A-Z]{2}\d{2}[A-Z0-9]{1,30}\b").expect("Failed to compile IBAN regex")
 });
 
-pub struct IbanDetector;
+pub struct IbanDetector {
+    national_checks: bool,
+}
 
 impl IbanDetector {
     pub fn new() -> Self {
-        Self
+        Self {
+            national_checks: true,
+        }
+    }
+
+    /// Enable or disable national account checksums (BE, ES, FI, FR, IT, NL, NO, SE)
+    ///
+    /// When enabled, IBANs that pass modulo-97 but fail the embedded national
+    /// check are reported with Medium instead of High confidence.
+    pub fn national_checks(mut self, enabled: bool) -> Self {
+        self.national_checks = enabled;
+        self
+    }
+
+    fn confidence_for(&self, iban: &str) -> Confidence {
+        // Wrong length for the country code: checksum matched by chance
+        if validate_iban_length(iban) == Some(false) {
+            return Confidence::Low;
+        }
+
+        if self.national_checks && validate_iban_national(iban) == Some(false) {
+            Confidence::Medium
+        } else {
+            Confidence::High
+        }
     }
 }
 
@@ -64,7 +96,10 @@ impl Detector for IbanDetector {
                         detector_id: self.id().to_string(),
                         detector_name: format!("{} ({})", self.name(), country_code),
                         country: country_code.to_lowercase(),
-                        value_masked: mask_iban(matched_text),
+                        value_masked: mask_preserving(
+                            matched_text,
+                            MaskKind::for_detector(self.id()),
+                        ),
                         location: crate::core::types::Location {
                             file_path: file_path.to_path_buf(),
                             line: line_num + 1,
@@ -72,10 +107,19 @@ impl Detector for IbanDetector {
                             start_byte: byte_offset + capture.start(),
                             end_byte: byte_offset + capture.end(),
                         },
-                        confidence: Confidence::High,
+                        confidence: self.confidence_for(matched_text),
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -87,7 +131,15 @@ impl Detector for IbanDetector {
     }
 
     fn validate(&self, value: &str) -> bool {
-        validate_iban(value)
+        validate_iban(value) && validate_iban_length(value) != Some(false)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("NL91ABNA0417164300")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("NL00ABNA0417164300")
     }
 
     fn description(&self) -> Option<String> {
@@ -139,6 +191,74 @@ mod tests {
         assert_eq!(matches[0].country, "be");
     }
 
+    #[test]
+    fn test_iban_belgian_wrong_national_check() {
+        let detector = IbanDetector::new();
+        // Mod-97 is correct, but the Belgian check digits (35) don't match
+        let text = "BE41539007547035";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+
+        let valid = detector.detect("BE68539007547034", &path);
+        assert_eq!(valid[0].confidence, Confidence::High);
+    }
+
+    #[test]
+    fn test_iban_italian_wrong_cin() {
+        let detector = IbanDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        // Mod-97 is correct, but the CIN letter should be X
+        let matches = detector.detect("IBAN: IT64Y0542811101000000123456", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+
+        let valid = detector.detect("IBAN: IT60X0542811101000000123456", &path);
+        assert_eq!(valid[0].confidence, Confidence::High);
+    }
+
+    #[test]
+    fn test_iban_french_wrong_rib_key() {
+        let detector = IbanDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        // Mod-97 is correct, but the RIB key should be 06
+        let matches = detector.detect("IBAN: FR8420041010050500013M02607", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+
+        let valid = detector.detect("IBAN: FR1420041010050500013M02606", &path);
+        assert_eq!(valid[0].confidence, Confidence::High);
+    }
+
+    #[test]
+    fn test_iban_national_checks_disabled() {
+        let detector = IbanDetector::new().national_checks(false);
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect("BE41539007547035", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::High);
+    }
+
+    #[test]
+    fn test_iban_wrong_country_length() {
+        let detector = IbanDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect("IBAN: DE89370400440532013000", &path);
+        assert_eq!(matches[0].confidence, Confidence::High);
+
+        // Passes modulo-97, but is 20 characters instead of 22
+        let matches = detector.detect("IBAN: DE863704004405320130", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+        assert!(!detector.validate("DE863704004405320130"));
+    }
+
     #[test]
     fn test_iban_invalid_checksum() {
         let detector = IbanDetector::new();
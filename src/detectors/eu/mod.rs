@@ -1,4 +1,6 @@
 /// Pan-European PII detectors (IBAN, VAT, etc.)
+pub mod eori;
 pub mod iban;
 
+pub use eori::EoriDetector;
 pub use iban::IbanDetector;
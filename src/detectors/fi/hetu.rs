@@ -166,6 +166,15 @@ impl Detector for HetuDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: crate::core::GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -174,6 +183,18 @@ impl Detector for HetuDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::validate_date(value) && Self::validate_hetu(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("131052-308T")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("131052-308U")
+    }
 }
 
 impl Default for HetuDetector {
@@ -1,9 +1,15 @@
 /// Credit Card detector with Luhn algorithm validation
 ///
 /// Detects Visa, Mastercard, American Express, and other major cards.
-/// Uses Luhn checksum to minimize false positives.
+/// Uses Luhn checksum to minimize false positives. After validation the card
+/// network is identified from the IIN/BIN range and card length (Visa,
+/// Mastercard incl. the 2221-2720 series, Amex, Maestro, Discover, JCB).
+///
+/// An expiry date (`MM/YY`, `MM/YYYY`) alone is noise, but next to a card
+/// number it means the full card data is exposed: such matches are raised to
+/// Critical and tagged `expiry` in `correlated_with`.
 use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
-use crate::utils::{mask_credit_card, validate_luhn};
+use crate::utils::{luhn_checksum, mask_preserving, validate_luhn, MaskKind};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::path::Path;
@@ -15,20 +21,40 @@ static VISA_PATTERN: Lazy<Regex> = Lazy::new(|| {
 });
 
 static MASTERCARD_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b5[1-5]\d{2}[\s\-]?\d{4}[\s\-]?\d{4}[\s\-]?\d{4}\b")
-        .expect("Failed to compile Mastercard regex")
+    // 51-55 and the 2-series 2221-2720
+    Regex::new(
+        r"\b(?:5[1-5]\d{2}|2(?:22[1-9]|2[3-9]\d|[3-6]\d{2}|7[01]\d|720))[\s\-]?\d{4}[\s\-]?\d{4}[\s\-]?\d{4}\b",
+    )
+    .expect("Failed to compile Mastercard regex")
 });
 
 static AMEX_PATTERN: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"\b3[47]\d{2}[\s\-]?\d{6}[\s\-]?\d{5}\b").expect("Failed to compile Amex regex")
 });
 
+static MAESTRO_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    // Maestro numbers are 12-19 digits long
+    Regex::new(r"\b(?:5[06-8]|6\d)\d{2}[\s\-]?\d{4}[\s\-]?\d{4}(?:[\s\-]?\d{1,4}){0,2}\b")
+        .expect("Failed to compile Maestro regex")
+});
+
 static GENERIC_CARD_PATTERN: Lazy<Regex> = Lazy::new(|| {
     // Catch-all for 13-19 digit card numbers
     Regex::new(r"\b\d{4}[\s\-]?\d{4}[\s\-]?\d{4}[\s\-]?\d{1,7}\b")
         .expect("Failed to compile generic card regex")
 });
 
+/// Card expiry date: month 01-12, then a 2- or 4-digit year
+static EXPIRY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:0[1-9]|1[0-2])\s?/\s?(?:20)?\d{2}\b").expect("Failed to compile expiry regex")
+});
+
+/// Maximum distance (bytes) between a card number and its expiry date
+const EXPIRY_PROXIMITY: usize = 32;
+
+/// Tag added to `correlated_with` when an expiry date is near the card number
+const EXPIRY_TAG: &str = "expiry";
+
 pub struct CreditCardDetector;
 
 impl CreditCardDetector {
@@ -36,24 +62,47 @@ impl CreditCardDetector {
         Self
     }
 
-    fn detect_card_type(&self, digits: &str) -> &'static str {
-        if digits.starts_with('4') {
-            "Visa"
-        } else if digits.starts_with('5') && digits.len() >= 2 {
-            let second = digits.chars().nth(1).unwrap();
-            if ('1'..='5').contains(&second) {
-                "Mastercard"
+    /// Whether an expiry date appears within [`EXPIRY_PROXIMITY`] of `start..end` in `line`
+    ///
+    /// Expiries that are part of a longer date (`12/05/2024`) don't count.
+    fn has_expiry_near(line: &str, start: usize, end: usize) -> bool {
+        EXPIRY_PATTERN.find_iter(line).any(|expiry| {
+            let full_date = line[..expiry.start()].ends_with(['/', '.', '-'])
+                || line[expiry.end()..].starts_with(['/', '.', '-']);
+            let distance = if expiry.start() >= end {
+                expiry.start() - end
             } else {
-                "Unknown"
-            }
-        } else if digits.starts_with("34") || digits.starts_with("37") {
-            "American Express"
-        } else {
-            "Unknown"
-        }
+                start.saturating_sub(expiry.end())
+            };
+
+            !full_date && distance <= EXPIRY_PROXIMITY
+        })
     }
 }
 
+/// Identify the card network from the IIN/BIN prefix and length of `digits`
+///
+/// Returns `None` when the prefix is unknown or the length does not fit the
+/// network (e.g. a 15-digit number with a Visa prefix).
+pub fn card_brand(digits: &str) -> Option<&'static str> {
+    let prefix = |n: usize| digits.get(..n).and_then(|p| p.parse::<u32>().ok());
+    let len = digits.len();
+
+    let (brand, lengths): (&str, &[usize]) = match (prefix(2)?, prefix(3)?, prefix(4)?) {
+        (34 | 37, _, _) => ("American Express", &[15]),
+        (40..=49, _, _) => ("Visa", &[13, 16, 19]),
+        (51..=55, _, _) | (_, _, 2221..=2720) => ("Mastercard", &[16]),
+        (65, _, _) | (_, 644..=649, _) | (_, _, 6011) => ("Discover", &[16, 17, 18, 19]),
+        (_, _, 3528..=3589) => ("JCB", &[16, 17, 18, 19]),
+        (50 | 56..=58 | 67, _, _) | (_, 639, _) | (_, _, 6304) => {
+            ("Maestro", &[12, 13, 14, 15, 16, 17, 18, 19])
+        }
+        _ => return None,
+    };
+
+    lengths.contains(&len).then_some(brand)
+}
+
 impl Default for CreditCardDetector {
     fn default() -> Self {
         Self::new()
@@ -77,6 +126,10 @@ impl Detector for CreditCardDetector {
         Severity::High
     }
 
+    fn warmup(&self) {
+        Lazy::force(&EXPIRY_PATTERN);
+    }
+
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
@@ -87,6 +140,7 @@ impl Detector for CreditCardDetector {
                 &*VISA_PATTERN,
                 &*MASTERCARD_PATTERN,
                 &*AMEX_PATTERN,
+                &*MAESTRO_PATTERN,
                 &*GENERIC_CARD_PATTERN,
             ];
 
@@ -100,15 +154,26 @@ impl Detector for CreditCardDetector {
                         .filter(|c| c.is_ascii_digit())
                         .collect();
 
-                    // Validate with Luhn algorithm
-                    if validate_luhn(&digits) {
-                        let card_type = self.detect_card_type(&digits);
+                    let brand = card_brand(&digits);
 
+                    // Validate with Luhn algorithm (Maestro cards can be 12 digits)
+                    let luhn_valid = validate_luhn(&digits)
+                        || (brand == Some("Maestro") && luhn_checksum(&digits));
+                    if luhn_valid {
+                        let with_expiry =
+                            Self::has_expiry_near(line, capture.start(), capture.end());
                         matches.push(Match {
                             detector_id: self.id().to_string(),
-                            detector_name: format!("{} ({})", self.name(), card_type),
+                            detector_name: format!(
+                                "{} ({})",
+                                self.name(),
+                                brand.unwrap_or("Unknown")
+                            ),
                             country: self.country().to_string(),
-                            value_masked: mask_credit_card(&digits),
+                            value_masked: mask_preserving(
+                                matched_text,
+                                MaskKind::for_detector(self.id()),
+                            ),
                             location: crate::core::types::Location {
                                 file_path: file_path.to_path_buf(),
                                 line: line_num + 1,
@@ -117,9 +182,26 @@ impl Detector for CreditCardDetector {
                                 end_byte: byte_offset + capture.end(),
                             },
                             confidence: Confidence::High,
-                            severity: self.base_severity(),
+                            severity: if with_expiry {
+                                Severity::Critical
+                            } else {
+                                self.base_severity()
+                            },
                             context: None,
                             gdpr_category: GdprCategory::Regular,
+                            fingerprint: String::new(),
+                            decoded_from_base64: false,
+                            partially_masked: false,
+                            nationality: None,
+                            issuing_country: None,
+                            brand: brand.map(str::to_string),
+                            icon: None,
+                            correlated_with: if with_expiry {
+                                vec![EXPIRY_TAG.to_string()]
+                            } else {
+                                Vec::new()
+                            },
+                            key_path: None,
                         });
                     }
                 }
@@ -136,14 +218,16 @@ impl Detector for CreditCardDetector {
     }
 
     fn validate(&self, value: &str) -> bool {
-        validate_luhn(value)
+        let digits: String = value.chars().filter(|c| c.is_ascii_digit()).collect();
+        validate_luhn(&digits) || (card_brand(&digits) == Some("Maestro") && luhn_checksum(&digits))
     }
 
     fn description(&self) -> Option<String> {
         Some(
-            "Detects credit card numbers (Visa, Mastercard, American Express, etc.). \
+            "Detects credit card numbers and identifies the network (Visa, Mastercard, \
+             American Express, Maestro, Discover, JCB) from the IIN range. \
              Uses Luhn algorithm validation to minimize false positives. \
-             Supports 13-19 digit card numbers."
+             Supports 12-19 digit card numbers."
                 .to_string(),
         )
     }
@@ -185,6 +269,91 @@ mod tests {
         let matches = detector.detect(text, &path);
         assert_eq!(matches.len(), 1);
         assert!(matches[0].detector_name.contains("American Express"));
+        assert_eq!(matches[0].brand.as_deref(), Some("American Express"));
+    }
+
+    #[test]
+    fn test_brand_visa() {
+        let detector = CreditCardDetector::new();
+        let matches = detector.detect("Card: 4532015112830366", &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Credit Card Number (Visa)");
+        assert_eq!(matches[0].brand.as_deref(), Some("Visa"));
+    }
+
+    #[test]
+    fn test_brand_mastercard_2_series() {
+        let detector = CreditCardDetector::new();
+        let matches = detector.detect("Card: 2223 0031 2200 3222", &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].brand.as_deref(), Some("Mastercard"));
+        assert_eq!(matches[0].value_masked, "2223 **** **** 3222");
+
+        // Range bounds
+        assert_eq!(card_brand("2221000000000009"), Some("Mastercard"));
+        assert_eq!(card_brand("2720990000000007"), Some("Mastercard"));
+        assert_eq!(card_brand("2721000000000006"), None);
+    }
+
+    #[test]
+    fn test_brand_maestro_variable_length() {
+        let detector = CreditCardDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect("Maestro: 6759649826438453", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].brand.as_deref(), Some("Maestro"));
+
+        // 12-digit and 19-digit Maestro numbers
+        let matches = detector.detect("Pas: 501800000009", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Credit Card Number (Maestro)");
+
+        let matches = detector.detect("Pas: 6759 6498 2643 8453 128", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].brand.as_deref(), Some("Maestro"));
+        assert_eq!(matches[0].location.end_byte, 28);
+    }
+
+    #[test]
+    fn test_brand_other_networks() {
+        assert_eq!(card_brand("6011111111111117"), Some("Discover"));
+        assert_eq!(card_brand("3530111333300000"), Some("JCB"));
+        // Visa prefix with an invalid length
+        assert_eq!(card_brand("453201511283036"), None);
+    }
+
+    #[test]
+    fn test_expiry_next_to_card_upgrades() {
+        let detector = CreditCardDetector::new();
+        let text = "Kaart: 4532 0151 1283 0366 geldig tot 09/27\n\
+                    Carte: 5425233430109903, expire fin 11/2028\n\
+                    Karte: 4532015112830366, Bestellung vom 12/05/2024";
+        let path = PathBuf::from("orders.csv");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert_eq!(matches[0].correlated_with, vec!["expiry"]);
+        assert_eq!(matches[1].severity, Severity::Critical);
+
+        // A full date is not an expiry
+        assert_eq!(matches[2].severity, Severity::High);
+        assert!(matches[2].correlated_with.is_empty());
+    }
+
+    #[test]
+    fn test_bare_expiry_not_matched() {
+        let text = "Gültig bis 09/27\nLe paiement expire le 11/2028";
+        let matches = crate::default_registry()
+            .all()
+            .iter()
+            .flat_map(|d| d.detect(text, &PathBuf::from("notes.txt")))
+            .collect::<Vec<_>>();
+
+        assert!(matches.is_empty(), "{:?}", matches);
     }
 
     #[test]
@@ -220,4 +389,15 @@ mod tests {
         assert!(masked.ends_with("0366"));
         assert!(masked.contains("****"));
     }
+
+    #[test]
+    fn test_masking_preserves_grouping() {
+        let detector = CreditCardDetector::new();
+        let text = "Card: 4532 0151 1283 0366";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert!(!matches.is_empty());
+        assert_eq!(matches[0].value_masked, "4532 **** **** 0366");
+    }
 }
@@ -0,0 +1,179 @@
+/// Card verification value (CVV/CVC) detector
+///
+/// A 3-4 digit number is only reported when a card verification label
+/// ("CVV", "CVC", "security code", ...) directly precedes it. When a
+/// Luhn-valid card number appears on the same line, confidence is high.
+/// "CID" (the Amex code) also labels connection, customer and Content-IDs,
+/// so it only counts next to a card number.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::validate_luhn;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Labeled CVV: keyword, up to 5 separator characters, 3-4 digits
+static CVV_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?P<label>cvv2?|cvc2?|cvn|cid|csc|security\s+code|kaartcode)\b[^\w\n]{0,5}(?P<cvv>\d{3,4})\b",
+    )
+    .expect("Failed to compile CVV regex")
+});
+
+/// Card number candidate used to raise confidence
+static PAN_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b\d{4}[\s\-]?\d{4}[\s\-]?\d{4}[\s\-]?\d{1,7}\b")
+        .expect("Failed to compile card number regex")
+});
+
+pub struct CvvDetector;
+
+impl CvvDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether a Luhn-valid card number appears on the line
+    fn has_card_number(line: &str) -> bool {
+        PAN_PATTERN.find_iter(line).any(|m| {
+            let digits: String = m.as_str().chars().filter(|c| c.is_ascii_digit()).collect();
+            (13..=19).contains(&digits.len()) && validate_luhn(&digits)
+        })
+    }
+}
+
+impl Default for CvvDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for CvvDetector {
+    fn id(&self) -> &str {
+        "card_cvv"
+    }
+
+    fn name(&self) -> &str {
+        "Card Verification Value (CVV)"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            let has_card_number = Self::has_card_number(line);
+            let confidence = if has_card_number {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+
+            for caps in CVV_PATTERN.captures_iter(line) {
+                let (Some(label), Some(cvv)) = (caps.name("label"), caps.name("cvv")) else {
+                    continue;
+                };
+                if label.as_str().eq_ignore_ascii_case("cid") && !has_card_number {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: "*".repeat(cvv.as_str().len()),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: cvv.start(),
+                        start_byte: byte_offset + cvv.start(),
+                        end_byte: byte_offset + cvv.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects card verification values (3-4 digits) labeled as CVV, CVC or \
+             security code (CID next to a card number). High confidence when a valid \
+             card number is on the same line."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_labeled_cvv_near_pan() {
+        let detector = CvvDetector::new();
+        let matches = detector.detect(
+            "card 4111 1111 1111 1111 exp 12/25 CVV: 123",
+            &PathBuf::from("test.txt"),
+        );
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::High);
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert_eq!(matches[0].value_masked, "***");
+    }
+
+    #[test]
+    fn test_labeled_cvv_without_pan() {
+        let detector = CvvDetector::new();
+        let matches = detector.detect("security code 4821", &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_bare_number_not_detected() {
+        let detector = CvvDetector::new();
+        let matches = detector.detect("Room 123, floor 4", &PathBuf::from("test.txt"));
+
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_cid_needs_card_number() {
+        let detector = CvvDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        assert!(detector.detect("conn cid: 123 closed", &path).is_empty());
+        assert!(detector.detect("Content-ID: 4821", &path).is_empty());
+
+        let matches = detector.detect("Amex 378282246310005 CID 1234", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::High);
+    }
+}
@@ -0,0 +1,189 @@
+/// Partially masked card number and SSN detector
+///
+/// Exports and support tools often show only the last digits of a card
+/// number (`****-****-****-1234`) or US Social Security number
+/// (`XXX-XX-6789`). Such a value is still personal data, and it shows that the
+/// system it came from stores the full number. Masked digits cannot be
+/// checked, so there is no checksum validation; matches carry the
+/// `partially_masked` marker and have Low severity.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Four groups of four digits or mask characters, optionally separated
+static MASKED_CARD_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[^\w*•#])((?:[\dXx*•#]{4}[ \-]?){3}[\dXx*•#]{4})(?:$|[^\w*•#])")
+        .expect("Failed to compile masked card regex")
+});
+
+/// `XXX-XX-1234` (masked area and group numbers, visible serial)
+static MASKED_SSN_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[^\w*•#])([Xx*•#]{3}-[Xx*•#]{2}-\d{4})(?:$|[^\w*•#])")
+        .expect("Failed to compile masked SSN regex")
+});
+
+/// Characters used to hide digits
+const MASK_CHARS: &[char] = &['X', 'x', '*', '•', '#'];
+
+pub struct MaskedValueDetector;
+
+impl MaskedValueDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// A masked card hides at least 4 digits and shows its last 4
+    fn is_masked_card(value: &str) -> bool {
+        let symbols: Vec<char> = value.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+        let masked = symbols.iter().filter(|c| MASK_CHARS.contains(c)).count();
+        let last_four_visible = symbols[symbols.len() - 4..]
+            .iter()
+            .all(|c| c.is_ascii_digit());
+
+        symbols.len() == 16 && masked >= 4 && last_four_visible
+    }
+
+    /// Masked values in one line: (start, end, kind)
+    fn find_in_line(line: &str) -> Vec<(usize, usize, &'static str)> {
+        let mut found = Vec::new();
+
+        for caps in MASKED_CARD_PATTERN.captures_iter(line) {
+            let Some(value) = caps.get(1) else { continue };
+            if Self::is_masked_card(value.as_str()) {
+                found.push((value.start(), value.end(), "Card Number"));
+            }
+        }
+
+        for caps in MASKED_SSN_PATTERN.captures_iter(line) {
+            let Some(value) = caps.get(1) else { continue };
+            found.push((value.start(), value.end(), "SSN"));
+        }
+
+        found.sort_by_key(|(start, ..)| *start);
+        found
+    }
+}
+
+impl Default for MaskedValueDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for MaskedValueDetector {
+    fn id(&self) -> &str {
+        "masked_value"
+    }
+
+    fn name(&self) -> &str {
+        "Partially Masked"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Low
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for (start, end, kind) in Self::find_in_line(line) {
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: format!("{} {}", self.name(), kind),
+                    country: self.country().to_string(),
+                    // Already masked at the source
+                    value_masked: line[start..end].to_string(),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: start,
+                        start_byte: byte_offset + start,
+                        end_byte: byte_offset + end,
+                    },
+                    confidence: Confidence::Medium,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: true,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        !Self::find_in_line(value).is_empty()
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects card numbers and SSNs that are already partially masked \
+             (****-****-****-1234, XXX-XX-6789), a sign that the full values are stored \
+             elsewhere. No checksum validation."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn detect(text: &str) -> Vec<Match> {
+        MaskedValueDetector::new().detect(text, &PathBuf::from("orders.csv"))
+    }
+
+    #[test]
+    fn test_masked_card() {
+        let text = "order 1042,card ****-****-****-1234,paid";
+        let matches = detect(text);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Partially Masked Card Number");
+        assert_eq!(matches[0].value_masked, "****-****-****-1234");
+        assert!(matches[0].partially_masked);
+        assert_eq!(matches[0].severity, Severity::Low);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "****-****-****-1234"
+        );
+
+        // First six and last four visible
+        assert_eq!(detect("PAN: 4111 11XX XXXX 1111").len(), 1);
+        // Fully visible or fully masked numbers are not partially masked cards
+        assert!(detect("4111 1111 1111 1111").is_empty());
+        assert!(detect("XXXX-XXXX-XXXX-XXXX").is_empty());
+    }
+
+    #[test]
+    fn test_masked_ssn() {
+        let matches = detect("SSN: XXX-XX-6789");
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Partially Masked SSN");
+        assert_eq!(matches[0].value_masked, "XXX-XX-6789");
+        assert!(matches[0].partially_masked);
+
+        assert_eq!(detect("ssn=***-**-6789").len(), 1);
+        assert!(detect("ref ABC-XX-6789").is_empty());
+    }
+}
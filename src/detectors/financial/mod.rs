@@ -1,4 +1,10 @@
-/// Universal financial detectors (credit cards, crypto, SWIFT)
+/// Universal financial detectors (credit cards, track data, CVV, partially masked values)
 pub mod creditcard;
+pub mod cvv;
+pub mod masked;
+pub mod track_data;
 
 pub use creditcard::CreditCardDetector;
+pub use cvv::CvvDetector;
+pub use masked::MaskedValueDetector;
+pub use track_data::TrackDataDetector;
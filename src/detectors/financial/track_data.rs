@@ -0,0 +1,179 @@
+/// Magnetic stripe track data detector
+///
+/// Detects Track 1 (`%B<PAN>^<NAME>^<YYMM><service code>...?`) and
+/// Track 2 (`;<PAN>=<YYMM><service code>...?`) data. Storing track data
+/// after authorization is never allowed, so matches are always critical.
+/// The embedded PAN is validated with the Luhn algorithm.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::{mask_preserving, validate_luhn, MaskKind};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Track 1: format code B, PAN, name, expiry + service code, discretionary data
+///
+/// The format code must start a token, so `...AB4111...^` inside a longer
+/// word is not track data.
+static TRACK1_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[^\w])(?P<track>%?B(?P<pan>\d{12,19})\^[^\^\n]{2,26}\^\d{7}[^?\n]*\??)")
+        .expect("Failed to compile Track 1 regex")
+});
+
+/// Track 2: PAN, separator, expiry + service code, discretionary data
+static TRACK2_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?P<track>;?\b(?P<pan>\d{12,19})=\d{7}\d*\??)")
+        .expect("Failed to compile Track 2 regex")
+});
+
+pub struct TrackDataDetector;
+
+impl TrackDataDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TrackDataDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for TrackDataDetector {
+    fn id(&self) -> &str {
+        "card_track_data"
+    }
+
+    fn name(&self) -> &str {
+        "Payment Card Track Data"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for pattern in [&*TRACK1_PATTERN, &*TRACK2_PATTERN] {
+                for caps in pattern.captures_iter(line) {
+                    let (Some(full), Some(pan)) = (caps.name("track"), caps.name("pan")) else {
+                        continue;
+                    };
+
+                    if !validate_luhn(pan.as_str()) {
+                        continue;
+                    }
+
+                    matches.push(Match {
+                        detector_id: self.id().to_string(),
+                        detector_name: self.name().to_string(),
+                        country: self.country().to_string(),
+                        value_masked: mask_preserving(
+                            pan.as_str(),
+                            MaskKind::for_detector(self.id()),
+                        ),
+                        location: crate::core::types::Location {
+                            file_path: file_path.to_path_buf(),
+                            line: line_num + 1,
+                            column: full.start(),
+                            start_byte: byte_offset + full.start(),
+                            end_byte: byte_offset + full.end(),
+                        },
+                        confidence: Confidence::High,
+                        severity: self.base_severity(),
+                        context: None,
+                        gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
+                    });
+                }
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects magnetic stripe Track 1 and Track 2 data. \
+             The embedded card number is validated with the Luhn algorithm."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_track2() {
+        let detector = TrackDataDetector::new();
+        let matches = detector.detect(
+            "swipe: ;4111111111111111=25121010000012300000?",
+            &PathBuf::from("test.txt"),
+        );
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert_eq!(matches[0].value_masked, "4111********1111");
+    }
+
+    #[test]
+    fn test_track1() {
+        let detector = TrackDataDetector::new();
+        let matches = detector.detect(
+            "%B4111111111111111^DOE/JOHN^2512101000000123000?",
+            &PathBuf::from("test.txt"),
+        );
+
+        assert_eq!(matches.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_pan() {
+        let detector = TrackDataDetector::new();
+        let matches = detector.detect(
+            ";4111111111111112=25121010000012300000?",
+            &PathBuf::from("test.txt"),
+        );
+
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_track1_inside_token() {
+        let detector = TrackDataDetector::new();
+        let matches = detector.detect(
+            "token=XB4111111111111111^DOE/JOHN^2512101000000123000",
+            &PathBuf::from("test.txt"),
+        );
+
+        assert!(matches.is_empty());
+
+        // A separator before the format code is fine; the match starts at it
+        let matches = detector.detect(
+            "data=%B4111111111111111^DOE/JOHN^2512101000000123000?",
+            &PathBuf::from("test.txt"),
+        );
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].location.start_byte, 5);
+    }
+}
@@ -0,0 +1,121 @@
+/// French driving licence number (numéro de permis de conduire) detector
+///
+/// French paper licences carry a 12-digit number without a public
+/// checksum, so a number is only reported when the word "permis" appears
+/// on the same line.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::driving_licence::detect_on_keyword_lines;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for permis de conduire detection
+/// Matches: 12 consecutive digits (e.g., 880775123456)
+static LICENCE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{12}\b").expect("Failed to compile driving licence regex"));
+
+/// Keyword that must appear on the line
+static LICENCE_KEYWORD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\bpermis\b").expect("Failed to compile driving licence keyword regex")
+});
+
+pub struct DrivingLicenceDetector;
+
+impl DrivingLicenceDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DrivingLicenceDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for DrivingLicenceDetector {
+    fn id(&self) -> &str {
+        "fr_driving_licence"
+    }
+
+    fn name(&self) -> &str {
+        "French Driving Licence Number (Permis de conduire)"
+    }
+
+    fn country(&self) -> &str {
+        "fr"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn warmup(&self) {
+        Lazy::force(&LICENCE_PATTERN);
+        Lazy::force(&LICENCE_KEYWORD);
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_on_keyword_lines(self, &LICENCE_PATTERN, &LICENCE_KEYWORD, text, file_path)
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        value.len() == 12
+            && value.chars().all(|c| c.is_ascii_digit())
+            && !value.chars().all(|c| value.starts_with(c))
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("880775123456")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("88077512345")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects French driving licence numbers (permis de conduire) on lines mentioning \"permis\". \
+             Format: 12 digits"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_licence_with_keyword() {
+        let detector = DrivingLicenceDetector::new();
+        let text = "N° de permis de conduire : 880775123456";
+        let matches = detector.detect(text, &PathBuf::from("location.csv"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "fr_driving_licence");
+        assert_eq!(matches[0].severity, Severity::High);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "880775123456"
+        );
+    }
+
+    #[test]
+    fn test_licence_without_keyword() {
+        let detector = DrivingLicenceDetector::new();
+        let matches = detector.detect("N° client : 880775123456", &PathBuf::from("location.csv"));
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_licence_keyword_whole_word() {
+        let detector = DrivingLicenceDetector::new();
+        let matches = detector.detect(
+            "Permission ID : 880775123456",
+            &PathBuf::from("location.csv"),
+        );
+        assert!(matches.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
 /// French PII detectors
+pub mod driving_licence;
 pub mod nir;
 
+pub use driving_licence::DrivingLicenceDetector;
 pub use nir::NirDetector;
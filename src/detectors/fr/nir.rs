@@ -12,7 +12,7 @@
 ///
 /// Format: 1 YY MM DD CCC OOO KK
 /// Example: 2 89 05 75 123 456 89
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::mask_value;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -90,6 +90,12 @@ impl Detector for NirDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Values failing their checksum are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -114,8 +120,8 @@ impl Detector for NirDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches
-                if confidence == Confidence::High {
+                // Checksum failures are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -132,6 +138,15 @@ impl Detector for NirDetector {
                         },
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -141,6 +156,19 @@ impl Detector for NirDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        let digits: String = value.chars().filter(|c| c.is_ascii_digit()).collect();
+        Self::validate_nir(&digits)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("1 89 05 75 123 456 71")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("189057512345600")
+    }
 }
 
 #[cfg(test)]
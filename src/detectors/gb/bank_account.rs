@@ -0,0 +1,290 @@
+/// UK bank account detector (sort code + account number)
+///
+/// UK accounts are identified by a 6-digit sort code (`NN-NN-NN`) and an
+/// 8-digit account number. Individually both look like random numbers, so
+/// only adjacent pairs are reported.
+///
+/// When a Vocalink modulus weight table (valacdos.txt) is configured, pairs
+/// covered by the table are checked with the MOD10, MOD11 or DBLAL
+/// algorithm. Exception codes are not implemented.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for sort code + account number pairs
+/// Matches: sort code, up to 20 non-digit separator characters, account number
+/// Examples: 20-00-00 55779911, 200000 / 55779911, sort code 20-00-00 account 55779911
+static BANK_ACCOUNT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(\d{2})-?(\d{2})-?(\d{2})\b[^\d\n]{1,20}?\b(\d{8})\b")
+        .expect("Failed to compile UK bank account regex")
+});
+
+/// Vocalink modulus check algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModulusMethod {
+    /// Weighted sum modulo 10
+    Mod10,
+    /// Weighted sum modulo 11
+    Mod11,
+    /// Double alternate: sum of the digits of each product, modulo 10
+    DblAl,
+}
+
+/// One row of the Vocalink modulus weight table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModulusRule {
+    /// First sort code covered by this rule
+    pub start: u32,
+    /// Last sort code covered by this rule
+    pub end: u32,
+    /// Check algorithm
+    pub method: ModulusMethod,
+    /// Weights for the 6 sort code and 8 account digits
+    pub weights: [u32; 14],
+}
+
+impl ModulusRule {
+    /// Run this rule against the 14 sort code + account digits
+    fn check(&self, digits: &[u32; 14]) -> bool {
+        let products = digits.iter().zip(self.weights.iter()).map(|(d, w)| d * w);
+
+        match self.method {
+            ModulusMethod::Mod10 => products.sum::<u32>().is_multiple_of(10),
+            ModulusMethod::Mod11 => products.sum::<u32>().is_multiple_of(11),
+            ModulusMethod::DblAl => products
+                .map(|p| p / 10 + p % 10)
+                .sum::<u32>()
+                .is_multiple_of(10),
+        }
+    }
+}
+
+/// Parse a Vocalink modulus weight table (valacdos.txt format)
+///
+/// Each line holds a sort code range, the method and 14 weights, optionally
+/// followed by an exception code. Lines that do not parse are skipped.
+pub fn parse_modulus_table(text: &str) -> Vec<ModulusRule> {
+    text.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 17 {
+                return None;
+            }
+
+            let method = match fields[2].to_uppercase().as_str() {
+                "MOD10" => ModulusMethod::Mod10,
+                "MOD11" => ModulusMethod::Mod11,
+                "DBLAL" => ModulusMethod::DblAl,
+                _ => return None,
+            };
+
+            let mut weights = [0u32; 14];
+            for (weight, field) in weights.iter_mut().zip(&fields[3..17]) {
+                *weight = field.parse().ok()?;
+            }
+
+            Some(ModulusRule {
+                start: fields[0].parse().ok()?,
+                end: fields[1].parse().ok()?,
+                method,
+                weights,
+            })
+        })
+        .collect()
+}
+
+pub struct BankAccountDetector {
+    modulus_rules: Vec<ModulusRule>,
+}
+
+impl BankAccountDetector {
+    pub fn new() -> Self {
+        Self {
+            modulus_rules: Vec::new(),
+        }
+    }
+
+    /// Enable modulus checking with the given Vocalink weight table
+    pub fn modulus_rules(mut self, rules: Vec<ModulusRule>) -> Self {
+        self.modulus_rules = rules;
+        self
+    }
+
+    /// Run the modulus check
+    ///
+    /// Returns `None` when no rule covers the sort code.
+    fn modulus_check(&self, sort_code: &str, account: &str) -> Option<bool> {
+        let code: u32 = sort_code.parse().ok()?;
+        let mut digits = [0u32; 14];
+        for (digit, c) in digits
+            .iter_mut()
+            .zip(sort_code.chars().chain(account.chars()))
+        {
+            *digit = c.to_digit(10)?;
+        }
+
+        let mut rules = self
+            .modulus_rules
+            .iter()
+            .filter(|r| (r.start..=r.end).contains(&code))
+            .peekable();
+
+        rules.peek()?;
+        Some(rules.all(|r| r.check(&digits)))
+    }
+}
+
+impl Default for BankAccountDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for BankAccountDetector {
+    fn id(&self) -> &str {
+        "gb_bank_account"
+    }
+
+    fn name(&self) -> &str {
+        "UK Bank Account (Sort Code + Account Number)"
+    }
+
+    fn country(&self) -> &str {
+        "gb"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for caps in BANK_ACCOUNT_PATTERN.captures_iter(line) {
+                let Some(full) = caps.get(0) else {
+                    continue;
+                };
+
+                let sort_code = format!("{}{}{}", &caps[1], &caps[2], &caps[3]);
+                let account = &caps[4];
+
+                let confidence = match self.modulus_check(&sort_code, account) {
+                    Some(true) => Confidence::High,
+                    Some(false) => continue,
+                    None => Confidence::Medium,
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(&format!("{}{}", sort_code, account)),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: full.start(),
+                        start_byte: byte_offset + full.start(),
+                        end_byte: byte_offset + full.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        let Some(caps) = BANK_ACCOUNT_PATTERN.captures(value) else {
+            return false;
+        };
+
+        let sort_code = format!("{}{}{}", &caps[1], &caps[2], &caps[3]);
+        self.modulus_check(&sort_code, &caps[4]) != Some(false)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("08-99-99 66374958")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("08-99-99 6637495")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects UK sort code + account number pairs. \
+             Optionally validated with the Vocalink modulus weight table. \
+             Format: NN-NN-NN NNNNNNNN"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const TABLE: &str = "089999 089999 MOD10 0 0 0 0 0 0 7 1 3 7 1 3 7 1\n";
+
+    #[test]
+    fn test_sort_code_and_account_pair() {
+        let detector = BankAccountDetector::new();
+        let matches = detector.detect(
+            "Sort code: 08-99-99, account: 66374958",
+            &PathBuf::from("test.txt"),
+        );
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+        assert_eq!(matches[0].severity, Severity::High);
+        assert_eq!(matches[0].country, "gb");
+    }
+
+    #[test]
+    fn test_lone_sort_code_not_detected() {
+        let detector = BankAccountDetector::new();
+        let matches = detector.detect("Sort code: 08-99-99", &PathBuf::from("test.txt"));
+
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_modulus_check() {
+        let rules = parse_modulus_table(TABLE);
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].method, ModulusMethod::Mod10);
+
+        let detector = BankAccountDetector::new().modulus_rules(rules);
+        let path = PathBuf::from("test.txt");
+
+        let valid = detector.detect("089999 66374958", &path);
+        assert_eq!(valid.len(), 1);
+        assert_eq!(valid[0].confidence, Confidence::High);
+
+        assert!(detector.detect("089999 66374959", &path).is_empty());
+
+        // Sort codes outside the table are reported unchecked
+        let unchecked = detector.detect("20-00-00 55779911", &path);
+        assert_eq!(unchecked[0].confidence, Confidence::Medium);
+    }
+}
@@ -1,4 +1,8 @@
 /// UK (Great Britain) PII detectors
+pub mod bank_account;
 pub mod nhs;
+pub mod utr;
 
+pub use bank_account::BankAccountDetector;
 pub use nhs::NhsDetector;
+pub use utr::UtrDetector;
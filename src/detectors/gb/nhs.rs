@@ -3,7 +3,7 @@
 /// NHS numbers are 10-digit numbers used to identify patients in the UK National Health Service.
 /// Format: XXX XXX XXXX (with spaces) or XXXXXXXXXX
 /// The last digit is a check digit calculated using modulus 11 algorithm.
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::{mask_value, validate_nhs_number};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -48,6 +48,12 @@ impl Detector for NhsDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Values failing their checksum are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -70,8 +76,8 @@ impl Detector for NhsDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches (strict mode)
-                if confidence == Confidence::High {
+                // Checksum failures are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -88,6 +94,15 @@ impl Detector for NhsDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -97,6 +112,18 @@ impl Detector for NhsDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_nhs_number(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("943 476 5919")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("9434765910")
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,169 @@
+/// UK Unique Taxpayer Reference (UTR) detector
+///
+/// UTRs are 10-digit HMRC references for Self Assessment and Corporation Tax.
+/// Format: XXXXX XXXXX, optionally followed by `K`
+/// The first digit is a modulus 11 check digit over the other nine.
+///
+/// A check digit alone passes for roughly one in eleven random numbers, so
+/// matches are High confidence only when a UTR keyword precedes them.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::{has_keyword_before, mask_value, validate_uk_utr};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for UTR detection
+/// Examples: 1955839661, 19558 39661, 1955839661K
+static UTR_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{5} ?\d{5}K?\b").expect("Failed to compile UTR regex"));
+
+/// Keywords (lowercase) that raise a valid UTR to High confidence
+const UTR_KEYWORDS: &[&str] = &["utr", "taxpayer", "tax reference", "self assessment"];
+
+pub struct UtrDetector;
+
+impl UtrDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for UtrDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for UtrDetector {
+    fn id(&self) -> &str {
+        "gb_utr"
+    }
+
+    fn name(&self) -> &str {
+        "UK Unique Taxpayer Reference (UTR)"
+    }
+
+    fn country(&self) -> &str {
+        "gb"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in UTR_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !validate_uk_utr(matched_text) {
+                    continue;
+                }
+
+                let confidence = if has_keyword_before(line, capture.start(), UTR_KEYWORDS) {
+                    Confidence::High
+                } else {
+                    Confidence::Low
+                };
+
+                let digits: String = matched_text
+                    .chars()
+                    .filter(|c| c.is_ascii_digit())
+                    .collect();
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(&digits),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_uk_utr(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("19558 39661")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("2955839661")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects UK Unique Taxpayer References (Self Assessment / Corporation Tax). \
+             Uses the HMRC modulus 11 check digit; High confidence requires a UTR keyword. \
+             Format: XXXXX XXXXX"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_utr_valid_with_keyword() {
+        let detector = UtrDetector::new();
+        let text = "Self Assessment UTR: 19558 39661";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "gb_utr");
+        assert_eq!(matches[0].confidence, Confidence::High);
+        assert_eq!(matches[0].severity, Severity::High);
+        assert_eq!(matches[0].location.start_byte, 21);
+    }
+
+    #[test]
+    fn test_utr_invalid_check_digit() {
+        let detector = UtrDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        assert!(detector.detect("UTR: 2955839661", &path).is_empty());
+    }
+
+    #[test]
+    fn test_utr_without_keyword_is_low_confidence() {
+        let detector = UtrDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect("Order 1955839661K shipped", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+    }
+}
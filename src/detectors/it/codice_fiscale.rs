@@ -11,7 +11,7 @@
 ///
 /// Format: RSSMRI YY M DD LLLL K
 /// Example: RSSMRA85T10A562S
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::mask_value;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -225,6 +225,12 @@ impl Detector for CodiceFiscaleDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Well-formed codes failing validation are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -245,8 +251,8 @@ impl Detector for CodiceFiscaleDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches
-                if confidence == Confidence::High {
+                // Pattern-only matches are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -263,6 +269,15 @@ impl Detector for CodiceFiscaleDetector {
                         },
                         context: None,
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -272,6 +287,18 @@ impl Detector for CodiceFiscaleDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::validate_codice_fiscale(&value.to_uppercase())
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("RSSMRA85T10A562S")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("RSSMRA85T10A562X")
+    }
 }
 
 #[cfg(test)]
@@ -392,6 +419,26 @@ mod tests {
         assert_eq!(matches.len(), 0);
     }
 
+    #[test]
+    fn test_codice_fiscale_pattern_only_reported_when_paranoid() {
+        use crate::core::Strictness;
+
+        let detector = CodiceFiscaleDetector::new();
+        let path = PathBuf::from("test.txt");
+        let text = "CF: RSSMRA85T10A562X";
+        let detect = |strictness| {
+            let context = ScanContext::default().strictness(strictness);
+            detector.detect_in_context(text, &path, &context)
+        };
+
+        assert!(detect(Strictness::Lenient).is_empty());
+        assert!(detect(Strictness::Balanced).is_empty());
+
+        let matches = detect(Strictness::Paranoid);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+    }
+
     #[test]
     fn test_codice_fiscale_masking() {
         let detector = CodiceFiscaleDetector::new();
@@ -1,6 +1,7 @@
 pub mod be; // Belgium
 pub mod de; // Germany
 pub mod dk; // Denmark
+pub(crate) mod driving_licence; // Shared keyword-gated licence scanning
 pub mod es; // Spain
 pub mod eu; // Pan-European
 pub mod fi; // Finland
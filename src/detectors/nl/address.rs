@@ -0,0 +1,188 @@
+/// Dutch postal address detector
+///
+/// Addresses are written as a street line followed by a postcode and city,
+/// usually on the next line:
+///
+/// ```text
+/// Damrak 70-2
+/// 1012 LM Amsterdam
+/// ```
+///
+/// The pattern runs over the whole document ([`Detector::multiline`]), so the
+/// street and postcode lines are reported as one finding. A street line and
+/// postcode on one line (`Damrak 70, 1012 LM Amsterdam`) match as well.
+/// Postcodes start with 1-9 and never use the letter pairs SA, SD or SS.
+use crate::core::{Confidence, Detector, GdprCategory, Location, Match, Severity};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Street and house number, then postcode and city on the same or the next line
+///
+/// An optional `Label:` before the street (e.g. `Adres:`) is not part of the match.
+static ADDRESS_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?m)^[ \t]*(?:[\p{L} ]{1,20}:[ \t]*)?(?P<street>\p{Lu}[\p{L}.'\-]+(?: [\p{L}.'\-]+){0,4} \d{1,5}(?:[ \-]?[A-Za-z]{1,2}\b|-\d{1,4})?)(?:,[ \t]*|[ \t]*\r?\n[ \t]*)(?P<postcode>[1-9]\d{3} ?[A-Z]{2})[ \t]+(?P<city>\p{Lu}[\p{L}'\-]+(?: [\p{L}'\-]+){0,2})",
+    )
+    .expect("Failed to compile Dutch address regex")
+});
+
+/// Postcode letter pairs that are never issued
+const UNUSED_LETTERS: &[&str] = &["SA", "SD", "SS"];
+
+pub struct AddressDetector;
+
+impl AddressDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a postcode: `1234AB` or `1234 AB`
+    fn is_valid_postcode(postcode: &str) -> bool {
+        let compact: String = postcode.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.len() != 6 || compact.starts_with('0') {
+            return false;
+        }
+
+        let (digits, letters) = compact.split_at(4);
+        digits.chars().all(|c| c.is_ascii_digit())
+            && letters.chars().all(|c| c.is_ascii_uppercase())
+            && !UNUSED_LETTERS.contains(&letters)
+    }
+}
+
+impl Default for AddressDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for AddressDetector {
+    fn id(&self) -> &str {
+        "nl_address"
+    }
+
+    fn name(&self) -> &str {
+        "Dutch Postal Address"
+    }
+
+    fn country(&self) -> &str {
+        "nl"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn multiline(&self) -> bool {
+        true
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+
+        for caps in ADDRESS_PATTERN.captures_iter(text) {
+            let (Some(street), Some(postcode), Some(city)) = (
+                caps.name("street"),
+                caps.name("postcode"),
+                caps.name("city"),
+            ) else {
+                continue;
+            };
+            if !Self::is_valid_postcode(postcode.as_str()) {
+                continue;
+            }
+
+            // Keep the city and postcode area, hide street and house number
+            let digits = &postcode.as_str()[..4];
+            matches.push(Match {
+                detector_id: self.id().to_string(),
+                detector_name: self.name().to_string(),
+                country: self.country().to_string(),
+                value_masked: format!("****, {}** {}", digits, city.as_str()),
+                location: Location::in_text(file_path, text, street.start(), city.end()),
+                confidence: Confidence::High,
+                severity: self.base_severity(),
+                context: None,
+                gdpr_category: GdprCategory::Regular,
+                fingerprint: String::new(),
+                decoded_from_base64: false,
+                partially_masked: false,
+                nationality: None,
+                issuing_country: None,
+                brand: None,
+                icon: None,
+                correlated_with: Vec::new(),
+                key_path: None,
+            });
+        }
+
+        matches
+    }
+
+    /// Validate a `street number, postcode city` address
+    fn validate(&self, value: &str) -> bool {
+        ADDRESS_PATTERN
+            .captures(value)
+            .and_then(|caps| caps.name("postcode"))
+            .is_some_and(|postcode| Self::is_valid_postcode(postcode.as_str()))
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Dutch postal addresses: a street and house number followed by a \
+             postcode (1234 AB) and city, on the same or the next line."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("Damrak 70, 1012 LM Amsterdam")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("Damrak 70, 1012 SS Amsterdam")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn detect(text: &str) -> Vec<Match> {
+        AddressDetector::new().detect(text, &PathBuf::from("letter.txt"))
+    }
+
+    #[test]
+    fn test_two_line_address_is_one_finding() {
+        let text = "Geachte heer Jansen,\n\nAdres: Keizersgracht 123-2\n1015 CJ Amsterdam\n\nMet vriendelijke groet";
+        let matches = detect(text);
+
+        assert_eq!(matches.len(), 1);
+        let location = &matches[0].location;
+        assert_eq!(
+            &text[location.start_byte..location.end_byte],
+            "Keizersgracht 123-2\n1015 CJ Amsterdam"
+        );
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, 7);
+        assert_eq!(matches[0].value_masked, "****, 1015** Amsterdam");
+        assert_eq!(matches[0].severity, Severity::Medium);
+    }
+
+    #[test]
+    fn test_single_line_address() {
+        let matches = detect("Bezorgadres: Stationsplein 1a, 3511ED Utrecht");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].value_masked, "****, 3511** Utrecht");
+    }
+
+    #[test]
+    fn test_invalid_postcodes_ignored() {
+        assert!(detect("Damrak 70\n0123 AB Amsterdam").is_empty());
+        assert!(detect("Damrak 70\n1012 SS Amsterdam").is_empty());
+        // Postcode without a street line
+        assert!(detect("Postcode: 1012 LM Amsterdam").is_empty());
+    }
+}
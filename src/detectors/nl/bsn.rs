@@ -2,7 +2,7 @@
 ///
 /// The BSN is the Dutch social security number. It consists of 9 digits
 /// and uses the 11-proef (modulo-11) validation algorithm.
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
 use crate::utils::{mask_value, validate_bsn_11_proef};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -47,6 +47,12 @@ impl Detector for BsnDetector {
     }
 
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Values failing their checksum are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
@@ -72,8 +78,8 @@ impl Detector for BsnDetector {
                     Confidence::Low
                 };
 
-                // Only report high-confidence matches (strict mode)
-                if confidence == Confidence::High {
+                // Checksum failures are reported only in paranoid scans
+                if confidence == Confidence::High || context.strictness.reports_pattern_only() {
                     matches.push(Match {
                         detector_id: self.id().to_string(),
                         detector_name: self.name().to_string(),
@@ -90,6 +96,15 @@ impl Detector for BsnDetector {
                         severity: self.base_severity(),
                         context: None, // Will be filled by context analyzer
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -105,6 +120,14 @@ impl Detector for BsnDetector {
         validate_bsn_11_proef(value)
     }
 
+    fn example_valid(&self) -> Option<&str> {
+        Some("111222333")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("123456789")
+    }
+
     fn description(&self) -> Option<String> {
         Some(
             "Detects Dutch BSN (Burgerservicenummer - Social Security Number). \
@@ -157,6 +180,19 @@ mod tests {
         assert_eq!(matches.len(), 0);
     }
 
+    #[test]
+    fn test_bsn_invalid_checksum_in_paranoid_scan() {
+        use crate::core::Strictness;
+
+        let detector = BsnDetector::new();
+        let context = ScanContext::default().strictness(Strictness::Paranoid);
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect_in_context("Invalid BSN: 123456789", &path, &context);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+    }
+
     #[test]
     fn test_bsn_masking() {
         let detector = BsnDetector::new();
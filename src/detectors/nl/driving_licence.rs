@@ -0,0 +1,111 @@
+/// Dutch driving licence number (rijbewijsnummer) detector
+///
+/// Dutch driving licences carry a 10-digit document number without a
+/// public checksum, so a number is only reported when "rijbewijs" appears on
+/// the same line.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::driving_licence::detect_on_keyword_lines;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for rijbewijsnummer detection
+/// Matches: 10 consecutive digits (e.g., 5012345678)
+static LICENCE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{10}\b").expect("Failed to compile driving licence regex"));
+
+/// Keyword that must appear on the line
+static LICENCE_KEYWORD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)rijbewijs").expect("Failed to compile driving licence keyword regex")
+});
+
+pub struct DrivingLicenceDetector;
+
+impl DrivingLicenceDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DrivingLicenceDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for DrivingLicenceDetector {
+    fn id(&self) -> &str {
+        "nl_driving_licence"
+    }
+
+    fn name(&self) -> &str {
+        "Dutch Driving Licence Number (Rijbewijsnummer)"
+    }
+
+    fn country(&self) -> &str {
+        "nl"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn warmup(&self) {
+        Lazy::force(&LICENCE_PATTERN);
+        Lazy::force(&LICENCE_KEYWORD);
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_on_keyword_lines(self, &LICENCE_PATTERN, &LICENCE_KEYWORD, text, file_path)
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        value.len() == 10
+            && value.chars().all(|c| c.is_ascii_digit())
+            && !value.chars().all(|c| value.starts_with(c))
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("5012345678")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("501234567")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Dutch driving licence numbers (rijbewijsnummer) on lines mentioning \"rijbewijs\". \
+             Format: 10 digits"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_licence_with_keyword() {
+        let detector = DrivingLicenceDetector::new();
+        let text = "Rijbewijsnummer: 5012345678";
+        let matches = detector.detect(text, &PathBuf::from("verzekering.csv"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "nl_driving_licence");
+        assert_eq!(matches[0].severity, Severity::High);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "5012345678"
+        );
+    }
+
+    #[test]
+    fn test_licence_without_keyword() {
+        let detector = DrivingLicenceDetector::new();
+        let matches = detector.detect("Klantnummer: 5012345678", &PathBuf::from("verzekering.csv"));
+        assert!(matches.is_empty());
+    }
+}
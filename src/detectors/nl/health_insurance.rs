@@ -0,0 +1,213 @@
+/// Dutch health insurance number detector
+///
+/// Dutch health insurers (zorgverzekeraars) issue member numbers
+/// (verzekerdennummers) of 8 to 10 digits, most of them protected by an
+/// 11-proof. Next to an insurance keyword (zorgverzekering, polisnummer,
+/// verzekerdennummer, ...) the number is tagged as GDPR Art. 9 medical data
+/// with Critical severity. Without one, roughly one in eleven 8-10 digit
+/// numbers would qualify, so the number is not reported, except by paranoid
+/// scans as a Low-confidence, Medium-severity regular finding.
+use crate::core::{
+    Confidence, Detector, GdprCategory, Match, ScanContext, Severity, SpecialCategory,
+};
+use crate::utils::{mask_value, validate_nl_health_insurance};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for health insurance numbers
+/// Matches: 8 to 10 consecutive digits
+static HEALTH_INSURANCE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{8,10}\b").expect("Failed to compile health insurance regex"));
+
+/// Keywords on the same line that mark the number as health insurance data
+const INSURANCE_KEYWORDS: &[&str] = &[
+    "zorgverzekering",
+    "zorgverzekeraar",
+    "verzekerdennummer",
+    "verzekerde",
+    "polisnummer",
+    "relatienummer",
+    "health insurance",
+];
+
+pub struct HealthInsuranceDetector;
+
+impl HealthInsuranceDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for HealthInsuranceDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for HealthInsuranceDetector {
+    fn id(&self) -> &str {
+        "nl_health_insurance"
+    }
+
+    fn name(&self) -> &str {
+        "Dutch Health Insurance Number (Verzekerdennummer)"
+    }
+
+    fn country(&self) -> &str {
+        "nl"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            let line_lower = line.to_lowercase();
+            let keywords: Vec<String> = INSURANCE_KEYWORDS
+                .iter()
+                .filter(|k| line_lower.contains(*k))
+                .map(|k| k.to_string())
+                .collect();
+
+            if keywords.is_empty() && !context.strictness.reports_pattern_only() {
+                byte_offset += line.len() + 1;
+                continue;
+            }
+
+            for capture in HEALTH_INSURANCE_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !validate_nl_health_insurance(matched_text) {
+                    continue;
+                }
+
+                // Without a keyword (paranoid scans) it is just a checksum-valid number
+                let (confidence, severity, gdpr_category) = if keywords.is_empty() {
+                    (Confidence::Low, Severity::Medium, GdprCategory::Regular)
+                } else {
+                    (
+                        Confidence::High,
+                        self.base_severity(),
+                        GdprCategory::Special {
+                            category: SpecialCategory::Medical,
+                            detected_keywords: keywords.clone(),
+                        },
+                    )
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence,
+                    severity,
+                    context: None,
+                    gdpr_category,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        validate_nl_health_insurance(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("734190204")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("734190205")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Dutch health insurance numbers (verzekerdennummer). \
+             Uses 11-proof validation; reported next to an insurance keyword only, \
+             as medical data (GDPR Art. 9). Format: 8-10 digits"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Strictness;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_keyword_marks_medical() {
+        let detector = HealthInsuranceDetector::new();
+        let matches = detector.detect("Verzekerdennummer: 734190204", &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert_eq!(matches[0].confidence, Confidence::High);
+        match &matches[0].gdpr_category {
+            GdprCategory::Special {
+                category,
+                detected_keywords,
+            } => {
+                assert_eq!(*category, SpecialCategory::Medical);
+                assert!(detected_keywords.contains(&"verzekerdennummer".to_string()));
+            }
+            GdprCategory::Regular => panic!("expected medical category"),
+        }
+    }
+
+    #[test]
+    fn test_without_keyword_not_detected() {
+        let detector = HealthInsuranceDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        // 734190204 passes the 11-proof, but so does any eleventh number
+        assert!(detector.detect("Reference 734190204", &path).is_empty());
+
+        // Paranoid scans report it, but not as Art. 9 data
+        let context = ScanContext::default().strictness(Strictness::Paranoid);
+        let matches = detector.detect_in_context("Reference 734190204", &path, &context);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].severity, Severity::Medium);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+        assert!(matches!(matches[0].gdpr_category, GdprCategory::Regular));
+    }
+
+    #[test]
+    fn test_invalid_checksum_not_detected() {
+        let detector = HealthInsuranceDetector::new();
+        let matches = detector.detect("Polisnummer: 734190205", &PathBuf::from("test.txt"));
+
+        assert!(matches.is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+/// Dutch KVK (Kamer van Koophandel) number detector
+///
+/// The KVK number identifies a business in the Dutch trade register. It
+/// consists of 8 digits and has no check digit, so only numbers on a line
+/// that mentions the chamber of commerce are reported.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for KVK numbers
+/// Matches: 8 consecutive digits
+static KVK_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{8}\b").expect("Failed to compile KVK regex"));
+
+/// Keywords (lowercase) one of which must appear on the same line
+const KVK_KEYWORDS: &[&str] = &[
+    "kvk",
+    "kamer van koophandel",
+    "handelsregister",
+    "chamber of commerce",
+];
+
+pub struct KvkDetector;
+
+impl KvkDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for KvkDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for KvkDetector {
+    fn id(&self) -> &str {
+        "nl_kvk"
+    }
+
+    fn name(&self) -> &str {
+        "Dutch KVK Number (Chamber of Commerce)"
+    }
+
+    fn country(&self) -> &str {
+        "nl"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn warmup(&self) {
+        Lazy::force(&KVK_PATTERN);
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            let line_lower = line.to_lowercase();
+            if !KVK_KEYWORDS.iter().any(|k| line_lower.contains(k)) {
+                byte_offset += line.len() + 1;
+                continue;
+            }
+
+            for capture in KVK_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !self.validate(matched_text) {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence: Confidence::High,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        value.len() == 8
+            && value.bytes().all(|b| b.is_ascii_digit())
+            && value.bytes().any(|b| b != b'0')
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("69599084")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("00000000")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Dutch chamber of commerce (KVK) numbers. \
+             Requires a keyword such as 'KvK' or 'handelsregister' on the same line. \
+             Format: 8 digits"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_lone_kvk_number() {
+        let detector = KvkDetector::new();
+        let matches = detector.detect("KvK-nummer: 69599084", &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "nl_kvk");
+        assert_eq!(matches[0].severity, Severity::Medium);
+        assert_eq!(matches[0].location.start_byte, 12);
+    }
+
+    #[test]
+    fn test_kvk_requires_keyword() {
+        let detector = KvkDetector::new();
+        let matches = detector.detect("Order 69599084 shipped", &PathBuf::from("test.txt"));
+
+        assert!(matches.is_empty());
+    }
+}
@@ -1,4 +1,18 @@
 /// Dutch (Netherlands) PII detectors
+pub mod address;
 pub mod bsn;
+pub mod driving_licence;
+pub mod health_insurance;
+pub mod kvk;
+pub mod phone;
+pub mod rsin;
+pub mod vat;
 
+pub use address::AddressDetector;
 pub use bsn::BsnDetector;
+pub use driving_licence::DrivingLicenceDetector;
+pub use health_insurance::HealthInsuranceDetector;
+pub use kvk::KvkDetector;
+pub use phone::PhoneDetector;
+pub use rsin::RsinDetector;
+pub use vat::BsnVatDetector;
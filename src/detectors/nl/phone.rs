@@ -0,0 +1,122 @@
+/// Netherlands phone number detector
+///
+/// Finds Dutch numbers in international (`+31`, `0031`) or national
+/// format with a trunk `0` (`020 1234567`, `06-12345678`). Extensions written as
+/// `ext 42`, `x42` or `doorkiesnummer 42` are split off: the base number is validated with
+/// libphonenumber metadata and the extension is noted in the match.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::personal::phone::{detect_extension_phones, is_region_phone_with_extension};
+use phonenumber::country;
+use std::path::Path;
+
+pub struct PhoneDetector;
+
+impl PhoneDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PhoneDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PhoneDetector {
+    fn id(&self) -> &str {
+        "nl_phone"
+    }
+
+    fn name(&self) -> &str {
+        "Dutch Phone Number"
+    }
+
+    fn country(&self) -> &str {
+        "nl"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_extension_phones(self, country::Id::NL, text, file_path)
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        &["phone"]
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        is_region_phone_with_extension(country::Id::NL, value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Dutch phone numbers (+31, 0031 or national format with a \
+             trunk 0), including numbers with an extension, validated against \
+             libphonenumber metadata."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("+31 20 1234567 ext 42")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("020 123")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Confidence;
+
+    #[test]
+    fn test_extension_syntaxes() {
+        let detector = PhoneDetector::new();
+        let path = Path::new("support.csv");
+
+        for text in [
+            "Bel +31 20 1234567 ext 42",
+            "Bel +31 20 1234567 x42",
+            "Bel +31201234567x42",
+            "Bel 020 1234567, doorkiesnummer 42",
+        ] {
+            let matches = detector.detect(text, path);
+            assert_eq!(matches.len(), 1, "{}", text);
+            assert_eq!(matches[0].detector_name, "Dutch Phone Number (ext. 42)");
+
+            // The match covers the base number only
+            let location = &matches[0].location;
+            let base = &text[location.start_byte..location.end_byte];
+            assert!(base.ends_with("1234567"), "{}", base);
+            assert!(detector.validate(&text[location.start_byte..]));
+        }
+    }
+
+    #[test]
+    fn test_number_without_extension() {
+        let detector = PhoneDetector::new();
+        let matches = detector.detect("Tel: 06-12345678", Path::new("klanten.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Dutch Phone Number");
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_invalid_base_number_with_extension() {
+        let detector = PhoneDetector::new();
+        // Too short to be a Dutch number, extension or not
+        assert!(detector
+            .detect("Bel 020 12345 ext 42", Path::new("support.csv"))
+            .is_empty());
+        assert!(!detector.validate("020 12345 ext 42"));
+        // German number in a Dutch detector
+        assert!(!detector.validate("+49 30 12345678 x42"));
+    }
+}
@@ -0,0 +1,208 @@
+/// Dutch RSIN (Rechtspersonen en Samenwerkingsverbanden Informatienummer) detector
+///
+/// The RSIN is the tax number of Dutch legal entities. Like the BSN it has 9
+/// digits protected by the 11-proef, so a bare RSIN is indistinguishable from
+/// a personal BSN. Numbers are only reported as RSIN shortly after an RSIN
+/// or business keyword ("rsin", "fiscaal nummer", "kvk", "bv", "b.v.") and
+/// not when a BSN label is closer, so BSNs elsewhere on a business line are
+/// left to the BSN detector. A reported RSIN supersedes the `nl_bsn` match
+/// on the same digits.
+/// The gate holds in paranoid scans too, since a keyword-less 9-digit number
+/// passing the 11-proef is a BSN and already reported by the BSN detector.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::{has_word_before, mask_value, validate_bsn_11_proef};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for RSIN numbers
+/// Matches: 9 consecutive digits
+static RSIN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{9}\b").expect("Failed to compile RSIN regex"));
+
+/// Keywords (lowercase, whole words) that must precede an RSIN
+const RSIN_KEYWORDS: &[&str] = &[
+    "rsin",
+    "fiscaal nummer",
+    "fiscaalnummer",
+    "kvk",
+    "bv",
+    "b.v.",
+];
+
+/// Keywords marking the number as a personal BSN instead
+const BSN_KEYWORDS: &[&str] = &["bsn", "burgerservicenummer", "sofi"];
+
+pub struct RsinDetector;
+
+impl RsinDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for RsinDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for RsinDetector {
+    fn id(&self) -> &str {
+        "nl_rsin"
+    }
+
+    fn name(&self) -> &str {
+        "Dutch RSIN (Legal Entity Tax Number)"
+    }
+
+    fn country(&self) -> &str {
+        "nl"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        &["nl_bsn"]
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in RSIN_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !has_word_before(line, capture.start(), RSIN_KEYWORDS)
+                    || has_word_before(line, capture.start(), BSN_KEYWORDS)
+                    || !validate_bsn_11_proef(matched_text)
+                {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence: Confidence::High,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        value.len() == 9 && validate_bsn_11_proef(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("111222333")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("123456789")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Dutch RSIN numbers of legal entities. Uses the 11-proef and requires \
+             an RSIN keyword ('RSIN', 'fiscaal nummer', 'KvK', 'B.V.') shortly before the number \
+             to avoid reporting personal BSNs. Format: 9 digits"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_rsin_with_business_keyword() {
+        let detector = RsinDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect("Acme B.V., RSIN 111222333", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "nl_rsin");
+        assert_eq!(matches[0].severity, Severity::Medium);
+        assert_eq!(matches[0].location.start_byte, 16);
+
+        let matches = detector.detect("Fiscaal nummer: 111222333", &path);
+        assert_eq!(matches.len(), 1);
+
+        let matches = detector.detect("Acme B.V., 111222333", &path);
+        assert_eq!(matches.len(), 1);
+
+        // Invalid 11-proef
+        assert!(detector.detect("RSIN 123456789", &path).is_empty());
+    }
+
+    #[test]
+    fn test_rsin_not_reported_without_keyword() {
+        let detector = RsinDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        // A personal BSN context is left to the BSN detector
+        assert!(detector.detect("BSN: 111222333", &path).is_empty());
+        assert!(detector
+            .detect("KvK 12345678, BSN 111222333", &path)
+            .is_empty());
+        // "bv" inside a word is not a business keyword
+        assert!(detector.detect("obviously 111222333", &path).is_empty());
+    }
+
+    #[test]
+    fn test_rsin_reported_once() {
+        use crate::core::DetectorRegistry;
+        use crate::detectors::nl::BsnDetector;
+        use crate::scanner::ScanEngine;
+        use std::fs;
+        use tempfile::TempDir;
+
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("kvk.txt");
+        fs::write(&file, "Acme B.V. 111222333\n").unwrap();
+
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(BsnDetector::new()));
+        registry.register(Box::new(RsinDetector::new()));
+        let ids: Vec<String> = ScanEngine::new(registry)
+            .show_progress(false)
+            .scan_file(&file)
+            .matches
+            .into_iter()
+            .map(|m| m.detector_id)
+            .collect();
+
+        // Not also a Critical nl_bsn finding
+        assert_eq!(ids, vec!["nl_rsin"]);
+    }
+}
@@ -0,0 +1,192 @@
+/// Dutch BSN-derived VAT number detector
+///
+/// Until 2020 the VAT identification number (btw-id) of a sole proprietor
+/// (ZZP'er) was the owner's BSN with `NL` in front and `B` + 2 digits behind:
+/// `NL` + BSN + `B01`. Such numbers are printed on every invoice and leak the
+/// underlying national ID, so they are reported as Critical and tagged
+/// `embedded_bsn` in `correlated_with`. Numbers issued since 2020 carry a
+/// random body checked with MOD 97; a number that passes both checks is
+/// ambiguous and gets Medium confidence.
+///
+/// German (USt-IdNr.) and French (TVA) numbers are assigned independently of
+/// the Steuer-ID and NIR, so no other country has a derived format to flag.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::checksum::vat_mod97_nl;
+use crate::utils::{mask_value, validate_bsn_11_proef};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// `NL` + 9 digits + `B` + 2 digits, optionally separated by spaces or dots
+static VAT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bNL[ .]?(\d{9})[ .]?B[ .]?(\d{2})\b").expect("Failed to compile Dutch VAT regex")
+});
+
+/// Tag added to `correlated_with`: the number embeds the owner's BSN
+pub const EMBEDDED_BSN_TAG: &str = "embedded_bsn";
+
+pub struct BsnVatDetector;
+
+impl BsnVatDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Embedded BSN of a well-formed Dutch VAT number, if it passes the 11-proef
+    fn embedded_bsn(value: &str) -> Option<&str> {
+        let caps = VAT_PATTERN.captures(value)?;
+        let whole = caps.get(0)?;
+        if whole.start() != 0 || whole.end() != value.len() {
+            return None;
+        }
+
+        let bsn = caps.get(1)?.as_str();
+        validate_bsn_11_proef(bsn).then_some(bsn)
+    }
+}
+
+impl Default for BsnVatDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for BsnVatDetector {
+    fn id(&self) -> &str {
+        "nl_vat_bsn"
+    }
+
+    fn name(&self) -> &str {
+        "Dutch BSN-derived VAT Number"
+    }
+
+    fn country(&self) -> &str {
+        "nl"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for caps in VAT_PATTERN.captures_iter(line) {
+                let Some(capture) = caps.get(0) else {
+                    continue;
+                };
+                let bsn = &caps[1];
+
+                if !validate_bsn_11_proef(bsn) {
+                    continue;
+                }
+
+                // Post-2020 numbers that happen to pass the 11-proef too
+                let confidence = if vat_mod97_nl(&format!("{}B{}", bsn, &caps[2])) {
+                    Confidence::Medium
+                } else {
+                    Confidence::High
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(capture.as_str()),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: vec![EMBEDDED_BSN_TAG.to_string()],
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::embedded_bsn(value.trim()).is_some()
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("NL111222333B01")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("NL123456789B13")
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects pre-2020 Dutch sole proprietor VAT numbers (NL + BSN + B01) \
+             that embed the owner's BSN. Reported as Critical and tagged \
+             embedded_bsn. Format: NL + 9 digits + B + 2 digits"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bsn_derived_vat_number() {
+        let detector = BsnVatDetector::new();
+        let text = "Factuur van J. de Vries, btw-id NL111222333B01";
+        let matches = detector.detect(text, Path::new("facturen.csv"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert_eq!(matches[0].confidence, Confidence::High);
+        // The name stays static; the cross-reference is a tag
+        assert_eq!(matches[0].detector_name, "Dutch BSN-derived VAT Number");
+        assert_eq!(matches[0].correlated_with, vec![EMBEDDED_BSN_TAG]);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "NL111222333B01"
+        );
+    }
+
+    #[test]
+    fn test_post_2020_vat_number_not_derived() {
+        let detector = BsnVatDetector::new();
+        // MOD 97 valid, but the body fails the 11-proef
+        assert!(detector
+            .detect("btw-id NL123456789B13", Path::new("facturen.csv"))
+            .is_empty());
+        assert!(!detector.validate("NL123456789B13"));
+
+        // Passes both checks: could be either format
+        let matches = detector.detect("btw-id NL111222333B82", Path::new("facturen.csv"));
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_separated_format() {
+        let detector = BsnVatDetector::new();
+        let matches = detector.detect("BTW: NL 111222333 B01", Path::new("facturen.csv"));
+        assert_eq!(matches.len(), 1);
+        assert!(detector.validate("NL111222333.B01"));
+    }
+}
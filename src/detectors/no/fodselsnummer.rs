@@ -157,6 +157,15 @@ impl Detector for FodselsnummerDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: crate::core::GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -165,6 +174,18 @@ impl Detector for FodselsnummerDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::validate_date(value) && Self::validate_fodselsnummer(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("15076500565")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("15076500566")
+    }
 }
 
 impl Default for FodselsnummerDetector {
@@ -1,4 +1,6 @@
 /// Norway detectors module
 pub mod fodselsnummer;
+pub mod phone;
 
 pub use fodselsnummer::FodselsnummerDetector;
+pub use phone::PhoneDetector;
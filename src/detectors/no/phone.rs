@@ -0,0 +1,104 @@
+/// Norway phone number detector
+///
+/// Finds Norwegian numbers in international (`+47`, `0047`) or national format:
+/// 8 digits without a trunk prefix (`41234567`, `412 34 567`, `22 12 34 56`).
+/// Candidates are validated as assignable Norwegian numbers with libphonenumber
+/// metadata, so numbers of other countries and too-short numbers are rejected.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::personal::phone::{detect_region_phones, is_region_phone};
+use once_cell::sync::Lazy;
+use phonenumber::country;
+use regex::Regex;
+use std::path::Path;
+
+/// National-format candidate: 8 digits, bare or grouped as `xxx xx xxx` / `xx xx xx xx`
+static NATIONAL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:\d{8}|\d{2} \d{2} \d{2} \d{2}|\d{3} \d{2} \d{3})\b")
+        .expect("Failed to compile Norwegian phone regex")
+});
+
+pub struct PhoneDetector;
+
+impl PhoneDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PhoneDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PhoneDetector {
+    fn id(&self) -> &str {
+        "no_phone"
+    }
+
+    fn name(&self) -> &str {
+        "Norwegian Phone Number"
+    }
+
+    fn country(&self) -> &str {
+        "no"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_region_phones(self, country::Id::NO, &NATIONAL_PATTERN, text, file_path)
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        &["phone"]
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        is_region_phone(country::Id::NO, &NATIONAL_PATTERN, value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Norwegian phone numbers (+47, 0047 or 8-digit national format) \
+             validated against libphonenumber metadata."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("+47 412 34 567")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("412 34")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Confidence;
+
+    #[test]
+    fn test_norwegian_formats() {
+        let detector = PhoneDetector::new();
+        let text = "Tlf 412 34 567 / 0047 22 12 34 56";
+        let matches = detector.detect(text, Path::new("kunder.txt"));
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+        assert_eq!(matches[1].confidence, Confidence::High);
+        assert_eq!(matches[1].location.column, 17);
+    }
+
+    #[test]
+    fn test_fodselsnummer_is_not_a_phone_number() {
+        let detector = PhoneDetector::new();
+        assert!(detector
+            .detect("Fnr 01019012345", Path::new("kunder.txt"))
+            .is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+/// Keyword-gated date of birth detector
+///
+/// Dates are everywhere, so a numeric date is only reported when a birth
+/// keyword ("date of birth", "DOB", "geboortedatum", "Geburtsdatum", ...)
+/// appears on the same line. Ambiguous dates are read in the order of the
+/// scan's `--locale` (`03/04/2020` is 3 April in `en-GB`, 4 March in
+/// `en-US`), and only real dates between 1900 and today are reported.
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
+use crate::utils::mask_value;
+use chrono::{Datelike, NaiveDate};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for numeric dates
+/// Matches: 03/04/2020, 3-4-85, 25.12.1985, 1985-12-25
+static DATE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:\d{4}[./-]\d{1,2}[./-]\d{1,2}|\d{1,2}[./-]\d{1,2}[./-](?:\d{4}|\d{2}))\b")
+        .expect("Failed to compile date of birth regex")
+});
+
+/// Keywords (lowercase) that must appear on the line
+const BIRTH_KEYWORDS: &[&str] = &[
+    "birth",
+    "dob",
+    "born",
+    "geboren",
+    "geboortedatum",
+    "geburtsdatum",
+    "naissance",
+    "nacimiento",
+    "nascita",
+    "nascimento",
+    "urodzenia",
+    "født",
+    "född",
+    "syntymä",
+];
+
+pub struct DateOfBirthDetector;
+
+impl DateOfBirthDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse `value` as a birth date in the context's locale
+    ///
+    /// Returns `None` for impossible dates and dates before 1900 or in the future.
+    pub fn parse_birth_date(&self, value: &str, context: &ScanContext) -> Option<NaiveDate> {
+        let date = context.locale.parse_date(value)?;
+        let today = chrono::Local::now().date_naive();
+        (date.year() >= 1900 && date <= today).then_some(date)
+    }
+}
+
+impl Default for DateOfBirthDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for DateOfBirthDetector {
+    fn id(&self) -> &str {
+        "date_of_birth"
+    }
+
+    fn name(&self) -> &str {
+        "Date of Birth"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            let line_lower = line.to_lowercase();
+            if !BIRTH_KEYWORDS.iter().any(|k| line_lower.contains(k)) {
+                byte_offset += line.len() + 1;
+                continue;
+            }
+
+            for capture in DATE_PATTERN.find_iter(line) {
+                if self.parse_birth_date(capture.as_str(), context).is_none() {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(capture.as_str()),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence: Confidence::High,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        self.parse_birth_date(value, &ScanContext::default())
+            .is_some()
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects numeric dates of birth on lines with a birth keyword (DOB, geboortedatum, \
+             Geburtsdatum, ...), read in the date order of --locale."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Locale;
+
+    fn context(tag: &str) -> ScanContext {
+        ScanContext::new(Locale::parse(tag).unwrap())
+    }
+
+    #[test]
+    fn test_date_order_follows_locale() {
+        let detector = DateOfBirthDetector::new();
+        assert_eq!(
+            detector.parse_birth_date("03/04/2020", &context("en-GB")),
+            NaiveDate::from_ymd_opt(2020, 4, 3)
+        );
+        assert_eq!(
+            detector.parse_birth_date("03/04/2020", &context("en-US")),
+            NaiveDate::from_ymd_opt(2020, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_detect_with_keyword_and_locale() {
+        let detector = DateOfBirthDetector::new();
+        let path = Path::new("patients.csv");
+        let text = "Name: Jan Jansen, DOB: 25/12/1985\nInvoice date: 25/12/1985";
+
+        let matches = detector.detect_in_context(text, path, &context("en-GB"));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].location.line, 1);
+
+        // Month 25 doesn't exist in month-day-year order
+        assert!(detector
+            .detect_in_context(text, path, &context("en-US"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_future_date_not_detected() {
+        let detector = DateOfBirthDetector::new();
+        assert!(!detector.validate("01/01/2999"));
+        assert!(!detector.validate("31/02/1985"));
+    }
+}
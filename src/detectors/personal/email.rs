@@ -3,8 +3,14 @@
 /// Detects email addresses using a practical regex pattern.
 /// While not 100% RFC 5322 compliant (which is extremely complex),
 /// this covers 99.9% of real-world email addresses.
-use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
-use crate::utils::mask_email;
+///
+/// Addresses in URLs are often percent-encoded (`?email=jan%40example.nl`,
+/// `mailto:jan%40example%2Enl`), which hides the `@` from the plain pattern.
+/// Candidates containing `%40` are URL-decoded and reported, masked, when the
+/// decoded text is a complete address. Addresses in `mailto:` links are
+/// labelled as such.
+use crate::core::{Confidence, Detector, GdprCategory, Location, Match, Severity};
+use crate::utils::{mask_preserving, MaskKind};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::path::Path;
@@ -16,12 +22,83 @@ static EMAIL_PATTERN: Lazy<Regex> = Lazy::new(|| {
         .expect("Failed to compile email regex")
 });
 
+/// Percent-encoded candidate: an encoded `@` with address characters around it
+static ENCODED_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)[A-Za-z0-9._%+-]+%40[A-Za-z0-9.%-]+")
+        .expect("Failed to compile encoded email regex")
+});
+
+/// A complete decoded address
+static DECODED_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[A-Za-z0-9._+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}$")
+        .expect("Failed to compile decoded email regex")
+});
+
+/// Decode `%XX` escapes; `None` for malformed escapes or non-UTF-8 results
+fn percent_decode(text: &str) -> Option<String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = text.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+/// Whether the address at `start` in `line` is the target of a `mailto:` link
+fn is_mailto(line: &str, start: usize) -> bool {
+    line[..start]
+        .get(start.saturating_sub(7)..)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("mailto:"))
+}
+
 pub struct EmailDetector;
 
 impl EmailDetector {
     pub fn new() -> Self {
         Self
     }
+
+    /// Detector name with the kind of link the address was found in
+    fn name_for(&self, line: &str, start: usize, encoded: bool) -> String {
+        match (is_mailto(line, start), encoded) {
+            (true, _) => format!("{} (mailto link)", self.name()),
+            (false, true) => format!("{} (URL-encoded)", self.name()),
+            (false, false) => self.name().to_string(),
+        }
+    }
+
+    fn build_match(&self, detector_name: String, address: &str, location: Location) -> Match {
+        Match {
+            detector_id: self.id().to_string(),
+            detector_name,
+            country: self.country().to_string(),
+            value_masked: mask_preserving(address, MaskKind::for_detector(self.id())),
+            location,
+            confidence: Confidence::High,
+            severity: self.base_severity(),
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        }
+    }
 }
 
 impl Default for EmailDetector {
@@ -47,31 +124,48 @@ impl Detector for EmailDetector {
         Severity::Medium
     }
 
+    fn warmup(&self) {
+        Lazy::force(&ENCODED_PATTERN);
+        Lazy::force(&DECODED_PATTERN);
+    }
+
     fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
         let mut matches = Vec::new();
         let mut byte_offset = 0;
 
         for (line_num, line) in text.lines().enumerate() {
+            let location = |start: usize, end: usize| Location {
+                file_path: file_path.to_path_buf(),
+                line: line_num + 1,
+                column: start,
+                start_byte: byte_offset + start,
+                end_byte: byte_offset + end,
+            };
+
             for capture in EMAIL_PATTERN.find_iter(line) {
-                let matched_text = capture.as_str();
-
-                matches.push(Match {
-                    detector_id: self.id().to_string(),
-                    detector_name: self.name().to_string(),
-                    country: self.country().to_string(),
-                    value_masked: mask_email(matched_text),
-                    location: crate::core::types::Location {
-                        file_path: file_path.to_path_buf(),
-                        line: line_num + 1,
-                        column: capture.start(),
-                        start_byte: byte_offset + capture.start(),
-                        end_byte: byte_offset + capture.end(),
-                    },
-                    confidence: Confidence::High,
-                    severity: self.base_severity(),
-                    context: None,
-                    gdpr_category: GdprCategory::Regular,
-                });
+                matches.push(self.build_match(
+                    self.name_for(line, capture.start(), false),
+                    capture.as_str(),
+                    location(capture.start(), capture.end()),
+                ));
+            }
+
+            // Cheap check first: most lines hold no encoded `@`
+            if line.contains("%40") {
+                for capture in ENCODED_PATTERN.find_iter(line) {
+                    let Some(address) = percent_decode(capture.as_str()) else {
+                        continue;
+                    };
+                    if !DECODED_PATTERN.is_match(&address) {
+                        continue;
+                    }
+
+                    matches.push(self.build_match(
+                        self.name_for(line, capture.start(), true),
+                        &address,
+                        location(capture.start(), capture.end()),
+                    ));
+                }
             }
 
             byte_offset += line.len() + 1;
@@ -134,8 +228,52 @@ mod tests {
         assert_eq!(matches.len(), 1);
 
         let masked = &matches[0].value_masked;
-        assert!(masked.starts_with("j"));
-        assert!(masked.contains("@example.com"));
-        assert!(masked.contains('*'));
+        assert_eq!(masked, "j*******@e******.com");
+        assert_eq!(masked.len(), "john.doe@example.com".len());
+    }
+
+    #[test]
+    fn test_url_encoded_query_param() {
+        let detector = EmailDetector::new();
+        let text = "GET /subscribe?email=jan.jansen%40example.nl&lang=nl HTTP/1.1";
+        let path = PathBuf::from("access.log");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Email Address (URL-encoded)");
+        assert_eq!(
+            matches[0].value_masked,
+            mask_preserving("jan.jansen@example.nl", MaskKind::Email)
+        );
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "jan.jansen%40example.nl"
+        );
+    }
+
+    #[test]
+    fn test_mailto_links() {
+        let detector = EmailDetector::new();
+        let text = r#"<a href="mailto:marie.dupont@example.fr">Marie</a> <a href="MAILTO:hans%40example%2Ede?subject=Hallo">Hans</a>"#;
+        let path = PathBuf::from("contact.html");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 2);
+        assert!(matches
+            .iter()
+            .all(|m| m.detector_name == "Email Address (mailto link)"));
+        assert_eq!(
+            matches[1].value_masked,
+            mask_preserving("hans@example.de", MaskKind::Email)
+        );
+    }
+
+    #[test]
+    fn test_non_email_query_not_matched() {
+        let detector = EmailDetector::new();
+        let text = "GET /search?q=caf%C3%A9+bij+station%40&page=2 HTTP/1.1";
+        let path = PathBuf::from("access.log");
+
+        assert!(detector.detect(text, &path).is_empty());
     }
 }
@@ -0,0 +1,345 @@
+/// Geolocation (latitude/longitude) detector
+///
+/// Precise coordinates in mobile and IoT data can pinpoint a home or
+/// workplace, which makes them personal data once linked to a person. This
+/// finds labeled pairs (`lat=52.3702&lng=4.8952`, `"latitude": 52.3702,
+/// "longitude": 4.8952`), GeoJSON `"coordinates": [lng, lat]` arrays and bare
+/// `lat, lng` pairs. Both values need at least four decimals (roughly 10 m);
+/// coarser coordinates only identify a town or street. Under a `--locale`
+/// with a decimal comma, labeled pairs such as `lat: 52,3702; lng: 4,8952`
+/// are found as well.
+use crate::core::{Confidence, Detector, GdprCategory, Locale, Match, ScanContext, Severity};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// `lat`/`latitude` followed by `lng`/`lon`/`longitude` on the same line
+static LABELED_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)\blat(?:itude)?["']?\s*[=:]\s*["']?(?P<lat>[-+]?\d{1,3}\.\d+)["']?[\s,;&]*["']?\b(?:lng|lon|long|longitude)["']?\s*[=:]\s*["']?(?P<lon>[-+]?\d{1,3}\.\d+)"#,
+    )
+    .expect("Failed to compile labeled coordinate regex")
+});
+
+/// Labeled pair written with decimal commas (`lat: 52,3702; lng: 4,8952`)
+static LABELED_DECIMAL_COMMA_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)\blat(?:itude)?["']?\s*[=:]\s*["']?(?P<lat>[-+]?\d{1,3},\d+)["']?[\s;&|]*["']?\b(?:lng|lon|long|longitude)["']?\s*[=:]\s*["']?(?P<lon>[-+]?\d{1,3},\d+)"#,
+    )
+    .expect("Failed to compile decimal comma coordinate regex")
+});
+
+/// GeoJSON point: `"coordinates": [lng, lat]`
+static GEOJSON_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#""coordinates"\s*:\s*\[\s*(?P<lon>[-+]?\d{1,3}\.\d+)\s*,\s*(?P<lat>[-+]?\d{1,3}\.\d+)"#,
+    )
+    .expect("Failed to compile GeoJSON coordinate regex")
+});
+
+/// Bare `lat, lng` pair, not part of a longer number
+static PAIR_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?:^|[\s(\[=:])(?P<lat>[-+]?\d{1,2}\.\d+),\s?(?P<lon>[-+]?\d{1,3}\.\d+)(?:$|[\s)\];,])",
+    )
+    .expect("Failed to compile coordinate pair regex")
+});
+
+/// Decimal places needed to single out a location
+const MIN_PRECISION: usize = 4;
+
+pub struct GeolocationDetector;
+
+impl GeolocationDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether a latitude and longitude are in range and precise enough
+    fn is_valid_pair(lat: &str, lon: &str) -> bool {
+        let precise = |value: &str| {
+            value
+                .split_once('.')
+                .is_some_and(|(_, decimals)| decimals.len() >= MIN_PRECISION)
+        };
+        let (Ok(lat_value), Ok(lon_value)) = (lat.parse::<f64>(), lon.parse::<f64>()) else {
+            return false;
+        };
+
+        precise(lat)
+            && precise(lon)
+            && (-90.0..=90.0).contains(&lat_value)
+            && (-180.0..=180.0).contains(&lon_value)
+            // "0.0000, 0.0000" is a missing fix, not a location
+            && (lat_value != 0.0 || lon_value != 0.0)
+    }
+
+    /// Keep two decimals of each coordinate (about 1 km)
+    fn mask_coordinate(value: &str) -> String {
+        match value.split_once('.') {
+            Some((whole, decimals)) => {
+                let kept: String = decimals.chars().take(2).collect();
+                format!(
+                    "{}.{}{}",
+                    whole,
+                    kept,
+                    "*".repeat(decimals.len().saturating_sub(2))
+                )
+            }
+            None => value.to_string(),
+        }
+    }
+
+    /// Coordinates in one line: (start, end, lat, lon, confidence)
+    fn find_in_line(
+        line: &str,
+        locale: &Locale,
+    ) -> Vec<(usize, usize, String, String, Confidence)> {
+        let mut found: Vec<(usize, usize, String, String, Confidence)> = Vec::new();
+
+        if locale.decimal_separator == ',' {
+            for caps in LABELED_DECIMAL_COMMA_PATTERN.captures_iter(line) {
+                let (Some(lat), Some(lon)) = (caps.name("lat"), caps.name("lon")) else {
+                    continue;
+                };
+                let (Some(lat_value), Some(lon_value)) = (
+                    locale.normalize_number(lat.as_str()),
+                    locale.normalize_number(lon.as_str()),
+                ) else {
+                    continue;
+                };
+                if !Self::is_valid_pair(&lat_value, &lon_value) {
+                    continue;
+                }
+                found.push((
+                    lat.start(),
+                    lon.end(),
+                    lat_value,
+                    lon_value,
+                    Confidence::High,
+                ));
+            }
+        }
+
+        for pattern in [&*LABELED_PATTERN, &*GEOJSON_PATTERN] {
+            for caps in pattern.captures_iter(line) {
+                let (Some(lat), Some(lon)) = (caps.name("lat"), caps.name("lon")) else {
+                    continue;
+                };
+                if !Self::is_valid_pair(lat.as_str(), lon.as_str()) {
+                    continue;
+                }
+                found.push((
+                    lat.start().min(lon.start()),
+                    lat.end().max(lon.end()),
+                    lat.as_str().to_string(),
+                    lon.as_str().to_string(),
+                    Confidence::High,
+                ));
+            }
+        }
+
+        for caps in PAIR_PATTERN.captures_iter(line) {
+            let (Some(lat), Some(lon)) = (caps.name("lat"), caps.name("lon")) else {
+                continue;
+            };
+            let overlaps = found
+                .iter()
+                .any(|(start, end, ..)| lat.start() < *end && *start < lon.end());
+            if overlaps || !Self::is_valid_pair(lat.as_str(), lon.as_str()) {
+                continue;
+            }
+            found.push((
+                lat.start(),
+                lon.end(),
+                lat.as_str().to_string(),
+                lon.as_str().to_string(),
+                Confidence::Medium,
+            ));
+        }
+
+        found.sort_by_key(|(start, ..)| *start);
+        found
+    }
+}
+
+impl Default for GeolocationDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for GeolocationDetector {
+    fn id(&self) -> &str {
+        "geolocation"
+    }
+
+    fn name(&self) -> &str {
+        "Geolocation Coordinates"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn warmup(&self) {
+        Lazy::force(&LABELED_PATTERN);
+        Lazy::force(&LABELED_DECIMAL_COMMA_PATTERN);
+        Lazy::force(&GEOJSON_PATTERN);
+        Lazy::force(&PAIR_PATTERN);
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for (start, end, lat, lon, confidence) in Self::find_in_line(line, &context.locale) {
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: format!(
+                        "{}, {}",
+                        Self::mask_coordinate(&lat),
+                        Self::mask_coordinate(&lon)
+                    ),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: start,
+                        start_byte: byte_offset + start,
+                        end_byte: byte_offset + end,
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    /// Validate a `lat, lng` pair
+    fn validate(&self, value: &str) -> bool {
+        value
+            .split_once(',')
+            .is_some_and(|(lat, lon)| Self::is_valid_pair(lat.trim(), lon.trim()))
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects latitude/longitude pairs with at least four decimals: labeled \
+             (lat=/lng=, latitude/longitude), GeoJSON coordinate arrays and bare pairs. \
+             Labeled pairs with decimal commas are found under a --locale that uses them."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("52.3702, 4.8952")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("95.3702, 4.8952")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn detect(text: &str) -> Vec<Match> {
+        GeolocationDetector::new().detect(text, &PathBuf::from("tracks.log"))
+    }
+
+    #[test]
+    fn test_labeled_pair() {
+        let text = "GET /nearby?lat=52.370216&lng=4.895168 HTTP/1.1";
+        let matches = detect(text);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "geolocation");
+        assert_eq!(matches[0].confidence, Confidence::High);
+        assert_eq!(matches[0].severity, Severity::Medium);
+        assert_eq!(matches[0].value_masked, "52.37****, 4.89****");
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "52.370216&lng=4.895168"
+        );
+
+        let json = r#"{"device": "a1", "latitude": 48.858370, "longitude": 2.294481}"#;
+        assert_eq!(detect(json).len(), 1);
+    }
+
+    #[test]
+    fn test_geojson_and_bare_pair() {
+        let geojson = r#"{"type": "Point", "coordinates": [4.895168, 52.370216]}"#;
+        let matches = detect(geojson);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].value_masked, "52.37****, 4.89****");
+
+        let matches = detect("last fix: 51.5007, -0.1246 (accuracy 5m)");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_low_precision_ignored() {
+        assert!(detect("lat=52.37&lng=4.89").is_empty());
+        assert!(detect("centre: 52.370, 4.895").is_empty());
+    }
+
+    #[test]
+    fn test_out_of_range_ignored() {
+        assert!(detect("lat=95.123456&lng=4.895168").is_empty());
+        assert!(detect("lat=52.370216&lng=184.895168").is_empty());
+        assert!(detect(r#""coordinates": [200.1234, 52.3702]"#).is_empty());
+        assert!(detect("lat=0.0000&lng=0.0000").is_empty());
+    }
+
+    #[test]
+    fn test_decimal_comma_follows_locale() {
+        let text = "lat: 52,370216; lng: 4,895168";
+        let in_locale = |tag: &str| {
+            GeolocationDetector::new().detect_in_context(
+                text,
+                &PathBuf::from("ritten.csv"),
+                &ScanContext::new(Locale::parse(tag).unwrap()),
+            )
+        };
+
+        let matches = in_locale("nl-NL");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].value_masked, "52.37****, 4.89****");
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "52,370216; lng: 4,895168"
+        );
+
+        assert!(in_locale("en-GB").is_empty());
+        assert!(detect(text).is_empty());
+    }
+}
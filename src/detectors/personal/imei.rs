@@ -0,0 +1,168 @@
+/// IMEI (device identifier) detector
+///
+/// IMEIs are 15-digit device identifiers ending in a Luhn check digit.
+/// Many unrelated 15-digit numbers also pass Luhn, so a match additionally
+/// requires the keyword "imei" shortly before the number.
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
+use crate::utils::{keyword_gate, mask_value, validate_luhn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for IMEI detection
+/// Matches: 15 digits, optionally grouped as NN-NNNNNN-NNNNNN-N
+static IMEI_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b\d{2}[ \-]?\d{6}[ \-]?\d{6}[ \-]?\d\b").expect("Failed to compile IMEI regex")
+});
+
+/// Keyword that must precede a candidate number
+const IMEI_KEYWORDS: &[&str] = &["imei"];
+
+pub struct ImeiDetector;
+
+impl ImeiDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ImeiDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for ImeiDetector {
+    fn id(&self) -> &str {
+        "imei"
+    }
+
+    fn name(&self) -> &str {
+        "IMEI (Device Identifier)"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Candidates without a keyword are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in IMEI_PATTERN.find_iter(line) {
+                let digits: String = capture
+                    .as_str()
+                    .chars()
+                    .filter(|c| c.is_ascii_digit())
+                    .collect();
+
+                if !self.validate(&digits) {
+                    continue;
+                }
+                let Some(confidence) = keyword_gate(
+                    line,
+                    capture.start(),
+                    IMEI_KEYWORDS,
+                    Confidence::High,
+                    context.strictness,
+                ) else {
+                    continue;
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(&digits),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        value.len() == 15 && validate_luhn(value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects IMEI device identifiers (15 digits, Luhn check digit) \
+             when preceded by the keyword 'IMEI'."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_valid_imei() {
+        let detector = ImeiDetector::new();
+        let matches = detector.detect("Device IMEI: 490154203237518", &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "imei");
+        assert_eq!(matches[0].severity, Severity::Medium);
+    }
+
+    #[test]
+    fn test_grouped_imei() {
+        let detector = ImeiDetector::new();
+        let matches = detector.detect("imei 49-015420-323751-8", &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_imei_checksum() {
+        let detector = ImeiDetector::new();
+        let matches = detector.detect("IMEI: 490154203237519", &PathBuf::from("test.txt"));
+
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_requires_keyword() {
+        let detector = ImeiDetector::new();
+        let matches = detector.detect("Reference 490154203237518", &PathBuf::from("test.txt"));
+
+        assert!(matches.is_empty());
+    }
+}
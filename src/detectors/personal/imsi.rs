@@ -0,0 +1,162 @@
+/// IMSI (subscriber identifier) detector
+///
+/// IMSIs are up to 15 digits: a 3-digit Mobile Country Code (MCC), a 2-3
+/// digit Mobile Network Code and the subscriber number (MSIN). There is no
+/// check digit, so matches require a plausible MCC and the keyword "imsi"
+/// shortly before the number.
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
+use crate::utils::{keyword_gate, mask_value};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for IMSI detection
+/// Matches: 14-15 consecutive digits
+static IMSI_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{14,15}\b").expect("Failed to compile IMSI regex"));
+
+/// Assigned MCC range (2xx Europe through 7xx South America)
+const MCC_RANGE: std::ops::RangeInclusive<u32> = 200..=799;
+
+/// Keyword that must precede a candidate number
+const IMSI_KEYWORDS: &[&str] = &["imsi"];
+
+pub struct ImsiDetector;
+
+impl ImsiDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ImsiDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for ImsiDetector {
+    fn id(&self) -> &str {
+        "imsi"
+    }
+
+    fn name(&self) -> &str {
+        "IMSI (Subscriber Identifier)"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Candidates without a keyword are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in IMSI_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !self.validate(matched_text) {
+                    continue;
+                }
+                let Some(confidence) = keyword_gate(
+                    line,
+                    capture.start(),
+                    IMSI_KEYWORDS,
+                    Confidence::Medium,
+                    context.strictness,
+                ) else {
+                    continue;
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        if !(14..=15).contains(&value.len()) || !value.chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+
+        value[..3]
+            .parse::<u32>()
+            .map(|mcc| MCC_RANGE.contains(&mcc))
+            .unwrap_or(false)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects IMSI subscriber identifiers (MCC + MNC + MSIN, up to 15 digits) \
+             when preceded by the keyword 'IMSI'."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_keyword_gated_imsi() {
+        let detector = ImsiDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        // MCC 204 (Netherlands), MNC 08
+        let matches = detector.detect("subscriber imsi=204080123456789", &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "imsi");
+        assert_eq!(matches[0].severity, Severity::Medium);
+
+        assert!(detector.detect("order 204080123456789", &path).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_mcc() {
+        let detector = ImsiDetector::new();
+        let matches = detector.detect("IMSI: 999080123456789", &PathBuf::from("test.txt"));
+
+        assert!(matches.is_empty());
+    }
+}
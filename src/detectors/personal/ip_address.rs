@@ -0,0 +1,200 @@
+/// IP address detector
+///
+/// Under GDPR an IP address is personal data when it can be linked to a
+/// subscriber, which is why server and access logs are in scope. A bare
+/// address is reported with Low severity; the access-log correlation pass
+/// ([`AccessLogCorrelation`](crate::scanner::AccessLogCorrelation)) raises it
+/// when a timestamp appears on the same line. Loopback, unspecified,
+/// broadcast, link-local and multicast addresses identify no one and are
+/// skipped, as are dotted numbers that are part of a longer sequence
+/// (version strings, OIDs).
+use crate::core::{Confidence, Detector, GdprCategory, Location, Match, Severity};
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+
+/// Dotted-quad candidate, range-checked when parsed
+static IPV4_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}\b").expect("Failed to compile IPv4 regex")
+});
+
+/// IPv6 candidate: hex groups with at least two colons, validated when parsed
+static IPV6_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:[0-9a-f]{0,4}:){2,7}[0-9a-f]{0,4}").expect("Failed to compile IPv6 regex")
+});
+
+pub struct IpAddressDetector;
+
+impl IpAddressDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether `ip` can identify a host on a network
+    fn is_personal(ip: &IpAddr) -> bool {
+        match ip {
+            IpAddr::V4(v4) => {
+                !(v4.is_loopback()
+                    || v4.is_unspecified()
+                    || v4.is_broadcast()
+                    || v4.is_link_local()
+                    || v4.is_multicast())
+            }
+            IpAddr::V6(v6) => {
+                !(v6.is_loopback()
+                    || v6.is_unspecified()
+                    || v6.is_multicast()
+                    || (v6.segments()[0] & 0xffc0) == 0xfe80)
+            }
+        }
+    }
+
+    /// Whether the candidate at `start..end` continues a longer dotted or
+    /// colon-separated token (`1.2.3.4.5`, `v1.2.3.4`); a trailing `:port` is fine
+    fn is_embedded(line: &str, start: usize, end: usize) -> bool {
+        let before = line[..start].chars().next_back();
+        let mut after = line[end..].chars();
+        let (next, next2) = (after.next(), after.next());
+
+        matches!(before, Some(c) if c.is_ascii_alphanumeric() || c == '.' || c == ':')
+            || matches!(next, Some(c) if c.is_ascii_alphanumeric())
+            || (next == Some('.') && next2.is_some_and(|c| c.is_ascii_digit()))
+    }
+}
+
+impl Default for IpAddressDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for IpAddressDetector {
+    fn id(&self) -> &str {
+        "ip_address"
+    }
+
+    fn name(&self) -> &str {
+        "IP Address"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Low
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            // Cheap check first: most lines hold neither form
+            let has_v4 = line.contains('.');
+            let has_v6 = line.contains("::") || line.matches(':').count() >= 7;
+
+            let v4 = IPV4_PATTERN
+                .find_iter(line)
+                .filter(|_| has_v4)
+                .filter_map(|m| Some((m, IpAddr::V4(m.as_str().parse::<Ipv4Addr>().ok()?))));
+            let v6 = IPV6_PATTERN
+                .find_iter(line)
+                .filter(|_| has_v6)
+                .filter_map(|m| Some((m, IpAddr::V6(m.as_str().parse::<Ipv6Addr>().ok()?))));
+
+            for (found, ip) in v4.chain(v6) {
+                if !Self::is_personal(&ip) || Self::is_embedded(line, found.start(), found.end()) {
+                    continue;
+                }
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(found.as_str()),
+                    location: Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: found.start(),
+                        start_byte: byte_offset + found.start(),
+                        end_byte: byte_offset + found.end(),
+                    },
+                    confidence: Confidence::Medium,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches.sort_by_key(|m| m.location.start_byte);
+        matches
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects public and private IPv4/IPv6 addresses. Severity is raised when a \
+             timestamp appears on the same line (access logs)."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<String> {
+        IpAddressDetector::new()
+            .detect(text, Path::new("access.log"))
+            .iter()
+            .map(|m| text[m.location.start_byte..m.location.end_byte].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_ipv4_and_ipv6() {
+        let text = "client 203.0.113.42 via proxy 2001:db8:85a3::8a2e:370:7334\n\
+                    retry from 10.1.2.3:443\n";
+        assert_eq!(
+            found(text),
+            vec!["203.0.113.42", "2001:db8:85a3::8a2e:370:7334", "10.1.2.3"]
+        );
+    }
+
+    #[test]
+    fn test_non_personal_addresses_skipped() {
+        let text = "listen 0.0.0.0:8080, health 127.0.0.1, ::1, 255.255.255.255, 224.0.0.1";
+        assert!(found(text).is_empty());
+    }
+
+    #[test]
+    fn test_versions_and_times_not_matched() {
+        let text =
+            "version 1.2.3.4.5 oid 1.3.6.1.4.1 build v10.2.0.1 at 10:32:15 mac 00:1a:2b:3c:4d:5e\n\
+                    range 999.1.1.1";
+        assert!(found(text).is_empty());
+    }
+
+    #[test]
+    fn test_bare_ip_is_low() {
+        let matches = IpAddressDetector::new().detect("peer=198.51.100.7", Path::new("access.log"));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].severity, Severity::Low);
+        assert!(matches[0].correlated_with.is_empty());
+    }
+}
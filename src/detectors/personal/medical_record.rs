@@ -0,0 +1,154 @@
+/// Medical record number (MRN) detector
+///
+/// Hospital patient numbers have no common format or checksum, so a number
+/// is only reported when a medical record label directly precedes it (`MRN`,
+/// `patiëntnummer`, `Patientennummer`, `dossier`). The label itself makes the
+/// match health data, so it is tagged as GDPR Art. 9 medical data directly.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity, SpecialCategory};
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Medical record label followed by the record number
+/// Matches: "MRN: 00123456", "Patiëntnummer 4455667", "N° dossier: H1234567"
+static MRN_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(mrn|medical record(?: number| no\.?)?|pati[eë]nt(?:en)?nummer|patientennr\.?|(?:n°\s*(?:de\s+)?)?dossier(?:\s+m[eé]dical)?(?:nummer|nr\.?)?)[\s:#.=-]*(?:(?:nr|no|n°)\.?\s*[:#]?\s*)?([A-Za-z]{0,3}\d{5,12})\b",
+    )
+    .expect("Failed to compile medical record number regex")
+});
+
+/// Record number without its label
+static MRN_VALUE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[A-Za-z]{0,3}\d{5,12}$").expect("Failed to compile medical record value regex")
+});
+
+pub struct MedicalRecordDetector;
+
+impl MedicalRecordDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for MedicalRecordDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for MedicalRecordDetector {
+    fn id(&self) -> &str {
+        "medical_record"
+    }
+
+    fn name(&self) -> &str {
+        "Medical Record Number"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in MRN_PATTERN.captures_iter(line) {
+                let (Some(label), Some(number)) = (capture.get(1), capture.get(2)) else {
+                    continue;
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(number.as_str()),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: number.start(),
+                        start_byte: byte_offset + number.start(),
+                        end_byte: byte_offset + number.end(),
+                    },
+                    confidence: Confidence::High,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Special {
+                        category: SpecialCategory::Medical,
+                        detected_keywords: vec![label.as_str().to_lowercase()],
+                    },
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        MRN_VALUE_PATTERN.is_match(value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects hospital medical record numbers (MRN, patiëntnummer, Patientennummer, \
+             dossier) when labelled; always classified as medical data (GDPR Art. 9)."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labeled_mrn_is_medical() {
+        let detector = MedicalRecordDetector::new();
+        let text = "Opname 12-03: patiëntnummer 00123456, afdeling cardiologie\nMRN: H7788990\nPatientennummer: 4455667\nN° dossier : 2023456789";
+        let matches = detector.detect(text, Path::new("export.csv"));
+
+        let values: Vec<_> = matches
+            .iter()
+            .map(|m| &text[m.location.start_byte..m.location.end_byte])
+            .collect();
+        assert_eq!(
+            values,
+            vec!["00123456", "H7788990", "4455667", "2023456789"]
+        );
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert!(matches!(
+            &matches[0].gdpr_category,
+            GdprCategory::Special {
+                category: SpecialCategory::Medical,
+                detected_keywords,
+            } if detected_keywords == &vec!["patiëntnummer".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_bare_number_not_detected() {
+        let detector = MedicalRecordDetector::new();
+        let text = "Order 00123456 shipped, invoice 4455667";
+        assert!(detector.detect(text, Path::new("orders.txt")).is_empty());
+        assert!(detector.validate("H7788990"));
+        assert!(!detector.validate("12"));
+    }
+}
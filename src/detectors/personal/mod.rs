@@ -1,4 +1,30 @@
-/// Universal personal data detectors (email, IP addresses)
+/// Universal personal data detectors (email, phone, passport, MRZ, IMEI, IMSI, tracking IDs,
+/// system usernames, medical record numbers, dates of birth, geolocation, IP addresses,
+/// opt-in person names)
+pub mod date_of_birth;
 pub mod email;
+pub mod geolocation;
+pub mod imei;
+pub mod imsi;
+pub mod ip_address;
+pub mod medical_record;
+pub mod mrz;
+pub mod name;
+pub mod passport;
+pub mod phone;
+pub mod system_identity;
+pub mod tracking_id;
 
+pub use date_of_birth::DateOfBirthDetector;
 pub use email::EmailDetector;
+pub use geolocation::GeolocationDetector;
+pub use imei::ImeiDetector;
+pub use imsi::ImsiDetector;
+pub use ip_address::IpAddressDetector;
+pub use medical_record::MedicalRecordDetector;
+pub use mrz::MrzDetector;
+pub use name::NameDetector;
+pub use passport::PassportKeywordDetector;
+pub use phone::PhoneDetector;
+pub use system_identity::SystemIdentityDetector;
+pub use tracking_id::TrackingIdDetector;
@@ -0,0 +1,329 @@
+/// Machine Readable Zone (MRZ) detector
+///
+/// Passports and identity cards carry a machine readable zone with the
+/// holder's name, document number, date of birth, nationality and issuing
+/// state. Supported layouts:
+///
+/// - TD3, passports: 2 lines of 44 characters (`P<NLD...`)
+/// - TD1, identity cards: 3 lines of 30 characters (`IDD<<...`)
+/// - French identity cards issued before 2021: 2 lines of 36 characters (`IDFRA...`)
+///
+/// The document number, date of birth and expiry check digits must be
+/// correct. The nationality and issuing state are exposed on the match; a
+/// nationality can reveal ethnic origin, so those matches are tagged as
+/// GDPR Art. 9 racial/ethnic data.
+use crate::core::{Confidence, Detector, GdprCategory, Location, Match, Severity, SpecialCategory};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// TD3: `P`, type, issuing state, name / document number, nationality, dates
+static TD3_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^(P[A-Z<][A-Z<]{3}[A-Z<]{39})\r?\n([A-Z0-9<]{9}[0-9<][A-Z<]{3}\d{7}[MFX<]\d{7}[A-Z0-9<]{14}[0-9<]\d)\r?$")
+        .expect("Failed to compile TD3 MRZ regex")
+});
+
+/// TD1: document number / dates, nationality / name
+static TD1_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^([ACI][A-Z<][A-Z<]{3}[A-Z0-9<]{9}[0-9<][A-Z0-9<]{15})\r?\n(\d{7}[MFX<]\d{7}[A-Z<]{3}[A-Z0-9<]{11}\d)\r?\n([A-Z<]{30})\r?$")
+        .expect("Failed to compile TD1 MRZ regex")
+});
+
+/// Pre-2021 French identity card: surname / document number, given names, birth date
+static FR_ID_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^(IDFRA[A-Z<]{25}[A-Z0-9<]{6})\r?\n([A-Z0-9]{12}\d[A-Z<]{14}\d{7}[MF]\d)\r?$")
+        .expect("Failed to compile French ID MRZ regex")
+});
+
+/// Fields read from a valid MRZ
+struct MrzFields {
+    issuing_country: String,
+    nationality: Option<String>,
+}
+
+pub struct MrzDetector;
+
+impl MrzDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// ICAO 9303 check digit: weights 7, 3, 1 over digits, letters (A=10) and fillers (0)
+    fn check_digit(field: &str) -> Option<u32> {
+        let mut sum = 0;
+        for (i, c) in field.chars().enumerate() {
+            let value = match c {
+                '<' => 0,
+                '0'..='9' => c.to_digit(10)?,
+                'A'..='Z' => c as u32 - 'A' as u32 + 10,
+                _ => return None,
+            };
+            sum += value * [7, 3, 1][i % 3];
+        }
+        Some(sum % 10)
+    }
+
+    /// Whether `check` is the check digit of `field`
+    fn is_checked(field: &str, check: &str) -> bool {
+        let expected = check.chars().next().and_then(|c| c.to_digit(10));
+        expected.is_some() && Self::check_digit(field) == expected
+    }
+
+    /// ICAO country code without `<` fillers (`D<<` is Germany)
+    fn country_code(field: &str) -> Option<String> {
+        let code = field.trim_end_matches('<');
+        (!code.is_empty() && code.chars().all(|c| c.is_ascii_uppercase())).then(|| code.to_string())
+    }
+
+    fn parse_td3(line1: &str, line2: &str) -> Option<MrzFields> {
+        let checked = Self::is_checked(&line2[0..9], &line2[9..10])
+            && Self::is_checked(&line2[13..19], &line2[19..20])
+            && Self::is_checked(&line2[21..27], &line2[27..28]);
+        checked.then_some(())?;
+
+        Some(MrzFields {
+            issuing_country: Self::country_code(&line1[2..5])?,
+            nationality: Self::country_code(&line2[10..13]),
+        })
+    }
+
+    fn parse_td1(line1: &str, line2: &str) -> Option<MrzFields> {
+        let checked = Self::is_checked(&line1[5..14], &line1[14..15])
+            && Self::is_checked(&line2[0..6], &line2[6..7])
+            && Self::is_checked(&line2[8..14], &line2[14..15]);
+        checked.then_some(())?;
+
+        Some(MrzFields {
+            issuing_country: Self::country_code(&line1[2..5])?,
+            nationality: Self::country_code(&line2[15..18]),
+        })
+    }
+
+    fn parse_french_id(line2: &str) -> Option<MrzFields> {
+        let checked = Self::is_checked(&line2[0..12], &line2[12..13])
+            && Self::is_checked(&line2[27..33], &line2[33..34]);
+        checked.then_some(())?;
+
+        // These cards are only issued to French nationals
+        Some(MrzFields {
+            issuing_country: "FRA".to_string(),
+            nationality: Some("FRA".to_string()),
+        })
+    }
+
+    /// All valid MRZs in `text`: (start, end, document code, fields)
+    fn find(text: &str) -> Vec<(usize, usize, String, MrzFields)> {
+        let mut found = Vec::new();
+
+        for caps in TD3_PATTERN.captures_iter(text) {
+            let (Some(line1), Some(line2)) = (caps.get(1), caps.get(2)) else {
+                continue;
+            };
+            if let Some(fields) = Self::parse_td3(line1.as_str(), line2.as_str()) {
+                found.push((
+                    line1.start(),
+                    line2.end(),
+                    line1.as_str()[..2].to_string(),
+                    fields,
+                ));
+            }
+        }
+
+        for caps in TD1_PATTERN.captures_iter(text) {
+            let (Some(line1), Some(line2), Some(line3)) = (caps.get(1), caps.get(2), caps.get(3))
+            else {
+                continue;
+            };
+            if let Some(fields) = Self::parse_td1(line1.as_str(), line2.as_str()) {
+                found.push((
+                    line1.start(),
+                    line3.end(),
+                    line1.as_str()[..2].to_string(),
+                    fields,
+                ));
+            }
+        }
+
+        for caps in FR_ID_PATTERN.captures_iter(text) {
+            let (Some(line1), Some(line2)) = (caps.get(1), caps.get(2)) else {
+                continue;
+            };
+            if let Some(fields) = Self::parse_french_id(line2.as_str()) {
+                found.push((line1.start(), line2.end(), "ID".to_string(), fields));
+            }
+        }
+
+        found.sort_by_key(|(start, ..)| *start);
+        found
+    }
+}
+
+impl Default for MrzDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for MrzDetector {
+    fn id(&self) -> &str {
+        "mrz"
+    }
+
+    fn name(&self) -> &str {
+        "Machine Readable Zone"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn multiline(&self) -> bool {
+        true
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+
+        for (start, end, document, fields) in Self::find(text) {
+            // Nationality is special category data (ethnic origin)
+            let (severity, gdpr_category) = match &fields.nationality {
+                Some(_) => (
+                    Severity::Critical,
+                    GdprCategory::Special {
+                        category: SpecialCategory::RacialEthnic,
+                        detected_keywords: vec!["nationality".to_string()],
+                    },
+                ),
+                None => (self.base_severity(), GdprCategory::Regular),
+            };
+
+            matches.push(Match {
+                detector_id: self.id().to_string(),
+                detector_name: self.name().to_string(),
+                country: self.country().to_string(),
+                value_masked: format!("{}{}{}", document, fields.issuing_country, "*".repeat(8)),
+                location: Location::in_text(file_path, text, start, end),
+                confidence: Confidence::High,
+                severity,
+                context: None,
+                gdpr_category,
+                fingerprint: String::new(),
+                decoded_from_base64: false,
+                partially_masked: false,
+                nationality: fields.nationality,
+                issuing_country: Some(fields.issuing_country),
+                brand: None,
+                icon: None,
+                correlated_with: Vec::new(),
+                key_path: None,
+            });
+        }
+
+        matches
+    }
+
+    /// Validate a complete MRZ (lines separated by newlines)
+    fn validate(&self, value: &str) -> bool {
+        !Self::find(value.trim()).is_empty()
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects passport (TD3) and identity card (TD1, French pre-2021) machine readable \
+             zones with valid check digits, and extracts the nationality and issuing state."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some(
+            "P<UTOERIKSSON<<ANNA<MARIA<<<<<<<<<<<<<<<<<<<\n\
+             L898902C36UTO7408122F1204159ZE184226B<<<<<10",
+        )
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some(
+            "P<UTOERIKSSON<<ANNA<MARIA<<<<<<<<<<<<<<<<<<<\n\
+             L898902C35UTO7408122F1204159ZE184226B<<<<<10",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn detect(text: &str) -> Vec<Match> {
+        MrzDetector::new().detect(text, &PathBuf::from("scan-ocr.txt"))
+    }
+
+    const DUTCH_PASSPORT: &str = "P<NLDDE<BRUIJN<<WILLEKE<LISELOTTE<<<<<<<<<<<\n\
+                                  SPECI20142NLD6503101F2403096999999990<<<<<84";
+
+    #[test]
+    fn test_td3_nationality_extracted() {
+        let text = format!("Scanned document:\n{}\n", DUTCH_PASSPORT);
+        let matches = detect(&text);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "mrz");
+        assert_eq!(matches[0].nationality.as_deref(), Some("NLD"));
+        assert_eq!(matches[0].issuing_country.as_deref(), Some("NLD"));
+        assert_eq!(matches[0].value_masked, "P<NLD********");
+        assert_eq!(matches[0].location.line, 2);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            DUTCH_PASSPORT
+        );
+    }
+
+    #[test]
+    fn test_nationality_is_special_category() {
+        let matches = detect(DUTCH_PASSPORT);
+
+        assert_eq!(matches[0].severity, Severity::Critical);
+        match &matches[0].gdpr_category {
+            GdprCategory::Special {
+                category,
+                detected_keywords,
+            } => {
+                assert_eq!(*category, SpecialCategory::RacialEthnic);
+                assert_eq!(detected_keywords, &vec!["nationality".to_string()]);
+            }
+            GdprCategory::Regular => panic!("nationality should be Art. 9 data"),
+        }
+    }
+
+    #[test]
+    fn test_td1_and_french_id() {
+        let german_id = "IDD<<T220001293<<<<<<<<<<<<<<<\n\
+                         6408125F3103315D<<<<<<<<<<<<<2\n\
+                         MUSTERMANN<<ERIKA<<<<<<<<<<<<<";
+        let matches = detect(german_id);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].nationality.as_deref(), Some("D"));
+        assert_eq!(matches[0].issuing_country.as_deref(), Some("D"));
+
+        let french_id = "IDFRABERTHIER<<<<<<<<<<<<<<<<<750123\n\
+                         8806923102858CORINNE<<<<<<<6507121F0";
+        let matches = detect(french_id);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].nationality.as_deref(), Some("FRA"));
+    }
+
+    #[test]
+    fn test_bad_check_digit_ignored() {
+        let tampered = DUTCH_PASSPORT.replace("SPECI20142", "SPECI20143");
+        assert!(detect(&tampered).is_empty());
+
+        let detector = MrzDetector::new();
+        assert!(detector.validate(detector.example_valid().unwrap()));
+        assert!(!detector.validate(detector.example_invalid().unwrap()));
+    }
+}
@@ -0,0 +1,407 @@
+/// Person name detector (Dutch, German, French)
+///
+/// Meant for data mapping rather than high-confidence detection: a
+/// capitalized two-token name ("Jan de Vries", "Anna Müller") is reported when
+/// one of its tokens is a common given name or surname, and either a name
+/// label ("naam", "Name", "nom", ...) directly precedes it or another
+/// finding (an email, phone or ID number, IBAN, ...) is close by on the same
+/// line. This detector reports the labeled names; names next to another
+/// finding are added by the scan engine once all detectors have run (see
+/// [`names_near_findings`](crate::scanner::names::names_near_findings)).
+/// Names in running text without such context are not reported.
+///
+/// Not part of the default registry; enabled with `--detect-names`.
+use crate::core::{Confidence, Detector, GdprCategory, Location, Match, Severity};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Two capitalized tokens, optionally joined by particles (`van der`, `von`, `de la`)
+static NAME_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"\b(\p{Lu}\p{Ll}+)(?:\s+(?:van|von|de|der|den|du|le|la|ter|ten|zu)){0,2}\s+(\p{Lu}\p{Ll}+(?:-\p{Lu}\p{Ll}+)?)\b",
+    )
+    .expect("Failed to compile name regex")
+});
+
+/// Name label right before the name (`naam: `, `"name": "`, `Nom =`)
+static LABEL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)\b(?:naam|voornaam|achternaam|name|vorname|nachname|full_?name|nom|prénom|nom_complet)["']?\s*[:=]?\s*["']?\s*$"#,
+    )
+    .expect("Failed to compile name label regex")
+});
+
+/// Common Dutch, German and French given names (lowercase)
+const GIVEN_NAMES: &[&str] = &[
+    // Dutch
+    "jan",
+    "piet",
+    "kees",
+    "henk",
+    "willem",
+    "johannes",
+    "pieter",
+    "hendrik",
+    "cornelis",
+    "gerrit",
+    "bram",
+    "daan",
+    "sem",
+    "lucas",
+    "ruben",
+    "thijs",
+    "maria",
+    "anna",
+    "johanna",
+    "anouk",
+    "sanne",
+    "lisa",
+    "emma",
+    "julia",
+    "sophie",
+    "fleur",
+    "femke",
+    "lotte",
+    "willeke",
+    "inge",
+    "marieke",
+    // German
+    "hans",
+    "peter",
+    "klaus",
+    "jürgen",
+    "wolfgang",
+    "michael",
+    "thomas",
+    "andreas",
+    "stefan",
+    "frank",
+    "lukas",
+    "leon",
+    "maximilian",
+    "felix",
+    "paul",
+    "ursula",
+    "monika",
+    "petra",
+    "sabine",
+    "andrea",
+    "claudia",
+    "katharina",
+    "lena",
+    "laura",
+    "leonie",
+    "erika",
+    "heike",
+    // French
+    "jean",
+    "pierre",
+    "michel",
+    "philippe",
+    "alain",
+    "nicolas",
+    "françois",
+    "louis",
+    "hugo",
+    "gabriel",
+    "arthur",
+    "jules",
+    "marie",
+    "nathalie",
+    "isabelle",
+    "sylvie",
+    "catherine",
+    "camille",
+    "chloé",
+    "léa",
+    "manon",
+    "inès",
+    "juliette",
+    "corinne",
+];
+
+/// Common Dutch, German and French surnames (lowercase, without particles)
+const SURNAMES: &[&str] = &[
+    // Dutch
+    "jansen",
+    "janssen",
+    "vries",
+    "berg",
+    "dijk",
+    "bakker",
+    "visser",
+    "smit",
+    "meijer",
+    "boer",
+    "mulder",
+    "groot",
+    "bos",
+    "vos",
+    "peters",
+    "hendriks",
+    "leeuwen",
+    "dekker",
+    "brouwer",
+    "wit",
+    "dijkstra",
+    "smits",
+    "graaf",
+    "meer",
+    "bruijn",
+    // German
+    "müller",
+    "schmidt",
+    "schneider",
+    "fischer",
+    "weber",
+    "meyer",
+    "wagner",
+    "becker",
+    "schulz",
+    "hoffmann",
+    "schäfer",
+    "koch",
+    "bauer",
+    "richter",
+    "klein",
+    "wolf",
+    "schröder",
+    "neumann",
+    "schwarz",
+    "zimmermann",
+    "mustermann",
+    // French
+    "martin",
+    "bernard",
+    "dubois",
+    "thomas",
+    "robert",
+    "richard",
+    "petit",
+    "durand",
+    "leroy",
+    "moreau",
+    "simon",
+    "laurent",
+    "lefebvre",
+    "michel",
+    "garcia",
+    "david",
+    "bertrand",
+    "roux",
+    "vincent",
+    "fournier",
+    "morel",
+    "girard",
+    "berthier",
+];
+
+pub struct NameDetector;
+
+impl NameDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether the given name or surname is in the embedded lists
+    fn is_known_name(given: &str, surname: &str) -> bool {
+        let given = given.to_lowercase();
+        // Double-barrelled surnames count when either part is known
+        let known_surname = surname
+            .split('-')
+            .any(|part| SURNAMES.contains(&part.to_lowercase().as_str()));
+        GIVEN_NAMES.contains(&given.as_str()) || known_surname
+    }
+
+    /// Known names in `line` that `accept(start, end, labeled)` takes, as byte ranges
+    ///
+    /// `labeled` tells whether a name label directly precedes the name. When a
+    /// name is not taken, its second token may start a name itself ("Name Jan
+    /// Jansen").
+    pub(crate) fn find_names(
+        line: &str,
+        accept: impl Fn(usize, usize, bool) -> bool,
+    ) -> Vec<std::ops::Range<usize>> {
+        let mut names = Vec::new();
+        let mut pos = 0;
+
+        while let Some(caps) = NAME_PATTERN.captures_at(line, pos) {
+            let (Some(full), Some(given), Some(surname)) = (caps.get(0), caps.get(1), caps.get(2))
+            else {
+                break;
+            };
+
+            let labeled = LABEL_PATTERN.is_match(&line[..full.start()]);
+            if !Self::is_known_name(given.as_str(), surname.as_str())
+                || !accept(full.start(), full.end(), labeled)
+            {
+                pos = full.start() + given.len();
+                continue;
+            }
+
+            names.push(full.range());
+            pos = full.end();
+        }
+
+        names
+    }
+
+    /// Finding of `detector` for the name at `range` of line `line_num` (0-indexed)
+    pub(crate) fn name_match(
+        detector: &dyn Detector,
+        line: &str,
+        range: std::ops::Range<usize>,
+        line_num: usize,
+        line_offset: usize,
+        file_path: &Path,
+    ) -> Match {
+        Match {
+            detector_id: detector.id().to_string(),
+            detector_name: detector.name().to_string(),
+            country: detector.country().to_string(),
+            value_masked: Self::mask_name(&line[range.clone()]),
+            location: Location {
+                file_path: file_path.to_path_buf(),
+                line: line_num + 1,
+                column: range.start,
+                start_byte: line_offset + range.start,
+                end_byte: line_offset + range.end,
+            },
+            confidence: Confidence::Low,
+            severity: detector.base_severity(),
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        }
+    }
+
+    /// Keep the initial of each token (`J** d* V****`)
+    fn mask_name(name: &str) -> String {
+        name.split_whitespace()
+            .map(|token| {
+                let mut chars = token.chars();
+                let initial = chars.next().unwrap_or('*');
+                format!("{}{}", initial, "*".repeat(chars.count()))
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Default for NameDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for NameDetector {
+    fn id(&self) -> &str {
+        "person_name"
+    }
+
+    fn name(&self) -> &str {
+        "Person Name"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Low
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for range in Self::find_names(line, |_, _, labeled| labeled) {
+                matches.push(Self::name_match(
+                    self,
+                    line,
+                    range,
+                    line_num,
+                    byte_offset,
+                    file_path,
+                ));
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        NAME_PATTERN.captures(value.trim()).is_some_and(|caps| {
+            let (given, surname) = (&caps[1], &caps[2]);
+            Self::is_known_name(given, surname)
+        })
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Flags probable Dutch, German and French person names next to a name label \
+             or another finding (opt-in with --detect-names, low confidence)."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("Naam: Jan de Vries")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("Grote Markt")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn detect(text: &str) -> Vec<Match> {
+        NameDetector::new().detect(text, &PathBuf::from("klanten.csv"))
+    }
+
+    #[test]
+    fn test_labeled_name() {
+        let matches = detect("Naam: Jan de Vries\nName Anna Müller\n{\"nom\": \"Marie Dubois\"}");
+
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[0].value_masked, "J** d* V****");
+        assert_eq!(matches[0].confidence, Confidence::Low);
+        assert_eq!(matches[0].severity, Severity::Low);
+        assert_eq!(matches[1].location.line, 2);
+        assert_eq!(matches[1].value_masked, "A*** M*****");
+        assert_eq!(matches[2].value_masked, "M**** D*****");
+    }
+
+    #[test]
+    fn test_unlabeled_name_left_to_engine() {
+        // Nearby findings are only known after all detectors ran
+        assert!(detect("Contact Peter Schmidt, peter.schmidt@example.de").is_empty());
+    }
+
+    #[test]
+    fn test_unlabeled_sentence_not_detected() {
+        assert!(detect("Yesterday Jan Jansen visited the office in Utrecht.").is_empty());
+        // Labeled, but not a known name
+        assert!(detect("Naam: Grote Markt").is_empty());
+
+        let detector = NameDetector::new();
+        assert!(detector.validate(detector.example_valid().unwrap()));
+        assert!(!detector.validate(detector.example_invalid().unwrap()));
+    }
+}
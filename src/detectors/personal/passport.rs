@@ -0,0 +1,203 @@
+/// Keyword-gated passport number detector
+///
+/// Passport numbers share no common structure across countries, so instead
+/// of per-country patterns this detector flags short alphanumeric tokens
+/// (6-9 characters) only when a passport keyword appears directly before them.
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
+use crate::utils::{keyword_gate, mask_value};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Candidate passport number: 6-9 uppercase letters/digits
+static PASSPORT_TOKEN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b[A-Z0-9]{6,9}\b").expect("Failed to compile passport token regex"));
+
+/// Keywords (lowercase) that must precede a candidate token
+const PASSPORT_KEYWORDS: &[&str] = &["passport", "passeport", "reisepass", "paspoort", "mrz"];
+
+pub struct PassportKeywordDetector;
+
+impl PassportKeywordDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PassportKeywordDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PassportKeywordDetector {
+    fn id(&self) -> &str {
+        "passport_keyword"
+    }
+
+    fn name(&self) -> &str {
+        "Passport Number"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Candidates without a keyword are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in PASSPORT_TOKEN_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if !self.validate(matched_text) {
+                    continue;
+                }
+
+                let Some(confidence) = keyword_gate(
+                    line,
+                    capture.start(),
+                    PASSPORT_KEYWORDS,
+                    Confidence::Medium,
+                    context.strictness,
+                ) else {
+                    continue;
+                };
+
+                matches.push(Match {
+                    detector_id: self.id().to_string(),
+                    detector_name: self.name().to_string(),
+                    country: self.country().to_string(),
+                    value_masked: mask_value(matched_text),
+                    location: crate::core::types::Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: capture.start(),
+                        start_byte: byte_offset + capture.start(),
+                        end_byte: byte_offset + capture.end(),
+                    },
+                    confidence,
+                    severity: self.base_severity(),
+                    context: None,
+                    gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
+                });
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        // Passport numbers always contain at least one digit; this also keeps
+        // the keywords themselves ("PASSPORT") from matching
+        (6..=9).contains(&value.len()) && value.chars().any(|c| c.is_ascii_digit())
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects passport numbers (6-9 alphanumeric characters) when preceded by a \
+             passport keyword such as 'passport', 'reisepass' or 'MRZ'."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_passport_with_keyword() {
+        let detector = PassportKeywordDetector::new();
+        let text = "Passport No: NX1234567";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "passport_keyword");
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+        assert_eq!(matches[0].severity, Severity::High);
+    }
+
+    #[test]
+    fn test_passport_other_languages() {
+        let detector = PassportKeywordDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        assert_eq!(detector.detect("Reisepass: C01X00T47", &path).len(), 1);
+        assert_eq!(detector.detect("Paspoort nummer BX2345678", &path).len(), 1);
+        assert_eq!(
+            detector
+                .detect("Numéro de passeport 12AB34567", &path)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_random_token_without_keyword() {
+        let detector = PassportKeywordDetector::new();
+        let text = "Order reference AB123456 shipped";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 0);
+    }
+
+    #[test]
+    fn test_token_without_keyword_in_paranoid_scan() {
+        use crate::core::Strictness;
+
+        let detector = PassportKeywordDetector::new();
+        let context = ScanContext::default().strictness(Strictness::Paranoid);
+        let text = "Order reference AB123456 shipped";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect_in_context(text, &path, &context);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::Low);
+    }
+
+    #[test]
+    fn test_keyword_outside_window() {
+        let detector = PassportKeywordDetector::new();
+        let text = "Passport holders must check in early; the booking code is AB123456";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 0);
+    }
+
+    #[test]
+    fn test_keyword_on_previous_line_ignored() {
+        let detector = PassportKeywordDetector::new();
+        let text = "Passport\nAB123456";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 0);
+    }
+}
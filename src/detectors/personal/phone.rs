@@ -0,0 +1,554 @@
+/// Region-aware phone number detector
+///
+/// Finds phone number candidates (international `+`/`00` format or national
+/// format with a trunk `0`) and validates them with libphonenumber metadata
+/// via the `phonenumber` crate. National-format numbers are only parsed when
+/// a default region is configured, and an optional region allowlist limits
+/// reported numbers to specific countries.
+///
+/// Vanity numbers (`0800-FLOWERS`) are translated to keypad digits before
+/// validation, but only on lines with a phone keyword ("call", "bel",
+/// "anrufen", "appelez", ...) so ordinary words next to digits don't match.
+/// They are reported in numeric form with Low severity.
+use crate::core::{Confidence, Detector, GdprCategory, Match, Severity};
+use crate::utils::mask_phone;
+use once_cell::sync::Lazy;
+use phonenumber::country;
+use regex::Regex;
+use std::path::Path;
+
+/// Phone number candidate: starts with `+` or a trunk/international `0`,
+/// followed by digits and common separators
+static PHONE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+|\(?\b0)\d[\d \-/().]{5,18}\d\b").expect("Failed to compile phone regex")
+});
+
+/// International-format candidate for a single-country phone detector
+/// (`+` or `00` prefix)
+static INTERNATIONAL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+|\b00)\d[\d \-]{6,16}\d\b")
+        .expect("Failed to compile international phone regex")
+});
+
+/// Vanity number candidate: a phone prefix followed by a block of at least
+/// three capital keypad letters (`0800-FLOWERS`, `+31 800 BLOEM24`)
+static VANITY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+|\b0)\d[\d \-/().]{1,12}[A-Z]{3}[A-Z\d\-]{0,10}\b")
+        .expect("Failed to compile vanity phone regex")
+});
+
+/// Phone keywords (EN/NL/DE/FR) required on a line before vanity numbers are read
+static VANITY_CONTEXT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:call|phone|tel|telephone|hotline|bel|bellen|telefoon|anrufen|ruf|rufen|telefon|appelez|appeler|appel|t[eé]l[eé]phone)\b",
+    )
+    .expect("Failed to compile vanity context regex")
+});
+
+/// Translate the keypad letters of a vanity number to digits, dropping separators
+///
+/// `0800-FLOWERS` → `08003569377`
+pub(crate) fn vanity_digits(candidate: &str) -> String {
+    candidate
+        .chars()
+        .filter_map(|c| match c.to_ascii_uppercase() {
+            '+' => Some('+'),
+            d @ '0'..='9' => Some(d),
+            'A'..='C' => Some('2'),
+            'D'..='F' => Some('3'),
+            'G'..='I' => Some('4'),
+            'J'..='L' => Some('5'),
+            'M'..='O' => Some('6'),
+            'P'..='S' => Some('7'),
+            'T'..='V' => Some('8'),
+            'W'..='Z' => Some('9'),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether `candidate` is a valid, assignable number of `region`
+///
+/// National-format candidates must match `national`, so each country decides
+/// which bare formats (trunk `0`, 8 digits) it accepts.
+pub(crate) fn is_region_phone(region: country::Id, national: &Regex, candidate: &str) -> bool {
+    let candidate = candidate.trim();
+    let international = candidate.starts_with('+') || candidate.starts_with("00");
+    if !international && !national.is_match(candidate) {
+        return false;
+    }
+
+    is_valid_region_number(region, candidate)
+}
+
+/// Phone numbers of `region` in `text`, reported as `detector`
+///
+/// International numbers (`+47 ...`, `0047 ...`) get High confidence and
+/// national-format numbers Medium, as bare digits are more ambiguous.
+pub(crate) fn detect_region_phones(
+    detector: &dyn Detector,
+    region: country::Id,
+    national: &Regex,
+    text: &str,
+    file_path: &Path,
+) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let mut byte_offset = 0;
+
+    for (line_num, line) in text.lines().enumerate() {
+        let international: Vec<_> = INTERNATIONAL_PATTERN.find_iter(line).collect();
+        let candidates = international.iter().map(|m| (*m, Confidence::High)).chain(
+            national
+                .find_iter(line)
+                // Skip the national part of an international number
+                .filter(|n| {
+                    !international
+                        .iter()
+                        .any(|m| n.start() < m.end() && m.start() < n.end())
+                })
+                .map(|m| (m, Confidence::Medium)),
+        );
+
+        for (capture, confidence) in candidates {
+            if !is_region_phone(region, national, capture.as_str()) {
+                continue;
+            }
+
+            matches.push(Match {
+                detector_id: detector.id().to_string(),
+                detector_name: detector.name().to_string(),
+                country: detector.country().to_string(),
+                value_masked: mask_phone(capture.as_str()),
+                location: crate::core::types::Location {
+                    file_path: file_path.to_path_buf(),
+                    line: line_num + 1,
+                    column: capture.start(),
+                    start_byte: byte_offset + capture.start(),
+                    end_byte: byte_offset + capture.end(),
+                },
+                confidence,
+                severity: detector.base_severity(),
+                context: None,
+                gdpr_category: GdprCategory::Regular,
+                fingerprint: String::new(),
+                decoded_from_base64: false,
+                partially_masked: false,
+                nationality: None,
+                issuing_country: None,
+                brand: None,
+                icon: None,
+                correlated_with: Vec::new(),
+                key_path: None,
+            });
+        }
+
+        byte_offset += line.len() + 1;
+    }
+
+    matches.sort_by_key(|m| m.location.start_byte);
+    matches
+}
+
+/// Phone number candidate with an optional extension (`ext 42`, `x42`,
+/// `doorkiesnummer 42`, `Durchwahl 42`, `poste 42`)
+///
+/// The extension is part of the candidate so a glued suffix (`...4567x42`)
+/// doesn't stop the number from matching at a word boundary.
+static EXTENSION_PHONE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?P<number>(?:\+|\b00)\d[\d \-]{6,16}\d|\b0\d[\d \-/]{5,14}\d)(?:\s*,?\s*(?:ext\.?|extension|x|doorkiesnummer|toestel|tst\.|durchwahl|dw\.|poste)\s*[:.]?\s*(?P<ext>\d{1,6}))?\b",
+    )
+    .expect("Failed to compile phone extension regex")
+});
+
+/// Split a phone number into its base number and extension (if any)
+///
+/// # Examples
+/// `+31 20 1234567 ext 42` → (`+31 20 1234567`, `Some("42")`)
+pub(crate) fn split_extension(value: &str) -> Option<(&str, Option<&str>)> {
+    let value = value.trim();
+    let caps = EXTENSION_PHONE_PATTERN.captures(value)?;
+    let whole = caps.get(0)?;
+    if whole.start() != 0 || whole.end() != value.len() {
+        return None;
+    }
+
+    Some((
+        caps.name("number")?.as_str(),
+        caps.name("ext").map(|ext| ext.as_str()),
+    ))
+}
+
+/// Whether `value` (with an optional extension) is a valid number of `region`
+pub(crate) fn is_region_phone_with_extension(region: country::Id, value: &str) -> bool {
+    split_extension(value).is_some_and(|(number, _)| is_valid_region_number(region, number))
+}
+
+fn is_valid_region_number(region: country::Id, number: &str) -> bool {
+    phonenumber::parse(Some(region), number)
+        .is_ok_and(|number| phonenumber::is_valid(&number) && number.country().id() == Some(region))
+}
+
+/// Phone numbers of `region` in `text` with extensions split off
+///
+/// The match covers the base number only, which is what gets validated and
+/// masked; a captured extension is noted in the detector name
+/// (`Dutch Phone Number (ext. 42)`). International numbers get High
+/// confidence and national-format numbers Medium.
+pub(crate) fn detect_extension_phones(
+    detector: &dyn Detector,
+    region: country::Id,
+    text: &str,
+    file_path: &Path,
+) -> Vec<Match> {
+    let mut matches = Vec::new();
+    let mut byte_offset = 0;
+
+    for (line_num, line) in text.lines().enumerate() {
+        for caps in EXTENSION_PHONE_PATTERN.captures_iter(line) {
+            let number = &caps["number"];
+            let Some(capture) = caps.name("number") else {
+                continue;
+            };
+
+            if !is_valid_region_number(region, number) {
+                continue;
+            }
+
+            let confidence = if number.starts_with('+') || number.starts_with("00") {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+            let detector_name = match caps.name("ext") {
+                Some(ext) => format!("{} (ext. {})", detector.name(), ext.as_str()),
+                None => detector.name().to_string(),
+            };
+
+            matches.push(Match {
+                detector_id: detector.id().to_string(),
+                detector_name,
+                country: detector.country().to_string(),
+                value_masked: mask_phone(number),
+                location: crate::core::types::Location {
+                    file_path: file_path.to_path_buf(),
+                    line: line_num + 1,
+                    column: capture.start(),
+                    start_byte: byte_offset + capture.start(),
+                    end_byte: byte_offset + capture.end(),
+                },
+                confidence,
+                severity: detector.base_severity(),
+                context: None,
+                gdpr_category: GdprCategory::Regular,
+                fingerprint: String::new(),
+                decoded_from_base64: false,
+                partially_masked: false,
+                nationality: None,
+                issuing_country: None,
+                brand: None,
+                icon: None,
+                correlated_with: Vec::new(),
+                key_path: None,
+            });
+        }
+
+        byte_offset += line.len() + 1;
+    }
+
+    matches
+}
+
+pub struct PhoneDetector {
+    default_region: Option<country::Id>,
+    regions: Vec<String>,
+}
+
+impl PhoneDetector {
+    pub fn new() -> Self {
+        Self {
+            default_region: None,
+            regions: Vec::new(),
+        }
+    }
+
+    /// Region (ISO 3166-1 alpha-2) used to parse national-format numbers
+    ///
+    /// Unknown region codes are ignored.
+    pub fn default_region(mut self, region: &str) -> Self {
+        self.default_region = region.trim().to_uppercase().parse().ok();
+        self
+    }
+
+    /// Only report numbers that belong to one of these regions
+    ///
+    /// An empty list reports numbers from any region.
+    pub fn regions(mut self, regions: Vec<String>) -> Self {
+        self.regions = regions
+            .into_iter()
+            .map(|r| r.trim().to_lowercase())
+            .filter(|r| !r.is_empty())
+            .collect();
+        self
+    }
+
+    /// Parse and validate a candidate, returning its region code (lowercase)
+    fn parse_region(&self, candidate: &str) -> Option<String> {
+        let number = phonenumber::parse(self.default_region, candidate).ok()?;
+
+        if !phonenumber::is_valid(&number) {
+            return None;
+        }
+
+        let region = number.country().id()?.as_ref().to_lowercase();
+
+        if !self.regions.is_empty() && !self.regions.contains(&region) {
+            return None;
+        }
+
+        Some(region)
+    }
+}
+
+impl Default for PhoneDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PhoneDetector {
+    fn id(&self) -> &str {
+        "phone"
+    }
+
+    fn name(&self) -> &str {
+        "Phone Number"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in PHONE_PATTERN.find_iter(line) {
+                let matched_text = capture.as_str();
+
+                if let Some(region) = self.parse_region(matched_text) {
+                    matches.push(Match {
+                        detector_id: self.id().to_string(),
+                        detector_name: format!("{} ({})", self.name(), region.to_uppercase()),
+                        country: self.country().to_string(),
+                        value_masked: mask_phone(matched_text),
+                        location: crate::core::types::Location {
+                            file_path: file_path.to_path_buf(),
+                            line: line_num + 1,
+                            column: capture.start(),
+                            start_byte: byte_offset + capture.start(),
+                            end_byte: byte_offset + capture.end(),
+                        },
+                        confidence: Confidence::High,
+                        severity: self.base_severity(),
+                        context: None,
+                        gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
+                    });
+                }
+            }
+
+            if VANITY_CONTEXT.is_match(line) {
+                for capture in VANITY_PATTERN.find_iter(line) {
+                    // A plain number followed by a capitalized word is not a vanity number
+                    let overlaps_number = matches.iter().any(|m| {
+                        m.location.start_byte < byte_offset + capture.end()
+                            && byte_offset + capture.start() < m.location.end_byte
+                    });
+                    if overlaps_number {
+                        continue;
+                    }
+
+                    let digits = vanity_digits(capture.as_str());
+
+                    if let Some(region) = self.parse_region(&digits) {
+                        matches.push(Match {
+                            detector_id: self.id().to_string(),
+                            detector_name: format!(
+                                "{} ({}, vanity)",
+                                self.name(),
+                                region.to_uppercase()
+                            ),
+                            country: self.country().to_string(),
+                            value_masked: mask_phone(&digits),
+                            location: crate::core::types::Location {
+                                file_path: file_path.to_path_buf(),
+                                line: line_num + 1,
+                                column: capture.start(),
+                                start_byte: byte_offset + capture.start(),
+                                end_byte: byte_offset + capture.end(),
+                            },
+                            confidence: Confidence::Medium,
+                            severity: Severity::Low,
+                            context: None,
+                            gdpr_category: GdprCategory::Regular,
+                            fingerprint: String::new(),
+                            decoded_from_base64: false,
+                            partially_masked: false,
+                            nationality: None,
+                            issuing_country: None,
+                            brand: None,
+                            icon: None,
+                            correlated_with: Vec::new(),
+                            key_path: None,
+                        });
+                    }
+                }
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn config_key(&self) -> String {
+        format!(
+            "default_region={:?} regions={}",
+            self.default_region,
+            self.regions.join(",")
+        )
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        self.parse_region(value).is_some()
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects phone numbers validated against libphonenumber metadata. \
+             National-format numbers require a default region (--phone-region)."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_international_number() {
+        let detector = PhoneDetector::new();
+        let text = "Call +49 30 901820 for details";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Phone Number (DE)");
+    }
+
+    #[test]
+    fn test_german_national_format_with_region() {
+        let detector = PhoneDetector::new()
+            .default_region("de")
+            .regions(vec!["de".to_string()]);
+        let text = "Telefon: 030 901820";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+    }
+
+    #[test]
+    fn test_national_format_without_region() {
+        let detector = PhoneDetector::new();
+        let text = "Telefon: 030 901820";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 0);
+    }
+
+    #[test]
+    fn test_us_number_filtered_by_regions() {
+        let text = "US office: +1 650-253-0000";
+        let path = PathBuf::from("test.txt");
+
+        let unrestricted = PhoneDetector::new();
+        assert_eq!(unrestricted.detect(text, &path).len(), 1);
+
+        let german_only = PhoneDetector::new()
+            .default_region("de")
+            .regions(vec!["de".to_string()]);
+        assert_eq!(german_only.detect(text, &path).len(), 0);
+    }
+
+    #[test]
+    fn test_vanity_number_with_call_keyword() {
+        let detector = PhoneDetector::new().default_region("nl");
+        let text = "Bestel bloemen? Call 0800-FLOWERS\n\
+                    Bestel vandaag nog FLOWERS voor 25 euro";
+        let path = PathBuf::from("campaign.csv");
+
+        assert_eq!(vanity_digits("0800-FLOWERS"), "08003569377");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "Phone Number (NL, vanity)");
+        assert_eq!(matches[0].value_masked, mask_phone("08003569377"));
+        assert_eq!(matches[0].severity, Severity::Low);
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "0800-FLOWERS"
+        );
+    }
+
+    #[test]
+    fn test_vanity_number_needs_keyword() {
+        let detector = PhoneDetector::new().default_region("nl");
+        let path = PathBuf::from("orders.csv");
+
+        // Same candidate without a phone keyword on the line
+        assert!(detector
+            .detect("Artikel 0800-FLOWERS op voorraad", &path)
+            .is_empty());
+        // An ordinary word after digits
+        assert!(detector
+            .detect("Call about order 0123 DELIVERED yesterday", &path)
+            .is_empty());
+        // A real number followed by a capitalized word
+        assert_eq!(
+            detector
+                .detect("Call +49 30 901820 NOW", &path)
+                .iter()
+                .map(|m| m.detector_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Phone Number (DE)"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_number() {
+        let detector = PhoneDetector::new();
+        let text = "Reference +00 000 000";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 0);
+    }
+}
@@ -0,0 +1,223 @@
+/// System identity detector for usernames leaked in logs and configuration
+///
+/// Infrastructure logs routinely contain home directory paths
+/// (`/home/jdoe`, `/Users/jdoe`, `C:\Users\jdoe`) and `user=`/`login=`
+/// assignments. The username in them usually identifies a person, so it is
+/// personal data. The detector extracts the username and reports its
+/// location; well-known service and system accounts are ignored.
+use crate::core::{Confidence, Detector, GdprCategory, Location, Match, Severity};
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Username patterns: (regex with the username in group 1, kind, confidence)
+static IDENTITY_PATTERNS: Lazy<Vec<(Regex, &'static str, Confidence)>> = Lazy::new(|| {
+    vec![
+        // Unix/macOS home directory: /home/jdoe, /Users/jdoe
+        (
+            Regex::new(r"(?:^|[^A-Za-z0-9_.~-])/(?:home|Users)/([A-Za-z0-9_][A-Za-z0-9._-]{1,31})")
+                .expect("Failed to compile unix home regex"),
+            "Home Directory",
+            Confidence::High,
+        ),
+        // Windows profile: C:\Users\jdoe (also with escaped backslashes)
+        (
+            Regex::new(r#"\b[A-Za-z]:\\{1,2}(?:Users|Documents and Settings)\\{1,2}([^\\/:*?"<>|\s]{2,64})"#)
+                .expect("Failed to compile windows profile regex"),
+            "Windows Profile",
+            Confidence::High,
+        ),
+        // Assignments: user=jdoe, login: "jdoe"
+        (
+            Regex::new(r#"(?i)\b(?:user|username|user_name|login|usr)\s*[=:]\s*["']?([A-Za-z0-9_][A-Za-z0-9._-]{1,63})"#)
+                .expect("Failed to compile username assignment regex"),
+            "Username Assignment",
+            Confidence::Medium,
+        ),
+    ]
+});
+
+/// Service and system accounts (lowercase) that identify no person
+const SYSTEM_ACCOUNTS: &[&str] = &[
+    "root",
+    "admin",
+    "administrator",
+    "nobody",
+    "daemon",
+    "www-data",
+    "postgres",
+    "mysql",
+    "ubuntu",
+    "ec2-user",
+    "centos",
+    "debian",
+    "git",
+    "runner",
+    "jenkins",
+    "node",
+    "guest",
+    "user",
+    "username",
+    "system",
+    "default",
+    "default user",
+    "public",
+    "all users",
+    "shared",
+    "test",
+    "null",
+    "none",
+];
+
+pub struct SystemIdentityDetector;
+
+impl SystemIdentityDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether `username` is worth reporting
+    fn is_personal(username: &str) -> bool {
+        let lower = username.to_lowercase();
+        !SYSTEM_ACCOUNTS.contains(&lower.as_str()) && !username.bytes().all(|b| b.is_ascii_digit())
+    }
+}
+
+impl Default for SystemIdentityDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for SystemIdentityDetector {
+    fn id(&self) -> &str {
+        "system_identity"
+    }
+
+    fn name(&self) -> &str {
+        "System Username"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Low
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for (pattern, kind, confidence) in IDENTITY_PATTERNS.iter() {
+                for captures in pattern.captures_iter(line) {
+                    let Some(username) = captures.get(1) else {
+                        continue;
+                    };
+                    // Trailing dots belong to the sentence, not the name
+                    let name = username.as_str().trim_end_matches('.');
+
+                    if !Self::is_personal(name) {
+                        continue;
+                    }
+
+                    matches.push(Match {
+                        detector_id: self.id().to_string(),
+                        detector_name: format!("{} ({})", self.name(), kind),
+                        country: self.country().to_string(),
+                        value_masked: mask_value(name),
+                        location: Location {
+                            file_path: file_path.to_path_buf(),
+                            line: line_num + 1,
+                            column: username.start(),
+                            start_byte: byte_offset + username.start(),
+                            end_byte: byte_offset + username.start() + name.len(),
+                        },
+                        confidence: *confidence,
+                        severity: self.base_severity(),
+                        context: None,
+                        gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
+                    });
+                }
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches.sort_by_key(|m| m.location.start_byte);
+        matches
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects usernames in home directory paths (/home/<user>, C:\\Users\\<user>) \
+             and user=/login= assignments in logs. System accounts such as root are ignored."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unix_home_path() {
+        let detector = SystemIdentityDetector::new();
+        let text = "ERROR open /home/jdoe/.ssh/id_rsa: permission denied";
+        let matches = detector.detect(text, Path::new("app.log"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_name, "System Username (Home Directory)");
+        assert_eq!(matches[0].value_masked, mask_value("jdoe"));
+        assert_eq!(
+            &text[matches[0].location.start_byte..matches[0].location.end_byte],
+            "jdoe"
+        );
+        assert_eq!(matches[0].severity, Severity::Low);
+    }
+
+    #[test]
+    fn test_windows_profile_path() {
+        let detector = SystemIdentityDetector::new();
+        let text = r#"{"path": "C:\\Users\\Marieke.Jansen\\AppData\\Local\\Temp"}"#;
+        let matches = detector.detect(text, Path::new("crash.json"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].detector_name,
+            "System Username (Windows Profile)"
+        );
+        assert_eq!(matches[0].value_masked, mask_value("Marieke.Jansen"));
+    }
+
+    #[test]
+    fn test_user_assignment() {
+        let detector = SystemIdentityDetector::new();
+        let text = "2024-05-01 sshd: Accepted login user=pvdberg from 10.0.0.12";
+        let matches = detector.detect(text, Path::new("auth.log"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].value_masked, mask_value("pvdberg"));
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_system_accounts_ignored() {
+        let detector = SystemIdentityDetector::new();
+        let text = "cd /home/runner/work && sudo -u root ls; user=root uid=1000 C:\\Users\\Public";
+        assert!(detector.detect(text, Path::new("ci.log")).is_empty());
+    }
+}
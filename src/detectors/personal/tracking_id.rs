@@ -0,0 +1,200 @@
+/// Keyword-gated advertising and analytics identifier detector
+///
+/// Recognizes mobile advertising IDs (GAID/IDFA UUIDs), Google Analytics
+/// `_ga` client IDs and Facebook pixel `_fbp`/`_fbc` cookies. These identify
+/// a device or browser and are personal data under GDPR, but UUIDs and
+/// dotted numbers are everywhere, so each format only matches when its key
+/// name appears directly before the value.
+use crate::core::{Confidence, Detector, GdprCategory, Match, ScanContext, Severity};
+use crate::utils::{keyword_gate, mask_value};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Advertising ID: GAID (Android) and IDFA (iOS) are both UUIDs
+static AD_ID_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}\b")
+        .expect("Failed to compile advertising ID regex")
+});
+
+/// Google Analytics client ID cookie: GA1.<depth>.<random>.<timestamp>
+static GA_COOKIE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bGA1\.\d\.\d{5,10}\.\d{10}\b").expect("Failed to compile GA cookie regex")
+});
+
+/// Facebook pixel cookie: fb.<subdomain>.<creation ms>.<random or fbclid>
+static FB_COOKIE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bfb\.\d\.\d{13}\.[A-Za-z0-9_-]{5,}").expect("Failed to compile FB cookie regex")
+});
+
+/// Keywords (lowercase) gating advertising IDs
+const AD_ID_KEYWORDS: &[&str] = &[
+    "gaid",
+    "idfa",
+    "advertising_id",
+    "advertisingid",
+    "advertising id",
+    "adid",
+    "ad_id",
+];
+
+/// Keywords gating GA client IDs
+const GA_KEYWORDS: &[&str] = &["_ga"];
+
+/// Keywords gating Facebook pixel cookies
+const FB_KEYWORDS: &[&str] = &["fbp", "fbc"];
+
+pub struct TrackingIdDetector;
+
+impl TrackingIdDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TrackingIdDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for TrackingIdDetector {
+    fn id(&self) -> &str {
+        "tracking_id"
+    }
+
+    fn name(&self) -> &str {
+        "Advertising/Tracking ID"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    /// Candidates without a keyword are reported as Low under
+    /// [`Strictness::Paranoid`](crate::core::Strictness::Paranoid) only
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        let formats: [(&Lazy<Regex>, &[&str]); 3] = [
+            (&AD_ID_PATTERN, AD_ID_KEYWORDS),
+            (&GA_COOKIE_PATTERN, GA_KEYWORDS),
+            (&FB_COOKIE_PATTERN, FB_KEYWORDS),
+        ];
+
+        for (line_num, line) in text.lines().enumerate() {
+            for (pattern, keywords) in formats {
+                for capture in pattern.find_iter(line) {
+                    let matched_text = capture.as_str();
+
+                    let Some(confidence) = keyword_gate(
+                        line,
+                        capture.start(),
+                        keywords,
+                        Confidence::High,
+                        context.strictness,
+                    ) else {
+                        continue;
+                    };
+
+                    matches.push(Match {
+                        detector_id: self.id().to_string(),
+                        detector_name: self.name().to_string(),
+                        country: self.country().to_string(),
+                        value_masked: mask_value(matched_text),
+                        location: crate::core::types::Location {
+                            file_path: file_path.to_path_buf(),
+                            line: line_num + 1,
+                            column: capture.start(),
+                            start_byte: byte_offset + capture.start(),
+                            end_byte: byte_offset + capture.end(),
+                        },
+                        confidence,
+                        severity: self.base_severity(),
+                        context: None,
+                        gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
+                    });
+                }
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        AD_ID_PATTERN.is_match(value)
+            || GA_COOKIE_PATTERN.is_match(value)
+            || FB_COOKIE_PATTERN.is_match(value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects advertising IDs (GAID/IDFA), Google Analytics '_ga' client IDs and \
+             Facebook '_fbp'/'_fbc' cookies when preceded by their key name."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_ga_cookie() {
+        let detector = TrackingIdDetector::new();
+        let text = "Cookie: _ga=GA1.2.1234567890.1700000000; _gid=GA1.2.987654.1700000000";
+        let path = PathBuf::from("test.txt");
+
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "tracking_id");
+        assert_eq!(matches[0].location.start_byte, 12);
+        assert_eq!(matches[0].severity, Severity::Medium);
+    }
+
+    #[test]
+    fn test_idfa_in_context() {
+        let detector = TrackingIdDetector::new();
+        let path = PathBuf::from("test.txt");
+
+        let text = r#"{"idfa": "6D92078A-8246-4BA4-AE5B-76104861E7DC", "os": "ios"}"#;
+        let matches = detector.detect(text, &path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::High);
+
+        // The same UUID without an advertising key is an ordinary identifier
+        let text = r#"{"request_id": "6D92078A-8246-4BA4-AE5B-76104861E7DC"}"#;
+        assert!(detector.detect(text, &path).is_empty());
+    }
+
+    #[test]
+    fn test_facebook_cookies() {
+        let detector = TrackingIdDetector::new();
+        let text = "_fbp=fb.1.1700000000000.1098115397 _fbc=fb.1.1700000000000.IwAR2xYz_abc";
+        let path = PathBuf::from("test.txt");
+
+        assert_eq!(detector.detect(text, &path).len(), 2);
+    }
+}
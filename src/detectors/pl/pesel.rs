@@ -13,6 +13,7 @@
 /// Validation: Weighted sum with weights [1,3,7,9,1,3,7,9,1,3] mod 10
 use crate::core::{Confidence, Detector, Match, Severity};
 use crate::utils::mask_value;
+use chrono::NaiveDate;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::path::Path;
@@ -50,39 +51,43 @@ impl PeselDetector {
         check_digit == digits[10]
     }
 
-    /// Validate date components
-    fn validate_date(pesel: &str) -> bool {
+    /// Decode the embedded date of birth
+    ///
+    /// The month carries the century: 81-92 for 1800-1899, 01-12 for
+    /// 1900-1999, 21-32 for 2000-2099, 41-52 for 2100-2199 and 61-72 for
+    /// 2200-2299. Returns `None` for impossible dates (e.g. 29 February in a
+    /// non-leap year).
+    pub fn birth_date(pesel: &str) -> Option<NaiveDate> {
         let digits: Vec<u32> = pesel.chars().filter_map(|c| c.to_digit(10)).collect();
         if digits.len() != 11 {
-            return false;
+            return None;
         }
 
+        let year = (digits[0] * 10 + digits[1]) as i32;
         let month_encoded = digits[2] * 10 + digits[3];
         let day = digits[4] * 10 + digits[5];
 
-        // Decode month (remove century offset)
-        let month = month_encoded % 20;
-
-        // Month must be 1-12
-        if !(1..=12).contains(&month) {
-            return false;
-        }
-
-        // Day must be 1-31
-        if !(1..=31).contains(&day) {
-            return false;
-        }
+        let century = match month_encoded / 20 {
+            0 => 1900,
+            1 => 2000,
+            2 => 2100,
+            3 => 2200,
+            4 => 1800,
+            _ => return None,
+        };
 
-        // Basic month-day validation
-        if month == 2 && day > 29 {
-            return false; // February max 29 days
-        }
+        NaiveDate::from_ymd_opt(century + year, month_encoded % 20, day)
+    }
 
-        if [4, 6, 9, 11].contains(&month) && day > 30 {
-            return false; // April, June, September, November have 30 days
-        }
+    /// Whether the PESEL belongs to a woman (even sequence digit)
+    pub fn is_female(pesel: &str) -> Option<bool> {
+        let digit = pesel.chars().nth(9)?.to_digit(10)?;
+        Some(digit % 2 == 0)
+    }
 
-        true
+    /// Validate date components
+    fn validate_date(pesel: &str) -> bool {
+        Self::birth_date(pesel).is_some()
     }
 }
 
@@ -138,6 +143,15 @@ impl Detector for PeselDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: crate::core::GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -146,6 +160,18 @@ impl Detector for PeselDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        Self::validate_date(value) && Self::validate_pesel(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("44051401458")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("44051401459")
+    }
 }
 
 impl Default for PeselDetector {
@@ -184,6 +210,49 @@ mod tests {
         assert!(!PeselDetector::validate_date("44131401458")); // Month 13
         assert!(!PeselDetector::validate_date("44013201458")); // Day 32
         assert!(!PeselDetector::validate_date("44023001458")); // Feb 30
+        assert!(!PeselDetector::validate_date("44932001458")); // Month 93 (no century)
+    }
+
+    #[test]
+    fn test_birth_date_century() {
+        // 1900s: month as-is
+        assert_eq!(
+            PeselDetector::birth_date("44051401458"),
+            NaiveDate::from_ymd_opt(1944, 5, 14)
+        );
+        // 2000s: month + 20
+        assert_eq!(
+            PeselDetector::birth_date("00272010219"),
+            NaiveDate::from_ymd_opt(2000, 7, 20)
+        );
+        // 1800s: month + 80
+        assert_eq!(
+            PeselDetector::birth_date("99811500000"),
+            NaiveDate::from_ymd_opt(1899, 1, 15)
+        );
+    }
+
+    #[test]
+    fn test_gender() {
+        assert_eq!(PeselDetector::is_female("44051401458"), Some(false));
+        assert_eq!(PeselDetector::is_female("02212112346"), Some(true));
+    }
+
+    #[test]
+    fn test_detector_rejects_impossible_date() {
+        // Checksum is valid, but 2001-02-29 does not exist
+        assert!(PeselDetector::validate_pesel("01222901236"));
+
+        let detector = PeselDetector::new();
+        let matches = detector.detect("PESEL: 01222901236", &PathBuf::from("test.txt"));
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_detector_finds_2000s_pesel() {
+        let detector = PeselDetector::new();
+        let matches = detector.detect("PESEL: 00272010219", &PathBuf::from("test.txt"));
+        assert_eq!(matches.len(), 1);
     }
 
     #[test]
@@ -2,7 +2,7 @@
 ///
 /// Allows users to define custom PII detectors via TOML configuration files.
 use crate::core::detector::Detector;
-use crate::core::types::{Confidence, GdprCategory, Location, Match, Severity};
+use crate::core::types::{Confidence, GdprCategory, Location, Match, Severity, SpecialCategory};
 use fancy_regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -24,6 +24,12 @@ pub struct PluginConfig {
     pub examples: Vec<String>,
     #[serde(default)]
     pub context_keywords: Vec<String>,
+    /// Special category every match is tagged with (e.g. "medical")
+    #[serde(default)]
+    pub gdpr_special_category: Option<SpecialCategory>,
+    /// Icon shown next to matches in terminal and HTML reports
+    #[serde(default)]
+    pub icon: Option<String>,
 }
 
 fn default_severity() -> String {
@@ -180,7 +186,22 @@ impl Detector for PluginDetector {
                     confidence: compiled.confidence,
                     severity: self.severity,
                     context: None,
-                    gdpr_category: GdprCategory::Regular,
+                    gdpr_category: match self.config.gdpr_special_category {
+                        Some(category) => GdprCategory::Special {
+                            category,
+                            detected_keywords: Vec::new(),
+                        },
+                        None => GdprCategory::Regular,
+                    },
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: self.config.icon.clone(),
+                    correlated_with: Vec::new(),
+                    key_path: None,
                 });
             }
         }
@@ -204,6 +225,10 @@ impl Detector for PluginDetector {
         self.severity
     }
 
+    fn config_key(&self) -> String {
+        format!("{:?}", self.config)
+    }
+
     fn validate(&self, value: &str) -> bool {
         self.validate_match(value)
     }
@@ -235,6 +260,8 @@ mod tests {
             }),
             examples: vec![],
             context_keywords: vec![],
+            gdpr_special_category: None,
+            icon: None,
         }
     }
 
@@ -78,6 +78,15 @@ impl Detector for NifDetector {
                         severity: self.base_severity(),
                         context: None, // Will be filled by context analyzer
                         gdpr_category: GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -92,6 +101,14 @@ impl Detector for NifDetector {
     fn validate(&self, value: &str) -> bool {
         validate_portugal_nif(value)
     }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("123456789")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("123456780")
+    }
 }
 
 #[cfg(test)]
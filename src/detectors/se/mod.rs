@@ -1,4 +1,6 @@
 /// Sweden detectors module
 pub mod personnummer;
+pub mod phone;
 
 pub use personnummer::PersonnummerDetector;
+pub use phone::PhoneDetector;
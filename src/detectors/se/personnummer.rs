@@ -176,6 +176,15 @@ impl Detector for PersonnummerDetector {
                         severity: self.base_severity(),
                         context: None,
                         gdpr_category: crate::core::GdprCategory::Regular,
+                        fingerprint: String::new(),
+                        decoded_from_base64: false,
+                        partially_masked: false,
+                        nationality: None,
+                        issuing_country: None,
+                        brand: None,
+                        icon: None,
+                        correlated_with: Vec::new(),
+                        key_path: None,
                     });
                 }
             }
@@ -184,6 +193,18 @@ impl Detector for PersonnummerDetector {
 
         matches
     }
+
+    fn validate(&self, value: &str) -> bool {
+        value.is_ascii() && Self::validate_date(value) && Self::validate_personnummer(value)
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("19900101-1003")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("19900101-0018")
+    }
 }
 
 impl Default for PersonnummerDetector {
@@ -0,0 +1,113 @@
+/// Sweden phone number detector
+///
+/// Finds Swedish numbers in international (`+46`, `0046`) or national format
+/// with a trunk `0` (`070-123 45 67`, `08-123 456 78`).
+/// Candidates are validated as assignable Swedish numbers with libphonenumber
+/// metadata, so numbers of other countries and too-short numbers are rejected.
+use crate::core::{Detector, Match, Severity};
+use crate::detectors::personal::phone::{detect_region_phones, is_region_phone};
+use once_cell::sync::Lazy;
+use phonenumber::country;
+use regex::Regex;
+use std::path::Path;
+
+/// National-format candidate: trunk `0` followed by the area code and subscriber number
+static NATIONAL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b0\d[\d \-]{6,11}\d\b").expect("Failed to compile Swedish phone regex")
+});
+
+pub struct PhoneDetector;
+
+impl PhoneDetector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for PhoneDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PhoneDetector {
+    fn id(&self) -> &str {
+        "se_phone"
+    }
+
+    fn name(&self) -> &str {
+        "Swedish Phone Number"
+    }
+
+    fn country(&self) -> &str {
+        "se"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Medium
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        detect_region_phones(self, country::Id::SE, &NATIONAL_PATTERN, text, file_path)
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        &["phone"]
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        is_region_phone(country::Id::SE, &NATIONAL_PATTERN, value)
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects Swedish phone numbers (+46, 0046 or national format with a trunk 0) \
+             validated against libphonenumber metadata."
+                .to_string(),
+        )
+    }
+
+    fn example_valid(&self) -> Option<&str> {
+        Some("+46 70 123 45 67")
+    }
+
+    fn example_invalid(&self) -> Option<&str> {
+        Some("070-123 45")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Confidence;
+
+    #[test]
+    fn test_swedish_mobile() {
+        let detector = PhoneDetector::new();
+        let text = "Mobil: 070-123 45 67, växel +46 8 123 456 78";
+        let matches = detector.detect(text, Path::new("kunder.csv"));
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].location.column, 7);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+        assert_eq!(matches[1].confidence, Confidence::High);
+        assert_eq!(matches[1].country, "se");
+    }
+
+    #[test]
+    fn test_too_short_number_rejected() {
+        let detector = PhoneDetector::new();
+        assert!(detector
+            .detect("Mobil: 070-123 45", Path::new("kunder.csv"))
+            .is_empty());
+        assert!(!detector.validate("+46 70 123"));
+    }
+
+    #[test]
+    fn test_other_country_rejected() {
+        let detector = PhoneDetector::new();
+        assert!(detector
+            .detect("Ring +47 412 34 567", Path::new("kunder.csv"))
+            .is_empty());
+    }
+}
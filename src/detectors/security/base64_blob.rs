@@ -0,0 +1,166 @@
+/// Base64 pre-processing detector
+///
+/// Finds plausible base64 runs, decodes them and re-runs the wrapped
+/// detectors on the decoded text. Matches keep the inner detector's ID and
+/// classification, point at the outer (encoded) span and carry the
+/// `decoded_from_base64` marker.
+use crate::core::{Detector, DetectorRegistry, Match, ScanContext, Severity};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Regex pattern for base64 runs
+/// Matches: 16+ base64 characters with optional padding
+static BASE64_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[A-Za-z0-9+/]{16,}={0,2}").expect("Failed to compile base64 regex"));
+
+/// Default cap on the decoded size of a single blob (64 KiB)
+const DEFAULT_MAX_DECODED_BYTES: usize = 64 * 1024;
+
+pub struct Base64Detector {
+    inner: DetectorRegistry,
+    max_decoded_bytes: usize,
+}
+
+impl Base64Detector {
+    /// Create a detector that re-runs `inner` on decoded blobs
+    pub fn new(inner: DetectorRegistry) -> Self {
+        Self {
+            inner,
+            max_decoded_bytes: DEFAULT_MAX_DECODED_BYTES,
+        }
+    }
+
+    /// Skip blobs whose decoded size would exceed `bytes`
+    pub fn max_decoded_bytes(mut self, bytes: usize) -> Self {
+        self.max_decoded_bytes = bytes;
+        self
+    }
+
+    /// Decode a candidate run, returning `None` for oversized, invalid or binary data
+    fn decode(&self, candidate: &str) -> Option<String> {
+        if !candidate.len().is_multiple_of(4) || candidate.len() / 4 * 3 > self.max_decoded_bytes {
+            return None;
+        }
+
+        let bytes = STANDARD.decode(candidate).ok()?;
+        let text = String::from_utf8(bytes).ok()?;
+
+        // Decoded text must be printable, otherwise it is binary data
+        if text
+            .chars()
+            .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+        {
+            return None;
+        }
+
+        Some(text)
+    }
+}
+
+impl Detector for Base64Detector {
+    fn id(&self) -> &str {
+        "base64"
+    }
+
+    fn name(&self) -> &str {
+        "Base64-Encoded PII"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        self.detect_in_context(text, file_path, &ScanContext::default())
+    }
+
+    fn detect_in_context(&self, text: &str, file_path: &Path, context: &ScanContext) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in BASE64_PATTERN.find_iter(line) {
+                let Some(decoded) = self.decode(capture.as_str()) else {
+                    continue;
+                };
+
+                for detector in self.inner.all() {
+                    for mut m in detector.detect_in_context(&decoded, file_path, context) {
+                        m.location.line = line_num + 1;
+                        m.location.column = capture.start();
+                        m.location.start_byte = byte_offset + capture.start();
+                        m.location.end_byte = byte_offset + capture.end();
+                        m.decoded_from_base64 = true;
+                        matches.push(m);
+                    }
+                }
+            }
+
+            // Update byte offset for next line (+1 for newline)
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    fn config_key(&self) -> String {
+        format!(
+            "max_decoded_bytes={} inner={}",
+            self.max_decoded_bytes,
+            crate::scanner::cache::scan_key(&self.inner, "")
+        )
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Decodes base64 blobs and re-runs the other detectors on the decoded text. \
+             Binary and oversized blobs are skipped."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::nl::BsnDetector;
+    use std::path::PathBuf;
+
+    fn bsn_only() -> Base64Detector {
+        let mut inner = DetectorRegistry::new();
+        inner.register(Box::new(BsnDetector::new()));
+        Base64Detector::new(inner)
+    }
+
+    #[test]
+    fn test_detects_bsn_in_base64() {
+        let encoded = STANDARD.encode("bsn=111222333");
+        let text = format!("token: {}", encoded);
+
+        let matches = bsn_only().detect(&text, &PathBuf::from("test.txt"));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "nl_bsn");
+        assert!(matches[0].decoded_from_base64);
+        assert_eq!(matches[0].location.start_byte, 7);
+        assert_eq!(matches[0].location.end_byte, text.len());
+    }
+
+    #[test]
+    fn test_skips_binary_and_oversized() {
+        let binary = STANDARD.encode([0u8, 159, 146, 150, 1, 2, 3, 4, 5, 6, 7, 8]);
+        assert!(bsn_only().decode(&binary).is_none());
+
+        let encoded = STANDARD.encode("bsn=111222333 and some padding text");
+        let detector = bsn_only().max_decoded_bytes(8);
+        assert!(detector
+            .detect(&encoded, &PathBuf::from("test.txt"))
+            .is_empty());
+    }
+}
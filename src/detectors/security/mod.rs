@@ -1,4 +1,24 @@
-/// Universal security detectors (API keys, private keys, tokens)
+/// Universal security detectors (API keys, high-entropy secrets, private keys, tokens, cloud secrets, chat tokens,
+/// OAuth credentials and JWKs, credential assignments, HTTP session credentials,
+/// base64 blobs)
 pub mod api_keys;
+pub mod base64_blob;
+pub mod chat_tokens;
+pub mod cloud_secrets;
+pub mod credentials;
+pub mod http_credentials;
+pub mod oauth;
 
-pub use api_keys::ApiKeyDetector;
+pub use api_keys::{ApiKeyDetector, EntropySecretDetector};
+pub use base64_blob::Base64Detector;
+pub use chat_tokens::ChatTokenDetector;
+pub use cloud_secrets::CloudSecretDetector;
+pub use credentials::CredentialAssignmentDetector;
+pub use http_credentials::HttpCredentialDetector;
+pub use oauth::OAuthCredentialDetector;
+
+/// Generic secret detectors that the specific ones supersede on the same value
+///
+/// A Stripe key in `api_key = "sk_live_..."` also looks like a credential
+/// assignment and a high-entropy string; only the specific match is kept.
+pub(crate) const GENERIC_SECRET_DETECTORS: &[&str] = &["credential_assignment", "entropy_secret"];
@@ -0,0 +1,278 @@
+/// OAuth client credential and JSON Web Key detector
+///
+/// Detects labelled `client_secret` / `client_id` assignments in JSON, YAML
+/// and `.env` syntax, and JSON Web Keys (`{"kty": ...}`) that carry private
+/// key material (`d`, `p`, `q`, `dp`, `dq`, `qi`, or the symmetric `k`).
+/// Public JWKs (only `n`/`e`, `x`/`y` or a key id) are not secrets and are
+/// not reported.
+use crate::core::{Confidence, Detector, GdprCategory, Location, Match, Severity};
+use crate::utils::mask_value;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+/// Labelled OAuth client credential (`client_secret: "..."`, `CLIENT_ID=...`)
+static CLIENT_CREDENTIAL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)client[_-]?(secret|id)["']?\s*[:=]\s*["']?([A-Za-z0-9._~+/=-]{8,})"#)
+        .expect("Failed to compile OAuth client credential regex")
+});
+
+/// JWK key type member
+static JWK_KTY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""kty"\s*:\s*"(?:RSA|EC|OKP|oct)""#).expect("Failed to compile JWK kty regex")
+});
+
+/// JWK private key member
+static JWK_PRIVATE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""(?:d|p|q|dp|dq|qi|k)"\s*:\s*"([A-Za-z0-9_-]{16,})""#)
+        .expect("Failed to compile JWK private member regex")
+});
+
+/// Values (lowercase substrings) that are placeholders, not credentials
+const PLACEHOLDERS: &[&str] = &["your", "xxxx", "changeme", "example", "placeholder"];
+
+pub struct OAuthCredentialDetector;
+
+impl OAuthCredentialDetector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Labelled client secrets and client ids, line by line
+    fn detect_client_credentials(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut byte_offset = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            for capture in CLIENT_CREDENTIAL_PATTERN.captures_iter(line) {
+                let (Some(kind), Some(value)) = (capture.get(1), capture.get(2)) else {
+                    continue;
+                };
+
+                let lower = value.as_str().to_lowercase();
+                if PLACEHOLDERS.iter().any(|p| lower.contains(p)) {
+                    continue;
+                }
+
+                let (kind, severity) = if kind.as_str().eq_ignore_ascii_case("secret") {
+                    ("Client Secret", Severity::Critical)
+                } else {
+                    ("Client ID", Severity::High)
+                };
+
+                matches.push(self.new_match(
+                    kind,
+                    value.as_str(),
+                    severity,
+                    Location {
+                        file_path: file_path.to_path_buf(),
+                        line: line_num + 1,
+                        column: value.start(),
+                        start_byte: byte_offset + value.start(),
+                        end_byte: byte_offset + value.end(),
+                    },
+                ));
+            }
+
+            byte_offset += line.len() + 1;
+        }
+
+        matches
+    }
+
+    /// JWK objects with private key material
+    ///
+    /// The object around each `kty` member is taken to run from the nearest
+    /// `{` before it to the first `}` after it, which covers the flat objects
+    /// JWKs and JWK sets use.
+    fn detect_private_jwks(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = Vec::new();
+
+        for kty in JWK_KTY_PATTERN.find_iter(text) {
+            let Some(start) = text[..kty.start()].rfind('{') else {
+                continue;
+            };
+            let end = text[kty.end()..]
+                .find('}')
+                .map_or(text.len(), |i| kty.end() + i);
+
+            let Some(private) = JWK_PRIVATE_PATTERN
+                .captures(&text[start..end])
+                .and_then(|c| c.get(1))
+            else {
+                continue;
+            };
+
+            let value_start = start + private.start();
+            let line_start = text[..value_start].rfind('\n').map_or(0, |i| i + 1);
+            matches.push(self.new_match(
+                "Private JSON Web Key",
+                private.as_str(),
+                Severity::Critical,
+                Location {
+                    file_path: file_path.to_path_buf(),
+                    line: text[..value_start].matches('\n').count() + 1,
+                    column: value_start - line_start,
+                    start_byte: value_start,
+                    end_byte: start + private.end(),
+                },
+            ));
+        }
+
+        matches
+    }
+
+    fn new_match(&self, kind: &str, value: &str, severity: Severity, location: Location) -> Match {
+        Match {
+            detector_id: self.id().to_string(),
+            detector_name: format!("{} ({})", self.name(), kind),
+            country: self.country().to_string(),
+            value_masked: mask_value(value),
+            location,
+            confidence: Confidence::High,
+            severity,
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        }
+    }
+}
+
+impl Default for OAuthCredentialDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for OAuthCredentialDetector {
+    fn id(&self) -> &str {
+        "oauth_credential"
+    }
+
+    fn name(&self) -> &str {
+        "OAuth Credential"
+    }
+
+    fn country(&self) -> &str {
+        "universal"
+    }
+
+    fn base_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn warmup(&self) {
+        Lazy::force(&JWK_PRIVATE_PATTERN);
+    }
+
+    fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+        let mut matches = self.detect_client_credentials(text, file_path);
+        matches.extend(self.detect_private_jwks(text, file_path));
+        matches.sort_by_key(|m| m.location.start_byte);
+        matches
+    }
+
+    fn supersedes(&self) -> &[&str] {
+        super::GENERIC_SECRET_DETECTORS
+    }
+
+    fn validate(&self, value: &str) -> bool {
+        value.len() >= 8
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "._~+/=-".contains(c))
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "Detects OAuth client secrets (Critical) and client IDs (High) in JSON, YAML and \
+             .env assignments, and JSON Web Keys containing private key material."
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_private_jwk() {
+        let detector = OAuthCredentialDetector::new();
+        let text = r#"{"keys": [
+  {
+    "kty": "RSA",
+    "kid": "2024-signing",
+    "n": "0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W",
+    "e": "AQAB",
+    "d": "X4cTteJY_gn4FYPsXB8rdXix5vwsg1FLN5E3EaG6RJoVH-HLLKD9M7dx5oo7GURknchnrRweUkC7hT5fJLM0WbFAKNLWY2vv7B6NqXSzUvxT0_YSfqijwp3RTzlBaCxWp4doFk5N2o8Gy_nHNKroADIkJ46pRUohsXywbReAdYaMwFs9tv8d_cPVY3i07a3t8MN6TNwm0dSawm9v47UiCl3Sk5ZiG7xojPLu4sbg1U2jx4IBTNBznbJSzFHK66jT8bgkuqsk0GjskDJk19Z4qwjwbsnn4j2WBii3RL-Us2lGVkY8fkFzme1z0HbIkfz0Y6mqnOYtqc0X4jfcKoAC8Q"
+  }
+]}"#;
+        let matches = detector.detect(text, Path::new("jwks.json"));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].detector_name,
+            "OAuth Credential (Private JSON Web Key)"
+        );
+        assert_eq!(matches[0].severity, Severity::Critical);
+        assert_eq!(matches[0].location.line, 7);
+        assert!(text[matches[0].location.start_byte..].starts_with("X4cTteJY"));
+    }
+
+    #[test]
+    fn test_client_secret_assignments() {
+        let detector = OAuthCredentialDetector::new();
+        let text = "OAUTH_CLIENT_ID=9d1c3b6e-4f7a-4c2e\nCLIENT_SECRET=Gq8s~Lm3Vz_Rt5Yp9WkXc2Nf\n  client_secret: \"h7Kp2Qw9Zx4Lm8Rt\"\n{\"client_secret\": \"your-client-secret\"}";
+        let matches = detector.detect(text, Path::new(".env"));
+
+        let found: Vec<_> = matches
+            .iter()
+            .map(|m| (m.detector_name.as_str(), m.severity, m.location.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("OAuth Credential (Client ID)", Severity::High, 1),
+                ("OAuth Credential (Client Secret)", Severity::Critical, 2),
+                ("OAuth Credential (Client Secret)", Severity::Critical, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_client_secret_reported_once() {
+        use crate::scanner::ScanEngine;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let file = tmp.path().join(".env");
+        std::fs::write(
+            &file,
+            "CLIENT_SECRET=Gq8sLm3VzRt5Yp9WkXc2NfA7bC9dE1fG2hJ4kL6m\n",
+        )
+        .unwrap();
+
+        let engine = ScanEngine::new(crate::default_registry()).show_progress(false);
+        let ids: Vec<String> = engine
+            .scan_file(&file)
+            .matches
+            .into_iter()
+            .map(|m| m.detector_id)
+            .collect();
+        assert_eq!(ids, vec!["oauth_credential"]);
+    }
+
+    #[test]
+    fn test_public_jwk_not_detected() {
+        let detector = OAuthCredentialDetector::new();
+        let text = r#"{"kty": "EC", "kid": "a1b2c3d4e5f6a7b8c9d0", "use": "sig"}"#;
+        assert!(detector.detect(text, Path::new("jwks.json")).is_empty());
+    }
+}
@@ -1,19 +1,31 @@
-/// Text extraction from document formats (PDF, DOCX, XLSX)
+/// Text extraction from document formats (PDF, DOCX, XLSX, XML/SVG, vCard/iCalendar,
+/// Jupyter notebooks, HAR network captures, SQL dumps)
 ///
 /// This module provides a trait-based system for extracting text from various
 /// document formats to enable PII scanning in non-plaintext files.
+use crate::core::Match;
 use std::path::Path;
 use thiserror::Error;
 
 pub mod docx;
+pub mod har;
+pub mod ipynb;
 pub mod pdf;
 pub mod registry;
+pub mod sql;
+pub mod vcard;
 pub mod xlsx;
+pub mod xml;
 
 pub use docx::DocxExtractor;
+pub use har::HarExtractor;
+pub use ipynb::NotebookExtractor;
 pub use pdf::PdfExtractor;
 pub use registry::ExtractorRegistry;
+pub use sql::SqlDumpExtractor;
+pub use vcard::VCardExtractor;
 pub use xlsx::XlsxExtractor;
+pub use xml::XmlExtractor;
 
 /// Error types for text extraction
 #[derive(Debug, Error)]
@@ -35,6 +47,151 @@ pub enum ExtractorError {
     ExtractionFailed(String),
 }
 
+/// Where one line of extracted text came from in the source file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLine {
+    /// Property or column the line's value belongs to (e.g. `TEL`, `users.email`)
+    pub key_path: Option<String>,
+
+    /// Line in the source file (1-indexed)
+    pub line: usize,
+
+    /// Column of the value in the source line (0-indexed)
+    pub column: usize,
+
+    /// Byte offset of the value in the source file
+    pub start_byte: usize,
+
+    /// Byte offset of the value in the extracted line (after its label)
+    pub value_offset: usize,
+}
+
+/// Source position of each line of extracted text, by line index
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    /// `None` where the source of a line is unknown
+    pub lines: Vec<Option<SourceLine>>,
+}
+
+impl SourceMap {
+    /// Move matches found in the extracted text to their source position
+    ///
+    /// Matches on a line with a known source get the source line, column and
+    /// byte offsets and the line's `key_path`; the offset within the value is
+    /// kept, so it is approximate where the extractor unescaped or joined it.
+    pub fn map_matches(&self, matches: &mut [Match]) {
+        for m in matches {
+            let Some(Some(source)) = self.lines.get(m.location.line.wrapping_sub(1)) else {
+                continue;
+            };
+
+            let offset = m.location.column.saturating_sub(source.value_offset);
+            let len = m.location.end_byte - m.location.start_byte;
+            m.location.line = source.line;
+            m.location.column = source.column + offset;
+            m.location.start_byte = source.start_byte + offset;
+            m.location.end_byte = m.location.start_byte + len;
+            m.key_path = source.key_path.clone();
+        }
+    }
+}
+
+/// Extracted text with the source position of each extracted line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extraction {
+    pub text: String,
+    pub source_map: SourceMap,
+}
+
+impl Extraction {
+    /// Text without source positions
+    pub fn text_only(text: String) -> Self {
+        Self {
+            text,
+            source_map: SourceMap::default(),
+        }
+    }
+
+    /// Append `label: value` as a line that came from `source`; empty values are skipped
+    ///
+    /// `source.value_offset` is filled in from the label, and the source
+    /// position moves past whitespace trimmed from the start of `value`.
+    pub(crate) fn push_line(&mut self, label: &str, value: &str, source: SourceLine) {
+        let trimmed = value.len() - value.trim_start().len();
+        let value = value.trim();
+        if value.is_empty() {
+            return;
+        }
+
+        self.text.push_str(label);
+        self.text.push_str(": ");
+        self.text.push_str(value);
+        self.text.push('\n');
+
+        self.push_source(Some(SourceLine {
+            column: source.column + trimmed,
+            start_byte: source.start_byte + trimmed,
+            value_offset: label.len() + 2,
+            ..source
+        }));
+    }
+
+    /// Append source lines as they are; `source` is the position of `text`
+    ///
+    /// `text` must be a slice of the source, so its later lines start at
+    /// column 0 of the following source lines.
+    pub(crate) fn push_verbatim(&mut self, text: &str, source: SourceLine) {
+        let mut position = source;
+        for raw in text.split_inclusive('\n') {
+            self.text.push_str(raw.trim_end_matches(['\n', '\r']));
+            self.text.push('\n');
+
+            let next = SourceLine {
+                line: position.line + 1,
+                column: 0,
+                start_byte: position.start_byte + raw.len(),
+                ..position.clone()
+            };
+            self.push_source(Some(position));
+            position = next;
+        }
+    }
+
+    /// Record the source of the line just appended
+    fn push_source(&mut self, source: Option<SourceLine>) {
+        // Lines pushed without a source keep their place
+        let lines = &mut self.source_map.lines;
+        lines.resize(self.text.lines().count() - 1, None);
+        lines.push(source);
+    }
+}
+
+/// Line starts of a source file, for turning byte offsets into source positions
+pub(crate) struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub(crate) fn new(content: &str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { starts }
+    }
+
+    /// Source position of `start_byte`
+    pub(crate) fn source(&self, key_path: Option<String>, start_byte: usize) -> SourceLine {
+        let line = self.starts.partition_point(|&start| start <= start_byte);
+        SourceLine {
+            key_path,
+            line,
+            column: start_byte - self.starts[line - 1],
+            start_byte,
+            value_offset: 0,
+        }
+    }
+}
+
 /// Trait for extracting text from document formats
 ///
 /// Implementors should:
@@ -55,6 +212,14 @@ pub trait TextExtractor: Send + Sync {
     /// * `Err(ExtractorError)` - If extraction fails
     fn extract(&self, path: &Path) -> Result<String, ExtractorError>;
 
+    /// Extract text together with the source position of each line
+    ///
+    /// The scan engine calls this instead of `extract()`. The default has no
+    /// positions, so matches keep their location in the extracted text.
+    fn extract_mapped(&self, path: &Path) -> Result<Extraction, ExtractorError> {
+        self.extract(path).map(Extraction::text_only)
+    }
+
     /// Get the file extensions supported by this extractor
     ///
     /// Extensions should be lowercase without the leading dot.
@@ -2,12 +2,58 @@
 use super::{ExtractorError, TextExtractor};
 use lopdf::Document;
 use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
 
-pub struct PdfExtractor;
+/// Default maximum number of pages extracted per document
+const DEFAULT_MAX_PAGES: usize = 5_000;
+
+/// Default maximum extracted text per document (64 MiB)
+const DEFAULT_MAX_TEXT_BYTES: usize = 64 * 1024 * 1024;
+
+/// Default wall-clock limit per document
+pub const DEFAULT_PDF_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// PDF extractor with page, text size and time limits
+///
+/// When the page or text cap is reached the text extracted so far is
+/// returned with a truncation note appended. Extraction runs on a worker
+/// thread; when the timeout passes, `extract` returns
+/// `ExtractorError::ExtractionFailed` even if the worker is stuck inside
+/// lopdf (loading the document or a single page). The abandoned worker stops
+/// at the next page boundary.
+#[derive(Debug, Clone, Copy)]
+pub struct PdfExtractor {
+    max_pages: usize,
+    max_text_bytes: usize,
+    timeout: Duration,
+}
 
 impl PdfExtractor {
     pub fn new() -> Self {
-        Self
+        Self {
+            max_pages: DEFAULT_MAX_PAGES,
+            max_text_bytes: DEFAULT_MAX_TEXT_BYTES,
+            timeout: DEFAULT_PDF_TIMEOUT,
+        }
+    }
+
+    /// Stop after extracting this many pages
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = pages;
+        self
+    }
+
+    /// Stop once this much text has been extracted
+    pub fn max_text_bytes(mut self, bytes: usize) -> Self {
+        self.max_text_bytes = bytes;
+        self
+    }
+
+    /// Abort extraction after this much wall-clock time
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
     }
 
     /// Extract text from a single page
@@ -15,14 +61,29 @@ impl PdfExtractor {
         doc.extract_text(&[page_num])
             .map_err(|e| ExtractorError::ExtractionFailed(format!("PDF page {}: {}", page_num, e)))
     }
-}
 
-impl TextExtractor for PdfExtractor {
-    fn extract(&self, path: &Path) -> Result<String, ExtractorError> {
+    fn timed_out(&self) -> ExtractorError {
+        ExtractorError::ExtractionFailed(format!(
+            "PDF extraction timed out after {}s",
+            self.timeout.as_secs()
+        ))
+    }
+
+    fn check_timeout(&self, start: Instant) -> Result<(), ExtractorError> {
+        if start.elapsed() > self.timeout {
+            return Err(self.timed_out());
+        }
+        Ok(())
+    }
+
+    /// Load the document and extract its pages, on the calling thread
+    fn extract_pages(&self, path: &Path, start: Instant) -> Result<String, ExtractorError> {
         // Load the PDF document
         let document = Document::load(path)
             .map_err(|e| ExtractorError::CorruptedFile(format!("Failed to load PDF: {}", e)))?;
 
+        self.check_timeout(start)?;
+
         // Get the total number of pages
         let pages = document.get_pages();
         if pages.is_empty() {
@@ -30,9 +91,21 @@ impl TextExtractor for PdfExtractor {
         }
 
         let mut text = String::new();
+        let mut truncated = None;
 
         // Extract text from each page
-        for page_num in pages.keys() {
+        for (index, page_num) in pages.keys().enumerate() {
+            if index >= self.max_pages {
+                truncated = Some(format!(
+                    "page limit reached, {} of {} pages extracted",
+                    index,
+                    pages.len()
+                ));
+                break;
+            }
+
+            self.check_timeout(start)?;
+
             match Self::extract_page_text(&document, *page_num) {
                 Ok(page_text) => {
                     text.push_str(&page_text);
@@ -43,10 +116,59 @@ impl TextExtractor for PdfExtractor {
                     eprintln!("Warning: {}", e);
                 }
             }
+
+            if text.len() > self.max_text_bytes {
+                let mut cut = self.max_text_bytes;
+                while !text.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                text.truncate(cut);
+                text.push('\n');
+                truncated = Some(format!(
+                    "text limit of {} bytes reached",
+                    self.max_text_bytes
+                ));
+                break;
+            }
+        }
+
+        if let Some(reason) = truncated {
+            text.push_str(&format!("[PDF truncated: {}]\n", reason));
         }
 
         Ok(text)
     }
+}
+
+impl TextExtractor for PdfExtractor {
+    fn extract(&self, path: &Path) -> Result<String, ExtractorError> {
+        let start = Instant::now();
+        let extractor = *self;
+        let owned_path = path.to_path_buf();
+        let (sender, receiver) = mpsc::channel();
+
+        // lopdf parses objects with rayon. On its own pool that work can't
+        // queue behind the scan pool's threads, which are blocked waiting here.
+        let worker = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .thread_name(|_| "pdf-extract".to_string())
+            .build()
+            .map_err(|e| {
+                ExtractorError::ExtractionFailed(format!("Failed to start PDF extraction: {}", e))
+            })?;
+        worker.spawn(move || {
+            // The receiver is gone after a timeout; nothing left to report to
+            let _ = sender.send(extractor.extract_pages(&owned_path, start));
+        });
+
+        match receiver.recv_timeout(self.timeout.saturating_sub(start.elapsed())) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(self.timed_out()),
+            Err(RecvTimeoutError::Disconnected) => Err(ExtractorError::ExtractionFailed(
+                "PDF extraction aborted".to_string(),
+            )),
+        }
+    }
 
     fn supported_extensions(&self) -> Vec<&str> {
         vec!["pdf"]
@@ -138,11 +260,105 @@ mod tests {
 
     #[test]
     fn test_pdf_extractor_default() {
-        let extractor = PdfExtractor;
+        let extractor = PdfExtractor::default();
         assert_eq!(extractor.name(), "PDF Extractor");
     }
 
-    // Note: Real PDF extraction tests with actual content would require
-    // creating fixture PDF files or using external test files.
-    // The above tests verify error handling and basic functionality.
+    /// Build a PDF with `count` pages, each containing one line of text
+    fn write_pdf(path: &Path, count: usize) {
+        use lopdf::content::{Content, Operation};
+        use lopdf::{dictionary, Object, Stream};
+
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Courier",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+
+        let kids: Vec<Object> = (0..count)
+            .map(|i| {
+                let content = Content {
+                    operations: vec![
+                        Operation::new("BT", vec![]),
+                        Operation::new("Tf", vec!["F1".into(), 12.into()]),
+                        Operation::new("Td", vec![100.into(), 600.into()]),
+                        Operation::new(
+                            "Tj",
+                            vec![Object::string_literal(format!("Page marker {}", i))],
+                        ),
+                        Operation::new("ET", vec![]),
+                    ],
+                };
+                let content_id =
+                    doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+                doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "Contents" => content_id,
+                })
+                .into()
+            })
+            .collect();
+
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => count as i64,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    #[test]
+    fn test_pdf_extractor_page_cap() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("large.pdf");
+        write_pdf(&path, 200);
+
+        let text = PdfExtractor::new().max_pages(5).extract(&path).unwrap();
+
+        assert!(text.contains("Page marker 4"));
+        assert!(!text.contains("Page marker 5"));
+        assert!(text.contains("[PDF truncated: page limit reached, 5 of 200 pages extracted]"));
+    }
+
+    #[test]
+    fn test_pdf_extractor_text_cap() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("large.pdf");
+        write_pdf(&path, 50);
+
+        let text = PdfExtractor::new()
+            .max_text_bytes(64)
+            .extract(&path)
+            .unwrap();
+
+        assert!(!text.contains("Page marker 49"));
+        assert!(text.contains("[PDF truncated: text limit of 64 bytes reached]"));
+    }
+
+    #[test]
+    fn test_pdf_extractor_timeout() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("large.pdf");
+        write_pdf(&path, 10);
+
+        let result = PdfExtractor::new().timeout(Duration::ZERO).extract(&path);
+
+        assert!(matches!(result, Err(ExtractorError::ExtractionFailed(_))));
+    }
 }
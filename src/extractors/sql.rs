@@ -0,0 +1,614 @@
+/// SQL dump text extraction
+///
+/// Database dumps keep rows in `INSERT INTO ... VALUES (...)` statements, where
+/// a finding's line number says little about where the value lives. This
+/// aligns every VALUES tuple with its column list and emits one
+/// `table.column: value` line per value, so findings can be traced back to
+/// the table and column they came from. Column lists come from the INSERT
+/// itself or from an earlier `CREATE TABLE`; values without a known column are
+/// keyed by position (`users.#3`). NULLs are skipped. Findings point at the
+/// value in the dump and carry its `table.column` as their key path.
+///
+/// Statements that are neither `CREATE TABLE` nor `INSERT` (e.g. `UPDATE`),
+/// and INSERTs that cannot be parsed, are emitted unchanged. A dump that
+/// cannot be split into statements is scanned as plain text.
+use super::{Extraction, ExtractorError, LineIndex, SourceLine, TextExtractor};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Table-level keywords that start a `CREATE TABLE` element that is not a column
+const CONSTRAINT_KEYWORDS: &[&str] = &[
+    "PRIMARY",
+    "KEY",
+    "UNIQUE",
+    "INDEX",
+    "CONSTRAINT",
+    "FOREIGN",
+    "CHECK",
+    "FULLTEXT",
+    "SPATIAL",
+];
+
+pub struct SqlDumpExtractor;
+
+impl SqlDumpExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Extract `table.column: value` lines from a dump
+    fn extract_text_from_sql(&self, content: &str) -> Extraction {
+        let Some(statements) = split_statements(content) else {
+            return Extraction::text_only(content.to_string());
+        };
+
+        let lines = LineIndex::new(content);
+        let mut columns: HashMap<String, Vec<String>> = HashMap::new();
+        let mut output = Extraction::default();
+
+        for statement in statements {
+            let body = skip_comments(statement);
+            if body.is_empty() {
+                continue;
+            }
+
+            let mut parser = Parser::new(body);
+            if parser.keywords(&["CREATE", "TABLE"]) {
+                if let Some((table, table_columns)) = parser.create_table() {
+                    columns.insert(table, table_columns);
+                }
+                continue;
+            }
+
+            let mut parser = Parser::new(body);
+            match parser.insert() {
+                Some(insert) => {
+                    let base = offset_in(content, body);
+                    push_rows(&mut output, &insert, &columns, |key_path, offset| {
+                        lines.source(Some(key_path), base + offset)
+                    })
+                }
+                None => {
+                    let statement = statement.trim();
+                    let source = lines.source(None, offset_in(content, statement));
+                    output.push_verbatim(statement, source);
+                }
+            }
+        }
+
+        output
+    }
+}
+
+/// A parsed INSERT statement
+struct Insert {
+    table: String,
+    columns: Option<Vec<String>>,
+    rows: Vec<Vec<Option<Value>>>,
+}
+
+/// A non-NULL value of a VALUES tuple
+struct Value {
+    text: String,
+    /// Byte offset of the value in the statement, inside its quotes
+    offset: usize,
+}
+
+/// Append one `table.column: value` line per non-NULL value
+///
+/// `source` gives the source position of a `table.column` value at an offset
+/// in the statement.
+fn push_rows(
+    output: &mut Extraction,
+    insert: &Insert,
+    known: &HashMap<String, Vec<String>>,
+    source: impl Fn(String, usize) -> SourceLine,
+) {
+    let columns = insert.columns.as_ref().or_else(|| known.get(&insert.table));
+
+    for row in &insert.rows {
+        for (index, value) in row.iter().enumerate() {
+            let Some(value) = value else { continue };
+            let column = columns
+                .and_then(|columns| columns.get(index))
+                .cloned()
+                .unwrap_or_else(|| format!("#{}", index + 1));
+            let key_path = format!("{}.{}", insert.table, column);
+            // Keep each value on its own line
+            let text = value.text.replace(['\r', '\n'], " ");
+            let source = source(key_path.clone(), value.offset);
+            output.push_line(&key_path, &text, source);
+        }
+    }
+}
+
+/// Byte offset of `part`, a slice of `content`, in `content`
+fn offset_in(content: &str, part: &str) -> usize {
+    part.as_ptr() as usize - content.as_ptr() as usize
+}
+
+/// Split a dump on `;` outside quotes and comments
+///
+/// Returns `None` when a quote or block comment is never closed.
+fn split_statements(content: &str) -> Option<Vec<&str>> {
+    let bytes = content.as_bytes();
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => i = skip_quoted(bytes, i, quote)?,
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = content[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            b'#' => i = content[i..].find('\n').map_or(bytes.len(), |end| i + end),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += content[i + 2..].find("*/")? + 4;
+            }
+            b';' => {
+                statements.push(&content[start..i]);
+                start = i + 1;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    statements.push(&content[start..]);
+    Some(statements)
+}
+
+/// Index after the quote closing the one at `open`
+///
+/// A doubled quote is an escaped quote; in strings a backslash escapes the next byte.
+fn skip_quoted(bytes: &[u8], open: usize, quote: u8) -> Option<usize> {
+    let mut i = open + 1;
+    while i < bytes.len() {
+        if quote == b'\'' && bytes[i] == b'\\' {
+            i += 2;
+        } else if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else {
+                return Some(i + 1);
+            }
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// Strip leading whitespace and comments from a statement
+fn skip_comments(statement: &str) -> &str {
+    let mut rest = statement.trim_start();
+    loop {
+        if rest.starts_with("--") || rest.starts_with('#') {
+            rest = rest.find('\n').map_or("", |end| &rest[end..]).trim_start();
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment
+                .find("*/")
+                .map_or("", |end| &comment[end + 2..])
+                .trim_start();
+        } else {
+            return rest;
+        }
+    }
+}
+
+/// Cursor over one statement
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consume `c` if it is the next non-whitespace character
+    fn symbol(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume a case-insensitive keyword
+    fn keyword(&mut self, keyword: &str) -> bool {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let matches = rest
+            .get(..keyword.len())
+            .is_some_and(|word| word.eq_ignore_ascii_case(keyword))
+            && !rest[keyword.len()..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+        if matches {
+            self.pos += keyword.len();
+        }
+        matches
+    }
+
+    /// Consume a sequence of keywords, or nothing
+    fn keywords(&mut self, keywords: &[&str]) -> bool {
+        let start = self.pos;
+        if keywords.iter().all(|keyword| self.keyword(keyword)) {
+            return true;
+        }
+        self.pos = start;
+        false
+    }
+
+    /// A plain or quoted identifier, without quotes
+    fn identifier(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let quote = rest.chars().next()?;
+
+        if matches!(quote, '`' | '"' | '[') {
+            let close = if quote == '[' { ']' } else { quote };
+            let end = rest[1..].find(close)? + 1;
+            self.pos += end + 1;
+            return Some(rest[1..end].to_string());
+        }
+
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .unwrap_or(rest.len());
+        (end > 0).then(|| {
+            self.pos += end;
+            rest[..end].to_string()
+        })
+    }
+
+    /// A table name; a schema prefix (`shop.users`) is dropped
+    fn table_name(&mut self) -> Option<String> {
+        let mut name = self.identifier()?;
+        while self.symbol('.') {
+            name = self.identifier()?;
+        }
+        Some(name)
+    }
+
+    /// `(a, b, c)` identifier list
+    fn column_list(&mut self) -> Option<Vec<String>> {
+        let mut columns = Vec::new();
+        loop {
+            columns.push(self.identifier()?);
+            if self.symbol(')') {
+                return Some(columns);
+            }
+            if !self.symbol(',') {
+                return None;
+            }
+        }
+    }
+
+    /// Everything after `CREATE TABLE`
+    fn create_table(&mut self) -> Option<(String, Vec<String>)> {
+        self.keywords(&["IF", "NOT", "EXISTS"]);
+        let table = self.table_name()?;
+        if !self.symbol('(') {
+            return None;
+        }
+
+        let mut columns = Vec::new();
+        for element in split_top_level(self.rest())? {
+            let mut element = Parser::new(element);
+            let is_constraint = CONSTRAINT_KEYWORDS.iter().any(|kw| element.keyword(kw));
+            if !is_constraint {
+                columns.push(element.identifier()?);
+            }
+        }
+        Some((table, columns))
+    }
+
+    /// `INSERT [IGNORE] INTO table [(columns)] VALUES (...), (...)`
+    fn insert(&mut self) -> Option<Insert> {
+        let is_insert = self.keywords(&["INSERT", "INTO"])
+            || self.keywords(&["INSERT", "IGNORE", "INTO"])
+            || self.keywords(&["REPLACE", "INTO"]);
+        if !is_insert {
+            return None;
+        }
+
+        let table = self.table_name()?;
+        let columns = if self.symbol('(') {
+            Some(self.column_list()?)
+        } else {
+            None
+        };
+        if !self.keyword("VALUES") {
+            return None;
+        }
+
+        let mut rows = Vec::new();
+        loop {
+            if !self.symbol('(') {
+                return None;
+            }
+            rows.push(self.tuple()?);
+            if !self.symbol(',') {
+                break;
+            }
+        }
+
+        // Only an upsert clause may follow the rows
+        self.skip_whitespace();
+        if !self.rest().is_empty() && !self.keyword("ON") {
+            return None;
+        }
+
+        Some(Insert {
+            table,
+            columns,
+            rows,
+        })
+    }
+
+    /// Values up to the closing `)`; NULLs are `None`
+    fn tuple(&mut self) -> Option<Vec<Option<Value>>> {
+        let mut values = Vec::new();
+        loop {
+            values.push(self.value()?);
+            if self.symbol(')') {
+                return Some(values);
+            }
+            if !self.symbol(',') {
+                return None;
+            }
+        }
+    }
+
+    /// One value: a string literal or any other expression up to `,` or `)`
+    fn value(&mut self) -> Option<Option<Value>> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let rest = self.rest();
+
+        if rest.starts_with('\'') {
+            let end = skip_quoted(rest.as_bytes(), 0, b'\'')?;
+            self.pos += end;
+            return Some(Some(Value {
+                text: unescape(&rest[1..end - 1]),
+                offset: start + 1,
+            }));
+        }
+
+        let end = split_top_level(rest)?.first()?.len();
+        self.pos += end;
+        let expression = rest[..end].trim_end();
+        Some((!expression.eq_ignore_ascii_case("NULL")).then(|| Value {
+            text: expression.to_string(),
+            offset: start,
+        }))
+    }
+}
+
+/// Split the text up to an unbalanced `)` on top-level commas
+fn split_top_level(text: &str) -> Option<Vec<&str>> {
+    let bytes = text.as_bytes();
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i = skip_quoted(bytes, i, quote)?;
+                continue;
+            }
+            b'(' => depth += 1,
+            b')' if depth == 0 => {
+                parts.push(&text[start..i]);
+                return Some(parts);
+            }
+            b')' => depth -= 1,
+            b',' if depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Resolve `''` and backslash escapes in a string literal
+fn unescape(literal: &str) -> String {
+    let mut value = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some('0') => {}
+                Some(other) => value.push(other),
+                None => {}
+            },
+            '\'' => {
+                // Second quote of a doubled ''
+                chars.next();
+                value.push('\'');
+            }
+            _ => value.push(c),
+        }
+    }
+    value
+}
+
+impl TextExtractor for SqlDumpExtractor {
+    fn extract(&self, path: &Path) -> Result<String, ExtractorError> {
+        self.extract_mapped(path).map(|extraction| extraction.text)
+    }
+
+    fn extract_mapped(&self, path: &Path) -> Result<Extraction, ExtractorError> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(self.extract_text_from_sql(&content))
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec!["sql"]
+    }
+
+    fn name(&self) -> &str {
+        "SQL Dump Extractor"
+    }
+}
+
+impl Default for SqlDumpExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Detector;
+    use crate::detectors::personal::EmailDetector;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const DUMP: &str = "-- MySQL dump 10.13\n\
+        /*!40101 SET NAMES utf8mb4 */;\n\
+        DROP TABLE IF EXISTS `users`;\n\
+        CREATE TABLE `users` (\n\
+          `id` int NOT NULL AUTO_INCREMENT,\n\
+          `name` varchar(255) DEFAULT NULL,\n\
+          `email` varchar(255) NOT NULL,\n\
+          `notes` text,\n\
+          PRIMARY KEY (`id`),\n\
+          UNIQUE KEY `email` (`email`)\n\
+        ) ENGINE=InnoDB;\n\
+        INSERT INTO `users` VALUES (1,'Jan Jansen','jan.jansen@example.nl',NULL),\
+        (2,'Piet O''Brien','piet@example.org','Says \\\"hi\\\"; ok');\n";
+
+    #[test]
+    fn test_sql_email_carries_table_and_column() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("backup.sql");
+        fs::write(&path, DUMP).unwrap();
+
+        let text = SqlDumpExtractor::new().extract(&path).unwrap();
+        assert!(text.contains("users.email: jan.jansen@example.nl\n"));
+        assert!(text.contains("users.name: Piet O'Brien\n"));
+        assert!(text.contains("users.notes: Says \"hi\"; ok\n"));
+        assert!(!text.contains("users.notes: NULL"));
+
+        let matches = EmailDetector::new().detect(&text, &path);
+        assert_eq!(matches.len(), 2);
+        let line = text.lines().nth(matches[0].location.line - 1).unwrap();
+        assert!(line.starts_with("users.email: "));
+    }
+
+    #[test]
+    fn test_sql_matches_point_at_source() {
+        use crate::extractors::ExtractorRegistry;
+        use crate::scanner::ScanEngine;
+        use std::sync::Arc;
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("backup.sql");
+        let dump = format!(
+            "{}UPDATE users SET notes = 'mail anna@example.com'\n  WHERE id = 2;\n",
+            DUMP
+        );
+        fs::write(&path, &dump).unwrap();
+
+        let mut extractors = ExtractorRegistry::new();
+        extractors.register(Arc::new(SqlDumpExtractor::new()));
+        let mut registry = crate::core::DetectorRegistry::new();
+        registry.register(Box::new(EmailDetector::new()));
+        let engine = ScanEngine::new(registry)
+            .show_progress(false)
+            .with_extractors(extractors);
+
+        let result = engine.scan_file(&path);
+        assert_eq!(result.matches.len(), 3);
+        for (m, key_path, line, value) in [
+            (
+                &result.matches[0],
+                Some("users.email"),
+                12,
+                "jan.jansen@example.nl",
+            ),
+            (
+                &result.matches[1],
+                Some("users.email"),
+                12,
+                "piet@example.org",
+            ),
+            (&result.matches[2], None, 13, "anna@example.com"),
+        ] {
+            assert_eq!(m.key_path.as_deref(), key_path);
+            assert_eq!(m.location.line, line);
+            assert_eq!(&dump[m.location.start_byte..m.location.end_byte], value);
+            let line_start = dump[..m.location.start_byte].rfind('\n').unwrap() + 1;
+            assert_eq!(m.location.column, m.location.start_byte - line_start);
+        }
+    }
+
+    #[test]
+    fn test_insert_with_column_list() {
+        let dump = "INSERT INTO shop.customers (id, contact) VALUES (7, 'anna@example.com'), \
+                    (8, LOWER('BOB@EXAMPLE.COM'));";
+        let text = SqlDumpExtractor::new().extract_text_from_sql(dump).text;
+        assert_eq!(
+            text,
+            "customers.id: 7\n\
+             customers.contact: anna@example.com\n\
+             customers.id: 8\n\
+             customers.contact: LOWER('BOB@EXAMPLE.COM')\n"
+        );
+
+        // Without a column list or CREATE TABLE, values are keyed by position
+        let text = SqlDumpExtractor::new()
+            .extract_text_from_sql("INSERT INTO t VALUES ('x@y.nl');")
+            .text;
+        assert_eq!(text, "t.#1: x@y.nl\n");
+    }
+
+    #[test]
+    fn test_sql_fallback_to_plain_text() {
+        let extractor = SqlDumpExtractor::new();
+
+        let update = "UPDATE users SET email = 'jan@example.nl' WHERE id = 1;";
+        assert_eq!(
+            extractor.extract_text_from_sql(update).text,
+            "UPDATE users SET email = 'jan@example.nl' WHERE id = 1\n"
+        );
+
+        // Malformed INSERT is kept as written
+        let broken = "INSERT INTO users VALUES (1, 'jan@example.nl' 'oops');";
+        assert!(extractor
+            .extract_text_from_sql(broken)
+            .text
+            .contains("'jan@example.nl' 'oops'"));
+
+        // Unterminated string: the whole dump is scanned as is
+        let unterminated = "INSERT INTO users VALUES (1, 'jan@example.nl);";
+        assert_eq!(
+            extractor.extract_text_from_sql(unterminated).text,
+            unterminated
+        );
+    }
+}
@@ -0,0 +1,302 @@
+/// vCard (.vcf) and iCalendar (.ics) text extraction
+///
+/// Unfolds continuation lines and emits one `PROPERTY: value` line per
+/// contact or attendee property (FN, TEL, EMAIL, ADR, ORG, ATTENDEE,
+/// ORGANIZER), so findings carry the property they came from. Structured
+/// values (ADR) are joined with commas, `mailto:` prefixes are stripped and
+/// the `CN` (common name) parameter of attendees is emitted as `PROPERTY/@CN`.
+/// Each line records the property and its position in the source file, so
+/// matches point at the original vCard line.
+use super::{Extraction, ExtractorError, SourceLine, TextExtractor};
+use std::path::Path;
+
+/// Properties whose values are extracted
+const PROPERTIES: &[&str] = &["FN", "TEL", "EMAIL", "ADR", "ORG", "ATTENDEE", "ORGANIZER"];
+
+pub struct VCardExtractor;
+
+impl VCardExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Extract the selected properties from vCard/iCalendar content
+    fn extract_text_from_vcard(&self, content: &str) -> Extraction {
+        let mut output = Extraction::default();
+
+        for line in unfold(content) {
+            let Some((head, value)) = line.text.split_once(':') else {
+                continue;
+            };
+
+            // Source position of a byte offset in the (unfolded) line
+            let source = |key_path: &str, offset: usize| SourceLine {
+                key_path: Some(key_path.to_string()),
+                line: line.number,
+                column: offset,
+                start_byte: line.start_byte + offset,
+                value_offset: 0,
+            };
+
+            let mut params = head.split(';');
+            // Strip an optional group prefix, e.g. `item1.TEL`
+            let name = params
+                .next()
+                .unwrap_or_default()
+                .rsplit('.')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_uppercase();
+
+            if !PROPERTIES.contains(&name.as_str()) {
+                continue;
+            }
+
+            let mut param_offset = head.find(';').unwrap_or(head.len());
+            for param in params {
+                param_offset += 1;
+                if let Some((key, cn)) = param.split_once('=') {
+                    if key.eq_ignore_ascii_case("CN") {
+                        let key_path = format!("{}/@CN", name);
+                        let quote = usize::from(cn.starts_with('"'));
+                        output.push_line(
+                            &key_path,
+                            cn.trim_matches('"'),
+                            source(&key_path, param_offset + key.len() + 1 + quote),
+                        );
+                    }
+                }
+                param_offset += param.len();
+            }
+
+            let mut value_start = head.len() + 1;
+            let value = if name == "ADR" || name == "ORG" {
+                split_components(value)
+                    .into_iter()
+                    .filter(|c| !c.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            } else {
+                let value = unescape(value);
+                match value.get(..7) {
+                    Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => {
+                        value_start += 7;
+                        value[7..].to_string()
+                    }
+                    _ => value,
+                }
+            };
+
+            output.push_line(&name, &value, source(&name, value_start));
+        }
+
+        output
+    }
+}
+
+/// A content line after unfolding, with the position of its first physical line
+struct UnfoldedLine {
+    text: String,
+    /// Source line number (1-indexed)
+    number: usize,
+    /// Byte offset of the line in the source
+    start_byte: usize,
+}
+
+/// Join folded lines (continuations start with a space or tab)
+fn unfold(content: &str) -> Vec<UnfoldedLine> {
+    let mut lines: Vec<UnfoldedLine> = Vec::new();
+    let mut start_byte = 0;
+
+    for (index, raw) in content.split_inclusive('\n').enumerate() {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.text.push_str(continuation),
+            _ => lines.push(UnfoldedLine {
+                text: line.to_string(),
+                number: index + 1,
+                start_byte,
+            }),
+        }
+        start_byte += raw.len();
+    }
+
+    lines
+}
+
+/// Split a structured value on unescaped `;`
+fn split_components(value: &str) -> Vec<String> {
+    let mut components = vec![String::new()];
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    components
+                        .last_mut()
+                        .expect("components is never empty")
+                        .push(unescape_char(next));
+                }
+            }
+            ';' => components.push(String::new()),
+            _ => components
+                .last_mut()
+                .expect("components is never empty")
+                .push(c),
+        }
+    }
+
+    components
+        .into_iter()
+        .map(|c| c.trim().to_string())
+        .collect()
+}
+
+/// Resolve vCard escapes (`\,`, `\;`, `\\`, `\n`)
+fn unescape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        if let Some(next) = chars.next() {
+            output.push(unescape_char(next));
+        }
+    }
+
+    output
+}
+
+/// Character for the escape sequence `\c` (newlines become spaces)
+fn unescape_char(c: char) -> char {
+    match c {
+        'n' | 'N' => ' ',
+        other => other,
+    }
+}
+
+impl TextExtractor for VCardExtractor {
+    fn extract(&self, path: &Path) -> Result<String, ExtractorError> {
+        self.extract_mapped(path).map(|extraction| extraction.text)
+    }
+
+    fn extract_mapped(&self, path: &Path) -> Result<Extraction, ExtractorError> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(self.extract_text_from_vcard(&content))
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec!["vcf", "ics"]
+    }
+
+    fn name(&self) -> &str {
+        "vCard/iCalendar Extractor"
+    }
+}
+
+impl Default for VCardExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Detector;
+    use crate::detectors::personal::{EmailDetector, PhoneDetector};
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Property name of the extracted line a match was found on
+    fn property_of(text: &str, line: usize) -> &str {
+        let line = text.lines().nth(line - 1).unwrap();
+        line.split_once(": ").unwrap().0
+    }
+
+    #[test]
+    fn test_vcard_tel_and_email() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("contacts.vcf");
+        fs::write(
+            &path,
+            "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Jan Jansen\r\nitem1.TEL;TYPE=CELL:+31 6 12345678\r\nEMAIL;TYPE=INTERNET:jan.jansen@\r\n example.com\r\nNOTE:not extracted\r\nEND:VCARD\r\n",
+        )
+        .unwrap();
+
+        let text = VCardExtractor::new().extract(&path).unwrap();
+        assert_eq!(
+            text,
+            "FN: Jan Jansen\nTEL: +31 6 12345678\nEMAIL: jan.jansen@example.com\n"
+        );
+
+        let phones = PhoneDetector::new().detect(&text, &path);
+        assert_eq!(phones.len(), 1);
+        assert_eq!(property_of(&text, phones[0].location.line), "TEL");
+
+        let emails = EmailDetector::new().detect(&text, &path);
+        assert_eq!(emails.len(), 1);
+        assert_eq!(property_of(&text, emails[0].location.line), "EMAIL");
+    }
+
+    #[test]
+    fn test_vcard_matches_point_at_source() {
+        use crate::extractors::ExtractorRegistry;
+        use crate::scanner::ScanEngine;
+        use std::sync::Arc;
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("contacts.vcf");
+        let content = "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Jan Jansen\r\nitem1.TEL;TYPE=CELL:+31 6 12345678\r\nEMAIL;TYPE=INTERNET:jan.jansen@example.com\r\nEND:VCARD\r\n";
+        fs::write(&path, content).unwrap();
+
+        let mut extractors = ExtractorRegistry::new();
+        extractors.register(Arc::new(VCardExtractor::new()));
+        let mut registry = crate::core::DetectorRegistry::new();
+        registry.register(Box::new(PhoneDetector::new()));
+        registry.register(Box::new(EmailDetector::new()));
+        let engine = ScanEngine::new(registry)
+            .show_progress(false)
+            .with_extractors(extractors);
+
+        let result = engine.scan_file(&path);
+        assert_eq!(result.matches.len(), 2);
+        for (id, key_path, line, value) in [
+            ("phone", "TEL", 4, "+31 6 12345678"),
+            ("email", "EMAIL", 5, "jan.jansen@example.com"),
+        ] {
+            let m = result.matches.iter().find(|m| m.detector_id == id).unwrap();
+            assert_eq!(m.key_path.as_deref(), Some(key_path));
+            assert_eq!(m.location.line, line);
+            assert_eq!(&content[m.location.start_byte..m.location.end_byte], value);
+            let line_start = content[..m.location.start_byte].rfind('\n').unwrap() + 1;
+            assert_eq!(m.location.column, m.location.start_byte - line_start);
+        }
+    }
+
+    #[test]
+    fn test_ical_attendee_and_adr() {
+        let extractor = VCardExtractor::new();
+        let text = extractor
+            .extract_text_from_vcard(
+                "BEGIN:VEVENT\nORGANIZER;CN=\"Piet de Vries\":mailto:piet@example.nl\nADR;TYPE=home:;;Kerkstraat 1;Amsterdam;;1011 AB;Netherlands\nSUMMARY:Intake\nEND:VEVENT\n",
+            )
+            .text;
+
+        assert_eq!(
+            text,
+            "ORGANIZER/@CN: Piet de Vries\nORGANIZER: piet@example.nl\nADR: Kerkstraat 1, Amsterdam, 1011 AB, Netherlands\n"
+        );
+    }
+
+    #[test]
+    fn test_vcard_extensions() {
+        let extractor = VCardExtractor::new();
+        assert_eq!(extractor.supported_extensions(), vec!["vcf", "ics"]);
+    }
+}
@@ -0,0 +1,346 @@
+/// XML and SVG text extraction using quick-xml
+///
+/// Emits one `element: value` line per line of text and per selected
+/// attribute (`tspan: ...`, `person/@label: ...`). Each line records its
+/// position in the source file and the XPath-like key path of the element
+/// (`/svg/g/text/tspan`, `/contacts/person/@label`), so findings point at the
+/// original text and can be traced back to the element they came from.
+use super::{Extraction, ExtractorError, LineIndex, TextExtractor};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::path::Path;
+
+/// Attributes whose values are extracted by default
+const DEFAULT_ATTRIBUTES: &[&str] = &["title", "desc", "label", "aria-label", "alt"];
+
+pub struct XmlExtractor {
+    attributes: Vec<String>,
+}
+
+/// Text read directly inside an element since its last child tag
+#[derive(Default)]
+struct TextRun {
+    text: String,
+    /// Offset in `text` and in the source where each text event starts
+    anchors: Vec<(usize, usize)>,
+}
+
+impl TextRun {
+    fn push(&mut self, piece: &str, source_offset: usize) {
+        self.anchors.push((self.text.len(), source_offset));
+        self.text.push_str(piece);
+    }
+
+    /// Source offset of an offset in `text`, exact up to the first unescaped entity
+    fn source_offset(&self, offset: usize) -> Option<usize> {
+        let (text_start, source_start) = self.anchors.iter().rev().find(|(t, _)| *t <= offset)?;
+        Some(source_start + (offset - text_start))
+    }
+}
+
+impl XmlExtractor {
+    pub fn new() -> Self {
+        Self {
+            attributes: DEFAULT_ATTRIBUTES.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    /// Attribute names whose values are extracted (default: title, desc, label, aria-label, alt)
+    pub fn attributes(mut self, attributes: Vec<String>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Extract text nodes and selected attributes from an XML document
+    fn extract_text_from_xml(&self, xml_content: &str) -> Result<Extraction, ExtractorError> {
+        let mut reader = Reader::from_str(xml_content);
+        reader.config_mut().trim_text(false);
+
+        let lines = LineIndex::new(xml_content);
+        let mut output = Extraction::default();
+        // Names of the open elements
+        let mut stack: Vec<String> = Vec::new();
+        let mut run = TextRun::default();
+
+        loop {
+            let event_start = reader.buffer_position() as usize;
+            match reader.read_event() {
+                Ok(Event::Start(e)) => {
+                    flush_text(&mut run, &stack, &lines, &mut output);
+                    stack.push(element_name(&e));
+                    self.push_attributes(
+                        &e,
+                        &stack,
+                        xml_content,
+                        event_start,
+                        &lines,
+                        &mut output,
+                    )?;
+                }
+                Ok(Event::Empty(e)) => {
+                    flush_text(&mut run, &stack, &lines, &mut output);
+                    stack.push(element_name(&e));
+                    self.push_attributes(
+                        &e,
+                        &stack,
+                        xml_content,
+                        event_start,
+                        &lines,
+                        &mut output,
+                    )?;
+                    stack.pop();
+                }
+                Ok(Event::Text(e)) => {
+                    run.push(&e.xml_content().map_err(decode_error)?, event_start);
+                }
+                Ok(Event::CData(e)) => {
+                    let content_start = event_start + "<![CDATA[".len();
+                    run.push(&e.xml_content().map_err(decode_error)?, content_start);
+                }
+                Ok(Event::GeneralRef(entity)) => {
+                    if let Ok(Some(c)) = entity.resolve_char_ref() {
+                        run.push(c.encode_utf8(&mut [0; 4]), event_start);
+                    } else {
+                        let name = entity.decode().map_err(decode_error)?;
+                        let resolved = match name.as_ref() {
+                            "amp" => "&",
+                            "lt" => "<",
+                            "gt" => ">",
+                            "quot" => "\"",
+                            "apos" => "'",
+                            _ => "", // Unknown entity, skip
+                        };
+                        run.push(resolved, event_start);
+                    }
+                }
+                Ok(Event::End(_)) => {
+                    flush_text(&mut run, &stack, &lines, &mut output);
+                    stack.pop();
+                }
+                Ok(Event::Eof) => break,
+                Ok(_) => {} // Ignore comments, declarations, processing instructions
+                Err(e) => {
+                    return Err(ExtractorError::ExtractionFailed(format!(
+                        "XML parse error: {}",
+                        e
+                    )))
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Emit the selected attributes of the innermost element in `stack`
+    ///
+    /// `tag_start` is the source offset of the element's tag, used for
+    /// attribute values that don't borrow from `xml_content`.
+    fn push_attributes(
+        &self,
+        element: &BytesStart,
+        stack: &[String],
+        xml_content: &str,
+        tag_start: usize,
+        lines: &LineIndex,
+        output: &mut Extraction,
+    ) -> Result<(), ExtractorError> {
+        let path = key_path(stack);
+        let element_name = stack.last().map(String::as_str).unwrap_or_default();
+
+        for attr in element.attributes().flatten() {
+            let name = String::from_utf8_lossy(attr.key.as_ref());
+            if !self
+                .attributes
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(&name))
+            {
+                continue;
+            }
+
+            let value = attr.unescape_value().map_err(|e| {
+                ExtractorError::ExtractionFailed(format!("XML attribute error: {}", e))
+            })?;
+            let value_start = borrowed_offset(xml_content, &attr.value).unwrap_or(tag_start);
+            // Keep the value on one extracted line, at the same byte offsets
+            let value = value.replace(['\n', '\r'], " ");
+
+            output.push_line(
+                &format!("{}/@{}", element_name, name),
+                &value,
+                lines.source(Some(format!("{}/@{}", path, name)), value_start),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Emit the text read inside the innermost element of `stack`, one line per source line
+fn flush_text(run: &mut TextRun, stack: &[String], lines: &LineIndex, output: &mut Extraction) {
+    let run = std::mem::take(run);
+    let Some(name) = stack.last() else {
+        return;
+    };
+    let path = key_path(stack);
+
+    let mut offset = 0;
+    for line in run.text.split('\n') {
+        if let Some(source_offset) = run.source_offset(offset) {
+            output.push_line(name, line, lines.source(Some(path.clone()), source_offset));
+        }
+        offset += line.len() + 1;
+    }
+}
+
+/// Qualified element name
+fn element_name(element: &BytesStart) -> String {
+    String::from_utf8_lossy(element.name().as_ref()).to_string()
+}
+
+/// XPath-like path of the open elements, e.g. `/svg/g/text`
+fn key_path(stack: &[String]) -> String {
+    stack.iter().map(|name| format!("/{}", name)).collect()
+}
+
+/// Byte offset of `part` in `content`, if it is a slice of it
+fn borrowed_offset(content: &str, part: &[u8]) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(content.as_ptr() as usize)?;
+    (offset + part.len() <= content.len()).then_some(offset)
+}
+
+fn decode_error(e: quick_xml::encoding::EncodingError) -> ExtractorError {
+    ExtractorError::ExtractionFailed(format!("XML decode error: {}", e))
+}
+
+impl TextExtractor for XmlExtractor {
+    fn extract(&self, path: &Path) -> Result<String, ExtractorError> {
+        self.extract_mapped(path).map(|extraction| extraction.text)
+    }
+
+    fn extract_mapped(&self, path: &Path) -> Result<Extraction, ExtractorError> {
+        let content = std::fs::read_to_string(path)?;
+        self.extract_text_from_xml(&content)
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec!["xml", "svg"]
+    }
+
+    fn name(&self) -> &str {
+        "XML Extractor"
+    }
+}
+
+impl Default for XmlExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Detector;
+    use crate::detectors::personal::{EmailDetector, PhoneDetector};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_xml_extractor_extensions() {
+        let extractor = XmlExtractor::new();
+        assert_eq!(extractor.supported_extensions(), vec!["xml", "svg"]);
+        assert_eq!(extractor.name(), "XML Extractor");
+    }
+
+    #[test]
+    fn test_svg_text_element() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("diagram.svg");
+        fs::write(
+            &path,
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><g><text x="10" y="20">Owner: <tspan>jan.jansen@example.com</tspan></text></g></svg>"#,
+        )
+        .unwrap();
+
+        let extraction = XmlExtractor::new().extract_mapped(&path).unwrap();
+        assert_eq!(
+            extraction.text,
+            "text: Owner:\ntspan: jan.jansen@example.com\n"
+        );
+
+        let mut matches = EmailDetector::new().detect(&extraction.text, &path);
+        extraction.source_map.map_matches(&mut matches);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].key_path.as_deref(), Some("/svg/g/text/tspan"));
+
+        // The match points at the address in the file, not at the extracted line
+        let content = fs::read_to_string(&path).unwrap();
+        let location = &matches[0].location;
+        assert_eq!(
+            &content[location.start_byte..location.end_byte],
+            "jan.jansen@example.com"
+        );
+        assert_eq!(location.line, 1);
+        assert_eq!(location.column, location.start_byte);
+    }
+
+    #[test]
+    fn test_xml_attribute_phone() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("contacts.xml");
+        fs::write(
+            &path,
+            r#"<?xml version="1.0"?>
+<contacts>
+  <person id="42" label="Call +31 6 12345678 &amp; ask for Piet"/>
+</contacts>"#,
+        )
+        .unwrap();
+
+        let extraction = XmlExtractor::new().extract_mapped(&path).unwrap();
+        assert_eq!(
+            extraction.text,
+            "person/@label: Call +31 6 12345678 & ask for Piet\n"
+        );
+
+        let mut matches = PhoneDetector::new().detect(&extraction.text, &path);
+        extraction.source_map.map_matches(&mut matches);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].key_path.as_deref(),
+            Some("/contacts/person/@label")
+        );
+        assert_eq!(matches[0].location.line, 3);
+
+        let content = fs::read_to_string(&path).unwrap();
+        let location = &matches[0].location;
+        assert_eq!(
+            &content[location.start_byte..location.end_byte],
+            "+31 6 12345678"
+        );
+    }
+
+    #[test]
+    fn test_multiline_text_keeps_source_lines() {
+        let xml = "<notes>\n  <note>Bel terug\n    mail: piet@example.nl</note>\n</notes>";
+        let extraction = XmlExtractor::new().extract_text_from_xml(xml).unwrap();
+
+        assert_eq!(
+            extraction.text,
+            "note: Bel terug\nnote: mail: piet@example.nl\n"
+        );
+        let source = extraction.source_map.lines[1].as_ref().unwrap();
+        assert_eq!(source.key_path.as_deref(), Some("/notes/note"));
+        assert_eq!(source.line, 3);
+        assert_eq!(source.column, 4);
+        assert_eq!(&xml[source.start_byte..source.start_byte + 4], "mail");
+    }
+
+    #[test]
+    fn test_xml_malformed() {
+        let extractor = XmlExtractor::new();
+        assert!(extractor
+            .extract_text_from_xml("<a><b>text</a></b>")
+            .is_err());
+    }
+}
@@ -18,54 +18,138 @@ pub mod database;
 // Re-export commonly used types
 pub use config::Config;
 pub use core::{
-    default_plugins_dir, load_plugins, Confidence, ContextAnalyzer, Detector, DetectorRegistry,
-    FileResult, GdprCategory, Match, PluginDetector, ScanResults, Severity, SpecialCategory,
+    default_plugins_dir, load_plugins, Confidence, ContextAnalyzer, Detector, DetectorConflict,
+    DetectorRegistry, DuplicatePolicy, FileResult, GdprCategory, Locale, Match, PluginDetector,
+    RiskLevel, RiskWeights, SampleInfo, ScanContext, ScanError, ScanErrorCategory, ScanResults,
+    Severity, SeverityOverrides, SpecialCategory, Strictness,
 };
 
-pub use crawler::{FileFilter, Walker};
+pub use crawler::{read_path_list, FileFilter, PathList, SkippedFile, Walker};
 pub use extractors::{
-    DocxExtractor, ExtractorError, ExtractorRegistry, PdfExtractor, TextExtractor, XlsxExtractor,
+    DocxExtractor, Extraction, ExtractorError, ExtractorRegistry, HarExtractor, NotebookExtractor,
+    PdfExtractor, SourceLine, SourceMap, SqlDumpExtractor, TextExtractor, VCardExtractor,
+    XlsxExtractor, XmlExtractor,
+};
+pub use reporter::{
+    CsvReporter, GroupBy, HtmlReporter, JsonReporter, JsonShape, ReportSummary, SarifReporter,
+    TerminalReporter,
+};
+pub use scanner::{
+    run_bench, scan_api_endpoint, scan_api_endpoints, AccessLogCorrelation, ApiScanConfig,
+    BenchReport, DetectorPathScope, DetectorStats, DetectorTiming, FileStatus, HttpMethod,
+    ManifestEntry, ParallelismMode, ScanCache, ScanEngine, ScanManifest, SeverityCeilings, Watcher,
 };
-pub use reporter::{CsvReporter, HtmlReporter, JsonReporter, TerminalReporter};
-pub use scanner::{scan_api_endpoint, scan_api_endpoints, ApiScanConfig, HttpMethod, ScanEngine};
 
 pub use utils::{
-    is_high_entropy, mask_credit_card, mask_email, mask_iban, mask_phone, mask_value,
-    shannon_entropy, validate_belgian_rrn, validate_bsn_11_proef, validate_iban, validate_luhn,
-    validate_nhs_number, validate_spain_id,
+    is_high_entropy, mask_credit_card, mask_email, mask_iban, mask_phone, mask_preserving,
+    mask_value, shannon_entropy, validate, validate_belgian_rrn, validate_bsn_11_proef,
+    validate_iban, validate_luhn, validate_nhs_number, validate_spain_id, validate_spain_naf,
+    validate_uk_utr, validate_vat, VALIDATION_SCHEMES,
 };
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Compile the patterns of all built-in detectors and the scan engine up front
+///
+/// Detector regexes are compiled lazily, so without this the first scanned
+/// file pays a one-time cost that skews its timing. Embedders with latency
+/// targets should call this once at startup. [`ScanEngine::scan_directory`]
+/// warms up its own registry before starting its timer.
+///
+/// ```
+/// pii_radar::warmup();
+/// ```
+pub fn warmup() {
+    ScanEngine::new(default_registry()).warmup();
+}
+
 /// Create a default detector registry with all available detectors
 pub fn default_registry() -> DetectorRegistry {
+    default_registry_with(&SeverityOverrides::new())
+}
+
+/// Create a default detector registry with per-detector severity overrides
+///
+/// Overrides also apply to matches found inside decoded base64 blobs.
+pub fn default_registry_with(overrides: &SeverityOverrides) -> DetectorRegistry {
+    let mut registry = base_registry();
+    registry.apply_severity_overrides(overrides);
+    with_base64_detector(registry)
+}
+
+/// Add the base64 detector, re-running the registered detectors on decoded blobs
+///
+/// Call this after the detector set is final (plugins loaded, detectors
+/// disabled or reconfigured), so decoded text is scanned with the same
+/// detectors and settings as plain text.
+pub fn with_base64_detector(mut registry: DetectorRegistry) -> DetectorRegistry {
+    let decoded = registry.share();
+    registry.register(Box::new(detectors::security::Base64Detector::new(decoded)));
+    registry
+}
+
+/// Check German legacy bank accounts with the check method of their bank
+///
+/// `methods` maps each BLZ to its method, e.g. from
+/// [`load_blz_file`](detectors::de::legacy_bank_account::load_blz_file).
+/// Registries without the legacy account detector are returned unchanged.
+pub fn with_check_methods(
+    mut registry: DetectorRegistry,
+    methods: std::collections::HashMap<String, detectors::de::legacy_bank_account::CheckMethod>,
+    overrides: &SeverityOverrides,
+) -> DetectorRegistry {
+    if registry.unregister("de_legacy_bank_account").is_some() {
+        registry.register(overrides.wrap(Box::new(
+            detectors::de::LegacyBankAccountDetector::new().check_methods(methods),
+        )));
+    }
+    registry
+}
+
+/// All detectors except the base64 pre-processing detector
+fn base_registry() -> DetectorRegistry {
     let mut registry = DetectorRegistry::new();
 
     // Country-specific detectors
     // Belgium
     registry.register(Box::new(detectors::be::RrnDetector::new()));
+    registry.register(Box::new(detectors::be::PhoneDetector::new()));
 
     // Denmark
     registry.register(Box::new(detectors::dk::CprDetector::new()));
+    registry.register(Box::new(detectors::dk::PhoneDetector::new()));
 
     // Finland
     registry.register(Box::new(detectors::fi::HetuDetector::new()));
 
     // France
     registry.register(Box::new(detectors::fr::NirDetector::new()));
+    registry.register(Box::new(detectors::fr::DrivingLicenceDetector::new()));
 
     // Germany
     registry.register(Box::new(detectors::de::SteuerIdDetector::new()));
+    registry.register(Box::new(detectors::de::SteuernummerDetector::new()));
+    registry.register(Box::new(detectors::de::DrivingLicenceDetector::new()));
+    registry.register(Box::new(detectors::de::LegacyBankAccountDetector::new()));
+    registry.register(Box::new(detectors::de::PhoneDetector::new()));
 
     // Italy
     registry.register(Box::new(detectors::it::CodiceFiscaleDetector::new()));
 
     // Netherlands
     registry.register(Box::new(detectors::nl::BsnDetector::new()));
+    registry.register(Box::new(detectors::nl::HealthInsuranceDetector::new()));
+    registry.register(Box::new(detectors::nl::KvkDetector::new()));
+    registry.register(Box::new(detectors::nl::RsinDetector::new()));
+    registry.register(Box::new(detectors::nl::DrivingLicenceDetector::new()));
+    registry.register(Box::new(detectors::nl::PhoneDetector::new()));
+    registry.register(Box::new(detectors::nl::BsnVatDetector::new()));
+    registry.register(Box::new(detectors::nl::AddressDetector::new()));
 
     // Norway
     registry.register(Box::new(detectors::no::FodselsnummerDetector::new()));
+    registry.register(Box::new(detectors::no::PhoneDetector::new()));
 
     // Poland
     registry.register(Box::new(detectors::pl::PeselDetector::new()));
@@ -76,24 +160,51 @@ pub fn default_registry() -> DetectorRegistry {
     // Spain
     registry.register(Box::new(detectors::es::DniDetector::new()));
     registry.register(Box::new(detectors::es::NieDetector::new()));
+    registry.register(Box::new(detectors::es::SocialSecurityDetector::new()));
 
     // Sweden
     registry.register(Box::new(detectors::se::PersonnummerDetector::new()));
+    registry.register(Box::new(detectors::se::PhoneDetector::new()));
 
     // United Kingdom
     registry.register(Box::new(detectors::gb::NhsDetector::new()));
+    registry.register(Box::new(detectors::gb::BankAccountDetector::new()));
+    registry.register(Box::new(detectors::gb::UtrDetector::new()));
 
     // Pan-European detectors
     registry.register(Box::new(detectors::eu::IbanDetector::new()));
+    registry.register(Box::new(detectors::eu::EoriDetector::new()));
 
     // Universal financial detectors
     registry.register(Box::new(detectors::financial::CreditCardDetector::new()));
+    registry.register(Box::new(detectors::financial::TrackDataDetector::new()));
+    registry.register(Box::new(detectors::financial::CvvDetector::new()));
+    registry.register(Box::new(detectors::financial::MaskedValueDetector::new()));
 
     // Universal personal detectors
     registry.register(Box::new(detectors::personal::EmailDetector::new()));
+    registry.register(Box::new(detectors::personal::PassportKeywordDetector::new()));
+    registry.register(Box::new(detectors::personal::MrzDetector::new()));
+    registry.register(Box::new(detectors::personal::MedicalRecordDetector::new()));
+    registry.register(Box::new(detectors::personal::DateOfBirthDetector::new()));
+    registry.register(Box::new(detectors::personal::PhoneDetector::new()));
+    registry.register(Box::new(detectors::personal::ImeiDetector::new()));
+    registry.register(Box::new(detectors::personal::ImsiDetector::new()));
+    registry.register(Box::new(detectors::personal::TrackingIdDetector::new()));
+    registry.register(Box::new(detectors::personal::SystemIdentityDetector::new()));
+    registry.register(Box::new(detectors::personal::GeolocationDetector::new()));
+    registry.register(Box::new(detectors::personal::IpAddressDetector::new()));
 
     // Universal security detectors
     registry.register(Box::new(detectors::security::ApiKeyDetector::new()));
+    registry.register(Box::new(detectors::security::EntropySecretDetector::new()));
+    registry.register(Box::new(detectors::security::CloudSecretDetector::new()));
+    registry.register(Box::new(detectors::security::ChatTokenDetector::new()));
+    registry.register(Box::new(detectors::security::OAuthCredentialDetector::new()));
+    registry.register(Box::new(detectors::security::HttpCredentialDetector::new()));
+    registry.register(Box::new(
+        detectors::security::CredentialAssignmentDetector::new(),
+    ));
 
     registry
 }
@@ -113,6 +224,21 @@ pub fn default_registry() -> DetectorRegistry {
 /// // registry now contains only GB, NL, and universal detectors
 /// ```
 pub fn registry_for_countries(countries: Vec<String>) -> DetectorRegistry {
+    registry_for_countries_with(countries, &SeverityOverrides::new())
+}
+
+/// Country-filtered registry with per-detector severity overrides
+pub fn registry_for_countries_with(
+    countries: Vec<String>,
+    overrides: &SeverityOverrides,
+) -> DetectorRegistry {
+    let mut registry = base_registry_for_countries(&countries);
+    registry.apply_severity_overrides(overrides);
+    with_base64_detector(registry)
+}
+
+/// Country-filtered detectors, without the base64 pre-processing detector
+fn base_registry_for_countries(countries: &[String]) -> DetectorRegistry {
     let mut registry = DetectorRegistry::new();
 
     let country_codes: Vec<&str> = countries.iter().map(|s| s.as_str()).collect();
@@ -123,11 +249,13 @@ pub fn registry_for_countries(countries: Vec<String>) -> DetectorRegistry {
     // Belgium
     if should_include("be") {
         registry.register(Box::new(detectors::be::RrnDetector::new()));
+        registry.register(Box::new(detectors::be::PhoneDetector::new()));
     }
 
     // Denmark
     if should_include("dk") {
         registry.register(Box::new(detectors::dk::CprDetector::new()));
+        registry.register(Box::new(detectors::dk::PhoneDetector::new()));
     }
 
     // Finland
@@ -138,11 +266,16 @@ pub fn registry_for_countries(countries: Vec<String>) -> DetectorRegistry {
     // France
     if should_include("fr") {
         registry.register(Box::new(detectors::fr::NirDetector::new()));
+        registry.register(Box::new(detectors::fr::DrivingLicenceDetector::new()));
     }
 
     // Germany
     if should_include("de") {
         registry.register(Box::new(detectors::de::SteuerIdDetector::new()));
+        registry.register(Box::new(detectors::de::SteuernummerDetector::new()));
+        registry.register(Box::new(detectors::de::DrivingLicenceDetector::new()));
+        registry.register(Box::new(detectors::de::LegacyBankAccountDetector::new()));
+        registry.register(Box::new(detectors::de::PhoneDetector::new()));
     }
 
     // Italy
@@ -153,11 +286,19 @@ pub fn registry_for_countries(countries: Vec<String>) -> DetectorRegistry {
     // Netherlands
     if should_include("nl") {
         registry.register(Box::new(detectors::nl::BsnDetector::new()));
+        registry.register(Box::new(detectors::nl::HealthInsuranceDetector::new()));
+        registry.register(Box::new(detectors::nl::KvkDetector::new()));
+        registry.register(Box::new(detectors::nl::RsinDetector::new()));
+        registry.register(Box::new(detectors::nl::DrivingLicenceDetector::new()));
+        registry.register(Box::new(detectors::nl::PhoneDetector::new()));
+        registry.register(Box::new(detectors::nl::BsnVatDetector::new()));
+        registry.register(Box::new(detectors::nl::AddressDetector::new()));
     }
 
     // Norway
     if should_include("no") {
         registry.register(Box::new(detectors::no::FodselsnummerDetector::new()));
+        registry.register(Box::new(detectors::no::PhoneDetector::new()));
     }
 
     // Poland
@@ -174,30 +315,121 @@ pub fn registry_for_countries(countries: Vec<String>) -> DetectorRegistry {
     if should_include("es") {
         registry.register(Box::new(detectors::es::DniDetector::new()));
         registry.register(Box::new(detectors::es::NieDetector::new()));
+        registry.register(Box::new(detectors::es::SocialSecurityDetector::new()));
     }
 
     // Sweden
     if should_include("se") {
         registry.register(Box::new(detectors::se::PersonnummerDetector::new()));
+        registry.register(Box::new(detectors::se::PhoneDetector::new()));
     }
 
     // United Kingdom
     if should_include("gb") {
         registry.register(Box::new(detectors::gb::NhsDetector::new()));
-    }
-
-    // Portugal
-    if should_include("pt") {
-        registry.register(Box::new(detectors::pt::NifDetector::new()));
+        registry.register(Box::new(detectors::gb::BankAccountDetector::new()));
+        registry.register(Box::new(detectors::gb::UtrDetector::new()));
     }
 
     // Always include Pan-European detectors
     registry.register(Box::new(detectors::eu::IbanDetector::new()));
+    registry.register(Box::new(detectors::eu::EoriDetector::new()));
 
     // Always include Universal detectors
     registry.register(Box::new(detectors::financial::CreditCardDetector::new()));
+    registry.register(Box::new(detectors::financial::TrackDataDetector::new()));
+    registry.register(Box::new(detectors::financial::CvvDetector::new()));
+    registry.register(Box::new(detectors::financial::MaskedValueDetector::new()));
     registry.register(Box::new(detectors::personal::EmailDetector::new()));
+    registry.register(Box::new(detectors::personal::PassportKeywordDetector::new()));
+    registry.register(Box::new(detectors::personal::MrzDetector::new()));
+    registry.register(Box::new(detectors::personal::MedicalRecordDetector::new()));
+    registry.register(Box::new(detectors::personal::DateOfBirthDetector::new()));
+    registry.register(Box::new(detectors::personal::PhoneDetector::new()));
+    registry.register(Box::new(detectors::personal::ImeiDetector::new()));
+    registry.register(Box::new(detectors::personal::ImsiDetector::new()));
+    registry.register(Box::new(detectors::personal::TrackingIdDetector::new()));
+    registry.register(Box::new(detectors::personal::SystemIdentityDetector::new()));
+    registry.register(Box::new(detectors::personal::GeolocationDetector::new()));
+    registry.register(Box::new(detectors::personal::IpAddressDetector::new()));
     registry.register(Box::new(detectors::security::ApiKeyDetector::new()));
+    registry.register(Box::new(detectors::security::EntropySecretDetector::new()));
+    registry.register(Box::new(detectors::security::CloudSecretDetector::new()));
+    registry.register(Box::new(detectors::security::ChatTokenDetector::new()));
+    registry.register(Box::new(detectors::security::OAuthCredentialDetector::new()));
+    registry.register(Box::new(detectors::security::HttpCredentialDetector::new()));
+    registry.register(Box::new(
+        detectors::security::CredentialAssignmentDetector::new(),
+    ));
+
+    registry
+}
+
+/// Built-in detector selections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// Every detector (the default)
+    Pii,
+    /// Credentials and payment card secrets only, skipping national IDs
+    Secrets,
+}
+
+impl Preset {
+    /// All presets, for listing
+    pub const ALL: [Preset; 2] = [Preset::Pii, Preset::Secrets];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Preset::Pii => "pii",
+            Preset::Secrets => "secrets",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Preset::Pii => "All detectors: national IDs, financial, personal and security",
+            Preset::Secrets => {
+                "API keys, cloud secrets, chat tokens, OAuth and HTTP credentials and payment card data (fast secrets scan)"
+            }
+        }
+    }
+}
+
+/// Create a registry with the detectors of a preset
+pub fn registry_preset(preset: Preset) -> DetectorRegistry {
+    registry_preset_with(preset, &SeverityOverrides::new())
+}
+
+/// Preset registry with per-detector severity overrides
+pub fn registry_preset_with(preset: Preset, overrides: &SeverityOverrides) -> DetectorRegistry {
+    match preset {
+        Preset::Pii => default_registry_with(overrides),
+        Preset::Secrets => {
+            let mut registry = base_registry_secrets();
+            registry.apply_severity_overrides(overrides);
+
+            // Secrets are often stored base64-encoded
+            with_base64_detector(registry)
+        }
+    }
+}
+
+/// Security and financial-secret detectors, without the base64 pre-processing detector
+fn base_registry_secrets() -> DetectorRegistry {
+    let mut registry = DetectorRegistry::new();
+
+    registry.register(Box::new(detectors::security::ApiKeyDetector::new()));
+    registry.register(Box::new(detectors::security::EntropySecretDetector::new()));
+    registry.register(Box::new(detectors::security::CloudSecretDetector::new()));
+    registry.register(Box::new(detectors::security::ChatTokenDetector::new()));
+    registry.register(Box::new(detectors::security::OAuthCredentialDetector::new()));
+    registry.register(Box::new(detectors::security::HttpCredentialDetector::new()));
+    registry.register(Box::new(
+        detectors::security::CredentialAssignmentDetector::new(),
+    ));
+    registry.register(Box::new(detectors::financial::CreditCardDetector::new()));
+    registry.register(Box::new(detectors::financial::TrackDataDetector::new()));
+    registry.register(Box::new(detectors::financial::CvvDetector::new()));
 
     registry
 }
@@ -206,11 +438,49 @@ pub fn registry_for_countries(countries: Vec<String>) -> DetectorRegistry {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_registry_preset_secrets() {
+        let registry = registry_preset(Preset::Secrets);
+        assert!(registry.get("api_key").is_some());
+        assert!(registry.get("creditcard").is_some());
+        assert!(registry.get("base64").is_some());
+        assert!(registry.get("nl_bsn").is_none());
+        assert!(registry.get("email").is_none());
+
+        let pii = registry_preset(Preset::Pii);
+        assert_eq!(pii.all().len(), default_registry().all().len());
+        assert!(pii.get("nl_bsn").is_some());
+    }
+
     #[test]
     fn test_version() {
         assert!(!VERSION.is_empty());
     }
 
+    #[test]
+    fn test_check_methods_reject_failing_accounts() {
+        use crate::detectors::de::legacy_bank_account::parse_blz_table;
+
+        let methods = parse_blz_table(&format!("{:<150}00", "37040044"));
+        let registry = with_check_methods(default_registry(), methods, &SeverityOverrides::new());
+        let detector = registry.get("de_legacy_bank_account").unwrap();
+        let path = std::path::Path::new("kunden_1998.txt");
+
+        let matches = detector.detect("Kto. 532013000, BLZ 370 400 44", path);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].confidence, Confidence::High);
+        assert!(detector
+            .detect("Kto. 532013001, BLZ 370 400 44", path)
+            .is_empty());
+
+        // Without a BLZ file the same account is reported unchecked
+        let matches = default_registry()
+            .get("de_legacy_bank_account")
+            .unwrap()
+            .detect("Kto. 532013001, BLZ 370 400 44", path);
+        assert_eq!(matches[0].confidence, Confidence::Medium);
+    }
+
     #[test]
     fn test_default_registry() {
         let registry = default_registry();
@@ -218,5 +488,143 @@ mod tests {
 
         // Should have at least BSN detector
         assert!(registry.get("nl_bsn").is_some());
+        assert!(registry.get("base64").is_some());
+    }
+
+    #[test]
+    fn test_duplicate_detector_ids() {
+        use crate::detectors::personal::EmailDetector;
+
+        let plugin = || -> Box<dyn Detector> {
+            let config: crate::core::PluginConfig = toml::from_str(
+                r#"
+[detector]
+id = "email"
+name = "Custom Email"
+country = "universal"
+pattern = '\S+@\S+'
+"#,
+            )
+            .unwrap();
+            Box::new(PluginDetector::new(config).unwrap())
+        };
+
+        // Default: keep the built-in, record the conflict
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(EmailDetector::new()));
+        registry.register(plugin());
+        assert_eq!(registry.all().len(), 1);
+        assert_eq!(registry.get("email").unwrap().name(), "Email Address");
+        assert_eq!(
+            registry.conflicts(),
+            &[DetectorConflict {
+                id: "email".to_string(),
+                kept: "Email Address".to_string(),
+                ignored: "Custom Email".to_string(),
+            }]
+        );
+
+        // Reject: the conflict is an error
+        registry.set_duplicate_policy(DuplicatePolicy::Reject);
+        let err = registry.try_register(plugin()).unwrap_err();
+        assert_eq!(err.id, "email");
+        assert_eq!(registry.all().len(), 1);
+
+        // The built-in registries have no duplicates
+        assert!(default_registry().conflicts().is_empty());
+        let mut all: Vec<String> = default_registry()
+            .all()
+            .iter()
+            .map(|d| d.country().to_string())
+            .collect();
+        all.sort();
+        all.dedup();
+        assert!(registry_for_countries(all).conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_base64_uses_final_detector_set() {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+        use std::path::Path;
+
+        let text = format!("blob: {}", STANDARD.encode("bsn=111222333"));
+        let mut registry = default_registry();
+        let base64 = registry.get("base64").unwrap();
+        assert_eq!(base64.detect(&text, Path::new("test.txt")).len(), 1);
+
+        // Disabling a detector also disables it inside decoded blobs
+        registry.unregister("nl_bsn");
+        registry.unregister("base64");
+        let registry = with_base64_detector(registry);
+        let base64 = registry.get("base64").unwrap();
+        assert!(base64.detect(&text, Path::new("test.txt")).is_empty());
+    }
+
+    #[test]
+    fn test_registry_for_countries_nordic_phones() {
+        let registry = registry_for_countries(vec!["se".to_string()]);
+        assert!(registry.get("se_phone").is_some());
+        assert!(registry.get("no_phone").is_none());
+        assert!(registry.get("dk_phone").is_none());
+    }
+
+    #[test]
+    fn test_country_detector_examples() {
+        let registry = default_registry();
+
+        for detector in registry.all() {
+            if detector.country() != "universal" {
+                assert!(
+                    detector.example_valid().is_some() && detector.example_invalid().is_some(),
+                    "{} has no examples",
+                    detector.id()
+                );
+            }
+
+            if let Some(example) = detector.example_valid() {
+                assert!(detector.validate(example), "{}: {}", detector.id(), example);
+            }
+            if let Some(example) = detector.example_invalid() {
+                assert!(
+                    !detector.validate(example),
+                    "{}: {}",
+                    detector.id(),
+                    example
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_registry_with_severity_overrides() {
+        use std::path::Path;
+
+        let overrides = SeverityOverrides::new()
+            .set("email", Severity::High)
+            .set("nl_bsn", Severity::Low);
+        let registry = default_registry_with(&overrides);
+        let text = "Mail jan.jansen@example.com, BSN 111222333";
+
+        let email = registry.get("email").unwrap();
+        assert_eq!(email.base_severity(), Severity::High);
+        let matches = email.detect(text, Path::new("test.txt"));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].severity, Severity::High);
+
+        // An override is a floor, so it cannot downgrade a detector
+        let bsn = registry.get("nl_bsn").unwrap();
+        assert_eq!(bsn.base_severity(), Severity::Critical);
+        let matches = bsn.detect(text, Path::new("test.txt"));
+        assert_eq!(matches[0].severity, Severity::Critical);
+
+        // Other detectors keep their defaults
+        assert_eq!(
+            registry.get("phone").unwrap().base_severity(),
+            default_registry().get("phone").unwrap().base_severity()
+        );
+        assert_eq!(
+            default_registry().get("email").unwrap().base_severity(),
+            Severity::Medium
+        );
     }
 }
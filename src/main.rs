@@ -1,22 +1,36 @@
 /// PII-Radar CLI entry point
-use clap::Parser;
-use pii_radar::cli::{Cli, Commands, OutputFormat};
+use clap::{Parser, ValueEnum};
+use pii_radar::cli::{
+    auto_fallback_warning, configure_colors, install_interrupt_handler, resolve_formats,
+    write_reports, Cli, Commands, ConfidenceLevel, LogSummary, OutputFormat, ReportOptions, RunDir,
+    ScanExitCode,
+};
+use pii_radar::config::ConfigSources;
+use pii_radar::detectors::de::legacy_bank_account::load_blz_file;
+use pii_radar::detectors::personal::{NameDetector, PhoneDetector};
 use pii_radar::{
-    default_registry, registry_for_countries, scan_api_endpoints, ApiScanConfig, CsvReporter,
-    DocxExtractor, ExtractorRegistry, HtmlReporter, HttpMethod, JsonReporter, PdfExtractor,
-    ScanEngine, TerminalReporter, Walker, XlsxExtractor,
+    default_registry, default_registry_with, read_path_list, registry_for_countries_with,
+    registry_preset, registry_preset_with, run_bench, scan_api_endpoints, with_base64_detector,
+    with_check_methods, AccessLogCorrelation, ApiScanConfig, Config, CsvReporter,
+    DetectorPathScope, DocxExtractor, ExtractorRegistry, HarExtractor, HtmlReporter, HttpMethod,
+    JsonReporter, Locale, NotebookExtractor, PdfExtractor, Preset, SarifReporter, ScanCache,
+    ScanContext, ScanEngine, ScanManifest, ScanResults, SeverityCeilings, SqlDumpExtractor,
+    TerminalReporter, VCardExtractor, Walker, Watcher, XlsxExtractor, XmlExtractor,
 };
 use std::collections::HashMap;
 use std::process;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(feature = "database")]
-use pii_radar::database::{DatabaseConfig, DatabaseScanner, DatabaseType, ScanOptions};
+use pii_radar::database::{DatabaseConfig, DatabaseScanner, DatabaseType, ScanOptions, ScanPlan};
+#[cfg(feature = "database")]
+use pii_radar::registry_for_countries;
 
 #[cfg(feature = "database")]
 #[tokio::main]
 async fn main() {
-    let cli = Cli::parse();
+    let cli = parse_cli();
     match &cli.command {
         Commands::ScanDb { .. } => {
             if let Commands::ScanDb {
@@ -29,11 +43,13 @@ async fn main() {
                 exclude_columns,
                 sample_percent,
                 row_limit,
+                text_columns_only,
                 pool_size,
                 format,
                 output,
                 countries,
                 no_progress,
+                dry_run,
             } = cli.command
             {
                 handle_scan_db(DbScanParams {
@@ -46,11 +62,13 @@ async fn main() {
                     exclude_columns,
                     sample_percent,
                     row_limit,
+                    text_columns_only,
                     pool_size,
                     format,
                     output,
                     countries,
                     no_progress,
+                    dry_run,
                 })
                 .await;
             }
@@ -63,56 +81,202 @@ async fn main() {
 
 #[cfg(not(feature = "database"))]
 fn main() {
-    let cli = Cli::parse();
+    let cli = parse_cli();
     handle_file_commands(cli.command);
 }
 
 fn handle_file_commands(command: Commands) {
+    let cli_overrides = command.cli_overrides().unwrap_or_default();
+
     match command {
         Commands::Scan {
             directory,
-            format,
-            output,
-            countries,
-            min_confidence,
-            no_context,
-            extract_documents,
-            no_progress,
-            full_paths,
-            max_depth,
-            threads,
-            max_filesize,
+            strictness,
+            max_files,
+            max_bytes,
             plugins,
+            duplicate_ids,
+            paths_from,
+            detect_names,
+            strict_exit,
+            fail_on,
+            csv_group_by,
+            csv_with_rows,
+            json_shape,
+            compress,
+            normalize,
+            preset,
+            parallelism,
+            manifest,
+            run_dir,
+            cache,
+            verbose,
+            confidence_histogram,
+            html_template,
+            locale,
+            log_summary,
+            dedup_files,
+            hash_files,
+            sample_percent,
+            seed,
+            expect_countries,
+            strict_coverage,
+            print_config,
+            explain,
+            ..
         } => {
-            // Validate directory
-            if !directory.exists() {
-                eprintln!(
-                    "❌ Error: Directory does not exist: {}",
-                    directory.display()
-                );
-                process::exit(1);
+            // Read explicit path list, or validate directory
+            let path_list = paths_from.map(|source| {
+                let list = if source.as_os_str() == "-" {
+                    read_path_list(std::io::stdin().lock())
+                } else {
+                    std::fs::File::open(&source)
+                        .and_then(|f| read_path_list(std::io::BufReader::new(f)))
+                };
+
+                match list {
+                    Ok(list) => {
+                        for missing in &list.missing {
+                            eprintln!("⚠️  Warning: Skipping missing file: {}", missing.display());
+                        }
+                        list.files
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "❌ Error: Failed to read path list {}: {}",
+                            source.display(),
+                            e
+                        );
+                        process::exit(ScanExitCode::Fatal.code());
+                    }
+                }
+            });
+
+            let directory = directory.unwrap_or_default();
+
+            // Effective settings: CLI flags > PII_RADAR_* env vars > config file
+            let config_path = Config::default_path();
+            let file_config = match config_path.as_ref().map(Config::load_from_file).transpose() {
+                Ok(config) => config.unwrap_or_default(),
+                Err(e) => {
+                    eprintln!("⚠️  Warning: Ignoring config file: {:#}", e);
+                    Config::default()
+                }
+            };
+            let mut sources = match &config_path {
+                Some(path) => ConfigSources::from_file(&file_config, path),
+                None => ConfigSources::default(),
+            };
+
+            let config = file_config.merge_with_cli_tracked(cli_overrides, &mut sources);
+
+            if print_config {
+                match config.to_toml(explain.then_some(&sources)) {
+                    Ok(toml) => print!("{}", toml),
+                    Err(e) => {
+                        eprintln!("❌ Error: {:#}", e);
+                        process::exit(ScanExitCode::Fatal.code());
+                    }
+                }
+                return;
             }
 
-            if !directory.is_dir() {
-                eprintln!("❌ Error: Path is not a directory: {}", directory.display());
-                process::exit(1);
+            let formats: Vec<OutputFormat> = match config
+                .output
+                .format
+                .split(',')
+                .map(|f| OutputFormat::from_str(f.trim(), true))
+                .collect()
+            {
+                Ok(formats) => formats,
+                Err(_) => {
+                    eprintln!("❌ Error: Unknown output format: {}", config.output.format);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            };
+
+            // --run-dir: all artifacts of this run go into a new timestamped folder
+            let run_dir = match run_dir.as_deref().map(RunDir::create).transpose() {
+                Ok(run_dir) => run_dir,
+                Err(e) => {
+                    eprintln!("❌ Error: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            };
+            let (output, manifest) = match &run_dir {
+                Some(run) => {
+                    println!("📂 Run directory: {}", run.path().display());
+                    // `auto` writes JSON into the run directory
+                    (
+                        Some(run.report_path(OutputFormat::Json)),
+                        Some(run.manifest_path()),
+                    )
+                }
+                None => (config.output.output_path.clone(), manifest),
+            };
+
+            // Infer `auto` from the --output extension before spending time on the scan
+            if let Some(warning) = auto_fallback_warning(&formats, output.as_deref()) {
+                eprintln!("⚠️  Warning: {}", warning);
             }
+            let formats = resolve_formats(&formats, output.as_deref());
+            let output = match &run_dir {
+                Some(run) => Some(run.report_path(formats[0])),
+                None => output,
+            };
 
-            // Build registry (with optional country filtering)
-            let mut registry = if let Some(country_list) = countries {
-                let codes: Vec<String> = country_list
-                    .split(',')
-                    .map(|s| s.trim().to_lowercase())
-                    .collect();
+            // Check a custom HTML template parses before spending time on the scan
+            if let Some(template) = &html_template {
+                if let Err(e) = HtmlReporter::with_template_file(template) {
+                    eprintln!("❌ Error: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            }
+
+            let min_confidence = match ConfidenceLevel::from_str(&config.scan.min_confidence, true)
+            {
+                Ok(level) => level,
+                Err(_) => {
+                    eprintln!(
+                        "❌ Error: Unknown confidence level: {}",
+                        config.scan.min_confidence
+                    );
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            };
+
+            if path_list.is_none() {
+                if !directory.exists() {
+                    eprintln!(
+                        "❌ Error: Directory does not exist: {}",
+                        directory.display()
+                    );
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+
+                if !directory.is_dir() {
+                    eprintln!("❌ Error: Path is not a directory: {}", directory.display());
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            }
+
+            // Build registry (preset, or all detectors with optional country filtering)
+            let preset = Preset::from(preset);
+            let mut registry = if preset != Preset::Pii {
+                println!("🔐 Preset: {} ({})", preset.name(), preset.description());
+                registry_preset_with(preset, &config.severity_overrides)
+            } else if !config.scan.countries.is_empty() {
+                let codes = config.scan.countries.clone();
 
                 println!("🌍 Filtering detectors for countries: {:?}", codes);
-                registry_for_countries(codes)
+                registry_for_countries_with(codes, &config.severity_overrides)
             } else {
-                default_registry()
+                default_registry_with(&config.severity_overrides)
             };
 
-            // Load plugin detectors
+            // Load plugin detectors; a plugin can't replace a detector with the same id
             let plugins_dir = plugins.unwrap_or_else(pii_radar::default_plugins_dir);
+            registry.set_duplicate_policy(duplicate_ids.into());
 
             if plugins_dir.exists() {
                 match pii_radar::load_plugins(&plugins_dir) {
@@ -120,8 +284,13 @@ fn handle_file_commands(command: Commands) {
                         if !plugin_detectors.is_empty() {
                             println!("🔌 Loaded {} plugin detector(s)\n", plugin_detectors.len());
                             for detector in plugin_detectors {
-                                registry.register(detector);
+                                let detector = config.severity_overrides.wrap(detector);
+                                if let Err(conflict) = registry.try_register(detector) {
+                                    eprintln!("❌ Error: {}", conflict);
+                                    process::exit(ScanExitCode::Fatal.code());
+                                }
                             }
+                            warn_detector_conflicts(&registry);
                         }
                     }
                     Err(e) => {
@@ -130,104 +299,474 @@ fn handle_file_commands(command: Commands) {
                 }
             }
 
+            // Restrict phone detection to specific regions
+            if !config.scan.phone_regions.is_empty() {
+                let codes = config.scan.phone_regions.clone();
+
+                if registry.unregister("phone").is_some() {
+                    let default_region = codes.first().cloned().unwrap_or_default();
+                    registry.register(
+                        config.severity_overrides.wrap(Box::new(
+                            PhoneDetector::new()
+                                .default_region(&default_region)
+                                .regions(codes),
+                        )),
+                    );
+                }
+            }
+
+            // Check German legacy accounts with the check method of their bank
+            if let Some(path) = &config.legacy_bank_account.blz_file {
+                match load_blz_file(path) {
+                    Ok(methods) => {
+                        registry = with_check_methods(registry, methods, &config.severity_overrides)
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "❌ Error: Failed to read BLZ file {}: {}",
+                            path.display(),
+                            e
+                        );
+                        process::exit(ScanExitCode::Fatal.code());
+                    }
+                }
+            }
+
+            // Name detection is opt-in: it is meant for data mapping and is noisy
+            if detect_names {
+                registry.register(
+                    config
+                        .severity_overrides
+                        .wrap(Box::new(NameDetector::new())),
+                );
+            }
+
+            // Drop detectors disabled via config or environment
+            for id in &config.scan.disabled_detectors {
+                if registry.unregister(id).is_none() {
+                    eprintln!("⚠️  Warning: Unknown detector to disable: {}", id);
+                }
+            }
+
+            // Decoded base64 blobs are scanned with the final detector set
+            if registry.unregister("base64").is_some() {
+                registry = with_base64_detector(registry);
+            }
+
+            for id in config.severity_overrides.ids() {
+                if registry.get(id).is_none() {
+                    eprintln!(
+                        "⚠️  Warning: Severity override for unknown detector: {}",
+                        id
+                    );
+                }
+            }
+
             println!("🔍 Using {} detectors\n", registry.all().len());
 
             // Configure walker
             let mut walker = Walker::new(&directory);
 
-            if let Some(depth) = max_depth {
+            if let Some(depth) = config.filters.max_depth {
                 walker = walker.max_depth(depth);
             }
 
-            if let Some(t) = threads {
+            if let Some(t) = config.scan.max_threads {
                 walker = walker.threads(t);
             }
 
-            let _walker = walker.max_filesize(max_filesize * 1024 * 1024);
+            let walker = match walker
+                .max_filesize(config.filters.max_filesize_mb * 1024 * 1024)
+                .include(&config.filters.include)
+            {
+                Ok(walker) => walker,
+                Err(e) => {
+                    eprintln!("❌ Error: Invalid --include glob: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            };
+
+            if !config.filters.include.is_empty() {
+                println!(
+                    "📂 Only scanning files matching: {}",
+                    config.filters.include.join(", ")
+                );
+            }
+
+            let ceilings = match SeverityCeilings::new(&directory, &config.severity_ceilings) {
+                Ok(ceilings) => ceilings,
+                Err(e) => {
+                    eprintln!("❌ Error: Invalid severity_ceilings glob: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            };
+
+            let scope = match DetectorPathScope::new(&directory, &config.detector_path_scope) {
+                Ok(scope) => scope,
+                Err(e) => {
+                    eprintln!("❌ Error: Invalid detector_path_scope glob: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            };
+
+            let correlation = if config.access_log.timestamp_patterns.is_empty() {
+                AccessLogCorrelation::default()
+            } else {
+                match AccessLogCorrelation::new(&config.access_log.timestamp_patterns) {
+                    Ok(correlation) => correlation,
+                    Err(e) => {
+                        eprintln!("❌ Error: Invalid access_log timestamp pattern: {}", e);
+                        process::exit(ScanExitCode::Fatal.code());
+                    }
+                }
+            };
 
             // Create engine
             let mut engine = ScanEngine::new(registry)
-                .enable_context(!no_context)
-                .show_progress(!no_progress);
+                .enable_context(!config.scan.no_context)
+                .show_progress(!config.output.no_progress)
+                .normalize(normalize)
+                .parallelism(parallelism.into())
+                .dedup_files(dedup_files)
+                // The manifest records the hash of the content that was scanned
+                .hash_files(hash_files || manifest.is_some())
+                .severity_ceilings(ceilings)
+                .access_log_correlation(correlation)
+                .detector_path_scope(scope);
+
+            // Date order and number separators for locale-aware detectors
+            match locale.as_deref().map(Locale::parse).transpose() {
+                Ok(locale) => {
+                    engine = engine.scan_context(
+                        ScanContext::new(locale.unwrap_or_default()).strictness(strictness.into()),
+                    );
+                }
+                Err(e) => {
+                    eprintln!("❌ Error: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            }
+
+            // Random sample of the discovered files; print the seed so the run can be repeated
+            if let Some(percent) = sample_percent {
+                let seed = seed.unwrap_or_else(rand::random);
+                engine = engine.sample(percent, seed);
+            }
+
+            // Cost caps: stop starting new files once either limit is reached
+            if let Some(files) = max_files {
+                engine = engine.max_files(files);
+            }
+            if let Some(bytes) = max_bytes {
+                engine = engine.max_bytes(bytes);
+            }
+
+            // Limit scan concurrency, not just file discovery
+            if let Some(t) = config.scan.max_threads {
+                engine = engine.threads(t);
+            }
 
             // Configure extractors if requested
-            if extract_documents {
+            if config.scan.extract_documents {
                 let mut extractor_registry = ExtractorRegistry::new();
-                extractor_registry.register(Arc::new(PdfExtractor));
+                extractor_registry.register(Arc::new(
+                    PdfExtractor::new().timeout(Duration::from_secs(config.scan.pdf_timeout_secs)),
+                ));
                 extractor_registry.register(Arc::new(DocxExtractor));
                 extractor_registry.register(Arc::new(XlsxExtractor));
+                extractor_registry.register(Arc::new(XmlExtractor::new()));
+                extractor_registry.register(Arc::new(VCardExtractor::new()));
+                extractor_registry.register(Arc::new(NotebookExtractor::new()));
+                extractor_registry.register(Arc::new(HarExtractor::new()));
+                extractor_registry.register(Arc::new(SqlDumpExtractor::new()));
 
-                println!("📄 Document extraction enabled (PDF, DOCX, XLSX)\n");
+                println!(
+                    "📄 Document extraction enabled (PDF, DOCX, XLSX, XML/SVG, VCF/ICS, IPYNB, HAR, SQL)\n"
+                );
                 engine = engine.with_extractors(extractor_registry);
             }
 
+            // Incremental cache: entries from a different detector set are rescanned
+            let scan_cache = cache.as_ref().map(|path| Arc::new(ScanCache::load(path)));
+            if let Some(ref scan_cache) = scan_cache {
+                engine = engine.with_cache(scan_cache.clone());
+            }
+
+            // Ctrl-C stops the scan early; files completed so far are still reported
+            let interrupted = install_interrupt_handler();
+            engine = engine.cancel_flag(interrupted.clone());
+
             // Scan
-            let results = engine.scan_directory(&directory);
+            let walked = path_list.is_none();
+            let results = match path_list {
+                Some(files) => {
+                    println!("📁 Scanning {} files from path list", files.len());
+                    engine.scan_paths(&files)
+                }
+                None => engine.scan_walker(&walker),
+            };
 
-            // Apply confidence filtering
-            let min_conf: pii_radar::Confidence = min_confidence.into();
-            let filtered_results = results.filter_by_confidence(min_conf);
+            if engine.is_cancelled() {
+                eprintln!(
+                    "⚠️  Scan interrupted: partial results for {} files",
+                    results.total_files
+                );
+            }
+            if results.limit_truncated {
+                eprintln!(
+                    "⚠️  Scan limit reached: partial results for {} files",
+                    results.total_files
+                );
+            }
 
-            // Output
-            match format {
-                OutputFormat::Terminal => {
-                    let reporter = TerminalReporter::new()
-                        .full_paths(full_paths)
-                        .show_context(!no_context);
-                    reporter.report(&filtered_results);
+            if let (Some(path), Some(scan_cache)) = (&cache, &scan_cache) {
+                if let Err(e) = scan_cache.save(path) {
+                    eprintln!("⚠️  Warning: {}", e);
                 }
-                OutputFormat::Json | OutputFormat::JsonCompact => {
-                    let pretty = matches!(format, OutputFormat::Json);
-                    let reporter = JsonReporter::new().pretty(pretty);
+            }
 
-                    if let Some(path) = output {
-                        if let Err(e) = reporter.write_to_file(&filtered_results, &path) {
-                            eprintln!("❌ Error: {}", e);
-                            process::exit(1);
-                        }
-                        println!("✅ Results written to: {}", path.display());
-                    } else if let Err(e) = reporter.print(&filtered_results) {
+            // Apply confidence filtering (lenient/paranoid override --min-confidence)
+            let strictness: pii_radar::Strictness = strictness.into();
+            let min_conf = strictness.min_confidence(min_confidence.into());
+            let mut filtered_results = results.filter_by_confidence(min_conf);
+            filtered_results.compute_risk(&config.risk);
+
+            // Output (all formats from the same scan)
+            let options = ReportOptions {
+                full_paths: config.output.full_paths,
+                show_context: !config.scan.no_context,
+                csv_group_by: csv_group_by.map(Into::into),
+                csv_with_rows,
+                verbose,
+                confidence_histogram,
+                html_template,
+                json_shape: json_shape.into(),
+                compress,
+            };
+
+            match write_reports(
+                &filtered_results,
+                &formats,
+                output.as_deref(),
+                options,
+                &mut std::io::stdout().lock(),
+            ) {
+                Ok(written) => {
+                    for (format, path) in written {
+                        println!("✅ {} written to: {}", format.label(), path.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Error: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+            }
+
+            if let Some(manifest_path) = manifest {
+                // Files the walker left out (size limit, hidden, ignored) are listed as skipped
+                let mut scan_manifest = ScanManifest::from_results(&filtered_results);
+                if walked {
+                    scan_manifest = scan_manifest.with_skipped(walker.skipped());
+                }
+                match scan_manifest.write_to_file(&manifest_path) {
+                    Ok(()) => println!("✅ Manifest written to: {}", manifest_path.display()),
+                    Err(e) => {
                         eprintln!("❌ Error: {}", e);
-                        process::exit(1);
+                        process::exit(ScanExitCode::Fatal.code());
                     }
                 }
-                OutputFormat::Html => {
-                    let reporter = HtmlReporter::new();
+            }
 
-                    let output_path =
-                        output.unwrap_or_else(|| std::path::PathBuf::from("pii-radar-report.html"));
+            // Expected countries without matches may mean the data format changed
+            // (not meaningful for an interrupted scan)
+            let coverage_gaps = expect_countries
+                .filter(|_| !engine.is_cancelled())
+                .map(|codes| {
+                    let expected: Vec<String> = codes.split(',').map(str::to_string).collect();
+                    filtered_results.coverage_gaps(&expected)
+                })
+                .unwrap_or_default();
+            if !coverage_gaps.is_empty() {
+                eprintln!(
+                    "⚠️  Coverage gap: no matches for expected countries: {}",
+                    coverage_gaps.join(", ")
+                );
+            }
 
-                    if let Err(e) = reporter.write_to_file(&filtered_results, &output_path) {
-                        eprintln!("❌ Error: {}", e);
-                        process::exit(1);
+            // Exit code 1 if PII (at --fail-on severity) found, 2 for file-level errors
+            // in strict mode, 4 for coverage gaps with --strict-coverage (for CI/CD)
+            let exit_code = if engine.is_cancelled() {
+                ScanExitCode::Interrupted
+            } else {
+                let fail_on = fail_on.map_or(pii_radar::Severity::Low, Into::into);
+                ScanExitCode::from_results_failing_on(&filtered_results, fail_on, strict_exit)
+                    .with_coverage_gaps(&coverage_gaps, strict_coverage)
+            };
+
+            let summary = LogSummary::new(&directory, &filtered_results, exit_code);
+            if log_summary {
+                eprintln!("{}", summary.to_json_line());
+            }
+            if let Some(run) = &run_dir {
+                let path = run.log_summary_path();
+                if let Err(e) = std::fs::write(&path, summary.to_json_line() + "\n") {
+                    eprintln!("❌ Error: Failed to write {}: {}", path.display(), e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
+                println!("📂 Run artifacts written to: {}", run.path().display());
+            }
+
+            if exit_code != ScanExitCode::Clean {
+                process::exit(exit_code.code());
+            }
+        }
+
+        Commands::Bench {
+            directory,
+            iterations,
+        } => {
+            if !directory.is_dir() {
+                eprintln!("❌ Error: Path is not a directory: {}", directory.display());
+                process::exit(ScanExitCode::Fatal.code());
+            }
+
+            if iterations == 0 {
+                eprintln!("❌ Error: --iterations must be at least 1");
+                process::exit(ScanExitCode::Fatal.code());
+            }
+
+            let report = run_bench(default_registry(), &directory, iterations);
+            print!("{}", report.summary());
+        }
+
+        Commands::Watch {
+            directory,
+            interval,
+            webhook,
+            debounce,
+        } => {
+            if !directory.is_dir() {
+                eprintln!("❌ Error: Path is not a directory: {}", directory.display());
+                process::exit(ScanExitCode::Fatal.code());
+            }
+
+            if interval == 0 {
+                eprintln!("❌ Error: --interval must be at least 1");
+                process::exit(ScanExitCode::Fatal.code());
+            }
+
+            let engine = ScanEngine::new(default_registry()).show_progress(false);
+            let mut watcher =
+                Watcher::new(engine, &directory).debounce(Duration::from_secs(debounce));
+            match webhook {
+                Some(url) => watcher = watcher.webhook(url),
+                None => eprintln!("⚠️  Warning: No --webhook given; new findings are only counted"),
+            }
+
+            println!(
+                "👀 Watching {} every {}s (Ctrl-C to stop)",
+                directory.display(),
+                interval
+            );
+            let interrupted = install_interrupt_handler();
+            watcher.run(Duration::from_secs(interval), &interrupted);
+        }
+
+        Commands::Merge {
+            inputs,
+            format,
+            output,
+            compress,
+        } => {
+            if let Some(warning) = auto_fallback_warning(&format, output.as_deref()) {
+                eprintln!("⚠️  Warning: {}", warning);
+            }
+            let formats = resolve_formats(&format, output.as_deref());
+
+            let mut shards = Vec::with_capacity(inputs.len());
+            for input in &inputs {
+                let shard = pii_radar::reporter::read_report_file(input)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| {
+                        serde_json::from_str::<ScanResults>(&json).map_err(|e| e.to_string())
+                    });
+
+                match shard {
+                    Ok(shard) => shards.push(shard),
+                    Err(e) => {
+                        eprintln!("❌ Error: Failed to read report {}: {}", input.display(), e);
+                        process::exit(ScanExitCode::Fatal.code());
                     }
-                    println!("✅ HTML report written to: {}", output_path.display());
                 }
-                OutputFormat::Csv => {
-                    let reporter = CsvReporter::new().with_context(!no_context);
+            }
 
-                    if let Some(path) = output {
-                        if let Err(e) = reporter.write_to_file(&filtered_results, &path) {
-                            eprintln!("❌ Error: {}", e);
-                            process::exit(1);
-                        }
-                        println!("✅ CSV report written to: {}", path.display());
-                    } else if let Err(e) = reporter.print(&filtered_results) {
-                        eprintln!("❌ Error: {}", e);
-                        process::exit(1);
+            let results = ScanResults::merge(shards);
+            println!(
+                "🔗 Merged {} reports into {} files",
+                inputs.len(),
+                results.total_files
+            );
+
+            let options = ReportOptions {
+                show_context: true,
+                compress,
+                ..Default::default()
+            };
+
+            match write_reports(
+                &results,
+                &formats,
+                output.as_deref(),
+                options,
+                &mut std::io::stdout().lock(),
+            ) {
+                Ok(written) => {
+                    for (format, path) in written {
+                        println!("✅ {} written to: {}", format.label(), path.display());
                     }
                 }
+                Err(e) => {
+                    eprintln!("❌ Error: {}", e);
+                    process::exit(ScanExitCode::Fatal.code());
+                }
             }
 
-            // Exit code 1 if PII found (for CI/CD)
-            if filtered_results.total_matches > 0 {
-                process::exit(1);
+            let exit_code = ScanExitCode::from_results(&results, false);
+            if exit_code != ScanExitCode::Clean {
+                process::exit(exit_code.code());
             }
         }
 
-        Commands::Detectors { verbose } => {
+        Commands::Detectors { verbose, json } => {
             let registry = default_registry();
 
+            if json {
+                let detectors: Vec<serde_json::Value> = registry
+                    .all()
+                    .iter()
+                    .map(|d| {
+                        serde_json::json!({
+                            "id": d.id(),
+                            "name": d.name(),
+                            "country": d.country(),
+                            "severity": d.base_severity(),
+                            "description": d.description(),
+                            "example_valid": d.example_valid(),
+                            "example_invalid": d.example_invalid(),
+                        })
+                    })
+                    .collect();
+
+                match serde_json::to_string_pretty(&detectors) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => {
+                        eprintln!("❌ Error: {}", e);
+                        process::exit(ScanExitCode::Fatal.code());
+                    }
+                }
+                return;
+            }
+
             println!(
                 "\n📋 Available PII Detectors ({} total)\n",
                 registry.all().len()
@@ -242,6 +781,12 @@ fn handle_file_commands(command: Commands) {
                 );
 
                 if verbose {
+                    if let Some(description) = detector.description() {
+                        println!("   {}", description);
+                    }
+                    if let Some(example) = detector.example_valid() {
+                        println!("   Example: {}", example);
+                    }
                     println!();
                 }
             }
@@ -249,6 +794,56 @@ fn handle_file_commands(command: Commands) {
             println!();
         }
 
+        Commands::Presets => {
+            println!("\n📋 Detector Presets\n");
+            for preset in Preset::ALL {
+                let registry = registry_preset(preset);
+                println!(
+                    "🔍 {} ({} detectors)\n   {}",
+                    preset.name(),
+                    registry.all().len(),
+                    preset.description()
+                );
+            }
+            println!();
+        }
+
+        Commands::SelfTest => {
+            let registry = default_registry();
+            let mut checked = 0;
+            let mut failures = 0;
+
+            println!("\n🧪 Detector Self-Test\n");
+
+            for detector in registry.all() {
+                let valid = detector.example_valid().map(|v| (v, true));
+                let invalid = detector.example_invalid().map(|v| (v, false));
+
+                for (example, expected) in valid.into_iter().chain(invalid) {
+                    checked += 1;
+                    if detector.validate(example) == expected {
+                        continue;
+                    }
+
+                    failures += 1;
+                    println!(
+                        "❌ {} ({}): {:?} should {}validate",
+                        detector.name(),
+                        detector.id(),
+                        example,
+                        if expected { "" } else { "not " }
+                    );
+                }
+            }
+
+            if failures > 0 {
+                eprintln!("\n❌ {} of {} examples failed", failures, checked);
+                process::exit(ScanExitCode::Fatal.code());
+            }
+
+            println!("✅ All {} examples passed\n", checked);
+        }
+
         Commands::Api {
             urls,
             method,
@@ -261,12 +856,17 @@ fn handle_file_commands(command: Commands) {
             min_confidence,
             plugins,
         } => {
+            if let Some(warning) = auto_fallback_warning(&[format], output.as_deref()) {
+                eprintln!("⚠️  Warning: {}", warning);
+            }
+            let format = format.resolve(output.as_deref());
+
             // Parse HTTP method
             let http_method = match method.parse::<HttpMethod>() {
                 Ok(m) => m,
                 Err(e) => {
                     eprintln!("❌ Error: {}", e);
-                    process::exit(1);
+                    process::exit(ScanExitCode::Fatal.code());
                 }
             };
 
@@ -280,7 +880,7 @@ fn handle_file_commands(command: Commands) {
                         "❌ Error: Invalid header format: {}. Expected KEY:VALUE",
                         header
                     );
-                    process::exit(1);
+                    process::exit(ScanExitCode::Fatal.code());
                 }
             }
 
@@ -308,6 +908,12 @@ fn handle_file_commands(command: Commands) {
                             for detector in plugin_detectors {
                                 registry.register(detector);
                             }
+                            warn_detector_conflicts(&registry);
+
+                            // Also run the plugins on decoded base64 blobs
+                            if registry.unregister("base64").is_some() {
+                                registry = with_base64_detector(registry);
+                            }
                         }
                     }
                     Err(e) => {
@@ -333,29 +939,31 @@ fn handle_file_commands(command: Commands) {
                 Ok(r) => r,
                 Err(e) => {
                     eprintln!("❌ Error: {}", e);
-                    process::exit(1);
+                    process::exit(ScanExitCode::Fatal.code());
                 }
             };
 
             // Output
             match format {
-                OutputFormat::Terminal => {
+                OutputFormat::Auto | OutputFormat::Terminal => {
                     let reporter = TerminalReporter::new().full_paths(true).show_context(true);
                     reporter.report(&results);
                 }
-                OutputFormat::Json | OutputFormat::JsonCompact => {
+                OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
                     let pretty = matches!(format, OutputFormat::Json);
-                    let reporter = JsonReporter::new().pretty(pretty);
+                    let reporter = JsonReporter::new()
+                        .pretty(pretty)
+                        .lines(matches!(format, OutputFormat::Ndjson));
 
                     if let Some(path) = output {
                         if let Err(e) = reporter.write_to_file(&results, &path) {
                             eprintln!("❌ Error: {}", e);
-                            process::exit(1);
+                            process::exit(ScanExitCode::Fatal.code());
                         }
                         println!("✅ Results written to: {}", path.display());
                     } else if let Err(e) = reporter.print(&results) {
                         eprintln!("❌ Error: {}", e);
-                        process::exit(1);
+                        process::exit(ScanExitCode::Fatal.code());
                     }
                 }
                 OutputFormat::Html => {
@@ -366,7 +974,7 @@ fn handle_file_commands(command: Commands) {
 
                     if let Err(e) = reporter.write_to_file(&results, &output_path) {
                         eprintln!("❌ Error: {}", e);
-                        process::exit(1);
+                        process::exit(ScanExitCode::Fatal.code());
                     }
                     println!("✅ HTML report written to: {}", output_path.display());
                 }
@@ -376,19 +984,33 @@ fn handle_file_commands(command: Commands) {
                     if let Some(path) = output {
                         if let Err(e) = reporter.write_to_file(&results, &path) {
                             eprintln!("❌ Error: {}", e);
-                            process::exit(1);
+                            process::exit(ScanExitCode::Fatal.code());
                         }
                         println!("✅ CSV report written to: {}", path.display());
                     } else if let Err(e) = reporter.print(&results) {
                         eprintln!("❌ Error: {}", e);
-                        process::exit(1);
+                        process::exit(ScanExitCode::Fatal.code());
+                    }
+                }
+                OutputFormat::Sarif => {
+                    let reporter = SarifReporter::new();
+
+                    if let Some(path) = output {
+                        if let Err(e) = reporter.write_to_file(&results, &path) {
+                            eprintln!("❌ Error: {}", e);
+                            process::exit(ScanExitCode::Fatal.code());
+                        }
+                        println!("✅ SARIF report written to: {}", path.display());
+                    } else if let Err(e) = reporter.print(&results) {
+                        eprintln!("❌ Error: {}", e);
+                        process::exit(ScanExitCode::Fatal.code());
                     }
                 }
             }
 
             // Exit code 1 if PII found (for CI/CD)
             if results.total_matches > 0 {
-                process::exit(1);
+                process::exit(ScanExitCode::PiiFound.code());
             }
         }
 
@@ -411,24 +1033,31 @@ struct DbScanParams {
     exclude_columns: Option<String>,
     sample_percent: Option<u8>,
     row_limit: Option<usize>,
+    text_columns_only: bool,
     pool_size: u32,
     format: OutputFormat,
     output: Option<std::path::PathBuf>,
     countries: Option<String>,
     no_progress: bool,
+    dry_run: bool,
 }
 
 #[cfg(feature = "database")]
-async fn handle_scan_db(params: DbScanParams) {
+async fn handle_scan_db(mut params: DbScanParams) {
     use std::str::FromStr;
 
+    if let Some(warning) = auto_fallback_warning(&[params.format], params.output.as_deref()) {
+        eprintln!("⚠️  Warning: {}", warning);
+    }
+    params.format = params.format.resolve(params.output.as_deref());
+
     // Parse database type
     let db_type = match DatabaseType::from_str(&params.db_type) {
         Ok(t) => t,
         Err(e) => {
             eprintln!("❌ Error: {}", e);
             eprintln!("Supported types: postgres, mongodb, sqlite");
-            process::exit(1);
+            process::exit(ScanExitCode::Fatal.code());
         }
     };
 
@@ -440,7 +1069,7 @@ async fn handle_scan_db(params: DbScanParams) {
             Some(name) => name,
             None => {
                 eprintln!("❌ Error: Database name required (use --database or include in connection string)");
-                process::exit(1);
+                process::exit(ScanExitCode::Fatal.code());
             }
         }
     };
@@ -483,6 +1112,7 @@ async fn handle_scan_db(params: DbScanParams) {
 
     scan_options.sample_percent = params.sample_percent;
     scan_options.row_limit = params.row_limit;
+    scan_options.text_columns_only = params.text_columns_only;
 
     // Build database config
     let config = DatabaseConfig::new(db_type, params.connection).with_pool_size(params.pool_size);
@@ -492,19 +1122,42 @@ async fn handle_scan_db(params: DbScanParams) {
         Ok(s) => s,
         Err(e) => {
             eprintln!("❌ Error connecting to database: {}", e);
-            process::exit(1);
+            process::exit(ScanExitCode::Fatal.code());
         }
     };
 
     println!("✅ Connected successfully\n");
 
+    if params.dry_run {
+        let plan = match scanner.plan(&db_name, &scan_options).await {
+            Ok(plan) => plan,
+            Err(e) => {
+                eprintln!("❌ Error planning database scan: {}", e);
+                process::exit(ScanExitCode::Fatal.code());
+            }
+        };
+
+        match params.format {
+            OutputFormat::Json | OutputFormat::JsonCompact => {
+                let json_str = if matches!(params.format, OutputFormat::Json) {
+                    serde_json::to_string_pretty(&plan).unwrap()
+                } else {
+                    serde_json::to_string(&plan).unwrap()
+                };
+                println!("{}", json_str);
+            }
+            _ => print_scan_plan(&plan, &scan_options),
+        }
+        return;
+    }
+
     // Scan database
     println!("🔍 Scanning database...\n");
     let results = match scanner.scan(&db_name, &scan_options).await {
         Ok(r) => r,
         Err(e) => {
             eprintln!("❌ Error scanning database: {}", e);
-            process::exit(1);
+            process::exit(ScanExitCode::Fatal.code());
         }
     };
 
@@ -521,7 +1174,7 @@ async fn handle_scan_db(params: DbScanParams) {
 
     // Output detailed results based on format
     match params.format {
-        OutputFormat::Terminal => {
+        OutputFormat::Auto | OutputFormat::Terminal => {
             println!("\n📋 Detailed Results:");
             for table in &results.tables_scanned {
                 if table.matches_found > 0 {
@@ -552,7 +1205,7 @@ async fn handle_scan_db(params: DbScanParams) {
             if let Some(path) = params.output {
                 if let Err(e) = std::fs::write(&path, json_str) {
                     eprintln!("❌ Error writing to file: {}", e);
-                    process::exit(1);
+                    process::exit(ScanExitCode::Fatal.code());
                 }
                 println!("\n✅ Results written to: {}", path.display());
             } else {
@@ -561,16 +1214,71 @@ async fn handle_scan_db(params: DbScanParams) {
         }
         OutputFormat::Html => {
             eprintln!("❌ HTML output format not yet implemented for database scans");
-            process::exit(1);
+            process::exit(ScanExitCode::Fatal.code());
         }
         OutputFormat::Csv => {
             eprintln!("❌ CSV output format not yet implemented for database scans");
-            process::exit(1);
+            process::exit(ScanExitCode::Fatal.code());
+        }
+        OutputFormat::Sarif => {
+            eprintln!("❌ SARIF output format not yet implemented for database scans");
+            process::exit(ScanExitCode::Fatal.code());
+        }
+        OutputFormat::Ndjson => {
+            eprintln!("❌ NDJSON output format not yet implemented for database scans");
+            process::exit(ScanExitCode::Fatal.code());
         }
     }
 
     // Exit code 1 if PII found (for CI/CD)
     if results.total_matches > 0 {
-        process::exit(1);
+        process::exit(ScanExitCode::PiiFound.code());
     }
 }
+
+/// Print a dry-run plan for a database scan
+#[cfg(feature = "database")]
+fn print_scan_plan(plan: &ScanPlan, options: &ScanOptions) {
+    println!(
+        "🧪 Dry run: {} ({}) - no row data read",
+        plan.database_name, plan.database_type
+    );
+    println!("   Tables/Collections in scope: {}", plan.tables.len());
+
+    for table in &plan.tables {
+        let rows = ScanPlan::rows_to_scan(table, options)
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let columns = if table.columns.is_empty() {
+            "all fields".to_string()
+        } else {
+            table.columns.join(", ")
+        };
+        println!("\n🗂️  {} (~{} rows to scan)", table.name, rows);
+        println!("   Columns: {}", columns);
+    }
+
+    println!("\n   Estimated total rows: {}", plan.estimated_rows());
+}
+
+/// Warn about plugin detectors that were ignored because their id was taken
+fn warn_detector_conflicts(registry: &pii_radar::DetectorRegistry) {
+    for conflict in registry.conflicts() {
+        eprintln!("⚠️  Warning: {}", conflict);
+    }
+}
+
+/// Parse arguments, exiting with the fatal exit code on invalid input
+fn parse_cli() -> Cli {
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        let code = if e.use_stderr() {
+            ScanExitCode::Fatal.code()
+        } else {
+            0 // --help and --version
+        };
+        let _ = e.print();
+        process::exit(code);
+    });
+    configure_colors(cli.no_color);
+    cli
+}
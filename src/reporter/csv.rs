@@ -1,17 +1,41 @@
 /// CSV reporter for spreadsheet-compatible output
-use crate::core::ScanResults;
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
+use crate::core::{Match, ScanResults};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// Field used to aggregate matches into a summary CSV
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Detector,
+    Country,
+    Severity,
+    File,
+}
+
+impl GroupBy {
+    /// Group key for a match
+    fn key(self, m: &Match) -> String {
+        match self {
+            GroupBy::Detector => m.detector_name.clone(),
+            GroupBy::Country => m.country.clone(),
+            GroupBy::Severity => format!("{:?}", m.severity),
+            GroupBy::File => m.location.file_path.to_string_lossy().to_string(),
+        }
+    }
+}
 
 pub struct CsvReporter {
     include_context: bool,
+    group_by: Option<GroupBy>,
+    include_rows: bool,
 }
 
 impl CsvReporter {
     pub fn new() -> Self {
         Self {
             include_context: false,
+            group_by: None,
+            include_rows: false,
         }
     }
 
@@ -20,38 +44,128 @@ impl CsvReporter {
         self
     }
 
+    /// Output a summary CSV (Group, Count, Distinct Files) instead of per-match rows
+    pub fn group_by(mut self, group_by: GroupBy) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// With `group_by`, also output the per-match rows
+    ///
+    /// The rows and the summary are different tables, so the summary is then
+    /// written to its own file (see [`summary_path`](Self::summary_path)).
+    pub fn include_rows(mut self, enabled: bool) -> Self {
+        self.include_rows = enabled;
+        self
+    }
+
     /// Print CSV to stdout
     pub fn print(&self, results: &ScanResults) -> Result<(), String> {
+        if self.separate_summary().is_some() {
+            return Err(
+                "CSV rows with a grouped summary are written to two files; use --output"
+                    .to_string(),
+            );
+        }
+
         let csv = self.generate_csv(results)?;
         println!("{}", csv);
         Ok(())
     }
 
-    /// Write CSV to file
+    /// Write CSV to file, gzip-compressed when the path ends in `.gz`
+    ///
+    /// With both per-match rows and a grouped summary, the summary goes to
+    /// [`summary_path`](Self::summary_path) next to `path`.
     pub fn write_to_file(&self, results: &ScanResults, path: &Path) -> Result<(), String> {
         let csv = self.generate_csv(results)?;
+        super::write_report_file(path, &csv)?;
 
-        let mut file = File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+        if let Some(group_by) = self.separate_summary() {
+            let summary = Self::generate_groups(results, group_by);
+            super::write_report_file(&Self::summary_path(path), &summary)?;
+        }
+        Ok(())
+    }
 
-        file.write_all(csv.as_bytes())
-            .map_err(|e| format!("Failed to write to file: {}", e))?;
+    /// Where the grouped summary is written next to per-match rows at `path`
+    ///
+    /// `report.csv` gives `report.summary.csv`, `report.csv.gz` gives
+    /// `report.summary.csv.gz`.
+    pub fn summary_path(path: &Path) -> PathBuf {
+        let gzip = super::is_gzip_path(path);
+        let base = if gzip {
+            path.with_extension("")
+        } else {
+            path.to_path_buf()
+        };
+        let extension = base
+            .extension()
+            .map_or_else(|| "csv".to_string(), |e| e.to_string_lossy().into_owned());
+
+        let mut summary = base
+            .with_extension(format!("summary.{}", extension))
+            .into_os_string();
+        if gzip {
+            summary.push(".gz");
+        }
+        PathBuf::from(summary)
+    }
 
-        Ok(())
+    /// Grouping written to a separate summary file, if any
+    fn separate_summary(&self) -> Option<GroupBy> {
+        self.group_by.filter(|_| self.include_rows)
     }
 
+    /// The main table: per-match rows, or only the summary when grouping without rows
     fn generate_csv(&self, results: &ScanResults) -> Result<String, String> {
+        match self.group_by {
+            Some(group_by) if !self.include_rows => Ok(Self::generate_groups(results, group_by)),
+            _ => self.generate_rows(results),
+        }
+    }
+
+    /// Summary CSV with one row per group
+    fn generate_groups(results: &ScanResults, group_by: GroupBy) -> String {
+        let mut groups: BTreeMap<String, (usize, BTreeSet<&Path>)> = BTreeMap::new();
+
+        for m in results.files.iter().flat_map(|f| &f.matches) {
+            let entry = groups.entry(group_by.key(m)).or_default();
+            entry.0 += 1;
+            entry.1.insert(m.location.file_path.as_path());
+        }
+
+        let mut output = String::from("Group,Count,Distinct Files\n");
+        for (group, (count, files)) in &groups {
+            output.push_str(&format!(
+                "{},{},{}\n",
+                Self::escape_csv_field(group),
+                count,
+                files.len()
+            ));
+        }
+
+        output
+    }
+
+    /// Per-match rows
+    fn generate_rows(&self, results: &ScanResults) -> Result<String, String> {
         let mut output = String::new();
 
+        // Files scanned with --hash-files get a trailing SHA-256 column
+        let with_hash = results.files.iter().any(|f| f.content_hash.is_some());
+
         // Header
+        output.push_str(
+            "File,Line,Column,Detector,Country,Masked Value,Confidence,Severity,GDPR Category",
+        );
         if self.include_context {
-            output.push_str(
-                "File,Line,Column,Detector,Country,Masked Value,Confidence,Severity,GDPR Category,Context\n",
-            );
-        } else {
-            output.push_str(
-                "File,Line,Column,Detector,Country,Masked Value,Confidence,Severity,GDPR Category\n",
-            );
+            output.push_str(",Context");
         }
+        if with_hash {
+            output.push_str(",SHA-256");
+        }
+        output.push('\n');
 
         // Data rows
         for file_result in &results.files {
@@ -102,6 +216,11 @@ impl CsvReporter {
                     row.push_str(&context_str);
                 }
 
+                if with_hash {
+                    row.push(',');
+                    row.push_str(file_result.content_hash.as_deref().unwrap_or(""));
+                }
+
                 output.push_str(&row);
                 output.push('\n');
             }
@@ -158,10 +277,22 @@ mod tests {
                     severity: Severity::Critical,
                     context: None,
                     gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
                 }],
                 size_bytes: 100,
                 scan_time_ms: 10,
                 error: None,
+                error_category: None,
+                content_hash: None,
+                suppressed: 0,
             }],
             total_files: 1,
             total_bytes: 100,
@@ -171,6 +302,14 @@ mod tests {
             by_country: HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = CsvReporter::new();
@@ -217,6 +356,14 @@ mod tests {
             by_country: HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = CsvReporter::new();
@@ -255,10 +402,22 @@ mod tests {
                         category: SpecialCategory::Medical,
                         detected_keywords: vec!["medical".to_string()],
                     },
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
                 }],
                 size_bytes: 100,
                 scan_time_ms: 10,
                 error: None,
+                error_category: None,
+                content_hash: None,
+                suppressed: 0,
             }],
             total_files: 1,
             total_bytes: 100,
@@ -268,6 +427,14 @@ mod tests {
             by_country: HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = CsvReporter::new().with_context(true);
@@ -291,6 +458,14 @@ mod tests {
             by_country: HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = CsvReporter::new();
@@ -301,4 +476,74 @@ mod tests {
         assert_eq!(lines.len(), 1); // Only header
         assert!(lines[0].starts_with("File,Line,Column"));
     }
+
+    fn grouping_match(detector: &str, file: &str) -> Match {
+        Match {
+            detector_id: detector.to_lowercase(),
+            detector_name: detector.to_string(),
+            country: "nl".to_string(),
+            value_masked: "***".to_string(),
+            location: Location {
+                file_path: PathBuf::from(file),
+                line: 1,
+                column: 0,
+                start_byte: 0,
+                end_byte: 3,
+            },
+            confidence: Confidence::High,
+            severity: Severity::Critical,
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_group_by_detector() {
+        let mut a = FileResult::new(PathBuf::from("a.txt"));
+        a.matches.push(grouping_match("Dutch BSN", "a.txt"));
+        a.matches.push(grouping_match("Email", "a.txt"));
+        let mut b = FileResult::new(PathBuf::from("b.txt"));
+        b.matches.push(grouping_match("Dutch BSN", "b.txt"));
+        let results = ScanResults::aggregate(vec![a, b]);
+
+        let csv = CsvReporter::new()
+            .group_by(GroupBy::Detector)
+            .generate_csv(&results)
+            .unwrap();
+
+        assert_eq!(
+            csv,
+            "Group,Count,Distinct Files\nDutch BSN,2,2\nEmail,1,1\n"
+        );
+
+        // Per-match rows and the summary are separate tables in separate files
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("report.csv");
+        let reporter = CsvReporter::new()
+            .group_by(GroupBy::Country)
+            .include_rows(true);
+        reporter.write_to_file(&results, &path).unwrap();
+
+        let rows = std::fs::read_to_string(&path).unwrap();
+        assert!(rows.starts_with("File,Line,Column"));
+        assert_eq!(rows.lines().count(), 4);
+        assert!(!rows.contains("Group,Count"));
+        let summary = std::fs::read_to_string(tmp.path().join("report.summary.csv")).unwrap();
+        assert_eq!(summary, "Group,Count,Distinct Files\nnl,3,2\n");
+
+        assert!(reporter.print(&results).is_err());
+        assert_eq!(
+            CsvReporter::summary_path(Path::new("out/scan.csv.gz")),
+            PathBuf::from("out/scan.summary.csv.gz")
+        );
+    }
 }
@@ -1,10 +1,41 @@
 /// HTML reporter with styled, interactive output
+///
+/// Small reports render every match as a table row. Above
+/// `PAGINATION_THRESHOLD` matches the rows are embedded as a JSON array and
+/// rendered one page at a time in the browser, with search running over the
+/// full set, so reports with tens of thousands of matches stay responsive.
+///
+/// The layout is a Tera template; [`HtmlReporter::with_template_file`] swaps
+/// in a user-supplied one. Templates receive these context variables:
+/// - `total_files`, `total_matches`, `total_time_ms`, `scan_date`,
+///   `files_with_pii`, `extracted_files`, `extraction_failures`
+/// - `severity_critical`, `severity_high`, `severity_medium`, `severity_low`
+/// - `matches`: rows with `file_path`, `detector_name`, `icon`, `country`,
+///   `value_masked`, `severity`, `severity_color`, `confidence`, `line`,
+///   `column` and `gdpr_special` (empty when `paginated`)
+/// - `paginated`, `page_size`, `matches_json` (all rows as JSON, only when
+///   `paginated`)
+/// - `groups_by_detector`, `groups_by_country`: groups with `key`, `label`,
+///   `count`, `hidden` and `matches`
 use crate::core::{GdprCategory, ScanResults, Severity};
 use chrono::Local;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use tera::{Context, Tera};
 
+/// Matches grouped by key, with a display label per group
+type GroupMap = BTreeMap<String, (String, Vec<serde_json::Value>)>;
+
+/// Match count above which the table is paginated client-side
+pub const PAGINATION_THRESHOLD: usize = 1000;
+
+/// Table rows per page in paginated reports
+pub const PAGE_SIZE: usize = 100;
+
+/// Matches listed per group panel in paginated reports
+const GROUP_PREVIEW_LIMIT: usize = 50;
+
 pub struct HtmlReporter {
     template: String,
 }
@@ -16,18 +47,55 @@ impl HtmlReporter {
         }
     }
 
+    /// Use a custom Tera template instead of the built-in one
+    ///
+    /// The template is parsed here, so syntax errors are reported before any
+    /// scanning happens. See the module docs for the available variables.
+    pub fn with_template_file(path: &Path) -> Result<Self, String> {
+        let template = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read HTML template {}: {}", path.display(), e))?;
+
+        Tera::default()
+            .add_raw_template("report.html", &template)
+            .map_err(|e| {
+                format!(
+                    "Invalid HTML template {}: {}",
+                    path.display(),
+                    Self::error_chain(&e)
+                )
+            })?;
+
+        Ok(Self { template })
+    }
+
     /// Generate HTML report and write to file
     pub fn write_to_file(&self, results: &ScanResults, output_path: &Path) -> std::io::Result<()> {
-        let html = self.generate_html(results);
+        let html = self.render(results).map_err(std::io::Error::other)?;
         fs::write(output_path, html)?;
         Ok(())
     }
 
     /// Generate HTML report as string
     pub fn generate_html(&self, results: &ScanResults) -> String {
+        self.render(results).expect("Failed to render template")
+    }
+
+    /// Tera error with its causes, e.g. the parse position or unknown variable
+    fn error_chain(error: &tera::Error) -> String {
+        let mut message = error.to_string();
+        let mut source = std::error::Error::source(error);
+        while let Some(cause) = source {
+            message.push_str(": ");
+            message.push_str(&cause.to_string());
+            source = cause.source();
+        }
+        message
+    }
+
+    fn render(&self, results: &ScanResults) -> Result<String, String> {
         let mut tera = Tera::default();
         tera.add_raw_template("report.html", &self.template)
-            .expect("Failed to parse template");
+            .map_err(|e| Self::error_chain(&e))?;
 
         let mut context = Context::new();
 
@@ -57,8 +125,10 @@ impl HtmlReporter {
             .filter(|f| !f.matches.is_empty())
             .collect();
 
-        // Prepare matches for template
+        // Prepare matches for template, plus per-detector and per-country groups
         let mut all_matches = Vec::new();
+        let mut by_detector: GroupMap = BTreeMap::new();
+        let mut by_country: GroupMap = BTreeMap::new();
         for file in &files_with_matches {
             for m in &file.matches {
                 let severity_color = match m.severity {
@@ -70,9 +140,10 @@ impl HtmlReporter {
 
                 let gdpr_special = matches!(m.gdpr_category, GdprCategory::Special { .. });
 
-                all_matches.push(serde_json::json!({
+                let entry = serde_json::json!({
                     "file_path": file.path.display().to_string(),
                     "detector_name": m.detector_name,
+                    "icon": m.icon,
                     "country": m.country.to_uppercase(),
                     "value_masked": m.value_masked,
                     "severity": format!("{:?}", m.severity),
@@ -81,15 +152,91 @@ impl HtmlReporter {
                     "line": m.location.line,
                     "column": m.location.column,
                     "gdpr_special": gdpr_special,
-                }));
+                });
+
+                // Group label: detector name without variant suffix, e.g. "(Visa)"
+                let detector_label = m
+                    .detector_name
+                    .split(" (")
+                    .next()
+                    .unwrap_or(&m.detector_name)
+                    .to_string();
+
+                by_detector
+                    .entry(m.detector_id.clone())
+                    .or_insert_with(|| (detector_label, Vec::new()))
+                    .1
+                    .push(entry.clone());
+                by_country
+                    .entry(m.country.to_uppercase())
+                    .or_insert_with(|| (m.country.to_uppercase(), Vec::new()))
+                    .1
+                    .push(entry.clone());
+
+                all_matches.push(entry);
             }
         }
 
-        context.insert("matches", &all_matches);
+        // Large result sets: embed the rows as JSON and cap the group panels
+        let paginated = all_matches.len() > PAGINATION_THRESHOLD;
+        let group_limit = if paginated {
+            GROUP_PREVIEW_LIMIT
+        } else {
+            usize::MAX
+        };
+
+        context.insert("paginated", &paginated);
+        context.insert("page_size", &PAGE_SIZE);
+        if paginated {
+            context.insert("matches", &Vec::<serde_json::Value>::new());
+            context.insert("matches_json", &Self::script_json(&all_matches));
+        } else {
+            context.insert("matches", &all_matches);
+        }
+        context.insert(
+            "groups_by_detector",
+            &Self::group_list(by_detector, group_limit),
+        );
+        context.insert(
+            "groups_by_country",
+            &Self::group_list(by_country, group_limit),
+        );
         context.insert("files_with_pii", &files_with_matches.len());
 
         tera.render("report.html", &context)
-            .expect("Failed to render template")
+            .map_err(|e| Self::error_chain(&e))
+    }
+
+    /// Serialize matches for a `<script type="application/json">` block
+    ///
+    /// `</` is escaped so a file path or value can't close the script element.
+    fn script_json(matches: &[serde_json::Value]) -> String {
+        serde_json::to_string(matches)
+            .expect("Failed to serialize matches")
+            .replace("</", "<\\/")
+    }
+
+    /// Turn grouped matches into template data, largest groups first
+    ///
+    /// Each group lists at most `limit` matches; `hidden` counts the rest.
+    fn group_list(groups: GroupMap, limit: usize) -> Vec<serde_json::Value> {
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        groups.sort_by(|a, b| b.1 .1.len().cmp(&a.1 .1.len()).then_with(|| a.0.cmp(&b.0)));
+
+        groups
+            .into_iter()
+            .map(|(key, (label, mut matches))| {
+                let count = matches.len();
+                matches.truncate(limit);
+                serde_json::json!({
+                    "key": key,
+                    "label": label,
+                    "count": count,
+                    "hidden": count - matches.len(),
+                    "matches": matches,
+                })
+            })
+            .collect()
     }
 
     fn default_template() -> String {
@@ -272,6 +419,79 @@ impl HtmlReporter {
             color: #6c757d;
             font-size: 0.9em;
         }
+        .grouped-views {
+            display: grid;
+            grid-template-columns: repeat(auto-fit, minmax(400px, 1fr));
+            gap: 30px;
+            padding: 30px;
+            background: white;
+            border-top: 1px solid #dee2e6;
+        }
+        .group-section h2 {
+            margin-bottom: 15px;
+            color: #333;
+        }
+        .group-panel {
+            border: 1px solid #dee2e6;
+            border-radius: 8px;
+            margin-bottom: 10px;
+            overflow: hidden;
+        }
+        .group-panel summary {
+            display: flex;
+            justify-content: space-between;
+            align-items: center;
+            padding: 12px 15px;
+            background: #f8f9fa;
+            cursor: pointer;
+            font-weight: 600;
+        }
+        .group-panel[open] summary {
+            border-bottom: 1px solid #dee2e6;
+        }
+        .group-count {
+            background: #667eea;
+            color: white;
+            border-radius: 12px;
+            padding: 2px 10px;
+            font-size: 0.85em;
+        }
+        .group-matches {
+            list-style: none;
+            padding: 10px 15px;
+            max-height: 300px;
+            overflow-y: auto;
+        }
+        .group-matches li {
+            padding: 6px 0;
+            border-bottom: 1px solid #f1f3f5;
+            font-size: 0.9em;
+        }
+        .group-more {
+            color: #6c757d;
+            font-style: italic;
+        }
+        .pagination {
+            display: flex;
+            justify-content: center;
+            align-items: center;
+            gap: 15px;
+            padding: 0 30px 30px;
+        }
+        .pagination button {
+            padding: 8px 16px;
+            border: 2px solid #667eea;
+            border-radius: 6px;
+            background: white;
+            color: #667eea;
+            font-weight: 600;
+            cursor: pointer;
+        }
+        .pagination button:disabled {
+            border-color: #dee2e6;
+            color: #adb5bd;
+            cursor: default;
+        }
         .no-matches {
             padding: 60px;
             text-align: center;
@@ -362,6 +582,43 @@ impl HtmlReporter {
             </div>
         </div>
 
+        {% block grouped_views %}
+        <div class="grouped-views">
+            <div class="group-section" id="groupsByDetector">
+                <h2>By Detector</h2>
+                {% for group in groups_by_detector %}
+                <details class="group-panel" data-group="{{ group.key }}">
+                    <summary><span class="group-name">{{ group.label }}</span> <span class="group-count">{{ group.count }}</span></summary>
+                    <ul class="group-matches">
+                    {% for match in group.matches %}
+                        <li><span class="code">{{ match.file_path }}</span> Line {{ match.line }}:{{ match.column }} • <span class="code">{{ match.value_masked }}</span> <span class="badge badge-{{ match.severity_color }}">{{ match.severity }}</span></li>
+                    {% endfor %}
+                    {% if group.hidden > 0 %}
+                        <li class="group-more">… and {{ group.hidden }} more (see the table below)</li>
+                    {% endif %}
+                    </ul>
+                </details>
+                {% endfor %}
+            </div>
+            <div class="group-section" id="groupsByCountry">
+                <h2>By Country</h2>
+                {% for group in groups_by_country %}
+                <details class="group-panel" data-group="{{ group.key }}">
+                    <summary><span class="group-name">{{ group.label }}</span> <span class="group-count">{{ group.count }}</span></summary>
+                    <ul class="group-matches">
+                    {% for match in group.matches %}
+                        <li><span class="code">{{ match.file_path }}</span> {% if match.icon %}{{ match.icon }} {% endif %}{{ match.detector_name }} • <span class="code">{{ match.value_masked }}</span> <span class="badge badge-{{ match.severity_color }}">{{ match.severity }}</span></li>
+                    {% endfor %}
+                    {% if group.hidden > 0 %}
+                        <li class="group-more">… and {{ group.hidden }} more (see the table below)</li>
+                    {% endif %}
+                    </ul>
+                </details>
+                {% endfor %}
+            </div>
+        </div>
+        {% endblock grouped_views %}
+
         <div class="search-box">
             <input type="text" id="searchInput" class="search-input" placeholder="🔍 Search by file, detector, or country...">
         </div>
@@ -379,10 +636,11 @@ impl HtmlReporter {
                     </tr>
                 </thead>
                 <tbody>
+                {% if not paginated %}
                 {% for match in matches %}
                     <tr>
                         <td><span class="code">{{ match.file_path }}</span></td>
-                        <td>{{ match.detector_name }}</td>
+                        <td>{% if match.icon %}{{ match.icon }} {% endif %}{{ match.detector_name }}</td>
                         <td>{{ match.country }}</td>
                         <td><span class="code">{{ match.value_masked }}</span></td>
                         <td>
@@ -394,9 +652,18 @@ impl HtmlReporter {
                         <td>Line {{ match.line }}:{{ match.column }}</td>
                     </tr>
                 {% endfor %}
+                {% endif %}
                 </tbody>
             </table>
         </div>
+        {% if paginated %}
+        <div class="pagination" id="pagination" data-page-size="{{ page_size }}">
+            <button type="button" id="prevPage">‹ Previous</button>
+            <span id="pageInfo"></span>
+            <button type="button" id="nextPage">Next ›</button>
+        </div>
+        <script type="application/json" id="matchesData">{{ matches_json | safe }}</script>
+        {% endif %}
         {% else %}
         <div class="no-matches">
             <h3>✅ No PII Found</h3>
@@ -410,11 +677,77 @@ impl HtmlReporter {
     </div>
 
     <script>
-        // Search functionality
         const searchInput = document.getElementById('searchInput');
         const table = document.getElementById('matchesTable');
-        
-        if (searchInput && table) {
+        const matchesData = document.getElementById('matchesData');
+
+        if (searchInput && table && matchesData) {
+            // Paginated: rows are rendered from the embedded JSON, one page at a time
+            const tbody = table.getElementsByTagName('tbody')[0];
+            const pagination = document.getElementById('pagination');
+            const pageSize = parseInt(pagination.dataset.pageSize, 10);
+            const pageInfo = document.getElementById('pageInfo');
+            const prevPage = document.getElementById('prevPage');
+            const nextPage = document.getElementById('nextPage');
+            const matches = JSON.parse(matchesData.textContent);
+            const searchText = matches.map(m =>
+                [m.file_path, m.detector_name, m.country, m.value_masked, m.severity].join(' ').toLowerCase());
+            let filtered = matches;
+            let page = 0;
+
+            function cell(row, text, code) {
+                const td = row.insertCell();
+                if (code) {
+                    const span = document.createElement('span');
+                    span.className = 'code';
+                    span.textContent = text;
+                    td.appendChild(span);
+                } else {
+                    td.textContent = text;
+                }
+                return td;
+            }
+
+            function badge(td, className, text) {
+                const span = document.createElement('span');
+                span.className = 'badge ' + className;
+                span.textContent = text;
+                td.appendChild(span);
+            }
+
+            function render() {
+                const pages = Math.max(1, Math.ceil(filtered.length / pageSize));
+                page = Math.min(page, pages - 1);
+                tbody.replaceChildren();
+                for (const m of filtered.slice(page * pageSize, (page + 1) * pageSize)) {
+                    const row = tbody.insertRow();
+                    cell(row, m.file_path, true);
+                    cell(row, (m.icon ? m.icon + ' ' : '') + m.detector_name, false);
+                    cell(row, m.country, false);
+                    cell(row, m.value_masked, true);
+                    const severity = cell(row, '', false);
+                    badge(severity, 'badge-' + m.severity_color, m.severity);
+                    if (m.gdpr_special) {
+                        badge(severity, 'gdpr-badge', 'GDPR Art.9');
+                    }
+                    cell(row, 'Line ' + m.line + ':' + m.column, false);
+                }
+                pageInfo.textContent = 'Page ' + (page + 1) + ' of ' + pages + ' (' + filtered.length + ' matches)';
+                prevPage.disabled = page === 0;
+                nextPage.disabled = page >= pages - 1;
+            }
+
+            searchInput.addEventListener('input', function() {
+                const searchTerm = this.value.toLowerCase();
+                filtered = matches.filter((m, i) => searchText[i].includes(searchTerm));
+                page = 0;
+                render();
+            });
+            prevPage.addEventListener('click', () => { page--; render(); });
+            nextPage.addEventListener('click', () => { page++; render(); });
+            render();
+        } else if (searchInput && table) {
+            // Search functionality
             searchInput.addEventListener('input', function() {
                 const searchTerm = this.value.toLowerCase();
                 const rows = table.getElementsByTagName('tbody')[0].getElementsByTagName('tr');
@@ -463,6 +796,14 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let html = reporter.generate_html(&results);
@@ -470,6 +811,54 @@ mod tests {
         assert!(html.contains("No PII Found"));
     }
 
+    #[test]
+    fn test_html_reporter_custom_template() {
+        let tmp = TempDir::new().unwrap();
+        let template_path = tmp.path().join("brand.html");
+        fs::write(
+            &template_path,
+            "<h1>ACME privacy scan</h1>\n\
+             <p>{{ total_files }} files, {{ severity_critical }} critical</p>\n\
+             {% for m in matches %}<li>{{ m.detector_name }}: {{ m.value_masked }}</li>{% endfor %}",
+        )
+        .unwrap();
+
+        let reporter = HtmlReporter::with_template_file(&template_path).unwrap();
+        let mut file_result = FileResult::new(PathBuf::from("crm.csv"));
+        file_result
+            .matches
+            .push(grouped_test_match("iban", "IBAN", "nl"));
+        let results = ScanResults::aggregate(vec![file_result]);
+
+        let html = reporter.generate_html(&results);
+        assert!(html.starts_with("<h1>ACME privacy scan</h1>"));
+        assert!(html.contains("<p>1 files, 0 critical</p>"));
+        assert!(html.contains("<li>IBAN: "));
+        assert!(!html.contains("PII-Radar Scan Report"));
+    }
+
+    #[test]
+    fn test_html_reporter_invalid_template() {
+        let tmp = TempDir::new().unwrap();
+        let template_path = tmp.path().join("broken.html");
+        fs::write(
+            &template_path,
+            "{% for m in matches %}<li>{{ m.line }}</li>",
+        )
+        .unwrap();
+
+        let err = HtmlReporter::with_template_file(&template_path)
+            .err()
+            .expect("unclosed block should fail to parse");
+        assert!(err.starts_with("Invalid HTML template"), "{}", err);
+        assert!(err.contains("broken.html"));
+
+        let err = HtmlReporter::with_template_file(&tmp.path().join("missing.html"))
+            .err()
+            .unwrap();
+        assert!(err.starts_with("Failed to read HTML template"), "{}", err);
+    }
+
     #[test]
     fn test_html_reporter_write_file() {
         let tmp = TempDir::new().unwrap();
@@ -486,6 +875,14 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         assert!(reporter.write_to_file(&results, &output_path).is_ok());
@@ -517,6 +914,15 @@ mod tests {
             },
             context: None,
             gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: Some("🏥".to_string()),
+            correlated_with: Vec::new(),
+            key_path: None,
         });
 
         let results = ScanResults {
@@ -534,11 +940,129 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let html = reporter.generate_html(&results);
         assert!(html.contains("Test Detector"));
         assert!(html.contains("123****89"));
         assert!(html.contains("Critical"));
+        assert!(html.contains("🏥 Test Detector"));
+    }
+
+    fn grouped_test_match(detector_id: &str, name: &str, country: &str) -> crate::core::Match {
+        crate::core::Match {
+            detector_id: detector_id.to_string(),
+            detector_name: name.to_string(),
+            country: country.to_string(),
+            value_masked: "123****89".to_string(),
+            severity: crate::core::Severity::High,
+            confidence: crate::core::Confidence::High,
+            location: crate::core::Location {
+                file_path: PathBuf::from("test.txt"),
+                line: 1,
+                column: 0,
+                start_byte: 0,
+                end_byte: 9,
+            },
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        }
+    }
+
+    #[test]
+    fn test_html_reporter_grouped_views() {
+        let reporter = HtmlReporter::new();
+        let mut file_result = FileResult::new(PathBuf::from("test.txt"));
+        file_result
+            .matches
+            .push(grouped_test_match("nl_bsn", "Dutch BSN", "nl"));
+        file_result
+            .matches
+            .push(grouped_test_match("nl_bsn", "Dutch BSN", "nl"));
+        file_result
+            .matches
+            .push(grouped_test_match("iban", "IBAN (DE)", "de"));
+        file_result
+            .matches
+            .push(grouped_test_match("iban", "IBAN (NL)", "nl"));
+        file_result
+            .matches
+            .push(grouped_test_match("email", "Email Address", "universal"));
+
+        let results = ScanResults::aggregate(vec![file_result]);
+        let html = reporter.generate_html(&results);
+
+        assert!(html.contains(r#"id="groupsByDetector""#));
+        assert!(html.contains(r#"id="groupsByCountry""#));
+        assert!(html.contains(r#"<details class="group-panel" data-group="nl_bsn">"#));
+        assert!(html.contains(r#"<details class="group-panel" data-group="UNIVERSAL">"#));
+
+        // Per-detector counts: BSN 2, IBAN 2 (label without variant suffix), Email 1
+        assert!(html.contains(
+            r#"<span class="group-name">Dutch BSN</span> <span class="group-count">2</span>"#
+        ));
+        assert!(html.contains(
+            r#"<span class="group-name">IBAN</span> <span class="group-count">2</span>"#
+        ));
+        assert!(html.contains(
+            r#"<span class="group-name">Email Address</span> <span class="group-count">1</span>"#
+        ));
+
+        // Per-country counts: NL 3, DE 1
+        assert!(html
+            .contains(r#"<span class="group-name">NL</span> <span class="group-count">3</span>"#));
+        assert!(html
+            .contains(r#"<span class="group-name">DE</span> <span class="group-count">1</span>"#));
+
+        // Existing search is kept
+        assert!(html.contains("searchInput"));
+    }
+
+    #[test]
+    fn test_html_reporter_paginates_large_result_set() {
+        let reporter = HtmlReporter::new();
+        let mut file_result = FileResult::new(PathBuf::from("dump</script>.txt"));
+        for _ in 0..PAGINATION_THRESHOLD + 500 {
+            file_result
+                .matches
+                .push(grouped_test_match("nl_bsn", "Dutch BSN", "nl"));
+        }
+
+        let results = ScanResults::aggregate(vec![file_result]);
+        let html = reporter.generate_html(&results);
+
+        // Rows are embedded as one JSON array instead of table rows
+        assert!(html.contains(r#"<script type="application/json" id="matchesData">"#));
+        assert!(html.contains(r#"data-page-size="100""#));
+        assert_eq!(html.matches("<tr>").count(), 1);
+        let start = html.find(r#"id="matchesData">"#).unwrap() + r#"id="matchesData">"#.len();
+        let end = start + html[start..].find("</script>").unwrap();
+        let data: Vec<serde_json::Value> = serde_json::from_str(&html[start..end]).unwrap();
+        assert_eq!(data.len(), PAGINATION_THRESHOLD + 500);
+        assert_eq!(data[0]["file_path"], "dump</script>.txt");
+
+        // Group panels keep the full count but list a preview
+        assert!(html.contains(
+            r#"<span class="group-name">Dutch BSN</span> <span class="group-count">1500</span>"#
+        ));
+        assert!(html.contains("… and 1450 more"));
+        assert!(html.contains("searchInput"));
     }
 }
@@ -1,17 +1,48 @@
 /// JSON reporter for machine-readable output
-use crate::core::ScanResults;
+use crate::core::{GdprKeywordSummary, Match, ScanResults};
+use serde::Serialize;
 use serde_json;
-use std::fs::File;
-use std::io::Write;
+use std::collections::BTreeMap;
 use std::path::Path;
 
+/// How matches are grouped in the JSON report
+///
+/// Totals and summaries stay at the top level in every shape. Only
+/// [`JsonShape::ByFile`] reports can be read back by `pii-radar merge`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonShape {
+    /// `files`: one entry per scanned file with its matches
+    #[default]
+    ByFile,
+    /// `detectors`: detector id → matches
+    ByDetector,
+    /// `matches`: a single array of all matches
+    Flat,
+}
+
 pub struct JsonReporter {
     pretty: bool,
+    shape: JsonShape,
+    lines: bool,
+}
+
+/// Serialized report: the scan results plus derived summaries
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    #[serde(flatten)]
+    results: &'a ScanResults,
+
+    /// Context keyword frequencies behind special category classifications
+    gdpr_keyword_summary: GdprKeywordSummary,
 }
 
 impl JsonReporter {
     pub fn new() -> Self {
-        Self { pretty: true }
+        Self {
+            pretty: true,
+            shape: JsonShape::default(),
+            lines: false,
+        }
     }
 
     pub fn pretty(mut self, enabled: bool) -> Self {
@@ -19,34 +50,82 @@ impl JsonReporter {
         self
     }
 
+    /// Set how matches are grouped
+    pub fn shape(mut self, shape: JsonShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Write one match per line (NDJSON) instead of a single document
+    ///
+    /// Totals, summaries and the shape are left out.
+    pub fn lines(mut self, enabled: bool) -> Self {
+        self.lines = enabled;
+        self
+    }
+
     /// Print JSON to stdout
     pub fn print(&self, results: &ScanResults) -> Result<(), String> {
-        let json = if self.pretty {
-            serde_json::to_string_pretty(results)
+        let json = self.generate_json(results)?;
+
+        if self.lines {
+            print!("{}", json);
         } else {
-            serde_json::to_string(results)
+            println!("{}", json);
         }
-        .map_err(|e| format!("Failed to serialize results: {}", e))?;
-
-        println!("{}", json);
         Ok(())
     }
 
-    /// Write JSON to file
+    /// Write JSON to file, gzip-compressed when the path ends in `.gz`
     pub fn write_to_file(&self, results: &ScanResults, path: &Path) -> Result<(), String> {
-        let json = if self.pretty {
-            serde_json::to_string_pretty(results)
-        } else {
-            serde_json::to_string(results)
+        let json = self.generate_json(results)?;
+        super::write_report_file(path, &json)
+    }
+
+    fn generate_json(&self, results: &ScanResults) -> Result<String, String> {
+        if self.lines {
+            let mut lines = String::new();
+            for m in results.files.iter().flat_map(|file| file.matches.iter()) {
+                let line = serde_json::to_string(m)
+                    .map_err(|e| format!("Failed to serialize results: {}", e))?;
+                lines.push_str(&line);
+                lines.push('\n');
+            }
+            return Ok(lines);
         }
-        .map_err(|e| format!("Failed to serialize results: {}", e))?;
 
-        let mut file = File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+        let report = JsonReport {
+            results,
+            gdpr_keyword_summary: results.gdpr_keyword_summary(),
+        };
+        let mut report = serde_json::to_value(&report)
+            .map_err(|e| format!("Failed to serialize results: {}", e))?;
 
-        file.write_all(json.as_bytes())
-            .map_err(|e| format!("Failed to write to file: {}", e))?;
+        // Replace the per-file list with the requested grouping
+        let matches = results.files.iter().flat_map(|file| file.matches.iter());
+        let regrouped = match self.shape {
+            JsonShape::ByFile => None,
+            JsonShape::ByDetector => {
+                let mut by_detector: BTreeMap<&str, Vec<&Match>> = BTreeMap::new();
+                for m in matches {
+                    by_detector.entry(&m.detector_id).or_default().push(m);
+                }
+                Some(("detectors", serde_json::to_value(by_detector)))
+            }
+            JsonShape::Flat => Some(("matches", serde_json::to_value(matches.collect::<Vec<_>>()))),
+        };
+        if let (Some((key, grouped)), Some(object)) = (regrouped, report.as_object_mut()) {
+            let grouped = grouped.map_err(|e| format!("Failed to serialize results: {}", e))?;
+            object.remove("files");
+            object.insert(key.to_string(), grouped);
+        }
 
-        Ok(())
+        if self.pretty {
+            serde_json::to_string_pretty(&report)
+        } else {
+            serde_json::to_string(&report)
+        }
+        .map_err(|e| format!("Failed to serialize results: {}", e))
     }
 }
 
@@ -74,6 +153,14 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = JsonReporter::new();
@@ -95,6 +182,14 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = JsonReporter::new();
@@ -114,9 +209,147 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = JsonReporter::new().pretty(false);
         assert!(reporter.print(&results).is_ok());
     }
+
+    #[test]
+    fn test_json_gdpr_keyword_summary() {
+        use crate::core::{
+            Confidence, FileResult, GdprCategory, Location, Match, Severity, SpecialCategory,
+        };
+        use std::path::PathBuf;
+
+        let mut file = FileResult::new(PathBuf::from("patients.txt"));
+        for keywords in [vec!["patient", "diagnosis"], vec!["patient"]] {
+            file.matches.push(Match {
+                detector_id: "nl_bsn".to_string(),
+                detector_name: "Dutch BSN".to_string(),
+                country: "nl".to_string(),
+                value_masked: "***".to_string(),
+                location: Location {
+                    file_path: PathBuf::from("patients.txt"),
+                    line: 1,
+                    column: 0,
+                    start_byte: 0,
+                    end_byte: 3,
+                },
+                confidence: Confidence::High,
+                severity: Severity::Critical,
+                context: None,
+                gdpr_category: GdprCategory::Special {
+                    category: SpecialCategory::Medical,
+                    detected_keywords: keywords.iter().map(|k| k.to_string()).collect(),
+                },
+                fingerprint: String::new(),
+                decoded_from_base64: false,
+                partially_masked: false,
+                nationality: None,
+                issuing_country: None,
+                brand: None,
+                icon: None,
+                correlated_with: Vec::new(),
+                key_path: None,
+            });
+        }
+        let results = ScanResults::aggregate(vec![file]);
+
+        let json = JsonReporter::new().generate_json(&results).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["total_matches"], 2);
+        assert_eq!(value["gdpr_keyword_summary"]["medical"]["patient"], 2);
+        assert_eq!(value["gdpr_keyword_summary"]["medical"]["diagnosis"], 1);
+        assert_eq!(value["risk_score"], 55.1);
+        assert_eq!(value["risk_level"], "high");
+
+        // The report still reads back as plain scan results (e.g. for `merge`)
+        let parsed: ScanResults = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.total_matches, 2);
+    }
+
+    #[test]
+    fn test_json_shapes() {
+        use crate::core::{Confidence, FileResult, GdprCategory, Location, Match, Severity};
+        use std::path::PathBuf;
+
+        let make_match = |detector_id: &str, file: &str, line: usize| Match {
+            detector_id: detector_id.to_string(),
+            detector_name: detector_id.to_string(),
+            country: "nl".to_string(),
+            value_masked: "***".to_string(),
+            location: Location {
+                file_path: PathBuf::from(file),
+                line,
+                column: 0,
+                start_byte: 0,
+                end_byte: 3,
+            },
+            confidence: Confidence::High,
+            severity: Severity::High,
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        };
+        let mut customers = FileResult::new(PathBuf::from("customers.csv"));
+        customers.matches = vec![
+            make_match("nl_bsn", "customers.csv", 1),
+            make_match("email", "customers.csv", 2),
+        ];
+        let mut notes = FileResult::new(PathBuf::from("notes.txt"));
+        notes.matches = vec![make_match("email", "notes.txt", 7)];
+        let results = ScanResults::aggregate(vec![customers, notes]);
+
+        let render = |shape| {
+            let json = JsonReporter::new()
+                .shape(shape)
+                .generate_json(&results)
+                .unwrap();
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()
+        };
+
+        let by_file = render(JsonShape::ByFile);
+        assert_eq!(by_file["total_matches"], 3);
+        assert_eq!(by_file["files"][0]["matches"].as_array().unwrap().len(), 2);
+        assert_eq!(by_file["files"][1]["matches"][0]["location"]["line"], 7);
+
+        let by_detector = render(JsonShape::ByDetector);
+        assert_eq!(by_detector["total_matches"], 3);
+        assert!(by_detector.get("files").is_none());
+        assert_eq!(
+            by_detector["detectors"]["nl_bsn"].as_array().unwrap().len(),
+            1
+        );
+        let emails = by_detector["detectors"]["email"].as_array().unwrap();
+        assert_eq!(emails.len(), 2);
+        assert_eq!(emails[1]["location"]["file_path"], "notes.txt");
+
+        let flat = render(JsonShape::Flat);
+        assert_eq!(flat["total_matches"], 3);
+        assert!(flat.get("files").is_none());
+        let lines: Vec<u64> = flat["matches"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["location"]["line"].as_u64().unwrap())
+            .collect();
+        assert_eq!(lines, vec![1, 2, 7]);
+    }
 }
@@ -1,10 +1,52 @@
 pub mod csv;
 pub mod html;
 pub mod json;
+pub mod sarif;
 /// Output formatters for scan results
 pub mod terminal;
 
-pub use csv::CsvReporter;
+pub use csv::{CsvReporter, GroupBy};
 pub use html::HtmlReporter;
-pub use json::JsonReporter;
-pub use terminal::TerminalReporter;
+pub use json::{JsonReporter, JsonShape};
+pub use sarif::SarifReporter;
+pub use terminal::{ReportSummary, TerminalReporter};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// Whether a report path asks for gzip compression (`.gz` extension)
+pub fn is_gzip_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+}
+
+/// Write a report file, gzip-compressed when the path ends in `.gz`
+pub(crate) fn write_report_file(path: &Path, contents: &str) -> Result<(), String> {
+    let mut file = File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+
+    let written = if is_gzip_path(path) {
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder
+            .write_all(contents.as_bytes())
+            .and_then(|_| encoder.finish().map(|_| ()))
+    } else {
+        file.write_all(contents.as_bytes())
+    };
+
+    written.map_err(|e| format!("Failed to write to file: {}", e))
+}
+
+/// Read a report file, decompressing it when the path ends in `.gz`
+pub fn read_report_file(path: &Path) -> std::io::Result<String> {
+    if !is_gzip_path(path) {
+        return std::fs::read_to_string(path);
+    }
+
+    let mut contents = String::new();
+    GzDecoder::new(File::open(path)?).read_to_string(&mut contents)?;
+    Ok(contents)
+}
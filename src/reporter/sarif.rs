@@ -0,0 +1,199 @@
+/// SARIF 2.1.0 reporter for code scanning platforms
+use crate::core::{ScanResults, Severity};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Key used for PII-Radar fingerprints in `partialFingerprints`
+const FINGERPRINT_KEY: &str = "piiRadarFingerprint/v1";
+
+pub struct SarifReporter {
+    pretty: bool,
+}
+
+impl SarifReporter {
+    pub fn new() -> Self {
+        Self { pretty: true }
+    }
+
+    pub fn pretty(mut self, enabled: bool) -> Self {
+        self.pretty = enabled;
+        self
+    }
+
+    /// Build the SARIF log as a JSON value
+    pub fn to_sarif(&self, results: &ScanResults) -> Value {
+        let mut rules: BTreeMap<&str, &str> = BTreeMap::new();
+        let mut sarif_results = Vec::new();
+
+        for file in &results.files {
+            for m in &file.matches {
+                rules.entry(&m.detector_id).or_insert(&m.detector_name);
+
+                sarif_results.push(json!({
+                    "ruleId": m.detector_id,
+                    "level": Self::level(m.severity),
+                    "message": {
+                        "text": format!("{} detected: {}", m.detector_name, m.value_masked),
+                    },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": {
+                                "uri": m.location.file_path.to_string_lossy().replace('\\', "/"),
+                            },
+                            "region": {
+                                "startLine": m.location.line,
+                                "startColumn": m.location.column + 1,
+                            },
+                        },
+                    }],
+                    "partialFingerprints": {
+                        FINGERPRINT_KEY: m.fingerprint,
+                    },
+                    "properties": {
+                        "confidence": m.confidence,
+                        "severity": m.severity,
+                        "country": m.country,
+                    },
+                }));
+            }
+        }
+
+        let rules: Vec<Value> = rules
+            .into_iter()
+            .map(|(id, name)| {
+                json!({
+                    "id": id,
+                    "name": name,
+                    "shortDescription": { "text": name },
+                })
+            })
+            .collect();
+
+        json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "PII-Radar",
+                        "version": crate::VERSION,
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": rules,
+                    },
+                },
+                "results": sarif_results,
+            }],
+        })
+    }
+
+    /// Print SARIF to stdout
+    pub fn print(&self, results: &ScanResults) -> Result<(), String> {
+        let sarif = self.serialize(results)?;
+        println!("{}", sarif);
+        Ok(())
+    }
+
+    /// Write SARIF to file
+    pub fn write_to_file(&self, results: &ScanResults, path: &Path) -> Result<(), String> {
+        let sarif = self.serialize(results)?;
+
+        let mut file = File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+
+        file.write_all(sarif.as_bytes())
+            .map_err(|e| format!("Failed to write to file: {}", e))?;
+
+        Ok(())
+    }
+
+    fn serialize(&self, results: &ScanResults) -> Result<String, String> {
+        let value = self.to_sarif(results);
+        if self.pretty {
+            serde_json::to_string_pretty(&value)
+        } else {
+            serde_json::to_string(&value)
+        }
+        .map_err(|e| format!("Failed to serialize results: {}", e))
+    }
+
+    /// Map PII severity to a SARIF result level
+    fn level(severity: Severity) -> &'static str {
+        match severity {
+            Severity::Critical | Severity::High => "error",
+            Severity::Medium => "warning",
+            Severity::Low => "note",
+        }
+    }
+}
+
+impl Default for SarifReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Confidence, FileResult, GdprCategory, Location, Match};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_sarif_contains_fingerprint() {
+        let mut file_result = FileResult::new(PathBuf::from("data/users.txt"));
+        file_result.matches.push(Match {
+            detector_id: "nl_bsn".to_string(),
+            detector_name: "Dutch BSN".to_string(),
+            country: "nl".to_string(),
+            value_masked: "111****33".to_string(),
+            location: Location {
+                file_path: PathBuf::from("data/users.txt"),
+                line: 3,
+                column: 5,
+                start_byte: 20,
+                end_byte: 29,
+            },
+            confidence: Confidence::High,
+            severity: Severity::High,
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: "abc123".to_string(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        });
+
+        let results = ScanResults::aggregate(vec![file_result]);
+        let sarif = SarifReporter::new().to_sarif(&results);
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "nl_bsn");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "nl_bsn");
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["partialFingerprints"][FINGERPRINT_KEY], "abc123");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            3
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startColumn"],
+            6
+        );
+    }
+
+    #[test]
+    fn test_sarif_empty_results() {
+        let sarif = SarifReporter::new().to_sarif(&ScanResults::new());
+        assert!(sarif["runs"][0]["results"].as_array().unwrap().is_empty());
+    }
+}
@@ -1,10 +1,42 @@
 /// Terminal/CLI reporter with colored output
-use crate::core::{GdprCategory, ScanResults, Severity};
+use crate::core::{GdprCategory, GdprKeywordSummary, RiskLevel, ScanResults, Severity};
 use colored::*;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+/// Summary counts computed for a terminal report
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportSummary {
+    /// Total files scanned
+    pub total_files: usize,
+
+    /// Files with at least one match
+    pub files_with_pii: usize,
+
+    /// Total matches
+    pub total_matches: usize,
+
+    /// Matches per detector name
+    pub by_detector: BTreeMap<String, usize>,
+
+    /// Matches per country code
+    pub by_country: BTreeMap<String, usize>,
+
+    /// Matches classified as GDPR Art. 9 special category data
+    pub special_category: usize,
+
+    /// Context keyword frequencies behind special category classifications
+    pub gdpr_keywords: GdprKeywordSummary,
+}
+
+/// Number of keywords listed per category in the verbose summary
+const TOP_KEYWORDS: usize = 5;
 
 pub struct TerminalReporter {
     show_full_paths: bool,
     show_context: bool,
+    verbose: bool,
+    confidence_histogram: bool,
 }
 
 impl TerminalReporter {
@@ -12,6 +44,8 @@ impl TerminalReporter {
         Self {
             show_full_paths: false,
             show_context: true,
+            verbose: false,
+            confidence_histogram: false,
         }
     }
 
@@ -25,131 +59,273 @@ impl TerminalReporter {
         self
     }
 
+    /// Include the top GDPR context keywords in the summary
+    pub fn verbose(mut self, enabled: bool) -> Self {
+        self.verbose = enabled;
+        self
+    }
+
+    /// Include Low/Medium/High match counts per detector in the summary
+    pub fn confidence_histogram(mut self, enabled: bool) -> Self {
+        self.confidence_histogram = enabled;
+        self
+    }
+
+    /// Compute summary counts as data
+    pub fn summary(&self, results: &ScanResults) -> ReportSummary {
+        let mut summary = ReportSummary {
+            total_files: results.total_files,
+            total_matches: results.total_matches,
+            gdpr_keywords: results.gdpr_keyword_summary(),
+            ..Default::default()
+        };
+
+        for file in &results.files {
+            if !file.matches.is_empty() {
+                summary.files_with_pii += 1;
+            }
+
+            for m in &file.matches {
+                *summary
+                    .by_detector
+                    .entry(m.detector_name.clone())
+                    .or_insert(0) += 1;
+                *summary.by_country.entry(m.country.clone()).or_insert(0) += 1;
+
+                if matches!(m.gdpr_category, GdprCategory::Special { .. }) {
+                    summary.special_category += 1;
+                }
+            }
+        }
+
+        summary
+    }
+
+    /// Print the summary to stdout
     pub fn print_summary(&self, results: &ScanResults) {
-        println!("\n{}", "═".repeat(80).bright_blue());
-        println!("{}", "  🎯 SCAN COMPLETE".bright_cyan().bold());
-        println!("{}", "═".repeat(80).bright_blue());
+        let _ = self.write_summary(results, &mut io::stdout().lock());
+    }
+
+    /// Write the summary to `out`
+    pub fn write_summary(&self, results: &ScanResults, out: &mut dyn Write) -> io::Result<()> {
+        let summary = self.summary(results);
+
+        writeln!(out, "\n{}", "═".repeat(80).bright_blue())?;
+        writeln!(out, "{}", "  🎯 SCAN COMPLETE".bright_cyan().bold())?;
+        writeln!(out, "{}", "═".repeat(80).bright_blue())?;
 
         // Overall statistics
-        println!("\n{}", "📊 Statistics:".bold());
-        println!(
+        writeln!(out, "\n{}", "📊 Statistics:".bold())?;
+        writeln!(
+            out,
             "  Files scanned:    {}",
             results.total_files.to_string().cyan()
-        );
+        )?;
 
         // Show extraction statistics if any documents were extracted
         if results.extracted_files > 0 {
-            println!(
+            writeln!(
+                out,
                 "  Documents extracted: {}",
                 results.extracted_files.to_string().cyan()
-            );
+            )?;
             if results.extraction_failures > 0 {
-                println!(
+                writeln!(
+                    out,
                     "  Extraction failures: {}",
                     results.extraction_failures.to_string().red()
-                );
+                )?;
             }
         }
 
-        let files_with_pii = results
-            .files
-            .iter()
-            .filter(|f| !f.matches.is_empty())
-            .count();
-        println!(
+        if let Some(sample) = results.sample {
+            writeln!(
+                out,
+                "  Sampled:          {}% of {} files (seed {})",
+                sample.percent,
+                sample.discovered_files.to_string().cyan(),
+                sample.seed
+            )?;
+        }
+
+        if results.limit_truncated {
+            writeln!(
+                out,
+                "  Truncated:        {}",
+                "scan limit reached (--max-files/--max-bytes), results are partial".yellow()
+            )?;
+        }
+
+        if results.duplicate_files > 0 {
+            writeln!(
+                out,
+                "  Duplicate files:  {} (matches reused)",
+                results.duplicate_files.to_string().cyan()
+            )?;
+        }
+
+        if results.suppressed_matches > 0 {
+            writeln!(
+                out,
+                "  Suppressed:       {} (pii-radar:ignore comments)",
+                results.suppressed_matches.to_string().cyan()
+            )?;
+        }
+
+        writeln!(
+            out,
             "  Files with PII:   {}",
-            files_with_pii.to_string().yellow()
-        );
-        println!(
+            summary.files_with_pii.to_string().yellow()
+        )?;
+        writeln!(
+            out,
             "  Total matches:    {}",
             results.total_matches.to_string().red().bold()
-        );
-        println!(
+        )?;
+        if let (Some(sample), Some(files), Some(matches)) = (
+            results.sample,
+            results.estimated_total(summary.files_with_pii),
+            results.estimated_total(results.total_matches),
+        ) {
+            writeln!(
+                out,
+                "  Estimated totals: ~{} files with PII, ~{} matches (estimated from {}% sample)",
+                files.to_string().yellow(),
+                matches.to_string().red().bold(),
+                sample.percent
+            )?;
+        }
+        writeln!(
+            out,
             "  Scan duration:    {} ms",
             results.total_time_ms.to_string().green()
-        );
+        )?;
+        let risk_label = format!("{:.1}/100 ({})", results.risk_score, results.risk_level);
+        let risk_label = match results.risk_level {
+            RiskLevel::Critical | RiskLevel::High => risk_label.red().bold(),
+            RiskLevel::Medium => risk_label.yellow(),
+            RiskLevel::Low | RiskLevel::None => risk_label.green(),
+        };
+        writeln!(out, "  Risk score:       {}", risk_label)?;
 
         // Severity breakdown
         if results.total_matches > 0 {
-            println!("\n{}", "⚠️  Severity Breakdown:".bold());
+            writeln!(out, "\n{}", "⚠️  Severity Breakdown:".bold())?;
 
             if results.by_severity.critical > 0 {
-                println!(
+                writeln!(
+                    out,
                     "  🔴 Critical:  {}",
                     results.by_severity.critical.to_string().red().bold()
-                );
+                )?;
             }
             if results.by_severity.high > 0 {
-                println!(
+                writeln!(
+                    out,
                     "  🟠 High:      {}",
                     results.by_severity.high.to_string().red()
-                );
+                )?;
             }
             if results.by_severity.medium > 0 {
-                println!(
+                writeln!(
+                    out,
                     "  🟡 Medium:    {}",
                     results.by_severity.medium.to_string().yellow()
-                );
+                )?;
             }
             if results.by_severity.low > 0 {
-                println!(
+                writeln!(
+                    out,
                     "  🔵 Low:       {}",
                     results.by_severity.low.to_string().blue()
-                );
+                )?;
             }
         }
 
         // Detector breakdown
-        println!("\n{}", "🔍 Detector Matches:".bold());
-        let mut detector_counts: std::collections::HashMap<String, usize> =
-            std::collections::HashMap::new();
-
-        for file in &results.files {
-            for m in &file.matches {
-                *detector_counts.entry(m.detector_name.clone()).or_insert(0) += 1;
-            }
-        }
-
-        for (detector, count) in detector_counts.iter() {
-            println!(
+        writeln!(out, "\n{}", "🔍 Detector Matches:".bold())?;
+        for (detector, count) in &summary.by_detector {
+            writeln!(
+                out,
                 "  {} {}",
                 "→".cyan(),
                 format!("{}: {}", detector, count).white()
-            );
+            )?;
+        }
+
+        // Confidence histogram (counted before --min-confidence filtering)
+        if self.confidence_histogram && !results.confidence_breakdown.is_empty() {
+            writeln!(out, "\n{}", "📶 Confidence Histogram:".bold())?;
+            writeln!(
+                out,
+                "  {:<24} {:>6} {:>6} {:>6}",
+                "Detector", "Low", "Medium", "High"
+            )?;
+
+            let mut detectors: Vec<_> = results.confidence_breakdown.iter().collect();
+            detectors.sort_by(|a, b| a.0.cmp(b.0));
+            for (detector, [low, medium, high]) in detectors {
+                writeln!(
+                    out,
+                    "  {:<24} {:>6} {:>6} {:>6}",
+                    detector, low, medium, high
+                )?;
+            }
         }
 
         // GDPR Art. 9 special category warnings
-        let special_category_count = results
-            .files
-            .iter()
-            .flat_map(|f| &f.matches)
-            .filter(|m| matches!(m.gdpr_category, GdprCategory::Special { .. }))
-            .count();
-
-        if special_category_count > 0 {
-            println!(
+        if summary.special_category > 0 {
+            writeln!(
+                out,
                 "\n{}",
                 "⚠️  GDPR Article 9 - Special Category Data:".red().bold()
-            );
-            println!(
+            )?;
+            writeln!(
+                out,
                 "  {} matches contain sensitive context (medical/biometric/genetic/criminal)",
-                special_category_count.to_string().red().bold()
-            );
-            println!("  These require extra protection under GDPR!");
+                summary.special_category.to_string().red().bold()
+            )?;
+            writeln!(out, "  These require extra protection under GDPR!")?;
+
+            if self.verbose && !summary.gdpr_keywords.is_empty() {
+                writeln!(out, "\n{}", "🔑 Top Context Keywords:".bold())?;
+                for (category, keywords) in &summary.gdpr_keywords {
+                    let mut keywords: Vec<(&String, &usize)> = keywords.iter().collect();
+                    keywords.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+                    let top = keywords
+                        .iter()
+                        .take(TOP_KEYWORDS)
+                        .map(|(keyword, count)| format!("{} ({})", keyword, count))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    writeln!(out, "  {} {}: {}", "→".cyan(), category, top.yellow())?;
+                }
+            }
         }
 
-        println!();
+        writeln!(out)
     }
 
+    /// Print the detailed findings to stdout
     pub fn print_detailed_results(&self, results: &ScanResults) {
+        let _ = self.write_detailed_results(results, &mut io::stdout().lock());
+    }
+
+    /// Write the detailed findings to `out`
+    pub fn write_detailed_results(
+        &self,
+        results: &ScanResults,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
         if results.total_matches == 0 {
-            println!("\n{}", "✅ No PII detected!".green().bold());
-            return;
+            writeln!(out, "\n{}", "✅ No PII detected!".green().bold())?;
+            return Ok(());
         }
 
-        println!("\n{}", "═".repeat(80).bright_blue());
-        println!("{}", "  📋 DETAILED FINDINGS".bright_cyan().bold());
-        println!("{}", "═".repeat(80).bright_blue());
+        writeln!(out, "\n{}", "═".repeat(80).bright_blue())?;
+        writeln!(out, "{}", "  📋 DETAILED FINDINGS".bright_cyan().bold())?;
+        writeln!(out, "{}", "═".repeat(80).bright_blue())?;
 
         for file in &results.files {
             if file.matches.is_empty() {
@@ -157,7 +333,7 @@ impl TerminalReporter {
             }
 
             // File header
-            println!("\n{}", "─".repeat(80).bright_black());
+            writeln!(out, "\n{}", "─".repeat(80).bright_black())?;
             let path_display = if self.show_full_paths {
                 file.path.display().to_string()
             } else {
@@ -167,16 +343,17 @@ impl TerminalReporter {
                     .unwrap_or_else(|| file.path.display().to_string())
             };
 
-            println!(
+            writeln!(
+                out,
                 "{} {} {} matches",
                 "📄".cyan(),
                 path_display.bold(),
                 format!("({})", file.matches.len()).yellow()
-            );
+            )?;
 
             // Print each match
             for (idx, m) in file.matches.iter().enumerate() {
-                println!();
+                writeln!(out)?;
 
                 // Match header with severity
                 let severity_icon = match m.severity {
@@ -186,43 +363,56 @@ impl TerminalReporter {
                     Severity::Low => "🔵",
                 };
 
-                println!(
-                    "  {} Match #{} - {}",
+                let detector_icon = m.icon.as_ref().map(|i| format!("{} ", i));
+                writeln!(
+                    out,
+                    "  {} Match #{} - {}{}",
                     severity_icon,
                     idx + 1,
+                    detector_icon.unwrap_or_default(),
                     m.detector_name.yellow().bold()
-                );
+                )?;
 
                 // Location
-                println!(
+                writeln!(
+                    out,
                     "    Location:   Line {}, Column {}",
                     m.location.line.to_string().cyan(),
                     m.location.column.to_string().cyan()
-                );
+                )?;
 
                 // Masked value
-                println!("    Value:      {}", m.value_masked.red().bold());
+                writeln!(out, "    Value:      {}", m.value_masked.red().bold())?;
 
                 // Confidence
-                println!("    Confidence: {}", format!("{:?}", m.confidence).green());
+                writeln!(
+                    out,
+                    "    Confidence: {}",
+                    format!("{:?}", m.confidence).green()
+                )?;
 
                 // GDPR category
                 match &m.gdpr_category {
                     GdprCategory::Regular => {
-                        println!("    GDPR:       Regular PII");
+                        writeln!(out, "    GDPR:       Regular PII")?;
                     }
                     GdprCategory::Special {
                         category,
                         detected_keywords,
                     } => {
-                        println!(
+                        writeln!(
+                            out,
                             "    GDPR:       {} {} - {}",
                             "⚠️ ".red(),
                             "Special Category (Art. 9)".red().bold(),
                             format!("{:?}", category).red()
-                        );
+                        )?;
                         if !detected_keywords.is_empty() {
-                            println!("    Keywords:   {}", detected_keywords.join(", ").yellow());
+                            writeln!(
+                                out,
+                                "    Keywords:   {}",
+                                detected_keywords.join(", ").yellow()
+                            )?;
                         }
                     }
                 }
@@ -230,7 +420,8 @@ impl TerminalReporter {
                 // Context (if available and enabled)
                 if self.show_context {
                     if let Some(ctx) = &m.context {
-                        println!(
+                        writeln!(
+                            out,
                             "    Context:    \"{}[PII]{}\"",
                             ctx.before
                                 .chars()
@@ -241,18 +432,24 @@ impl TerminalReporter {
                                 .rev()
                                 .collect::<String>(),
                             ctx.after.chars().take(30).collect::<String>()
-                        );
+                        )?;
                     }
                 }
             }
         }
 
-        println!("\n{}", "═".repeat(80).bright_blue());
+        writeln!(out, "\n{}", "═".repeat(80).bright_blue())
     }
 
+    /// Print the full report (summary and findings) to stdout
     pub fn report(&self, results: &ScanResults) {
-        self.print_summary(results);
-        self.print_detailed_results(results);
+        let _ = self.write_report(results, &mut io::stdout().lock());
+    }
+
+    /// Write the full report (summary and findings) to `out`
+    pub fn write_report(&self, results: &ScanResults, out: &mut dyn Write) -> io::Result<()> {
+        self.write_summary(results, out)?;
+        self.write_detailed_results(results, out)
     }
 }
 
@@ -282,6 +479,14 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = TerminalReporter::new();
@@ -315,6 +520,15 @@ mod tests {
                 category: SpecialCategory::Medical,
                 detected_keywords: vec!["patient".to_string()],
             },
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
         });
 
         let results = ScanResults {
@@ -332,9 +546,165 @@ mod tests {
             by_country: std::collections::HashMap::new(),
             extracted_files: 0,
             extraction_failures: 0,
+            errors: Vec::new(),
+            risk_score: 0.0,
+            risk_level: crate::core::RiskLevel::None,
+            confidence_breakdown: std::collections::HashMap::new(),
+            duplicate_files: 0,
+            suppressed_matches: 0,
+            sample: None,
+            limit_truncated: false,
         };
 
         let reporter = TerminalReporter::new();
         reporter.report(&results); // Should not panic
     }
+
+    #[test]
+    fn test_terminal_reporter_writes_to_buffer() {
+        let mut file_result = FileResult::new(PathBuf::from("patients.txt"));
+        for (detector, country) in [
+            ("Dutch BSN", "nl"),
+            ("Dutch BSN", "nl"),
+            ("Email", "universal"),
+        ] {
+            file_result.matches.push(Match {
+                detector_id: detector.to_lowercase(),
+                detector_name: detector.to_string(),
+                country: country.to_string(),
+                value_masked: "***".to_string(),
+                location: Location {
+                    file_path: PathBuf::from("patients.txt"),
+                    line: 1,
+                    column: 0,
+                    start_byte: 0,
+                    end_byte: 3,
+                },
+                confidence: Confidence::High,
+                severity: Severity::High,
+                context: None,
+                gdpr_category: if country == "nl" {
+                    GdprCategory::Special {
+                        category: SpecialCategory::Medical,
+                        detected_keywords: vec!["patient".to_string()],
+                    }
+                } else {
+                    GdprCategory::Regular
+                },
+                fingerprint: String::new(),
+                decoded_from_base64: false,
+                partially_masked: false,
+                nationality: None,
+                issuing_country: None,
+                brand: None,
+                icon: None,
+                correlated_with: Vec::new(),
+                key_path: None,
+            });
+        }
+
+        let results = ScanResults::aggregate(vec![
+            file_result,
+            FileResult::new(PathBuf::from("clean.txt")),
+        ]);
+
+        let reporter = TerminalReporter::new();
+        let summary = reporter.summary(&results);
+        assert_eq!(summary.total_files, 2);
+        assert_eq!(summary.files_with_pii, 1);
+        assert_eq!(summary.total_matches, 3);
+        assert_eq!(summary.by_detector.get("Dutch BSN"), Some(&2));
+        assert_eq!(summary.by_detector.get("Email"), Some(&1));
+        assert_eq!(summary.by_country.get("nl"), Some(&2));
+        assert_eq!(summary.special_category, 2);
+
+        let mut buffer = Vec::new();
+        reporter.write_report(&results, &mut buffer).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("SCAN COMPLETE"));
+        assert!(output.contains("Dutch BSN: 2"));
+        assert!(output.contains("patients.txt"));
+        assert!(!output.contains("clean.txt"));
+        assert!(output.contains("GDPR Article 9"));
+        assert!(!output.contains("Top Context Keywords"));
+        assert!(!output.contains("Confidence Histogram"));
+
+        let mut buffer = Vec::new();
+        TerminalReporter::new()
+            .confidence_histogram(true)
+            .write_report(&results, &mut buffer)
+            .unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Confidence Histogram"));
+        assert!(output.contains(&format!("  {:<24} {:>6} {:>6} {:>6}", "dutch bsn", 0, 0, 2)));
+
+        assert_eq!(
+            summary.gdpr_keywords[&SpecialCategory::Medical].get("patient"),
+            Some(&2)
+        );
+        let mut buffer = Vec::new();
+        reporter
+            .verbose(true)
+            .write_summary(&results, &mut buffer)
+            .unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Top Context Keywords"));
+        assert!(output.contains("patient (2)"));
+    }
+
+    #[test]
+    fn test_terminal_reporter_sample_estimate() {
+        let mut file_result = FileResult::new(PathBuf::from("patients.txt"));
+        file_result.matches.push(Match {
+            detector_id: "email".to_string(),
+            detector_name: "Email".to_string(),
+            country: "universal".to_string(),
+            value_masked: "***".to_string(),
+            location: Location {
+                file_path: PathBuf::from("patients.txt"),
+                line: 1,
+                column: 0,
+                start_byte: 0,
+                end_byte: 3,
+            },
+            confidence: Confidence::High,
+            severity: Severity::Medium,
+            context: None,
+            gdpr_category: GdprCategory::Regular,
+            fingerprint: String::new(),
+            decoded_from_base64: false,
+            partially_masked: false,
+            nationality: None,
+            issuing_country: None,
+            brand: None,
+            icon: None,
+            correlated_with: Vec::new(),
+            key_path: None,
+        });
+
+        let mut results = ScanResults::aggregate(vec![
+            file_result,
+            FileResult::new(PathBuf::from("clean.txt")),
+        ]);
+        let mut buffer = Vec::new();
+        TerminalReporter::new()
+            .write_summary(&results, &mut buffer)
+            .unwrap();
+        assert!(!String::from_utf8(buffer).unwrap().contains("Estimated"));
+
+        // 2 of 20 discovered files scanned
+        results.sample = Some(crate::core::SampleInfo {
+            percent: 10,
+            seed: 7,
+            discovered_files: 20,
+        });
+        let mut buffer = Vec::new();
+        TerminalReporter::new()
+            .write_summary(&results, &mut buffer)
+            .unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("10% of 20 files (seed 7)"));
+        assert!(output.contains("~10 files with PII, ~10 matches (estimated from 10% sample)"));
+    }
 }
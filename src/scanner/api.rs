@@ -7,7 +7,7 @@ use std::str::FromStr;
 use std::time::Duration;
 use url::Url;
 
-use crate::core::types::{FileResult, ScanResults};
+use crate::core::types::{FileResult, ScanError, ScanErrorCategory, ScanResults};
 use crate::core::Detector;
 
 /// Configuration for API endpoint scanning
@@ -180,8 +180,9 @@ pub fn scan_api_endpoint(
     let mut all_matches = Vec::new();
     for detector in detectors {
         let matches = detector.detect(&response_text, &api_path);
-        for m in matches {
+        for mut m in matches {
             if &m.confidence >= min_confidence {
+                m.fingerprint = m.compute_fingerprint(&response_text);
                 all_matches.push(m);
             }
         }
@@ -196,6 +197,9 @@ pub fn scan_api_endpoint(
         size_bytes: response_size as u64,
         scan_time_ms: scan_time.as_millis() as u64,
         error: None,
+        error_category: None,
+        content_hash: None,
+        suppressed: 0,
     };
 
     Ok(ScanResults {
@@ -232,6 +236,9 @@ pub fn scan_api_endpoints(
                     size_bytes: 0,
                     scan_time_ms: 0,
                     error: Some(e.to_string()),
+                    error_category: Some(ScanErrorCategory::Read),
+                    content_hash: None,
+                    suppressed: 0,
                 });
             }
         }
@@ -239,10 +246,16 @@ pub fn scan_api_endpoints(
 
     let _scan_duration = start_time.elapsed();
 
+    let errors = all_files
+        .iter()
+        .filter_map(ScanError::from_file_result)
+        .collect();
+
     Ok(ScanResults {
         total_files: endpoints.len(),
         total_matches,
         files: all_files,
+        errors,
         ..Default::default()
     })
 }
@@ -297,6 +310,15 @@ mod tests {
                     severity: Severity::Critical,
                     context: None,
                     gdpr_category: GdprCategory::Regular,
+                    fingerprint: String::new(),
+                    decoded_from_base64: false,
+                    partially_masked: false,
+                    nationality: None,
+                    issuing_country: None,
+                    brand: None,
+                    icon: None,
+                    correlated_with: Vec::new(),
+                    key_path: None,
                 })
                 .collect()
         }
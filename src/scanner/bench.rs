@@ -0,0 +1,134 @@
+/// Detector benchmark harness
+///
+/// Runs the scan engine repeatedly over a fixed set of files and reports
+/// throughput plus per-detector average time. Findings are discarded.
+use crate::core::DetectorRegistry;
+use crate::crawler::Walker;
+use crate::scanner::stats::{DetectorStats, DetectorTiming};
+use crate::scanner::ScanEngine;
+use rayon::prelude::*;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Result of a benchmark run
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    /// Number of scan iterations
+    pub iterations: usize,
+
+    /// Files scanned across all iterations
+    pub files: usize,
+
+    /// Bytes scanned across all iterations
+    pub bytes: u64,
+
+    /// Wall-clock time across all iterations
+    pub elapsed: Duration,
+
+    /// Per-detector statistics, sorted by detector ID
+    pub detectors: Vec<DetectorTiming>,
+}
+
+impl BenchReport {
+    /// Files scanned per second
+    pub fn files_per_sec(&self) -> f64 {
+        self.files as f64 / self.elapsed_secs()
+    }
+
+    /// Megabytes scanned per second
+    pub fn mb_per_sec(&self) -> f64 {
+        self.bytes as f64 / (1024.0 * 1024.0) / self.elapsed_secs()
+    }
+
+    fn elapsed_secs(&self) -> f64 {
+        self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Stable, line-oriented summary suitable for tracking in CI
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "iterations: {}", self.iterations);
+        let _ = writeln!(out, "files: {}", self.files);
+        let _ = writeln!(out, "bytes: {}", self.bytes);
+        let _ = writeln!(
+            out,
+            "elapsed_ms: {:.3}",
+            self.elapsed.as_secs_f64() * 1000.0
+        );
+        let _ = writeln!(out, "files_per_sec: {:.2}", self.files_per_sec());
+        let _ = writeln!(out, "mb_per_sec: {:.3}", self.mb_per_sec());
+        let _ = writeln!(out, "detector\tavg_us\tmatches");
+
+        for timing in &self.detectors {
+            let _ = writeln!(
+                out,
+                "{}\t{:.2}\t{}",
+                timing.detector_id,
+                timing.average_time().as_secs_f64() * 1_000_000.0,
+                timing.matches
+            );
+        }
+
+        out
+    }
+}
+
+/// Scan `root` `iterations` times with the given detectors
+pub fn run_bench(registry: DetectorRegistry, root: &Path, iterations: usize) -> BenchReport {
+    let files = Walker::new(root).walk_parallel();
+    let stats = Arc::new(DetectorStats::new());
+    let engine = ScanEngine::new(registry)
+        .show_progress(false)
+        .with_detector_stats(Arc::clone(&stats));
+
+    let mut scanned_files = 0;
+    let mut bytes = 0;
+    let start = Instant::now();
+
+    for _ in 0..iterations {
+        let results: Vec<_> = files
+            .par_iter()
+            .map(|path| engine.scan_file(path))
+            .collect();
+
+        scanned_files += results.len();
+        bytes += results.iter().map(|r| r.size_bytes).sum::<u64>();
+    }
+
+    BenchReport {
+        iterations,
+        files: scanned_files,
+        bytes,
+        elapsed: start.elapsed(),
+        detectors: stats.snapshot(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default_registry;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_bench_single_iteration() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("a.txt"), "BSN: 111222333\n").unwrap();
+        fs::write(tmp.path().join("b.txt"), "contact: jan@example.com\n").unwrap();
+
+        let report = run_bench(default_registry(), tmp.path(), 1);
+
+        assert_eq!(report.iterations, 1);
+        assert_eq!(report.files, 2);
+        assert!(report.bytes > 0);
+        assert!(report.files_per_sec() > 0.0);
+        assert!(report.mb_per_sec() > 0.0);
+        assert!(!report.detectors.is_empty());
+        assert!(report.detectors.iter().all(|d| d.calls == 2));
+        assert!(report.summary().contains("files_per_sec: "));
+    }
+}
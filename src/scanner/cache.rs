@@ -0,0 +1,266 @@
+/// Incremental scan cache
+///
+/// Stores the matches of each scanned file together with its size and
+/// modification time, so unchanged files can be skipped on the next run.
+/// Every entry also records the scan key: a hash of the crate version, the
+/// active detectors with their settings and the engine options that affect
+/// matches. Adding a plugin, changing `--countries`, a severity override or
+/// `--phone-region`, or upgrading pii-radar changes the key, which forces a
+/// full rescan instead of serving stale matches.
+use crate::core::{DetectorRegistry, Match};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+/// Cached matches for one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Scan key the matches were produced with
+    scan_key: String,
+
+    /// File size when scanned
+    size_bytes: u64,
+
+    /// Modification time when scanned (nanoseconds since the Unix epoch)
+    modified_ns: u64,
+
+    matches: Vec<Match>,
+
+    /// Matches dropped by `pii-radar:ignore` comments
+    #[serde(default)]
+    suppressed: usize,
+}
+
+/// Per-file match cache shared by the scan workers
+#[derive(Debug, Default)]
+pub struct ScanCache {
+    entries: Mutex<HashMap<PathBuf, CacheEntry>>,
+}
+
+impl ScanCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a cache file; a missing or unreadable file gives an empty cache
+    pub fn load(path: &Path) -> Self {
+        let entries = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
+        Self {
+            entries: Mutex::new(entries),
+        }
+    }
+
+    /// Write the cache as JSON
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let entries = self.entries.lock().map_err(|e| e.to_string())?;
+        let json = serde_json::to_string(&*entries)
+            .map_err(|e| format!("Failed to serialize cache: {}", e))?;
+
+        std::fs::write(path, json).map_err(|e| format!("Failed to write cache: {}", e))
+    }
+
+    /// Cached matches and suppressed-match count for `path`, if the file is
+    /// unchanged and was scanned with `scan_key`
+    pub fn get(&self, path: &Path, scan_key: &str) -> Option<(Vec<Match>, usize)> {
+        let (size_bytes, modified_ns) = file_stamp(path)?;
+        let entries = self.entries.lock().ok()?;
+        let entry = entries.get(path)?;
+
+        (entry.scan_key == scan_key
+            && entry.size_bytes == size_bytes
+            && entry.modified_ns == modified_ns)
+            .then(|| (entry.matches.clone(), entry.suppressed))
+    }
+
+    /// Record the matches found in `path` with `scan_key`
+    pub fn insert(&self, path: &Path, scan_key: &str, matches: &[Match], suppressed: usize) {
+        let Some((size_bytes, modified_ns)) = file_stamp(path) else {
+            return;
+        };
+
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                path.to_path_buf(),
+                CacheEntry {
+                    scan_key: scan_key.to_string(),
+                    size_bytes,
+                    modified_ns,
+                    matches: matches.to_vec(),
+                    suppressed,
+                },
+            );
+        }
+    }
+
+    /// Number of cached files
+    pub fn len(&self) -> usize {
+        self.entries.lock().map_or(0, |e| e.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Hash of the crate version, the sorted detector IDs with each detector's
+/// [`config_key`](crate::core::Detector::config_key) and `options`
+///
+/// `options` carries engine settings that change the reported matches.
+pub fn scan_key(registry: &DetectorRegistry, options: &str) -> String {
+    let mut ids = registry.list_ids();
+    ids.sort();
+
+    let mut hasher = Sha256::new();
+    hasher.update(crate::VERSION.as_bytes());
+    for id in &ids {
+        hasher.update(b"\0");
+        hasher.update(id.as_bytes());
+        if let Some(detector) = registry.get(id) {
+            hasher.update(b"\0");
+            hasher.update(detector.config_key().as_bytes());
+        }
+    }
+    hasher.update(b"\0");
+    hasher.update(options.as_bytes());
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// File size and modification time
+fn file_stamp(path: &Path) -> Option<(u64, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((metadata.len(), modified.as_nanos() as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::nl::BsnDetector;
+    use crate::detectors::personal::EmailDetector;
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn registry_with(email: bool) -> DetectorRegistry {
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(BsnDetector::new()));
+        if email {
+            registry.register(Box::new(EmailDetector::new()));
+        }
+        registry
+    }
+
+    #[test]
+    fn test_scan_key_changes_with_detectors() {
+        assert_eq!(
+            scan_key(&registry_with(false), ""),
+            scan_key(&registry_with(false), "")
+        );
+        assert_ne!(
+            scan_key(&registry_with(false), ""),
+            scan_key(&registry_with(true), "")
+        );
+        assert_ne!(
+            scan_key(&registry_with(false), "a"),
+            scan_key(&registry_with(false), "b")
+        );
+    }
+
+    #[test]
+    fn test_detector_change_forces_rescan() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("notes.txt");
+        fs::write(&file, "BSN 111222333, mail jan.jansen@example.com\n").unwrap();
+        let cache = Arc::new(ScanCache::new());
+
+        // Registry A: BSN only
+        let engine = ScanEngine::new(registry_with(false))
+            .show_progress(false)
+            .with_cache(cache.clone());
+        assert_eq!(engine.scan_file(&file).matches.len(), 1);
+        assert_eq!(cache.len(), 1);
+
+        // Same registry, unchanged file: served from the cache
+        let key = engine.cache_key();
+        assert_eq!(cache.get(&file, &key).unwrap().0.len(), 1);
+
+        // Registry B adds a detector: A's cached matches must not be reused
+        let engine = ScanEngine::new(registry_with(true))
+            .show_progress(false)
+            .with_cache(cache.clone());
+        let result = engine.scan_file(&file);
+        assert_eq!(result.matches.len(), 2);
+        assert!(result.matches.iter().any(|m| m.detector_id == "email"));
+    }
+
+    #[test]
+    fn test_detector_config_change_forces_rescan() {
+        use crate::core::{Severity, SeverityOverrides};
+        use crate::detectors::personal::PhoneDetector;
+
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("notes.txt");
+        fs::write(&file, "mail jan.jansen@example.com\n").unwrap();
+        let cache = Arc::new(ScanCache::new());
+
+        let scan = |overrides: &SeverityOverrides| {
+            let mut registry = DetectorRegistry::new();
+            registry.register(overrides.wrap(Box::new(EmailDetector::new())));
+            let engine = ScanEngine::new(registry)
+                .show_progress(false)
+                .with_cache(cache.clone());
+            engine.scan_file(&file).matches[0].severity
+        };
+
+        assert_eq!(scan(&SeverityOverrides::new()), Severity::Medium);
+        // Same detector IDs, different override: the cached severity is stale
+        let overrides = SeverityOverrides::new().set("email", Severity::High);
+        assert_eq!(scan(&overrides), Severity::High);
+
+        // Detector settings such as the phone regions are part of the key
+        let phone = |regions: &[&str]| {
+            let mut registry = DetectorRegistry::new();
+            registry.register(Box::new(
+                PhoneDetector::new().regions(regions.iter().map(|r| r.to_string()).collect()),
+            ));
+            scan_key(&registry, "")
+        };
+        assert_eq!(phone(&["nl"]), phone(&["nl"]));
+        assert_ne!(phone(&["nl"]), phone(&["de"]));
+    }
+
+    #[test]
+    fn test_cache_round_trip_and_modified_file() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("notes.txt");
+        let cache_path = tmp.path().join("cache.json");
+        fs::write(&file, "BSN 111222333\n").unwrap();
+
+        let cache = ScanCache::new();
+        let key = scan_key(&registry_with(false), "");
+        cache.insert(&file, &key, &[], 0);
+        cache.save(&cache_path).unwrap();
+
+        let loaded = ScanCache::load(&cache_path);
+        assert_eq!(loaded.get(&file, &key).map(|(m, _)| m.len()), Some(0));
+
+        // A changed file is rescanned
+        fs::write(&file, "BSN 111222333 and 123456782\n").unwrap();
+        assert!(loaded.get(&file, &key).is_none());
+
+        assert!(ScanCache::load(&tmp.path().join("missing.json")).is_empty());
+    }
+}
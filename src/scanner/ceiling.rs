@@ -0,0 +1,115 @@
+/// Per-path severity ceilings
+///
+/// Caps the severity of matches in files matching a glob (e.g. `docs/** ->
+/// medium`), so example data in documentation still shows up in reports but
+/// can't raise critical alerts. Ceilings are applied after context analysis,
+/// so a special-category upgrade can't exceed the cap either.
+use crate::core::{Match, Severity};
+use ignore::overrides::{Override, OverrideBuilder};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Severity ceiling rules, one glob matcher per rule
+#[derive(Debug, Clone, Default)]
+pub struct SeverityCeilings {
+    rules: Vec<(Override, Severity)>,
+}
+
+impl SeverityCeilings {
+    /// Build ceilings from `glob -> max severity` rules, with globs relative to `root`
+    pub fn new(root: &Path, rules: &BTreeMap<String, Severity>) -> Result<Self, ignore::Error> {
+        let rules = rules
+            .iter()
+            .map(|(glob, &severity)| {
+                let mut builder = OverrideBuilder::new(root);
+                builder.add(glob)?;
+                Ok((builder.build()?, severity))
+            })
+            .collect::<Result<_, ignore::Error>>()?;
+
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Lowest ceiling of the rules matching `path`, if any
+    pub fn ceiling_for(&self, path: &Path) -> Option<Severity> {
+        self.rules
+            .iter()
+            .filter(|(glob, _)| glob.matched(path, false).is_whitelist())
+            .map(|&(_, severity)| severity)
+            .min()
+    }
+
+    /// Cap the severity of `matches` found in `path`
+    pub fn apply(&self, path: &Path, matches: &mut [Match]) {
+        if let Some(ceiling) = self.ceiling_for(path) {
+            for m in matches {
+                m.severity = m.severity.min(ceiling);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GdprCategory;
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ceiling_for_glob() {
+        let root = Path::new("/repo");
+        let rules = BTreeMap::from([
+            ("docs/**".to_string(), Severity::Medium),
+            ("*.example".to_string(), Severity::Low),
+        ]);
+        let ceilings = SeverityCeilings::new(root, &rules).unwrap();
+
+        assert_eq!(
+            ceilings.ceiling_for(Path::new("/repo/docs/guide/setup.md")),
+            Some(Severity::Medium)
+        );
+        assert_eq!(
+            ceilings.ceiling_for(Path::new("/repo/docs/env.example")),
+            Some(Severity::Low)
+        );
+        assert_eq!(ceilings.ceiling_for(Path::new("/repo/src/main.rs")), None);
+    }
+
+    #[test]
+    fn test_context_upgrade_capped_under_docs() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("docs")).unwrap();
+        let text = "Patient diagnose diabetes, BSN 111222333\n";
+        fs::write(tmp.path().join("docs").join("example.txt"), text).unwrap();
+        fs::write(tmp.path().join("records.txt"), text).unwrap();
+
+        let rules = BTreeMap::from([("docs/**".to_string(), Severity::Medium)]);
+        let engine = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .severity_ceilings(SeverityCeilings::new(tmp.path(), &rules).unwrap());
+        let results = engine.scan_directory(tmp.path());
+
+        let severity_in = |name: &str| {
+            let file = results
+                .files
+                .iter()
+                .find(|f| f.path.ends_with(name))
+                .unwrap();
+            assert!(matches!(
+                file.matches[0].gdpr_category,
+                GdprCategory::Special { .. }
+            ));
+            file.matches[0].severity
+        };
+
+        // The match is still reported, but capped
+        assert_eq!(severity_in("example.txt"), Severity::Medium);
+        assert_eq!(severity_in("records.txt"), Severity::Critical);
+    }
+}
@@ -0,0 +1,184 @@
+/// Access-log correlation of IP addresses and timestamps
+///
+/// An IP address next to a timestamp records who was online when, which is
+/// personal data under GDPR even where the address alone might not identify
+/// anyone. After detection, `ip_address` matches on a line that also holds a
+/// timestamp are raised to High confidence and at least Medium severity, and
+/// tagged `timestamp` in `correlated_with`, so they survive the default
+/// `--min-confidence high`. The timestamp patterns are configurable; by default
+/// they cover ISO-8601, Apache/Nginx common log format, syslog and
+/// day-first European date-times.
+use crate::core::{Confidence, Match, Severity};
+use regex::Regex;
+
+/// Detector whose matches are correlated
+const IP_DETECTOR_ID: &str = "ip_address";
+
+/// Tag added to `correlated_with` on upgraded matches
+const TIMESTAMP_TAG: &str = "timestamp";
+
+/// Built-in timestamp patterns
+pub const DEFAULT_TIMESTAMP_PATTERNS: &[&str] = &[
+    // ISO-8601: 2024-03-15T10:32:15Z, 2024-03-15 10:32:15.123+01:00
+    r"\b\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}(?::\d{2})?",
+    // Common log format: [15/Mar/2024:10:32:15 +0100]
+    r"\b\d{2}/[A-Za-z]{3}/\d{4}:\d{2}:\d{2}:\d{2}",
+    // Syslog: Mar 15 10:32:15
+    r"\b(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)\s+\d{1,2}\s+\d{2}:\d{2}:\d{2}\b",
+    // Day-first date-time: 15.03.2024 10:32, 15/03/2024 10:32:15
+    r"\b\d{1,2}[./-]\d{1,2}[./-]\d{4},?\s+\d{1,2}:\d{2}(?::\d{2})?\b",
+];
+
+/// IP + timestamp correlation rules
+#[derive(Debug, Clone)]
+pub struct AccessLogCorrelation {
+    timestamp_patterns: Vec<Regex>,
+}
+
+impl AccessLogCorrelation {
+    /// Correlation with custom timestamp regexes instead of the built-ins
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Result<Self, regex::Error> {
+        let timestamp_patterns = patterns
+            .iter()
+            .map(|p| Regex::new(p.as_ref()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { timestamp_patterns })
+    }
+
+    /// Source of the timestamp patterns, part of the scan cache key
+    pub fn cache_key(&self) -> String {
+        self.timestamp_patterns
+            .iter()
+            .map(Regex::as_str)
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    /// Whether `line` holds a timestamp
+    pub fn has_timestamp(&self, line: &str) -> bool {
+        self.timestamp_patterns.iter().any(|p| p.is_match(line))
+    }
+
+    /// Upgrade the IP address matches of `text` that share a line with a timestamp
+    pub fn apply(&self, text: &str, matches: &mut [Match]) {
+        for m in matches
+            .iter_mut()
+            .filter(|m| m.detector_id == IP_DETECTOR_ID)
+        {
+            let start = m.location.start_byte.min(text.len());
+            let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = text[start..].find('\n').map_or(text.len(), |i| start + i);
+
+            if self.has_timestamp(&text[line_start..line_end]) {
+                m.confidence = Confidence::High;
+                m.severity = m.severity.max(Severity::Medium);
+                if !m.correlated_with.iter().any(|tag| tag == TIMESTAMP_TAG) {
+                    m.correlated_with.push(TIMESTAMP_TAG.to_string());
+                }
+            }
+        }
+    }
+}
+
+impl Default for AccessLogCorrelation {
+    fn default() -> Self {
+        Self::new(DEFAULT_TIMESTAMP_PATTERNS).expect("Failed to compile timestamp regexes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Detector;
+    use crate::detectors::personal::IpAddressDetector;
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ip_with_iso_timestamp_upgraded() {
+        let tmp = TempDir::new().unwrap();
+        let log = tmp.path().join("access.log");
+        fs::write(
+            &log,
+            "2024-03-15T10:32:15+01:00 203.0.113.42 GET /account 200\n\
+             upstream 198.51.100.7 marked healthy\n",
+        )
+        .unwrap();
+
+        let result = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .scan_file(&log);
+        let ips: Vec<_> = result
+            .matches
+            .iter()
+            .filter(|m| m.detector_id == "ip_address")
+            .collect();
+
+        assert_eq!(ips.len(), 2);
+        assert_eq!(ips[0].location.line, 1);
+        assert_eq!(ips[0].severity, Severity::Medium);
+        assert_eq!(ips[0].correlated_with, vec!["timestamp"]);
+
+        // Only an IP on the line: not upgraded
+        assert_eq!(ips[1].location.line, 2);
+        assert_eq!(ips[1].severity, Severity::Low);
+        assert!(ips[1].correlated_with.is_empty());
+    }
+
+    #[test]
+    fn test_correlated_ip_kept_at_default_confidence() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("access.log"),
+            "2024-03-15T10:32:15+01:00 203.0.113.42 GET /account 200\n\
+             upstream 198.51.100.7 marked healthy\n",
+        )
+        .unwrap();
+
+        // The CLI drops everything below --min-confidence high by default
+        let results = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .scan_directory(tmp.path())
+            .filter_by_confidence(Confidence::High);
+        let ips: Vec<_> = results
+            .files
+            .iter()
+            .flat_map(|f| &f.matches)
+            .filter(|m| m.detector_id == "ip_address")
+            .collect();
+
+        assert_eq!(ips.len(), 1);
+        assert_eq!(ips[0].location.line, 1);
+        assert_eq!(ips[0].confidence, Confidence::High);
+        assert_eq!(ips[0].correlated_with, vec!["timestamp"]);
+    }
+
+    #[test]
+    fn test_default_log_formats() {
+        let correlation = AccessLogCorrelation::default();
+        assert!(correlation.has_timestamp(
+            r#"203.0.113.42 - - [15/Mar/2024:10:32:15 +0100] "GET / HTTP/1.1" 200"#
+        ));
+        assert!(correlation.has_timestamp("Mar 15 10:32:15 web sshd[812]: from 203.0.113.42"));
+        assert!(correlation.has_timestamp("15.03.2024 10:32 Anmeldung von 203.0.113.42"));
+        assert!(!correlation.has_timestamp("allow 203.0.113.42; # added 15.03.2024"));
+    }
+
+    #[test]
+    fn test_custom_timestamp_pattern() {
+        let text = "ts=1710495135 ip=203.0.113.42\n";
+        let mut matches = IpAddressDetector::new().detect(text, Path::new("app.log"));
+
+        AccessLogCorrelation::default().apply(text, &mut matches);
+        assert_eq!(matches[0].severity, Severity::Low);
+
+        AccessLogCorrelation::new(&[r"\bts=\d{10}\b"])
+            .unwrap()
+            .apply(text, &mut matches);
+        assert_eq!(matches[0].severity, Severity::Medium);
+        assert_eq!(matches[0].correlated_with, vec!["timestamp"]);
+    }
+}
@@ -1,19 +1,94 @@
 /// Multi-threaded scan engine using Rayon for parallel processing
-use crate::core::{ContextAnalyzer, DetectorRegistry, FileResult, GdprCategory, ScanResults};
+use crate::core::{
+    ContextAnalyzer, DetectorRegistry, FileResult, GdprCategory, SampleInfo, ScanContext,
+    ScanErrorCategory, ScanResults,
+};
 use crate::crawler::Walker;
 use crate::extractors::ExtractorRegistry;
-use indicatif::{ProgressBar, ProgressStyle};
+use crate::scanner::cache::{scan_key, ScanCache};
+use crate::scanner::ceiling::SeverityCeilings;
+use crate::scanner::correlate::AccessLogCorrelation;
+use crate::scanner::progress::ScanProgress;
+use crate::scanner::scope::DetectorPathScope;
+use crate::scanner::stats::DetectorStats;
+use crate::scanner::suppress::Suppressions;
+use crate::scanner::window::{
+    detect_windowed, detect_windowed_parallel, needs_window, DEFAULT_WINDOW_SIZE,
+};
+use crate::utils::normalize_text;
+use indicatif::HumanBytes;
+use once_cell::sync::Lazy;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use rayon::prelude::*;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
+/// Describe a file read error, prefixed so it can be categorized later
+fn read_error_message(e: &std::io::Error) -> String {
+    match e.kind() {
+        std::io::ErrorKind::PermissionDenied => format!("Permission denied: {}", e),
+        std::io::ErrorKind::InvalidData => format!("Failed to decode file: {}", e),
+        _ => format!("Failed to read file: {}", e),
+    }
+}
+
+/// Default file size (bytes) above which `Hybrid` scans a file in parallel chunks
+pub const DEFAULT_INTRA_FILE_THRESHOLD: usize = 8 * 1024 * 1024;
+
+/// Default file size (bytes) below which `--dedup-files` doesn't hash files
+///
+/// Hashing a tiny file costs about as much as scanning it.
+pub const DEFAULT_DEDUP_MIN_SIZE: u64 = 4 * 1024;
+
+/// Pick `percent` of `files` (rounded up) at random, keeping their order
+fn sample_files(files: &[PathBuf], percent: u8, seed: u64) -> Vec<PathBuf> {
+    let amount = (files.len() * percent as usize).div_ceil(100);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut picked = rand::seq::index::sample(&mut rng, files.len(), amount).into_vec();
+    picked.sort_unstable();
+
+    picked.into_iter().map(|i| files[i].clone()).collect()
+}
+
+/// How scan work is spread over threads
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParallelismMode {
+    /// One file per thread (best for many small files)
+    #[default]
+    PerFile,
+    /// Files one at a time, each split into chunks scanned in parallel
+    IntraFile,
+    /// Per-file parallelism, chunking files above the intra-file threshold
+    Hybrid,
+}
+
 pub struct ScanEngine {
     registry: Arc<DetectorRegistry>,
     context_analyzer: Arc<ContextAnalyzer>,
     extractor_registry: Option<Arc<ExtractorRegistry>>,
     enable_context: bool,
     show_progress: bool,
+    detector_stats: Option<Arc<DetectorStats>>,
+    normalize: bool,
+    window_size: usize,
+    cache: Option<Arc<ScanCache>>,
+    severity_ceilings: SeverityCeilings,
+    access_log_correlation: AccessLogCorrelation,
+    detector_path_scope: DetectorPathScope,
+    parallelism: ParallelismMode,
+    intra_file_threshold: usize,
+    cancel: Option<Arc<AtomicBool>>,
+    threads: Option<usize>,
+    dedup_files: bool,
+    dedup_min_size: u64,
+    hash_files: bool,
+    context: ScanContext,
+    sample: Option<(u8, u64)>,
+    max_files: Option<usize>,
+    max_bytes: Option<u64>,
 }
 
 impl ScanEngine {
@@ -24,6 +99,24 @@ impl ScanEngine {
             extractor_registry: None,
             enable_context: true,
             show_progress: true,
+            detector_stats: None,
+            normalize: false,
+            window_size: DEFAULT_WINDOW_SIZE,
+            cache: None,
+            severity_ceilings: SeverityCeilings::default(),
+            access_log_correlation: AccessLogCorrelation::default(),
+            detector_path_scope: DetectorPathScope::default(),
+            parallelism: ParallelismMode::default(),
+            intra_file_threshold: DEFAULT_INTRA_FILE_THRESHOLD,
+            cancel: None,
+            threads: None,
+            dedup_files: false,
+            dedup_min_size: DEFAULT_DEDUP_MIN_SIZE,
+            hash_files: false,
+            context: ScanContext::default(),
+            sample: None,
+            max_files: None,
+            max_bytes: None,
         }
     }
 
@@ -42,6 +135,203 @@ impl ScanEngine {
         self
     }
 
+    /// Record per-detector timing and match counts into `stats`
+    pub fn with_detector_stats(mut self, stats: Arc<DetectorStats>) -> Self {
+        self.detector_stats = Some(stats);
+        self
+    }
+
+    /// Strip zero-width characters and map homoglyphs to ASCII before detection
+    ///
+    /// Reported locations still point into the original text.
+    pub fn normalize(mut self, enable: bool) -> Self {
+        self.normalize = enable;
+        self
+    }
+
+    /// Scan files whose longest line exceeds `bytes` in overlapping windows of that size
+    ///
+    /// Keeps single-line dumps from being handed to detectors as one huge line.
+    pub fn window_size(mut self, bytes: usize) -> Self {
+        self.window_size = bytes;
+        self
+    }
+
+    /// Reuse matches from `cache` for unchanged files and record new results in it
+    pub fn with_cache(mut self, cache: Arc<ScanCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Cap match severities per path glob, after context analysis
+    pub fn severity_ceilings(mut self, ceilings: SeverityCeilings) -> Self {
+        self.severity_ceilings = ceilings;
+        self
+    }
+
+    /// Timestamp patterns for raising IP addresses on access-log lines
+    pub fn access_log_correlation(mut self, correlation: AccessLogCorrelation) -> Self {
+        self.access_log_correlation = correlation;
+        self
+    }
+
+    /// Run scoped detectors only on files matching their globs
+    pub fn detector_path_scope(mut self, scope: DetectorPathScope) -> Self {
+        self.detector_path_scope = scope;
+        self
+    }
+
+    /// Spread work per file, within files, or both (see [`ParallelismMode`])
+    pub fn parallelism(mut self, mode: ParallelismMode) -> Self {
+        self.parallelism = mode;
+        self
+    }
+
+    /// File size (bytes) above which `Hybrid` mode chunks a file
+    pub fn intra_file_threshold(mut self, bytes: usize) -> Self {
+        self.intra_file_threshold = bytes;
+        self
+    }
+
+    /// Scan-wide settings handed to every detector (e.g. the `--locale`)
+    pub fn scan_context(mut self, context: ScanContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Scan files with identical content only once
+    ///
+    /// Files of at least [`DEFAULT_DEDUP_MIN_SIZE`] bytes are hashed first;
+    /// copies reuse the matches of the first file with the same hash.
+    pub fn dedup_files(mut self, enable: bool) -> Self {
+        self.dedup_files = enable;
+        self
+    }
+
+    /// Record the SHA-256 of each scanned file in [`FileResult::content_hash`]
+    pub fn hash_files(mut self, enable: bool) -> Self {
+        self.hash_files = enable;
+        self
+    }
+
+    /// Set the file size (bytes) below which files are not deduplicated
+    pub fn dedup_min_size(mut self, bytes: u64) -> Self {
+        self.dedup_min_size = bytes;
+        self
+    }
+
+    /// Content hash of `path` for deduplication, `None` for small or unreadable files
+    ///
+    /// The file extension, the severity ceiling and the detectors scoped out
+    /// of the path are part of the key: the extension picks the extractor,
+    /// and matches copied to a path under a different ceiling or scope would
+    /// be wrong there.
+    fn dedup_key(&self, path: &Path) -> Option<String> {
+        use sha2::{Digest, Sha256};
+
+        if std::fs::metadata(path).ok()?.len() < self.dedup_min_size {
+            return None;
+        }
+
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut std::fs::File::open(path).ok()?, &mut hasher).ok()?;
+        let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase());
+        hasher.update(format!("{:?}", extension).as_bytes());
+        hasher.update(format!("{:?}", self.severity_ceilings.ceiling_for(path)).as_bytes());
+        hasher.update(format!("{:?}", self.detector_path_scope.excluded(path)).as_bytes());
+
+        Some(
+            hasher
+                .finalize()
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect(),
+        )
+    }
+
+    /// Stop starting new files once `flag` is set
+    ///
+    /// Files already being scanned finish, so the results are partial but
+    /// complete per file.
+    pub fn cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(flag);
+        self
+    }
+
+    /// Limit scan concurrency to `threads` worker threads
+    ///
+    /// Scans then run in a dedicated Rayon pool instead of the global one.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Scan a random `percent` (1-100) of the files, picked with `seed`
+    ///
+    /// The results carry a [`SampleInfo`] so totals can be extrapolated with
+    /// [`ScanResults::estimated_total`]. The same seed over the same file list
+    /// scans the same files.
+    pub fn sample(mut self, percent: u8, seed: u64) -> Self {
+        self.sample = Some((percent.clamp(1, 100), seed));
+        self
+    }
+
+    /// Stop starting new files once `files` have been scanned
+    ///
+    /// Like cancellation, the results are partial but complete per file;
+    /// they are marked with [`ScanResults::limit_truncated`]. Copies reported
+    /// through [`dedup_files`](Self::dedup_files) count toward the limit too.
+    /// They are not read again, so they don't count toward
+    /// [`max_bytes`](Self::max_bytes).
+    pub fn max_files(mut self, files: usize) -> Self {
+        self.max_files = Some(files);
+        self
+    }
+
+    /// Stop starting new files once `bytes` have been scanned
+    ///
+    /// A file that crosses the limit is still scanned in full.
+    pub fn max_bytes(mut self, bytes: u64) -> Self {
+        self.max_bytes = Some(bytes);
+        self
+    }
+
+    /// Whether the cancellation flag has been set
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+
+    /// Cache key for this engine's detector set and match-affecting options
+    pub fn cache_key(&self) -> String {
+        let options = format!(
+            "context={} normalize={} window={} extractors={} parallelism={:?} locale={} strictness={:?} timestamps={}",
+            self.enable_context,
+            self.normalize,
+            self.window_size,
+            self.extractor_registry.is_some(),
+            self.parallelism,
+            self.context.locale.tag,
+            self.context.strictness,
+            self.access_log_correlation.cache_key()
+        );
+        scan_key(&self.registry, &options)
+    }
+
+    /// Read a file as UTF-8 text, hashing the bytes read when enabled
+    ///
+    /// Invalid UTF-8 is reported as [`InvalidData`](std::io::ErrorKind::InvalidData),
+    /// like [`std::fs::read_to_string`]; the hash is still recorded.
+    fn read_text(&self, path: &Path, result: &mut FileResult) -> std::io::Result<String> {
+        let bytes = std::fs::read(path)?;
+        if self.hash_files {
+            result.content_hash = Some(super::manifest::sha256_hex(&bytes));
+        }
+        String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
     /// Scan a single file
     pub fn scan_file(&self, path: &Path) -> FileResult {
         let start = Instant::now();
@@ -52,57 +342,158 @@ impl ScanEngine {
             result.size_bytes = metadata.len();
         }
 
+        // Detectors scoped out of this file are part of its cache key
+        let cache_key = self.cache.as_ref().map(|_| {
+            let mut key = self.cache_key();
+            for id in self.detector_path_scope.excluded(path) {
+                key.push('-');
+                key.push_str(id);
+            }
+            key
+        });
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some((matches, suppressed)) = cache.get(path, key) {
+                if self.hash_files {
+                    result.content_hash = super::manifest::hash_file(path);
+                }
+                result.matches = matches;
+                result.suppressed = suppressed;
+                self.severity_ceilings.apply(path, &mut result.matches);
+                result.scan_time_ms = start.elapsed().as_millis() as u64;
+                return result;
+            }
+        }
+
         // Try to extract text from document formats if extractors are enabled
+        let mut source_map = None;
         let content = if let Some(ref extractors) = self.extractor_registry {
             // Check if this is a document format we can extract from
             if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
                 if let Some(extractor) = extractors.get_by_extension(extension) {
+                    // Extractors read the file themselves
+                    if self.hash_files {
+                        result.content_hash = super::manifest::hash_file(path);
+                    }
+
                     // Try to extract text
-                    match extractor.extract(path) {
-                        Ok(extracted_text) => {
+                    match extractor.extract_mapped(path) {
+                        Ok(extraction) => {
                             // Successfully extracted, use extracted text
-                            extracted_text
+                            source_map = Some(extraction.source_map);
+                            extraction.text
                         }
                         Err(e) => {
                             // Extraction failed, record error and return
-                            result.error = Some(format!("Extraction failed: {}", e));
+                            result.set_error(
+                                ScanErrorCategory::Extraction,
+                                format!("Extraction failed: {}", e),
+                            );
                             return result;
                         }
                     }
                 } else {
                     // Not a document format, read as plain text
-                    match std::fs::read_to_string(path) {
+                    match self.read_text(path, &mut result) {
                         Ok(c) => c,
                         Err(e) => {
-                            result.error = Some(format!("Failed to read file: {}", e));
+                            result
+                                .set_error(ScanErrorCategory::from_io(&e), read_error_message(&e));
                             return result;
                         }
                     }
                 }
             } else {
                 // No extension, try reading as text
-                match std::fs::read_to_string(path) {
+                match self.read_text(path, &mut result) {
                     Ok(c) => c,
                     Err(e) => {
-                        result.error = Some(format!("Failed to read file: {}", e));
+                        result.set_error(ScanErrorCategory::from_io(&e), read_error_message(&e));
                         return result;
                     }
                 }
             }
         } else {
             // No extractors enabled, read as plain text
-            match std::fs::read_to_string(path) {
+            match self.read_text(path, &mut result) {
                 Ok(c) => c,
                 Err(e) => {
-                    result.error = Some(format!("Failed to read file: {}", e));
+                    result.set_error(ScanErrorCategory::from_io(&e), read_error_message(&e));
                     return result;
                 }
             }
         };
 
-        // Run all detectors
-        for detector in self.registry.all() {
-            let mut matches = detector.detect(&content, path);
+        let normalized = if self.normalize {
+            normalize_text(&content)
+        } else {
+            None
+        };
+        let detect_text = normalized.as_ref().map_or(content.as_str(), |n| &n.text);
+        let chunked = match self.parallelism {
+            ParallelismMode::PerFile => false,
+            ParallelismMode::IntraFile => true,
+            ParallelismMode::Hybrid => detect_text.len() > self.intra_file_threshold,
+        };
+        let windowed = needs_window(detect_text, self.window_size);
+
+        let detect = |detector: &dyn crate::core::Detector| {
+            let detect_start = Instant::now();
+            let matches = if detector.multiline() {
+                // Cross-line records need the whole document in one piece
+                detector.detect_in_context(detect_text, path, &self.context)
+            } else if chunked {
+                // Chunks are windows, so they share the long-line window size
+                detect_windowed_parallel(
+                    detector,
+                    detect_text,
+                    path,
+                    &self.context,
+                    self.window_size,
+                )
+            } else if windowed {
+                detect_windowed(detector, detect_text, path, &self.context, self.window_size)
+            } else {
+                detector.detect_in_context(detect_text, path, &self.context)
+            };
+            (matches, detect_start.elapsed())
+        };
+
+        // Detectors with a path scope only run on matching files
+        let detectors: Vec<&dyn crate::core::Detector> = self
+            .registry
+            .all()
+            .iter()
+            .map(|d| d.as_ref())
+            .filter(|d| self.detector_path_scope.allows(d.id(), path))
+            .collect();
+
+        // Run all detectors, in parallel for chunked files
+        let detected: Vec<_> = if chunked {
+            detectors.par_iter().map(|&d| detect(d)).collect()
+        } else {
+            detectors.iter().map(|&d| detect(d)).collect()
+        };
+
+        for (detector, (mut matches, elapsed)) in detectors.iter().zip(detected) {
+            if let Some(ref stats) = self.detector_stats {
+                stats.record(detector.id(), elapsed, matches.len());
+            }
+
+            // Map locations found in normalized text back to the original
+            if let Some(ref normalized) = normalized {
+                for m in &mut matches {
+                    m.location.start_byte = normalized.original_start(m.location.start_byte);
+                    m.location.end_byte = normalized.original_end(m.location.end_byte);
+                    let line_start = content[..m.location.start_byte]
+                        .rfind('\n')
+                        .map_or(0, |i| i + 1);
+                    m.location.column = m.location.start_byte - line_start;
+                }
+            }
+
+            for m in &mut matches {
+                m.fingerprint = m.compute_fingerprint(&content);
+            }
 
             // Apply context analysis if enabled
             if self.enable_context {
@@ -128,21 +519,129 @@ impl ScanEngine {
             result.matches.extend(matches);
         }
 
+        // A value matched by e.g. `phone` and `dk_phone` is reported by the specific one
+        super::supersede::drop_superseded(&mut result.matches, &detectors);
+
+        // Raise IP addresses that share a line with a timestamp
+        self.access_log_correlation
+            .apply(&content, &mut result.matches);
+
+        // Unlabeled names count when another finding is next to them
+        if let Some(&names) = detectors
+            .iter()
+            .find(|d| d.id() == super::names::NAME_DETECTOR_ID)
+        {
+            let found = super::names::names_near_findings(&content, &result.matches, names, path);
+            result.matches.extend(found);
+        }
+
+        // IBANs next to a name and an amount are reported as financial records
+        let records = super::financial::financial_records(&content, &result.matches);
+        result.matches.extend(records);
+
+        // Drop matches on lines marked with a `pii-radar:ignore` comment
+        result.suppressed = Suppressions::parse(&content).apply(&mut result.matches);
+
+        // Point matches in extracted text at their source line and property
+        if let Some(source_map) = source_map {
+            source_map.map_matches(&mut result.matches);
+        }
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            cache.insert(path, key, &result.matches, result.suppressed);
+        }
+
+        self.severity_ceilings.apply(path, &mut result.matches);
+
         result.scan_time_ms = start.elapsed().as_millis() as u64;
         result
     }
 
+    /// Compile the patterns of all registered detectors up front
+    ///
+    /// Also compiles the engine's own patterns (inline suppressions, financial
+    /// records). [`scan_walker`](Self::scan_walker) calls this before starting
+    /// its timer, so the first file does not pay for pattern compilation.
+    pub fn warmup(&self) {
+        self.registry.warmup();
+        Lazy::force(&super::suppress::IGNORE_PATTERN);
+        Lazy::force(&super::financial::NAME_LABEL_PATTERN);
+        Lazy::force(&super::financial::AMOUNT_PATTERN);
+    }
+
     /// Scan entire directory (parallel)
     pub fn scan_directory(&self, root: &Path) -> ScanResults {
+        self.scan_walker(&Walker::new(root))
+    }
+
+    /// Scan the files discovered by a configured walker (parallel)
+    pub fn scan_walker(&self, walker: &Walker) -> ScanResults {
+        self.warmup();
         let overall_start = Instant::now();
 
         println!("🔍 Discovering files...");
 
-        // Discover all files
-        let walker = Walker::new(root);
-        let files = walker.walk_parallel();
+        // Discover all files, summing their sizes for the progress ETA
+        let (files, total_bytes) = walker.walk_parallel_with_size();
+
+        println!(
+            "📁 Found {} files ({})",
+            files.len(),
+            HumanBytes(total_bytes)
+        );
+
+        let mut scan_results = self.scan_discovered(&files, Some(total_bytes));
+        scan_results.total_time_ms = overall_start.elapsed().as_millis() as u64;
+        scan_results
+    }
+
+    /// Scan an explicit list of files (parallel), bypassing directory discovery
+    pub fn scan_paths(&self, files: &[PathBuf]) -> ScanResults {
+        self.scan_discovered(files, None)
+    }
+
+    /// Scan `files`, whose total size is `total_bytes` when already known
+    fn scan_discovered(&self, files: &[PathBuf], total_bytes: Option<u64>) -> ScanResults {
+        let Some((percent, seed)) = self.sample else {
+            return self.scan_paths_with_threads(files, total_bytes);
+        };
+
+        let sampled = sample_files(files, percent, seed);
+        println!(
+            "🎲 Sampling {} of {} files ({}%, seed {})",
+            sampled.len(),
+            files.len(),
+            percent,
+            seed
+        );
+
+        let mut results = self.scan_paths_with_threads(&sampled, None);
+        results.sample = Some(SampleInfo {
+            percent,
+            seed,
+            discovered_files: files.len(),
+        });
+        results
+    }
+
+    fn scan_paths_with_threads(&self, files: &[PathBuf], total_bytes: Option<u64>) -> ScanResults {
+        let Some(threads) = self.threads else {
+            return self.scan_paths_in_pool(files, total_bytes);
+        };
+
+        match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+            Ok(pool) => pool.install(|| self.scan_paths_in_pool(files, total_bytes)),
+            Err(e) => {
+                eprintln!("⚠️  Warning: Failed to build thread pool: {}", e);
+                self.scan_paths_in_pool(files, total_bytes)
+            }
+        }
+    }
+
+    /// Scan files on the current Rayon pool
+    fn scan_paths_in_pool(&self, files: &[PathBuf], total_bytes: Option<u64>) -> ScanResults {
+        let overall_start = Instant::now();
 
-        println!("📁 Found {} files", files.len());
         println!(
             "🚀 Scanning with {} threads...\n",
             rayon::current_num_threads()
@@ -153,69 +652,164 @@ impl ScanEngine {
         let failure_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let matches_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
 
-        // Create progress bar if enabled
-        let progress = if self.show_progress {
-            let pb = ProgressBar::new(files.len() as u64);
-            pb.set_style(
-                ProgressStyle::default_bar()
-                    .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} files ({per_sec}) | {msg}")
-                    .unwrap()
-                    .progress_chars("█▓▒░  "),
-            );
-            pb.set_message("Scanning...");
-            Some(pb)
+        // With dedup, only the first file of each content hash is scanned
+        let duplicate_of: Vec<Option<usize>> = if self.dedup_files {
+            let keys: Vec<Option<String>> =
+                files.par_iter().map(|path| self.dedup_key(path)).collect();
+            let mut first_seen = std::collections::HashMap::new();
+            keys.into_iter()
+                .enumerate()
+                .map(|(i, key)| {
+                    let first = *first_seen.entry(key?).or_insert(i);
+                    (first != i).then_some(first)
+                })
+                .collect()
         } else {
-            None
+            vec![None; files.len()]
         };
 
-        // Scan files in parallel
-        let results: Vec<FileResult> = files
-            .par_iter()
-            .map(|path| {
-                // Check if this file will be extracted
-                if let Some(ref extractors) = self.extractor_registry {
-                    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                        if extractors.get_by_extension(ext).is_some() {
-                            // This file will attempt extraction
-                            extracted_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-                        }
-                    }
+        // Progress counts the bytes of the files that are actually scanned
+        let has_duplicates = duplicate_of.iter().any(Option::is_some);
+        let total_bytes = match total_bytes {
+            Some(total) if !has_duplicates => total,
+            _ if !self.show_progress => 0,
+            _ => files
+                .par_iter()
+                .zip(&duplicate_of)
+                .filter(|(_, original)| original.is_none())
+                .map(|(path, _)| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0))
+                .sum(),
+        };
+        let progress = ScanProgress::new(files.len(), total_bytes, self.show_progress);
+
+        // --max-files / --max-bytes: each file reserves its share up front
+        let started_files = std::sync::atomic::AtomicUsize::new(0);
+        let started_bytes = std::sync::atomic::AtomicU64::new(0);
+        let limit_reached = AtomicBool::new(false);
+        let within_limits = |path: &Path| {
+            if limit_reached.load(Ordering::Relaxed) {
+                return false;
+            }
+            if let Some(max) = self.max_files {
+                if started_files.fetch_add(1, Ordering::Relaxed) >= max {
+                    limit_reached.store(true, Ordering::Relaxed);
+                    return false;
                 }
-
-                let result = self.scan_file(path);
-
-                // Track matches
-                if !result.matches.is_empty() {
-                    matches_count
-                        .fetch_add(result.matches.len(), std::sync::atomic::Ordering::Relaxed);
+            }
+            if let Some(max) = self.max_bytes {
+                let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                if started_bytes.fetch_add(size, Ordering::Relaxed) >= max {
+                    limit_reached.store(true, Ordering::Relaxed);
+                    return false;
                 }
+            }
+            true
+        };
+
+        // Scan files in parallel, or one at a time when each file is chunked.
+        // Once cancelled or over a limit, files that haven't started yet are skipped.
+        let scan_one = |path: &PathBuf| {
+            if self.is_cancelled() || !within_limits(path) {
+                return None;
+            }
 
-                // Check if extraction failed
-                if let Some(ref err_msg) = result.error {
-                    if err_msg.contains("Extraction failed") {
-                        failure_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            // Check if this file will be extracted
+            if let Some(ref extractors) = self.extractor_registry {
+                if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+                    if extractors.get_by_extension(ext).is_some() {
+                        // This file will attempt extraction
+                        extracted_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                     }
                 }
+            }
 
-                // Update progress bar
-                if let Some(ref pb) = progress {
-                    pb.inc(1);
-                    let current_matches = matches_count.load(std::sync::atomic::Ordering::Relaxed);
-                    if current_matches > 0 {
-                        pb.set_message(format!("🔴 {} PII matches found", current_matches));
-                    } else {
-                        pb.set_message("✅ No PII found yet");
-                    }
+            let result = self.scan_file(path);
+
+            // Track matches
+            if !result.matches.is_empty() {
+                matches_count.fetch_add(result.matches.len(), std::sync::atomic::Ordering::Relaxed);
+            }
+
+            // Check if extraction failed
+            if let Some(ref err_msg) = result.error {
+                if err_msg.contains("Extraction failed") {
+                    failure_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                 }
+            }
 
-                result
+            // Update progress bar
+            progress.advance(
+                1,
+                result.size_bytes,
+                matches_count.load(std::sync::atomic::Ordering::Relaxed),
+            );
+
+            Some(result)
+        };
+        let unique = (0..files.len()).filter(|&i| duplicate_of[i].is_none());
+        let scan_indexed = |i: usize| scan_one(&files[i]).map(|result| (i, result));
+        let mut results: Vec<(usize, FileResult)> =
+            if self.parallelism == ParallelismMode::IntraFile {
+                unique.filter_map(scan_indexed).collect()
+            } else {
+                unique
+                    .collect::<Vec<_>>()
+                    .into_par_iter()
+                    .filter_map(scan_indexed)
+                    .collect()
+            };
+
+        // Copies reuse the matches of their scanned original, re-pathed
+        let scanned: std::collections::HashMap<usize, &FileResult> =
+            results.iter().map(|(i, result)| (*i, result)).collect();
+        let mut copies: Vec<(usize, FileResult)> = duplicate_of
+            .iter()
+            .enumerate()
+            .filter_map(|(i, original)| {
+                let mut copy = (*scanned.get(&(*original)?)?).clone();
+                copy.path = files[i].clone();
+                copy.scan_time_ms = 0;
+                for m in &mut copy.matches {
+                    m.location.file_path = files[i].clone();
+                }
+                Some((i, copy))
             })
             .collect();
+        if let Some(max) = self.max_files {
+            let room = max.saturating_sub(results.len());
+            if copies.len() > room {
+                copies.truncate(room);
+                limit_reached.store(true, Ordering::Relaxed);
+            }
+        }
+        let duplicate_files = copies.len();
+        matches_count.fetch_add(
+            copies.iter().map(|(_, copy)| copy.matches.len()).sum(),
+            std::sync::atomic::Ordering::Relaxed,
+        );
+        progress.advance(
+            duplicate_files,
+            0,
+            matches_count.load(std::sync::atomic::Ordering::Relaxed),
+        );
+        results.extend(copies);
+        results.sort_by_key(|(i, _)| *i);
+        let results: Vec<FileResult> = results.into_iter().map(|(_, result)| result).collect();
 
         // Finish progress bar
-        if let Some(pb) = progress {
+        if let Some(pb) = progress.bar() {
             let final_matches = matches_count.load(std::sync::atomic::Ordering::Relaxed);
-            if final_matches > 0 {
+            if self.is_cancelled() {
+                pb.abandon_with_message(format!(
+                    "⚠️  Scan interrupted - {} PII matches found so far",
+                    final_matches
+                ));
+            } else if limit_reached.load(Ordering::Relaxed) {
+                pb.abandon_with_message(format!(
+                    "⚠️  Scan limit reached - {} PII matches found so far",
+                    final_matches
+                ));
+            } else if final_matches > 0 {
                 pb.finish_with_message(format!(
                     "🔴 Scan complete - {} PII matches found",
                     final_matches
@@ -232,6 +826,8 @@ impl ScanEngine {
         // Update extraction statistics
         scan_results.extracted_files = extracted_count.load(std::sync::atomic::Ordering::Relaxed);
         scan_results.extraction_failures = failure_count.load(std::sync::atomic::Ordering::Relaxed);
+        scan_results.duplicate_files = duplicate_files;
+        scan_results.limit_truncated = limit_reached.load(Ordering::Relaxed);
 
         scan_results
     }
@@ -257,6 +853,16 @@ mod tests {
         assert_eq!(result.matches[0].detector_id, "nl_bsn");
     }
 
+    #[test]
+    fn test_warmup_compiles_engine_patterns() {
+        ScanEngine::new(DetectorRegistry::new()).warmup();
+
+        // Reached only on lines with an ignore comment or near an IBAN
+        assert!(Lazy::get(&crate::scanner::suppress::IGNORE_PATTERN).is_some());
+        assert!(Lazy::get(&crate::scanner::financial::NAME_LABEL_PATTERN).is_some());
+        assert!(Lazy::get(&crate::scanner::financial::AMOUNT_PATTERN).is_some());
+    }
+
     #[test]
     fn test_scan_file_with_context() {
         let registry = crate::default_registry();
@@ -280,6 +886,496 @@ mod tests {
         assert_eq!(result.matches[0].severity, crate::core::Severity::Critical);
     }
 
+    #[test]
+    fn test_sample_scans_deterministic_subset() {
+        let tmp = TempDir::new().unwrap();
+        let files: Vec<PathBuf> = (0..20)
+            .map(|i| {
+                let path = tmp.path().join(format!("file{:02}.txt", i));
+                fs::write(&path, "Patient BSN: 111222333").unwrap();
+                path
+            })
+            .collect();
+
+        let scanned = |seed| {
+            let engine = ScanEngine::new(crate::default_registry())
+                .show_progress(false)
+                .sample(25, seed);
+            let results = engine.scan_paths(&files);
+            let paths: Vec<PathBuf> = results.files.iter().map(|f| f.path.clone()).collect();
+            (results, paths)
+        };
+
+        let (results, first) = scanned(42);
+        let (_, second) = scanned(42);
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, second);
+        assert!(first.iter().all(|path| files.contains(path)));
+
+        assert_eq!(
+            results.sample,
+            Some(SampleInfo {
+                percent: 25,
+                seed: 42,
+                discovered_files: 20
+            })
+        );
+        // Every file has one BSN, so the sample extrapolates to all 20
+        assert_eq!(results.total_matches, 5);
+        assert_eq!(results.estimated_total(results.total_matches), Some(20));
+    }
+
+    #[test]
+    fn test_fingerprint_stable_across_blank_lines() {
+        let registry = crate::default_registry();
+        let engine = ScanEngine::new(registry);
+
+        let tmp = TempDir::new().unwrap();
+        let file_path = tmp.path().join("test.txt");
+
+        fs::write(&file_path, "header\nBSN: 111222333\n").unwrap();
+        let before = engine.scan_file(&file_path);
+
+        fs::write(&file_path, "header\n\n   \nBSN:  111222333\n").unwrap();
+        let after = engine.scan_file(&file_path);
+
+        assert_eq!(before.matches.len(), 1);
+        assert_eq!(after.matches.len(), 1);
+        assert_ne!(
+            before.matches[0].location.line,
+            after.matches[0].location.line
+        );
+        assert!(!before.matches[0].fingerprint.is_empty());
+        assert_eq!(before.matches[0].fingerprint, after.matches[0].fingerprint);
+    }
+
+    fn email_matches(result: &FileResult) -> Vec<&crate::core::Match> {
+        result
+            .matches
+            .iter()
+            .filter(|m| m.detector_id == "email")
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize_zero_width_email() {
+        let tmp = TempDir::new().unwrap();
+        let file_path = tmp.path().join("test.txt");
+        let content = "Contact:\nmail j\u{200B}ohn.doe@exa\u{200D}mple.com today\n";
+        fs::write(&file_path, content).unwrap();
+
+        let plain = ScanEngine::new(crate::default_registry()).scan_file(&file_path);
+        assert!(email_matches(&plain)
+            .iter()
+            .all(|m| &content[m.location.start_byte..m.location.end_byte]
+                != "j\u{200B}ohn.doe@exa\u{200D}mple.com"));
+
+        let engine = ScanEngine::new(crate::default_registry()).normalize(true);
+        let result = engine.scan_file(&file_path);
+        let emails = email_matches(&result);
+        assert_eq!(emails.len(), 1);
+
+        let location = &emails[0].location;
+        assert_eq!(
+            &content[location.start_byte..location.end_byte],
+            "j\u{200B}ohn.doe@exa\u{200D}mple.com"
+        );
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 5);
+    }
+
+    #[test]
+    fn test_normalize_homoglyph_domain() {
+        let tmp = TempDir::new().unwrap();
+        let file_path = tmp.path().join("test.txt");
+        // Cyrillic 'а' and 'е' in the domain
+        let content = "login: admin@p\u{0430}ypal-s\u{0435}cure.com\n";
+        fs::write(&file_path, content).unwrap();
+
+        let engine = ScanEngine::new(crate::default_registry()).normalize(true);
+        let result = engine.scan_file(&file_path);
+        let emails = email_matches(&result);
+        assert_eq!(emails.len(), 1);
+
+        let location = &emails[0].location;
+        assert_eq!(location.start_byte, 7);
+        assert_eq!(location.end_byte, content.len() - 1);
+        assert_eq!(
+            &content[location.start_byte..location.end_byte],
+            "admin@p\u{0430}ypal-s\u{0435}cure.com"
+        );
+    }
+
+    #[test]
+    fn test_scan_walker_with_include() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("db")).unwrap();
+        fs::write(tmp.path().join("db").join("users.sql"), "BSN: 111222333\n").unwrap();
+        fs::write(tmp.path().join("notes.txt"), "BSN: 111222333\n").unwrap();
+        fs::write(tmp.path().join("config.yml"), "BSN: 111222333\n").unwrap();
+
+        let walker = Walker::new(tmp.path())
+            .include(&["**/*.sql".to_string()])
+            .unwrap();
+        let engine = ScanEngine::new(crate::default_registry()).show_progress(false);
+        let results = engine.scan_walker(&walker);
+
+        assert_eq!(results.total_files, 1);
+        assert!(results.files[0].path.ends_with("db/users.sql"));
+        assert_eq!(results.total_matches, 1);
+    }
+
+    #[test]
+    fn test_hybrid_parallelism_huge_and_small_files() {
+        let tmp = TempDir::new().unwrap();
+        let mut huge = String::new();
+        for i in 0..10_000 {
+            if i % 250 == 0 {
+                huge.push_str("record BSN 111222333 and jan.jansen@example.com\n");
+            } else {
+                huge.push_str("lorem ipsum dolor sit amet, consectetur adipiscing\n");
+            }
+        }
+        let huge_path = tmp.path().join("huge.txt");
+        fs::write(&huge_path, &huge).unwrap();
+
+        let mut paths = vec![huge_path.clone()];
+        for i in 0..50 {
+            let path = tmp.path().join(format!("small_{}.txt", i));
+            fs::write(&path, "Patient BSN: 111222333\n").unwrap();
+            paths.push(path);
+        }
+
+        let engine = |mode| {
+            ScanEngine::new(crate::default_registry())
+                .show_progress(false)
+                .enable_context(false)
+                .parallelism(mode)
+                .intra_file_threshold(64 * 1024)
+        };
+        let hybrid = engine(ParallelismMode::Hybrid).scan_paths(&paths);
+        let per_file = engine(ParallelismMode::PerFile).scan_paths(&paths);
+
+        assert_eq!(hybrid.total_files, 51);
+        assert_eq!(hybrid.total_matches, 40 * 2 + 50);
+
+        let locations = |results: &ScanResults, path: &Path| {
+            let file = results.files.iter().find(|f| f.path == path).unwrap();
+            let mut locations: Vec<_> = file
+                .matches
+                .iter()
+                .map(|m| {
+                    (
+                        m.detector_id.clone(),
+                        m.location.line,
+                        m.location.start_byte,
+                    )
+                })
+                .collect();
+            locations.sort();
+            locations
+        };
+        let huge_matches = locations(&hybrid, &huge_path);
+        assert_eq!(huge_matches.len(), 80);
+        assert_eq!(huge_matches, locations(&per_file, &huge_path));
+        assert!(huge_matches
+            .iter()
+            .any(|(id, line, _)| id == "nl_bsn" && *line == 9_751));
+
+        for path in &paths[1..] {
+            assert_eq!(locations(&hybrid, path), locations(&per_file, path));
+        }
+    }
+
+    #[test]
+    fn test_cancel_mid_scan_returns_partial_results() {
+        use crate::core::{Detector, Match, Severity};
+        use std::sync::atomic::AtomicUsize;
+
+        /// Sets the cancellation flag after scanning `after` files
+        struct CancelAfter {
+            flag: Arc<AtomicBool>,
+            seen: AtomicUsize,
+            after: usize,
+        }
+
+        impl Detector for CancelAfter {
+            fn id(&self) -> &str {
+                "cancel_after"
+            }
+            fn name(&self) -> &str {
+                "Cancel After"
+            }
+            fn country(&self) -> &str {
+                "universal"
+            }
+            fn base_severity(&self) -> Severity {
+                Severity::Low
+            }
+            fn detect(&self, _text: &str, _file_path: &Path) -> Vec<Match> {
+                if self.seen.fetch_add(1, Ordering::SeqCst) + 1 >= self.after {
+                    self.flag.store(true, Ordering::SeqCst);
+                }
+                Vec::new()
+            }
+        }
+
+        let tmp = TempDir::new().unwrap();
+        let paths: Vec<PathBuf> = (0..200)
+            .map(|i| {
+                let path = tmp.path().join(format!("file_{}.txt", i));
+                fs::write(&path, "Patient BSN: 111222333\n").unwrap();
+                path
+            })
+            .collect();
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let mut registry = crate::default_registry();
+        registry.register(Box::new(CancelAfter {
+            flag: flag.clone(),
+            seen: AtomicUsize::new(0),
+            after: 20,
+        }));
+        let engine = ScanEngine::new(registry)
+            .show_progress(false)
+            .cancel_flag(flag);
+        let results = engine.scan_paths(&paths);
+
+        assert!(engine.is_cancelled());
+        assert!(results.total_files >= 20);
+        assert!(results.total_files < paths.len());
+        // Every file that was scanned is complete
+        assert_eq!(results.total_matches, results.total_files);
+    }
+
+    #[test]
+    fn test_max_files_truncates_scan() {
+        let tmp = TempDir::new().unwrap();
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            fs::write(tmp.path().join(name), "Patient BSN: 111222333").unwrap();
+        }
+
+        let results = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .max_files(2)
+            .scan_directory(tmp.path());
+
+        assert_eq!(results.total_files, 2);
+        assert!(results.limit_truncated);
+
+        // A limit the tree stays under doesn't truncate
+        let results = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .max_files(3)
+            .max_bytes(1024)
+            .scan_directory(tmp.path());
+
+        assert_eq!(results.total_files, 3);
+        assert!(!results.limit_truncated);
+    }
+
+    #[test]
+    fn test_single_thread_scans_one_file_at_a_time() {
+        use crate::core::{Detector, Match, Severity};
+        use std::sync::atomic::AtomicUsize;
+
+        /// Records the highest number of concurrent `detect` calls
+        #[derive(Default)]
+        struct ConcurrencyProbe {
+            active: Arc<AtomicUsize>,
+            max_active: Arc<AtomicUsize>,
+        }
+
+        impl Detector for ConcurrencyProbe {
+            fn id(&self) -> &str {
+                "concurrency_probe"
+            }
+            fn name(&self) -> &str {
+                "Concurrency Probe"
+            }
+            fn country(&self) -> &str {
+                "universal"
+            }
+            fn base_severity(&self) -> Severity {
+                Severity::Low
+            }
+            fn detect(&self, _text: &str, _file_path: &Path) -> Vec<Match> {
+                let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_active.fetch_max(active, Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(2));
+                self.active.fetch_sub(1, Ordering::SeqCst);
+                Vec::new()
+            }
+        }
+
+        let tmp = TempDir::new().unwrap();
+        let paths: Vec<PathBuf> = (0..32)
+            .map(|i| {
+                let path = tmp.path().join(format!("file_{}.txt", i));
+                fs::write(&path, "Patient BSN: 111222333\n").unwrap();
+                path
+            })
+            .collect();
+
+        let probe = ConcurrencyProbe::default();
+        let max_active = probe.max_active.clone();
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(probe));
+
+        let results = ScanEngine::new(registry)
+            .show_progress(false)
+            .threads(1)
+            .scan_paths(&paths);
+
+        assert_eq!(results.total_files, 32);
+        assert_eq!(max_active.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_dedup_files_scans_copies_once() {
+        use crate::core::{Detector, Match, Severity};
+        use std::sync::atomic::AtomicUsize;
+
+        /// Counts `detect` calls and delegates to the BSN detector
+        struct CountingBsn {
+            calls: Arc<AtomicUsize>,
+        }
+
+        impl Detector for CountingBsn {
+            fn id(&self) -> &str {
+                "nl_bsn"
+            }
+            fn name(&self) -> &str {
+                "Counting BSN"
+            }
+            fn country(&self) -> &str {
+                "nl"
+            }
+            fn base_severity(&self) -> Severity {
+                Severity::Critical
+            }
+            fn detect(&self, text: &str, file_path: &Path) -> Vec<Match> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                crate::detectors::nl::BsnDetector::new().detect(text, file_path)
+            }
+        }
+
+        let tmp = TempDir::new().unwrap();
+        let content = format!("{}Patient BSN: 111222333\n", "padding line\n".repeat(500));
+        fs::create_dir(tmp.path().join("backup")).unwrap();
+        let original = tmp.path().join("patients.txt");
+        let copy = tmp.path().join("backup").join("patients.txt");
+        // Same bytes under another extension may be extracted differently
+        let renamed = tmp.path().join("backup").join("patients.csv");
+        fs::write(&original, &content).unwrap();
+        fs::write(&copy, &content).unwrap();
+        fs::write(&renamed, &content).unwrap();
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(CountingBsn {
+            calls: calls.clone(),
+        }));
+        let results = ScanEngine::new(registry)
+            .show_progress(false)
+            .dedup_files(true)
+            .scan_paths(&[original.clone(), copy.clone(), renamed]);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(results.total_files, 3);
+        assert_eq!(results.total_matches, 3);
+        assert_eq!(results.duplicate_files, 1);
+        assert_eq!(results.files[1].path, copy);
+        assert_eq!(results.files[1].matches[0].location.file_path, copy);
+        assert_eq!(results.files[1].matches[0].location.line, 501);
+    }
+
+    #[test]
+    fn test_max_files_counts_dedup_copies() {
+        let tmp = TempDir::new().unwrap();
+        let content = format!("{}Patient BSN: 111222333\n", "padding line\n".repeat(500));
+        let paths: Vec<PathBuf> = ["a.txt", "b.txt", "c.txt"]
+            .iter()
+            .map(|name| {
+                let path = tmp.path().join(name);
+                fs::write(&path, &content).unwrap();
+                path
+            })
+            .collect();
+
+        let results = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .dedup_files(true)
+            .max_files(2)
+            .scan_paths(&paths);
+
+        assert_eq!(results.total_files, 2);
+        assert_eq!(results.duplicate_files, 1);
+        assert!(results.limit_truncated);
+    }
+
+    #[test]
+    fn test_hash_files_records_sha256() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("patients.txt");
+        fs::write(&path, "Patient BSN: 111222333\n").unwrap();
+
+        let registry = crate::default_registry();
+        let unhashed = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .scan_file(&path);
+        assert_eq!(unhashed.content_hash, None);
+
+        let result = ScanEngine::new(registry)
+            .show_progress(false)
+            .hash_files(true)
+            .scan_file(&path);
+        assert_eq!(
+            result.content_hash.as_deref(),
+            Some("8217127f9bba7a53d9687b2fed97c64df8dd34933c6cdd3fc618e4b19ea34a20")
+        );
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["content_hash"], result.content_hash.unwrap().as_str());
+    }
+
+    #[test]
+    fn test_single_line_dump_windowed() {
+        let ibans = [
+            "NL91ABNA0417164300",
+            "DE89370400440532013000",
+            "BE68539007547034",
+        ];
+        let mut content = String::new();
+        let mut offsets = Vec::new();
+        for (i, iban) in ibans.iter().cycle().take(12).enumerate() {
+            content.push_str(&"0123456789abcdef ".repeat(4_900 + i * 7));
+            offsets.push(content.len());
+            content.push_str(iban);
+            content.push(' ');
+        }
+        content.push_str(&"x".repeat(1_048_576 - content.len()));
+        assert!(!content.contains('\n'));
+
+        let tmp = TempDir::new().unwrap();
+        let file_path = tmp.path().join("dump.txt");
+        fs::write(&file_path, &content).unwrap();
+
+        let result = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .scan_file(&file_path);
+        let mut found: Vec<(usize, usize, usize)> = result
+            .matches
+            .iter()
+            .filter(|m| m.detector_id == "iban")
+            .map(|m| (m.location.start_byte, m.location.line, m.location.column))
+            .collect();
+        found.sort();
+
+        let expected: Vec<(usize, usize, usize)> = offsets.iter().map(|&o| (o, 1, o)).collect();
+        assert_eq!(found, expected);
+    }
+
     #[test]
     fn test_scan_directory() {
         let registry = crate::default_registry();
@@ -294,11 +1390,73 @@ mod tests {
         assert!(results.total_matches >= 2);
     }
 
+    #[test]
+    fn test_scan_paths_from_list() {
+        let registry = crate::default_registry();
+        let engine = ScanEngine::new(registry).show_progress(false);
+
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("file1.txt"), "BSN: 111222333").unwrap();
+        fs::write(tmp.path().join("file2.txt"), "Email: test@example.com").unwrap();
+        fs::write(tmp.path().join("file3.txt"), "Email: other@example.com").unwrap();
+
+        let list_path = tmp.path().join("paths.txt");
+        fs::write(
+            &list_path,
+            format!(
+                "{}\n{}\n",
+                tmp.path().join("file1.txt").display(),
+                tmp.path().join("file3.txt").display()
+            ),
+        )
+        .unwrap();
+
+        let reader = std::io::BufReader::new(fs::File::open(&list_path).unwrap());
+        let list = crate::crawler::read_path_list(reader).unwrap();
+        assert!(list.missing.is_empty());
+
+        let results = engine.scan_paths(&list.files);
+        assert_eq!(results.total_files, 2);
+
+        let mut scanned: Vec<_> = results
+            .files
+            .iter()
+            .map(|f| f.path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        scanned.sort();
+        assert_eq!(scanned, vec!["file1.txt", "file3.txt"]);
+    }
+
+    #[test]
+    fn test_scan_errors_in_json() {
+        let registry = crate::default_registry();
+        let engine = ScanEngine::new(registry).show_progress(false);
+
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("readable.txt"), "Email: test@example.com").unwrap();
+        fs::write(
+            tmp.path().join("binary.dat"),
+            [0xff, 0xfe, 0x00, 0xc3, 0x28],
+        )
+        .unwrap();
+
+        let results = engine.scan_directory(tmp.path());
+        assert_eq!(results.total_files, 2);
+        assert_eq!(results.errors.len(), 1);
+
+        let json = serde_json::to_value(&results).unwrap();
+        let errors = json["errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]["path"].as_str().unwrap().ends_with("binary.dat"));
+        assert_eq!(errors[0]["category"], "decode");
+        assert!(!errors[0]["message"].as_str().unwrap().is_empty());
+    }
+
     #[test]
     fn test_scan_with_extractors_enabled() {
         let registry = crate::default_registry();
         let mut extractor_registry = ExtractorRegistry::new();
-        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor));
+        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor::new()));
         extractor_registry.register(Arc::new(crate::extractors::DocxExtractor));
         extractor_registry.register(Arc::new(crate::extractors::XlsxExtractor));
 
@@ -322,7 +1480,7 @@ mod tests {
     fn test_extraction_statistics_tracking() {
         let registry = crate::default_registry();
         let mut extractor_registry = ExtractorRegistry::new();
-        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor));
+        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor::new()));
         extractor_registry.register(Arc::new(crate::extractors::DocxExtractor));
 
         let engine = ScanEngine::new(registry).with_extractors(extractor_registry);
@@ -349,7 +1507,7 @@ mod tests {
     fn test_extraction_failure_tracking() {
         let registry = crate::default_registry();
         let mut extractor_registry = ExtractorRegistry::new();
-        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor));
+        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor::new()));
 
         let engine = ScanEngine::new(registry).with_extractors(extractor_registry);
 
@@ -372,7 +1530,7 @@ mod tests {
     fn test_mixed_file_types_with_extractors() {
         let registry = crate::default_registry();
         let mut extractor_registry = ExtractorRegistry::new();
-        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor));
+        extractor_registry.register(Arc::new(crate::extractors::PdfExtractor::new()));
         extractor_registry.register(Arc::new(crate::extractors::DocxExtractor));
         extractor_registry.register(Arc::new(crate::extractors::XlsxExtractor));
 
@@ -0,0 +1,157 @@
+/// Financial record clusters: IBAN + name + amount
+///
+/// An IBAN on its own may be a company's published account, but next to a
+/// person's name and an amount it is a payment record (an invoice, a tax
+/// assessment, a salary slip). After detection, every `iban` match with a
+/// name and a currency amount within one line of it yields an extra
+/// `financial_record` finding with High severity and confidence. Its
+/// `correlated_with` lists the contributing signals: the IBAN match (by
+/// fingerprint), the name (a `person_name` match or a name label) and the
+/// amount.
+use crate::core::{Confidence, GdprCategory, Match, Severity};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Detector ID of the synthetic findings
+pub const FINANCIAL_RECORD_ID: &str = "financial_record";
+
+/// Lines before and after the IBAN's line searched for a name and an amount
+const WINDOW_LINES: usize = 1;
+
+/// Name label followed by a capitalized word (EN/NL/DE/FR)
+pub(super) static NAME_LABEL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i:\b(?:name|naam|t\.?n\.?v\.?|ten name van|rekeninghouder|begunstigde|kontoinhaber|empfänger|begünstigter|nom|titulaire|bénéficiaire|account holder|beneficiary|payee))\s*[:=]?\s*\p{Lu}\p{Ll}+",
+    )
+    .expect("Failed to compile name label regex")
+});
+
+/// Currency amount: `€1.234,56`, `1.234,56 EUR`, `EUR 1,234.56`, `€ 250`
+pub(super) static AMOUNT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?:€|\bEUR\b)\s?\d{1,3}(?:[.,\s]\d{3})*(?:[.,]\d{2})?|\b\d{1,3}(?:[.,\s]\d{3})*[.,]\d{2}\s?(?:€|EUR\b)",
+    )
+    .expect("Failed to compile amount regex")
+});
+
+/// Synthetic `financial_record` findings for the IBANs among `matches`
+pub fn financial_records(text: &str, matches: &[Match]) -> Vec<Match> {
+    let lines: Vec<&str> = text.lines().collect();
+
+    matches
+        .iter()
+        .filter(|m| m.detector_id == "iban")
+        .filter_map(|iban| {
+            let line = iban.location.line;
+            let first = line.saturating_sub(WINDOW_LINES).max(1);
+            let last = (line + WINDOW_LINES).min(lines.len());
+            let window = lines.get(first - 1..last)?.join("\n");
+
+            let name = matches
+                .iter()
+                .find(|m| {
+                    m.detector_id == super::names::NAME_DETECTOR_ID
+                        && (first..=last).contains(&m.location.line)
+                })
+                .map(|m| format!("person_name:{}", m.fingerprint))
+                .or_else(|| {
+                    NAME_LABEL_PATTERN
+                        .is_match(&window)
+                        .then(|| "name_label".to_string())
+                })?;
+            if !AMOUNT_PATTERN.is_match(&window) {
+                return None;
+            }
+
+            let mut record = Match {
+                detector_id: FINANCIAL_RECORD_ID.to_string(),
+                detector_name: "Financial Record (IBAN + name + amount)".to_string(),
+                country: iban.country.clone(),
+                value_masked: iban.value_masked.clone(),
+                location: iban.location.clone(),
+                confidence: Confidence::High,
+                severity: Severity::High,
+                context: None,
+                gdpr_category: GdprCategory::Regular,
+                fingerprint: String::new(),
+                decoded_from_base64: iban.decoded_from_base64,
+                partially_masked: false,
+                nationality: None,
+                issuing_country: None,
+                brand: None,
+                icon: None,
+                correlated_with: vec![
+                    format!("iban:{}", iban.fingerprint),
+                    name,
+                    "amount".to_string(),
+                ],
+                key_path: None,
+            };
+            record.fingerprint = record.compute_fingerprint(text);
+            Some(record)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_iban_name_amount_clustered() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("aanslag.txt");
+        fs::write(
+            &file,
+            "Rekeninghouder: Jansen, IBAN NL91ABNA0417164300, te betalen €1.234,56\n\
+             \n\
+             \n\
+             Onze rekening: NL91ABNA0417164300\n",
+        )
+        .unwrap();
+
+        let result = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .scan_file(&file);
+        let iban = result
+            .matches
+            .iter()
+            .find(|m| m.detector_id == "iban" && m.location.line == 1)
+            .unwrap();
+        let records: Vec<_> = result
+            .matches
+            .iter()
+            .filter(|m| m.detector_id == FINANCIAL_RECORD_ID)
+            .collect();
+
+        // Only the IBAN with a name and an amount next to it
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].severity, Severity::High);
+        assert_eq!(records[0].location.start_byte, iban.location.start_byte);
+        assert_eq!(
+            records[0].correlated_with,
+            vec![
+                format!("iban:{}", iban.fingerprint),
+                "name_label".to_string(),
+                "amount".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_amount_formats() {
+        for amount in [
+            "€1.234,56",
+            "1.234,56 EUR",
+            "EUR 1,234.56",
+            "€ 250",
+            "99,95 €",
+        ] {
+            assert!(AMOUNT_PATTERN.is_match(amount), "{}", amount);
+        }
+        assert!(!AMOUNT_PATTERN.is_match("Factuur 2024-0042"));
+    }
+}
@@ -0,0 +1,224 @@
+/// Scan manifest recording which files were scanned, skipped or failed
+///
+/// The manifest is written independently of the findings report and lists
+/// every discovered file with its status, size, content hash and match count,
+/// as evidence of scan coverage.
+use crate::core::{ScanErrorCategory, ScanResults};
+use crate::crawler::SkippedFile;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Outcome for a single discovered file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    /// File content was scanned by the detectors
+    Scanned,
+
+    /// File was not scanned (binary content, too large, hidden or ignored)
+    Skipped,
+
+    /// File could not be read or extracted
+    Errored,
+}
+
+/// Manifest entry for one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// File path
+    pub path: PathBuf,
+
+    /// Scan status
+    pub status: FileStatus,
+
+    /// Why the file was skipped or errored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// File size in bytes
+    pub size_bytes: u64,
+
+    /// SHA-256 of the scanned file content (hex), if the file was read
+    pub sha256: Option<String>,
+
+    /// Number of reported matches
+    pub matches: usize,
+}
+
+/// Record of exactly what a scan covered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanManifest {
+    /// Manifest creation time (RFC 3339)
+    pub generated_at: String,
+
+    /// Number of scanned files
+    pub scanned: usize,
+
+    /// Number of skipped files
+    pub skipped: usize,
+
+    /// Number of files that failed
+    pub errored: usize,
+
+    /// One entry per discovered file
+    pub files: Vec<ManifestEntry>,
+}
+
+impl ScanManifest {
+    /// Build a manifest from scan results
+    ///
+    /// File hashes are the [`content_hash`](crate::core::FileResult::content_hash)
+    /// recorded during the scan, so the engine must run with
+    /// [`hash_files`](crate::scanner::ScanEngine::hash_files).
+    pub fn from_results(results: &ScanResults) -> Self {
+        let files: Vec<ManifestEntry> = results
+            .files
+            .iter()
+            .map(|file| {
+                let (status, reason) = match (&file.error, file.error_category) {
+                    (None, _) => (FileStatus::Scanned, None),
+                    (Some(_), Some(ScanErrorCategory::Decode)) => {
+                        (FileStatus::Skipped, Some("binary content".to_string()))
+                    }
+                    (Some(message), _) => (FileStatus::Errored, Some(message.clone())),
+                };
+
+                ManifestEntry {
+                    path: file.path.clone(),
+                    status,
+                    reason,
+                    size_bytes: file.size_bytes,
+                    sha256: file.content_hash.clone(),
+                    matches: file.matches.len(),
+                }
+            })
+            .collect();
+
+        let mut manifest = Self {
+            generated_at: chrono::Local::now().to_rfc3339(),
+            scanned: 0,
+            skipped: 0,
+            errored: 0,
+            files,
+        };
+        manifest.count();
+        manifest
+    }
+
+    /// Add the files the walker left out (see [`Walker::skipped`](crate::Walker::skipped))
+    pub fn with_skipped(mut self, skipped: Vec<SkippedFile>) -> Self {
+        self.files
+            .extend(skipped.into_iter().map(|file| ManifestEntry {
+                path: file.path,
+                status: FileStatus::Skipped,
+                reason: Some(file.reason),
+                size_bytes: file.size_bytes,
+                sha256: None,
+                matches: 0,
+            }));
+        self.count();
+        self
+    }
+
+    /// Update the per-status totals
+    fn count(&mut self) {
+        let count = |status| self.files.iter().filter(|f| f.status == status).count();
+        (self.scanned, self.skipped, self.errored) = (
+            count(FileStatus::Scanned),
+            count(FileStatus::Skipped),
+            count(FileStatus::Errored),
+        );
+    }
+
+    /// Write the manifest as pretty-printed JSON
+    pub fn write_to_file(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+
+        std::fs::write(path, json).map_err(|e| format!("Failed to write manifest: {}", e))
+    }
+}
+
+/// SHA-256 of a file's content as hex
+pub(crate) fn hash_file(path: &Path) -> Option<String> {
+    std::fs::read(path).ok().map(|content| sha256_hex(&content))
+}
+
+/// SHA-256 of `bytes` as hex
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_manifest_scanned_and_skipped() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("notes.txt"), "BSN: 111222333\n").unwrap();
+        fs::write(
+            tmp.path().join("image.dat"),
+            [0x89, b'P', b'N', b'G', 0xff, 0xfe],
+        )
+        .unwrap();
+        fs::write(tmp.path().join("huge.log"), "x".repeat(4096)).unwrap();
+
+        let walker = crate::Walker::new(tmp.path()).max_filesize(1024);
+        let engine = ScanEngine::new(crate::default_registry())
+            .show_progress(false)
+            .hash_files(true);
+        let results = engine.scan_walker(&walker);
+
+        let manifest_path = tmp.path().join("manifest.json");
+        ScanManifest::from_results(&results)
+            .with_skipped(walker.skipped())
+            .write_to_file(&manifest_path)
+            .unwrap();
+
+        let manifest: ScanManifest =
+            serde_json::from_str(&fs::read_to_string(&manifest_path).unwrap()).unwrap();
+        assert_eq!(manifest.files.len(), 3);
+        assert_eq!(
+            (manifest.scanned, manifest.skipped, manifest.errored),
+            (1, 2, 0)
+        );
+
+        let entry = |name: &str| {
+            manifest
+                .files
+                .iter()
+                .find(|f| f.path.ends_with(name))
+                .unwrap()
+        };
+
+        let notes = entry("notes.txt");
+        assert_eq!(notes.status, FileStatus::Scanned);
+        assert_eq!(notes.size_bytes, 15);
+        assert_eq!(notes.matches, 1);
+        assert_eq!(notes.sha256.as_ref().map(String::len), Some(64));
+
+        let image = entry("image.dat");
+        assert_eq!(image.status, FileStatus::Skipped);
+        assert_eq!(image.reason.as_deref(), Some("binary content"));
+        assert_eq!(image.matches, 0);
+        assert!(image.sha256.is_some());
+
+        // Left out by the walker, so never read
+        let huge = entry("huge.log");
+        assert_eq!(huge.status, FileStatus::Skipped);
+        assert_eq!(
+            huge.reason.as_deref(),
+            Some("larger than the maximum file size")
+        );
+        assert_eq!(huge.size_bytes, 4096);
+        assert_eq!(huge.sha256, None);
+    }
+}
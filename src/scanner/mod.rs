@@ -4,5 +4,60 @@ pub mod engine;
 /// API endpoint scanning module
 pub mod api;
 
+/// Per-detector statistics
+pub mod stats;
+
+/// Benchmark harness
+pub mod bench;
+
+/// Scan coverage manifest
+pub mod manifest;
+
+/// Incremental scan cache
+pub mod cache;
+
+/// Per-path severity ceilings
+pub mod ceiling;
+
+/// Per-detector path scopes
+pub mod scope;
+
+/// Sliding-window detection for long lines
+pub mod window;
+
+/// Continuous monitoring with new-finding webhooks
+pub mod watch;
+
+/// Inline `pii-radar:ignore` suppression comments
+pub mod suppress;
+
+/// Byte-based progress reporting
+pub mod progress;
+
+/// IP + timestamp access-log correlation
+pub mod correlate;
+
+/// IBAN + name + amount financial record clusters
+pub mod financial;
+
+/// Person names next to other findings
+pub mod names;
+
+/// Overlap resolution between generic and specific detectors
+pub mod supersede;
+
 pub use api::{scan_api_endpoint, scan_api_endpoints, ApiScanConfig, HttpMethod};
-pub use engine::ScanEngine;
+pub use bench::{run_bench, BenchReport};
+pub use cache::{scan_key, ScanCache};
+pub use ceiling::SeverityCeilings;
+pub use correlate::AccessLogCorrelation;
+pub use engine::{ParallelismMode, ScanEngine};
+pub use financial::{financial_records, FINANCIAL_RECORD_ID};
+pub use manifest::{FileStatus, ManifestEntry, ScanManifest};
+pub use names::{names_near_findings, NAME_DETECTOR_ID};
+pub use progress::ScanProgress;
+pub use scope::DetectorPathScope;
+pub use stats::{DetectorStats, DetectorTiming};
+pub use supersede::drop_superseded;
+pub use suppress::Suppressions;
+pub use watch::Watcher;
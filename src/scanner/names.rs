@@ -0,0 +1,141 @@
+/// Person names next to other findings
+///
+/// A common name without a label is only personal data worth reporting when
+/// it sits next to another identifier, e.g. `Peter Schmidt,
+/// peter.schmidt@example.de`. After detection, every such name within
+/// [`NAME_WINDOW`] bytes of another finding on the same line is reported by
+/// the `person_name` detector, with the finding (by fingerprint) in its
+/// `correlated_with`. Labeled names are reported by the detector itself.
+use crate::core::{Detector, Match};
+use crate::detectors::personal::NameDetector;
+use std::path::Path;
+
+/// Detector ID of person name findings
+pub const NAME_DETECTOR_ID: &str = "person_name";
+
+/// Bytes between a name and another finding on the same line
+pub const NAME_WINDOW: usize = 40;
+
+/// Unlabeled names near one of `matches`, reported as findings of `detector`
+pub fn names_near_findings(
+    text: &str,
+    matches: &[Match],
+    detector: &dyn Detector,
+    file_path: &Path,
+) -> Vec<Match> {
+    let mut names = Vec::new();
+    let mut byte_offset = 0;
+
+    for (line_num, line) in text.lines().enumerate() {
+        let line_end = byte_offset + line.len();
+        let on_line: Vec<&Match> = matches
+            .iter()
+            .filter(|m| m.location.start_byte >= byte_offset && m.location.start_byte < line_end)
+            .collect();
+        let others: Vec<&Match> = on_line
+            .iter()
+            .copied()
+            .filter(|m| m.detector_id != NAME_DETECTOR_ID)
+            .collect();
+
+        if !others.is_empty() {
+            let near = |start: usize, end: usize| {
+                let (start, end) = (byte_offset + start, byte_offset + end);
+                others.iter().copied().find(|m| {
+                    m.location.start_byte < end + NAME_WINDOW
+                        && m.location.end_byte + NAME_WINDOW > start
+                })
+            };
+            // Labeled names are the detector's own; "Name Jan" must not shadow "Jan Jansen"
+            let labeled_names: Vec<&Match> = on_line
+                .iter()
+                .copied()
+                .filter(|m| m.detector_id == NAME_DETECTOR_ID)
+                .collect();
+
+            let found = NameDetector::find_names(line, |start, end, labeled| {
+                labeled || near(start, end).is_some()
+            });
+            for range in found {
+                let (start, end) = (byte_offset + range.start, byte_offset + range.end);
+                if labeled_names
+                    .iter()
+                    .any(|m| m.location.start_byte < end && start < m.location.end_byte)
+                {
+                    continue;
+                }
+                let Some(finding) = near(range.start, range.end) else {
+                    continue;
+                };
+
+                let mut name = NameDetector::name_match(
+                    detector,
+                    line,
+                    range,
+                    line_num,
+                    byte_offset,
+                    file_path,
+                );
+                name.correlated_with =
+                    vec![format!("{}:{}", finding.detector_id, finding.fingerprint)];
+                name.fingerprint = name.compute_fingerprint(text);
+                names.push(name);
+            }
+        }
+
+        byte_offset = line_end + 1;
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DetectorRegistry;
+    use crate::detectors::personal::EmailDetector;
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_name_next_to_finding() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("klanten.csv");
+        fs::write(
+            &file,
+            "Contact Peter Schmidt, peter.schmidt@example.de\n\
+             Naam: Jan de Vries, jan@example.nl\n\
+             Jan Jansen belde over bestelling 0612345678901234\n",
+        )
+        .unwrap();
+
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(NameDetector::new()));
+        registry.register(Box::new(EmailDetector::new()));
+        let result = ScanEngine::new(registry)
+            .show_progress(false)
+            .scan_file(&file);
+
+        let names: Vec<&Match> = result
+            .matches
+            .iter()
+            .filter(|m| m.detector_id == NAME_DETECTOR_ID)
+            .collect();
+        let email = result
+            .matches
+            .iter()
+            .find(|m| m.detector_id == "email" && m.location.line == 1)
+            .unwrap();
+
+        // The digit run on line 3 is not a finding, so that name is not reported
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].value_masked, "J** d* V****");
+        assert!(names[0].correlated_with.is_empty());
+        assert_eq!(names[1].value_masked, "P**** S******");
+        assert_eq!(
+            names[1].correlated_with,
+            vec![format!("email:{}", email.fingerprint)]
+        );
+    }
+}
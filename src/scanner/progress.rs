@@ -0,0 +1,117 @@
+/// Byte-based scan progress
+///
+/// The bar advances by the size of each scanned file, so its rate and ETA
+/// follow throughput instead of file count: a scan that is through 90% of its
+/// files with one huge file left shows the time that file will take. The
+/// file count stays visible in the message.
+use indicatif::{ProgressBar, ProgressStyle};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+pub struct ScanProgress {
+    bar: Option<ProgressBar>,
+    total_files: usize,
+    total_bytes: u64,
+    files_done: AtomicUsize,
+    bytes_done: AtomicU64,
+}
+
+impl ScanProgress {
+    /// Track `total_files` files of `total_bytes` bytes, drawing a bar when `show` is set
+    pub fn new(total_files: usize, total_bytes: u64, show: bool) -> Self {
+        let bar = show.then(|| {
+            let pb = ProgressBar::new(total_bytes);
+            pb.set_style(
+                ProgressStyle::default_bar()
+                    .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec}, ETA {eta}) | {msg}")
+                    .unwrap()
+                    .progress_chars("█▓▒░  "),
+            );
+            pb.set_message(format!("0/{} files | Scanning...", total_files));
+            pb
+        });
+
+        Self {
+            bar,
+            total_files,
+            total_bytes,
+            files_done: AtomicUsize::new(0),
+            bytes_done: AtomicU64::new(0),
+        }
+    }
+
+    /// Record `files` finished files of `bytes` in total, with `matches` PII matches found so far
+    pub fn advance(&self, files: usize, bytes: u64, matches: usize) {
+        let files_done = self.files_done.fetch_add(files, Ordering::Relaxed) + files;
+        self.bytes_done.fetch_add(bytes, Ordering::Relaxed);
+
+        if let Some(ref pb) = self.bar {
+            pb.inc(bytes);
+            let status = if matches > 0 {
+                format!("🔴 {} PII matches found", matches)
+            } else {
+                "✅ No PII found yet".to_string()
+            };
+            pb.set_message(format!(
+                "{}/{} files | {}",
+                files_done, self.total_files, status
+            ));
+        }
+    }
+
+    /// Completed fraction of the bytes (of the files when there are no bytes)
+    pub fn fraction(&self) -> f64 {
+        let fraction = if self.total_bytes > 0 {
+            self.bytes_done.load(Ordering::Relaxed) as f64 / self.total_bytes as f64
+        } else if self.total_files > 0 {
+            self.files_done.load(Ordering::Relaxed) as f64 / self.total_files as f64
+        } else {
+            1.0
+        };
+        // Files can grow between discovery and scanning
+        fraction.min(1.0)
+    }
+
+    pub fn files_done(&self) -> usize {
+        self.files_done.load(Ordering::Relaxed)
+    }
+
+    /// The progress bar, when shown
+    pub fn bar(&self) -> Option<&ProgressBar> {
+        self.bar.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction_follows_bytes_not_files() {
+        // Nine small files and one large one
+        let sizes = [10u64, 10, 10, 10, 10, 10, 10, 10, 10, 910];
+        let progress = ScanProgress::new(sizes.len(), sizes.iter().sum(), false);
+        assert_eq!(progress.fraction(), 0.0);
+
+        for &size in &sizes[..9] {
+            progress.advance(1, size, 0);
+        }
+        // 90% of the files, but only 9% of the bytes
+        assert_eq!(progress.files_done(), 9);
+        assert!((progress.fraction() - 0.09).abs() < 1e-9);
+
+        progress.advance(1, sizes[9], 0);
+        assert_eq!(progress.fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_fraction_of_empty_files() {
+        let progress = ScanProgress::new(2, 0, false);
+        progress.advance(1, 0, 0);
+        assert_eq!(progress.fraction(), 0.5);
+
+        // Grown files never report more than done
+        let progress = ScanProgress::new(1, 100, false);
+        progress.advance(1, 150, 0);
+        assert_eq!(progress.fraction(), 1.0);
+    }
+}
@@ -0,0 +1,101 @@
+/// Per-detector path scopes
+///
+/// Limits a detector to files matching its globs (e.g. `entropy_secret ->
+/// ["*.env", "config/**"]`), so a detector that is noisy on some files (minified
+/// assets) can still run where it is useful. Detectors without a scope run on
+/// every file.
+use ignore::overrides::{Override, OverrideBuilder};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Path scope rules, one glob matcher per scoped detector
+#[derive(Debug, Clone, Default)]
+pub struct DetectorPathScope {
+    rules: BTreeMap<String, Override>,
+}
+
+impl DetectorPathScope {
+    /// Build scopes from `detector ID -> globs` rules, with globs relative to `root`
+    pub fn new(root: &Path, rules: &BTreeMap<String, Vec<String>>) -> Result<Self, ignore::Error> {
+        let rules = rules
+            .iter()
+            .map(|(id, globs)| {
+                let mut builder = OverrideBuilder::new(root);
+                for glob in globs {
+                    builder.add(glob)?;
+                }
+                Ok((id.clone(), builder.build()?))
+            })
+            .collect::<Result<_, ignore::Error>>()?;
+
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether the detector `id` runs on `path`
+    pub fn allows(&self, id: &str, path: &Path) -> bool {
+        self.rules
+            .get(id)
+            .is_none_or(|globs| globs.matched(path, false).is_whitelist())
+    }
+
+    /// IDs of the scoped detectors that do not run on `path`
+    pub fn excluded(&self, path: &Path) -> Vec<&str> {
+        self.rules
+            .keys()
+            .map(String::as_str)
+            .filter(|id| !self.allows(id, path))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crawler::Walker;
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_entropy_scoped_to_env_files() {
+        let tmp = TempDir::new().unwrap();
+        let secret = "token = \"q8Zr2Xk9Lm4Pv7Ns1Tb6Wc3Yd5Hf0Jg8Ke2Rt9Ua\"\n";
+        fs::write(tmp.path().join(".env"), secret).unwrap();
+        fs::create_dir(tmp.path().join("dist")).unwrap();
+        fs::write(tmp.path().join("dist").join("app.min.js"), secret).unwrap();
+
+        let entropy_files = |engine: ScanEngine| {
+            // `.env` is a hidden file
+            let walker = Walker::new(tmp.path()).hidden(false);
+            let results = engine.show_progress(false).scan_walker(&walker);
+            let mut names: Vec<String> = results
+                .files
+                .iter()
+                .filter(|f| f.matches.iter().any(|m| m.detector_id == "entropy_secret"))
+                .map(|f| f.path.file_name().unwrap().to_string_lossy().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+
+        // Unscoped: the detector runs everywhere
+        let engine = ScanEngine::new(crate::default_registry());
+        assert_eq!(entropy_files(engine), vec![".env", "app.min.js"]);
+
+        let rules = BTreeMap::from([(
+            "entropy_secret".to_string(),
+            vec!["*.env".to_string(), ".env".to_string()],
+        )]);
+        let scope = DetectorPathScope::new(tmp.path(), &rules).unwrap();
+        assert!(scope.allows("entropy_secret", &tmp.path().join(".env")));
+        assert!(!scope.allows("entropy_secret", &tmp.path().join("dist/app.min.js")));
+        assert!(scope.allows("email", &tmp.path().join("dist/app.min.js")));
+
+        let engine = ScanEngine::new(crate::default_registry()).detector_path_scope(scope);
+        assert_eq!(entropy_files(engine), vec![".env"]);
+    }
+}
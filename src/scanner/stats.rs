@@ -0,0 +1,97 @@
+/// Per-detector timing and match statistics
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Accumulated statistics for a single detector
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DetectorTiming {
+    /// Detector ID
+    pub detector_id: String,
+
+    /// Number of times the detector ran (once per scanned file)
+    pub calls: u64,
+
+    /// Total time spent in the detector
+    pub total_time: Duration,
+
+    /// Total matches reported by the detector
+    pub matches: u64,
+}
+
+impl DetectorTiming {
+    /// Average time per call
+    pub fn average_time(&self) -> Duration {
+        if self.calls == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos((self.total_time.as_nanos() / self.calls as u128) as u64)
+        }
+    }
+}
+
+/// Thread-safe collector shared between scan threads
+#[derive(Debug, Default)]
+pub struct DetectorStats {
+    timings: Mutex<BTreeMap<String, DetectorTiming>>,
+}
+
+impl DetectorStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one detector run
+    pub fn record(&self, detector_id: &str, elapsed: Duration, matches: usize) {
+        let mut timings = self.timings.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = timings
+            .entry(detector_id.to_string())
+            .or_insert_with(|| DetectorTiming {
+                detector_id: detector_id.to_string(),
+                ..Default::default()
+            });
+
+        entry.calls += 1;
+        entry.total_time += elapsed;
+        entry.matches += matches as u64;
+    }
+
+    /// Snapshot of all timings, sorted by detector ID
+    pub fn snapshot(&self) -> Vec<DetectorTiming> {
+        let timings = self.timings.lock().unwrap_or_else(|e| e.into_inner());
+        timings.values().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_average() {
+        let stats = DetectorStats::new();
+        stats.record("email", Duration::from_micros(10), 1);
+        stats.record("email", Duration::from_micros(30), 0);
+        stats.record("nl_bsn", Duration::from_micros(5), 2);
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].detector_id, "email");
+        assert_eq!(snapshot[0].calls, 2);
+        assert_eq!(snapshot[0].matches, 1);
+        assert_eq!(snapshot[0].average_time(), Duration::from_micros(20));
+        assert_eq!(snapshot[1].matches, 2);
+    }
+
+    #[test]
+    fn test_average_beyond_u32_calls() {
+        // 2^32 calls would wrap to 0 as a u32 divisor
+        let timing = DetectorTiming {
+            detector_id: "email".to_string(),
+            calls: 1 << 32,
+            total_time: Duration::from_secs(1 << 32),
+            matches: 0,
+        };
+        assert_eq!(timing.average_time(), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,94 @@
+/// Overlap resolution between generic and specific detectors
+///
+/// Some values match both a generic detector and a more specific one, e.g. a
+/// `+45` number matches the universal `phone` detector and `dk_phone`.
+/// Detectors list the generic IDs they replace in
+/// [`Detector::supersedes`]; a generic match overlapping a match of such a
+/// detector is dropped, so the value is reported once.
+use crate::core::{Detector, Match};
+
+/// Drop matches superseded by an overlapping match of a more specific detector
+///
+/// Returns the number of dropped matches.
+pub fn drop_superseded(matches: &mut Vec<Match>, detectors: &[&dyn Detector]) -> usize {
+    let spans: Vec<(usize, usize, &[&str])> = matches
+        .iter()
+        .filter_map(|m| {
+            let detector = detectors.iter().find(|d| d.id() == m.detector_id)?;
+            let superseded = detector.supersedes();
+            (!superseded.is_empty()).then_some((
+                m.location.start_byte,
+                m.location.end_byte,
+                superseded,
+            ))
+        })
+        .collect();
+
+    if spans.is_empty() {
+        return 0;
+    }
+
+    let before = matches.len();
+    matches.retain(|m| {
+        !spans.iter().any(|&(start, end, superseded)| {
+            superseded.contains(&m.detector_id.as_str())
+                && start < m.location.end_byte
+                && m.location.start_byte < end
+        })
+    });
+    before - matches.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::DetectorRegistry;
+    use crate::detectors::{de, dk, nl, personal};
+    use crate::scanner::ScanEngine;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_country_phone_supersedes_universal_phone() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("kunder.txt");
+        fs::write(&file, "Tlf. +45 32 12 34 56 eller +49 30 901820\n").unwrap();
+
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(personal::PhoneDetector::new()));
+        registry.register(Box::new(dk::PhoneDetector::new()));
+        let engine = ScanEngine::new(registry).show_progress(false);
+
+        let ids: Vec<String> = engine
+            .scan_file(&file)
+            .matches
+            .into_iter()
+            .map(|m| m.detector_id)
+            .collect();
+
+        // The Danish number once, by dk_phone; the German one stays universal
+        assert_eq!(ids.iter().filter(|id| *id == "dk_phone").count(), 1);
+        assert_eq!(ids.iter().filter(|id| *id == "phone").count(), 1);
+        assert_eq!(ids.len(), 2);
+    }
+
+    #[test]
+    fn test_nl_and_de_phone_supersede_universal_phone() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("klanten.txt");
+        fs::write(&file, "Bel +31 20 123 4567 of +49 30 901820\n").unwrap();
+
+        let mut registry = DetectorRegistry::new();
+        registry.register(Box::new(personal::PhoneDetector::new()));
+        registry.register(Box::new(nl::PhoneDetector::new()));
+        registry.register(Box::new(de::PhoneDetector::new()));
+        let engine = ScanEngine::new(registry).show_progress(false);
+
+        let ids: Vec<String> = engine
+            .scan_file(&file)
+            .matches
+            .into_iter()
+            .map(|m| m.detector_id)
+            .collect();
+        assert_eq!(ids, vec!["nl_phone", "de_phone"]);
+    }
+}
@@ -0,0 +1,159 @@
+/// Inline suppression comments
+///
+/// A `pii-radar:ignore` comment drops the matches on its own line and on the
+/// line below it, so it can trail the value or sit above it:
+///
+/// ```text
+/// test_bsn = "111222333"  # pii-radar:ignore
+/// // pii-radar:ignore[email,nl_bsn]
+/// const FIXTURE = "jan@example.com";
+/// ```
+///
+/// A bracketed list limits the suppression to those detector IDs; an ID
+/// split into several detectors covers all of them. The comment may start
+/// with `//`, `#`, `--`, `;`, `/*` or `<!--`.
+use crate::core::{Match, SPLIT_DETECTOR_IDS};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Comment marker; group 1 is the optional comma-separated detector ID list
+pub(super) static IGNORE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?://|#|--|;|/\*|<!--)\s*pii-radar:ignore(?:\[([\w,\s]*)\])?")
+        .expect("Failed to compile pii-radar:ignore regex")
+});
+
+/// Detectors suppressed on a line: `None` for all of them
+type LineSuppression = Option<Vec<String>>;
+
+/// Suppressed lines (1-indexed) of a document
+pub struct Suppressions {
+    lines: HashMap<usize, LineSuppression>,
+}
+
+impl Suppressions {
+    /// Find the `pii-radar:ignore` comments in `text`
+    pub fn parse(text: &str) -> Self {
+        let mut lines: HashMap<usize, LineSuppression> = HashMap::new();
+
+        for (index, line) in text.lines().enumerate() {
+            // Cheap check first: most lines have no comment at all
+            if !line.contains("pii-radar:ignore") {
+                continue;
+            }
+            let Some(caps) = IGNORE_PATTERN.captures(line) else {
+                continue;
+            };
+            let ids: LineSuppression = caps.get(1).map(|list| {
+                list.as_str()
+                    .split(',')
+                    .map(|id| id.trim().to_string())
+                    .filter(|id| !id.is_empty())
+                    .collect()
+            });
+
+            // The comment covers its own line and the next one
+            for line_num in [index + 1, index + 2] {
+                let entry = lines.entry(line_num).or_insert_with(|| Some(Vec::new()));
+                match (entry.as_mut(), &ids) {
+                    (Some(existing), Some(ids)) => existing.extend(ids.iter().cloned()),
+                    _ => *entry = None,
+                }
+            }
+        }
+
+        Self { lines }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Whether a match is covered by a suppression comment
+    pub fn suppresses(&self, m: &Match) -> bool {
+        match self.lines.get(&m.location.line) {
+            Some(None) => true,
+            Some(Some(ids)) => ids.iter().any(|id| {
+                id == &m.detector_id
+                    || SPLIT_DETECTOR_IDS.contains(&(id.as_str(), m.detector_id.as_str()))
+            }),
+            None => false,
+        }
+    }
+
+    /// Drop suppressed matches, returning how many were dropped
+    pub fn apply(&self, matches: &mut Vec<Match>) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let before = matches.len();
+        matches.retain(|m| !self.suppresses(m));
+        before - matches.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Detector;
+    use crate::detectors::nl::BsnDetector;
+    use crate::detectors::personal::EmailDetector;
+    use std::path::Path;
+
+    fn detect_all(text: &str) -> Vec<Match> {
+        let path = Path::new("fixtures.py");
+        let mut matches = BsnDetector::new().detect(text, path);
+        matches.extend(EmailDetector::new().detect(text, path));
+        matches
+    }
+
+    #[test]
+    fn test_unqualified_ignore() {
+        let text = "bsn = \"111222333\"  # pii-radar:ignore\n\
+                    # pii-radar:ignore\n\
+                    owner = \"jan@example.com\"\n\
+                    contact = \"piet@example.com\"\n";
+        let mut matches = detect_all(text);
+        assert_eq!(matches.len(), 3);
+
+        let suppressed = Suppressions::parse(text).apply(&mut matches);
+        assert_eq!(suppressed, 2);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].location.line, 4);
+    }
+
+    #[test]
+    fn test_detector_qualified_ignore() {
+        let text = "// pii-radar:ignore[email]\n\
+                    const FIXTURE = { bsn: \"111222333\", email: \"jan@example.com\" };\n";
+        let mut matches = detect_all(text);
+        assert_eq!(matches.len(), 2);
+
+        let suppressed = Suppressions::parse(text).apply(&mut matches);
+        assert_eq!(suppressed, 1);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].detector_id, "nl_bsn");
+    }
+
+    #[test]
+    fn test_split_detector_id_still_suppresses() {
+        use crate::detectors::security::EntropySecretDetector;
+
+        let text = "# pii-radar:ignore[api_key]\n\
+                    secret_token = \"dGhpc2lzYXZlcnlsb25nYmFzZTY0ZW5jb2RlZHNlY3JldGtleXRoYXRsb29rc3JhbmRvbQ==\"\n";
+        let mut matches = EntropySecretDetector::new().detect(text, Path::new("config.py"));
+        assert!(!matches.is_empty());
+
+        Suppressions::parse(text).apply(&mut matches);
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_marker_outside_comment_ignored() {
+        let text = "note: pii-radar:ignore is documented\nbsn 111222333\n";
+        let mut matches = detect_all(text);
+        assert_eq!(Suppressions::parse(text).apply(&mut matches), 0);
+        assert_eq!(matches.len(), 1);
+    }
+}
@@ -0,0 +1,299 @@
+/// Continuous monitoring: periodic rescans that report only new findings
+///
+/// A [`Watcher`] rescans a directory whenever its files change, diffs the
+/// match fingerprints against the previous run and POSTs the newly
+/// introduced findings to a webhook. The first scan only records a baseline.
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+use walkdir::WalkDir;
+
+use super::ScanEngine;
+use crate::core::types::{Match, ScanResults};
+use crate::crawler::Walker;
+
+/// Default quiet period before a changed tree is rescanned
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_secs(5);
+
+/// Size and modification time of every file under the watched root
+type TreeState = Vec<(PathBuf, u64, Option<SystemTime>)>;
+
+/// Identity of a finding across runs: file plus location-independent fingerprint
+type FindingKey = (PathBuf, String);
+
+/// JSON body POSTed to the webhook
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    event: &'static str,
+    root: &'a Path,
+    new_findings: usize,
+    matches: &'a [Match],
+}
+
+pub struct Watcher {
+    engine: ScanEngine,
+    root: PathBuf,
+    webhook: Option<String>,
+    debounce: Duration,
+    client: Client,
+    /// Findings of the last completed run (`None` until the baseline scan)
+    known: Option<HashSet<FindingKey>>,
+    /// Tree state of the last completed run
+    scanned: Option<TreeState>,
+    /// Changed tree state waiting for the debounce period to pass
+    pending: Option<(TreeState, Instant)>,
+}
+
+impl Watcher {
+    pub fn new<P: AsRef<Path>>(engine: ScanEngine, root: P) -> Self {
+        Self {
+            engine,
+            root: root.as_ref().to_path_buf(),
+            webhook: None,
+            debounce: DEFAULT_DEBOUNCE,
+            client: Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .unwrap_or_default(),
+            known: None,
+            scanned: None,
+            pending: None,
+        }
+    }
+
+    /// Set the webhook URL that receives new findings
+    pub fn webhook(mut self, url: impl Into<String>) -> Self {
+        self.webhook = Some(url.into());
+        self
+    }
+
+    /// Set how long the tree must stay unchanged before it is rescanned
+    ///
+    /// Stops a rapidly-changing tree (e.g. a checkout or build in progress)
+    /// from triggering a scan and a notification for every intermediate state.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Check the tree once, rescanning and notifying if it changed
+    ///
+    /// Returns the number of new findings sent to the webhook. The first call
+    /// records a baseline and never notifies. When the webhook request fails
+    /// the run is not recorded, so the same findings are retried later.
+    pub fn tick(&mut self) -> Result<usize> {
+        let state = tree_state(&self.root);
+        if self.scanned.as_ref() == Some(&state) {
+            self.pending = None;
+            return Ok(0);
+        }
+
+        let now = Instant::now();
+        let since = match self.pending.take() {
+            Some((pending, since)) if pending == state => since,
+            _ => now,
+        };
+        if self.known.is_some() && now.duration_since(since) < self.debounce {
+            self.pending = Some((state, since));
+            return Ok(0);
+        }
+
+        let results = self.engine.scan_walker(&Walker::new(&self.root));
+        let findings: HashSet<FindingKey> = all_matches(&results).map(finding_key).collect();
+
+        let new_matches: Vec<Match> = match &self.known {
+            Some(known) => all_matches(&results)
+                .filter(|m| !known.contains(&finding_key(m)))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        };
+
+        if !new_matches.is_empty() {
+            self.notify(&new_matches)?;
+        }
+
+        self.known = Some(findings);
+        self.scanned = Some(state);
+        Ok(new_matches.len())
+    }
+
+    /// Call [`tick`](Self::tick) every `interval` until `cancel` is set
+    ///
+    /// Webhook failures are reported on stderr and retried on the next tick.
+    pub fn run(&mut self, interval: Duration, cancel: &AtomicBool) {
+        while !cancel.load(Ordering::SeqCst) {
+            match self.tick() {
+                Ok(0) => {}
+                Ok(count) => println!("🔔 Sent {} new finding(s) to webhook", count),
+                Err(e) => eprintln!("⚠️  Warning: {:#}", e),
+            }
+
+            // Sleep in short steps so Ctrl-C is handled promptly
+            let wake = Instant::now() + interval;
+            while !cancel.load(Ordering::SeqCst) && Instant::now() < wake {
+                std::thread::sleep(Duration::from_millis(100).min(interval));
+            }
+        }
+    }
+
+    fn notify(&self, matches: &[Match]) -> Result<()> {
+        let Some(url) = &self.webhook else {
+            return Ok(());
+        };
+
+        let payload = WebhookPayload {
+            event: "new_findings",
+            root: &self.root,
+            new_findings: matches.len(),
+            matches,
+        };
+
+        self.client
+            .post(url)
+            .json(&payload)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Webhook request to {} failed", url))?;
+
+        Ok(())
+    }
+}
+
+fn all_matches(results: &ScanResults) -> impl Iterator<Item = &Match> {
+    results.files.iter().flat_map(|file| file.matches.iter())
+}
+
+fn finding_key(m: &Match) -> FindingKey {
+    (m.location.file_path.clone(), m.fingerprint.clone())
+}
+
+fn tree_state(root: &Path) -> TreeState {
+    let mut state: TreeState = WalkDir::new(root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some((entry.into_path(), metadata.len(), metadata.modified().ok()))
+        })
+        .collect();
+    state.sort();
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Preset;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use tempfile::TempDir;
+
+    /// Minimal HTTP server that answers 200 and forwards each request body
+    fn mock_webhook() -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap_or(0);
+                        }
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                stream
+                    .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                    .unwrap();
+                if tx.send(String::from_utf8(body).unwrap()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        (url, rx)
+    }
+
+    fn secrets_engine() -> ScanEngine {
+        ScanEngine::new(crate::registry_preset(Preset::Secrets)).show_progress(false)
+    }
+
+    #[test]
+    fn test_new_secret_sends_one_notification() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("README.md"), "Nothing to see here\n").unwrap();
+
+        let (url, notifications) = mock_webhook();
+        let mut watcher = Watcher::new(secrets_engine(), tmp.path())
+            .webhook(url)
+            .debounce(Duration::ZERO);
+
+        // Baseline run never notifies
+        assert_eq!(watcher.tick().unwrap(), 0);
+
+        fs::write(
+            tmp.path().join("settings.env"),
+            "DB_PASSWORD=\"Tr0ub4dor&3xQz\"\n",
+        )
+        .unwrap();
+        assert_eq!(watcher.tick().unwrap(), 1);
+
+        // Unchanged tree: no rescan, no notification
+        assert_eq!(watcher.tick().unwrap(), 0);
+
+        let body = notifications
+            .recv_timeout(Duration::from_secs(5))
+            .expect("webhook was not called");
+        let payload: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(payload["event"], "new_findings");
+        assert_eq!(payload["new_findings"], 1);
+        assert!(payload["matches"][0]["location"]["file_path"]
+            .as_str()
+            .unwrap()
+            .ends_with("settings.env"));
+
+        assert!(notifications
+            .recv_timeout(Duration::from_millis(200))
+            .is_err());
+    }
+
+    #[test]
+    fn test_debounce_waits_for_stable_tree() {
+        let tmp = TempDir::new().unwrap();
+        let mut watcher =
+            Watcher::new(secrets_engine(), tmp.path()).debounce(Duration::from_secs(60));
+
+        assert_eq!(watcher.tick().unwrap(), 0);
+
+        fs::write(
+            tmp.path().join("settings.env"),
+            "DB_PASSWORD=\"Tr0ub4dor&3xQz\"\n",
+        )
+        .unwrap();
+
+        // Changed, but not yet stable for the debounce period
+        assert_eq!(watcher.tick().unwrap(), 0);
+        assert!(watcher.pending.is_some());
+        assert_eq!(watcher.known.as_ref().map(HashSet::len), Some(0));
+    }
+}
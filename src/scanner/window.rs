@@ -0,0 +1,183 @@
+//! Sliding-window detection for files with very long lines
+//!
+//! Single-line dumps (minified JSON, fixed-width exports) would otherwise be
+//! handed to the detectors as one multi-megabyte "line". The text is split
+//! into overlapping windows instead; each window owns the matches that start
+//! before the next window, so a match shorter than the overlap is reported
+//! exactly once even when it straddles a window boundary. The same windows
+//! let large files be scanned in parallel (intra-file parallelism).
+
+use crate::core::{Detector, Match, ScanContext};
+use rayon::prelude::*;
+use std::path::Path;
+
+/// Default longest-line length (bytes) above which windowed detection is used
+pub const DEFAULT_WINDOW_SIZE: usize = 64 * 1024;
+
+/// Bytes shared by consecutive windows; the longest match guaranteed to be found
+pub const WINDOW_OVERLAP: usize = 512;
+
+/// Whether the longest line of `text` exceeds `window_size`
+pub fn needs_window(text: &str, window_size: usize) -> bool {
+    text.split('\n').any(|line| line.len() > window_size)
+}
+
+/// Run `detector` over overlapping windows of `text`
+///
+/// Returned locations are global: byte offsets into `text`, with line and
+/// column recomputed from the newlines in `text`.
+pub fn detect_windowed(
+    detector: &dyn Detector,
+    text: &str,
+    path: &Path,
+    context: &ScanContext,
+    window_size: usize,
+) -> Vec<Match> {
+    let line_starts = line_starts(text);
+
+    window_ranges(text, window_size)
+        .into_iter()
+        .flat_map(|range| detect_in_window(detector, text, path, context, &line_starts, range))
+        .collect()
+}
+
+/// Run `detector` over overlapping windows of `text`, windows in parallel
+///
+/// Same results as [`detect_windowed`], for intra-file parallelism on large files.
+pub fn detect_windowed_parallel(
+    detector: &dyn Detector,
+    text: &str,
+    path: &Path,
+    context: &ScanContext,
+    window_size: usize,
+) -> Vec<Match> {
+    let line_starts = line_starts(text);
+
+    window_ranges(text, window_size)
+        .into_par_iter()
+        .flat_map_iter(|range| detect_in_window(detector, text, path, context, &line_starts, range))
+        .collect()
+}
+
+/// Byte offsets at which the lines of `text` start
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// Matches owned by one window, with global locations
+fn detect_in_window(
+    detector: &dyn Detector,
+    text: &str,
+    path: &Path,
+    context: &ScanContext,
+    line_starts: &[usize],
+    (start, end, owned_end): (usize, usize, usize),
+) -> Vec<Match> {
+    // A window starting inside a word would report the tail of a token
+    // that the previous window already saw in full
+    let cut_word = text[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric());
+
+    let mut matches = detector.detect_in_context(&text[start..end], path, context);
+    matches.retain(|m| {
+        m.location.start_byte + start < owned_end && !(cut_word && m.location.start_byte == 0)
+    });
+
+    for m in &mut matches {
+        m.location.start_byte += start;
+        m.location.end_byte += start;
+        let line = line_starts.partition_point(|&s| s <= m.location.start_byte);
+        m.location.line = line;
+        m.location.column = m.location.start_byte - line_starts[line - 1];
+    }
+
+    matches
+}
+
+/// Window ranges as `(start, end, owned_end)` byte offsets
+///
+/// Matches starting in `start..owned_end` belong to the window; `owned_end`
+/// is the start of the next window.
+fn window_ranges(text: &str, window_size: usize) -> Vec<(usize, usize, usize)> {
+    let window_size = window_size.max(4 * WINDOW_OVERLAP);
+    let mut ranges = Vec::new();
+    let mut start = 0;
+
+    loop {
+        if start + window_size >= text.len() {
+            ranges.push((start, text.len(), text.len()));
+            return ranges;
+        }
+
+        let end = floor_char_boundary(text, start + window_size);
+        let next = floor_char_boundary(text, end - WINDOW_OVERLAP);
+        ranges.push((start, end, next));
+        start = next;
+    }
+}
+
+/// Largest char boundary at or before `index`
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::eu::IbanDetector;
+
+    #[test]
+    fn test_window_ranges_overlap() {
+        let text = "a".repeat(10_000);
+        let ranges = window_ranges(&text, 4096);
+
+        assert_eq!(ranges[0], (0, 4096, 4096 - WINDOW_OVERLAP));
+        assert_eq!(ranges.last().unwrap().1, text.len());
+        for pair in ranges.windows(2) {
+            assert_eq!(pair[0].2, pair[1].0);
+            assert_eq!(pair[0].1 - pair[1].0, WINDOW_OVERLAP);
+        }
+    }
+
+    #[test]
+    fn test_match_straddling_boundary_reported_once() {
+        let iban = "NL91ABNA0417164300";
+        // Place the IBAN across the end of the first window
+        let offset = 4096 - 8;
+        let text = format!("{} {} {}", "x".repeat(offset - 1), iban, "y".repeat(6000));
+
+        let matches = detect_windowed(
+            &IbanDetector::new(),
+            &text,
+            Path::new("dump.txt"),
+            &ScanContext::default(),
+            4096,
+        );
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].location.start_byte, offset);
+        assert_eq!(matches[0].location.end_byte, offset + iban.len());
+    }
+
+    #[test]
+    fn test_line_positions_recomputed() {
+        let text = format!("header\n{} NL91ABNA0417164300", "z".repeat(5000));
+
+        let matches = detect_windowed(
+            &IbanDetector::new(),
+            &text,
+            Path::new("dump.txt"),
+            &ScanContext::default(),
+            2048,
+        );
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].location.line, 2);
+        assert_eq!(matches[0].location.column, 5001);
+    }
+}
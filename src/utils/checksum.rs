@@ -70,15 +70,22 @@ pub fn validate_bsn_11_proef(bsn: &str) -> bool {
 /// assert!(!validate_luhn("1234567890123456")); // Invalide
 /// ```
 pub fn validate_luhn(number: &str) -> bool {
-    // Verwijder alle niet-cijfer karakters
-    let digits: Vec<u32> = number
-        .chars()
-        .filter(|c| c.is_ascii_digit())
-        .filter_map(|c| c.to_digit(10))
-        .collect();
-
     // Creditcards zijn tussen 13 en 19 cijfers (meestal 16)
-    if digits.len() < 13 || digits.len() > 19 {
+    let len = number.chars().filter(|c| c.is_ascii_digit()).count();
+    if !(13..=19).contains(&len) {
+        return false;
+    }
+
+    luhn_checksum(number)
+}
+
+/// Luhn checksum (modulo-10) zonder lengtecontrole
+///
+/// Voor nummers buiten het bereik van 13-19 cijfers, zoals 12-cijferige
+/// Maestro kaarten. Niet-cijfer karakters worden genegeerd.
+pub fn luhn_checksum(number: &str) -> bool {
+    let digits: Vec<u32> = number.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.is_empty() {
         return false;
     }
 
@@ -176,6 +183,123 @@ pub fn validate_iban(iban: &str) -> bool {
     remainder == Some(1)
 }
 
+/// IBAN length per country code, from the ISO 13616 IBAN registry
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+];
+
+/// Registered IBAN length for a country code (e.g. `"DE"` → 22)
+pub fn iban_length(country: &str) -> Option<usize> {
+    IBAN_LENGTHS
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(country))
+        .map(|&(_, len)| len)
+}
+
+/// Checks an IBAN's length against the registered length for its country
+///
+/// Returns `None` when the country code is not in the IBAN registry.
+///
+/// # Examples
+/// ```
+/// use pii_radar::utils::checksum::validate_iban_length;
+///
+/// assert_eq!(validate_iban_length("DE89 3704 0044 0532 0130 00"), Some(true));
+/// assert_eq!(validate_iban_length("DE863704004405320130"), Some(false));
+/// assert_eq!(validate_iban_length("XX00123456789012"), None);
+/// ```
+pub fn validate_iban_length(iban: &str) -> Option<bool> {
+    let clean: String = iban.chars().filter(|c| !c.is_whitespace()).collect();
+    let expected = iban_length(clean.get(..2)?)?;
+    Some(clean.len() == expected)
+}
+
 /// Basis modulo-10 checksum
 pub fn checksum_mod10(digits: &[u32]) -> u32 {
     digits.iter().sum::<u32>() % 10
@@ -245,6 +369,49 @@ pub fn validate_nhs_number(nhs: &str) -> bool {
     digits[9] == check_digit
 }
 
+/// Validates a UK Unique Taxpayer Reference (UTR) using modulus 11
+///
+/// UTRs are 10 digits with the first digit being the check digit, optionally
+/// followed by a `K`. Algorithm: multiply digits 2-10 by weights
+/// (6,7,8,9,10,5,4,3,2), take the sum modulo 11 and subtract it from 11;
+/// results of 10 and 11 map to check digits 1 and 2.
+///
+/// # Examples
+/// ```
+/// use pii_radar::validate_uk_utr;
+///
+/// assert!(validate_uk_utr("1955839661")); // Valid UTR
+/// assert!(validate_uk_utr("19558 39661K")); // With space and K suffix
+/// assert!(!validate_uk_utr("2955839661")); // Invalid check digit
+/// ```
+pub fn validate_uk_utr(utr: &str) -> bool {
+    let utr = utr.trim_end_matches(['K', 'k']);
+    let digits: Vec<u32> = utr
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_digit(10))
+        .collect::<Option<_>>()
+        .unwrap_or_default();
+
+    if digits.len() != 10 {
+        return false;
+    }
+
+    let weights = [6, 7, 8, 9, 10, 5, 4, 3, 2];
+    let sum: u32 = digits[1..]
+        .iter()
+        .zip(weights.iter())
+        .map(|(d, w)| d * w)
+        .sum();
+
+    let check_digit = match 11 - sum % 11 {
+        n @ 10..=11 => n - 9,
+        n => n,
+    };
+
+    digits[0] == check_digit
+}
+
 /// Validates Spanish DNI/NIE check letter using modulus 23 algorithm
 ///
 /// DNI: 8 digits + 1 check letter
@@ -316,6 +483,52 @@ pub fn validate_spain_id(id: &str) -> bool {
     check_letter == expected_letter
 }
 
+/// Validates Spanish social security numbers (NAF/NUSS) using modulus 97
+///
+/// Format: PP-SSSSSSSS-CC (12 digits)
+/// - PP: Province code (01-53)
+/// - SSSSSSSS: Serial number
+/// - CC: Check digits, `(PP * 10^8 + SSSSSSSS) mod 97`
+///
+/// Serials below 10,000,000 are combined as `PP * 10^7 + SSSSSSSS`, as the
+/// Seguridad Social does for old numbers with a 7-digit serial.
+///
+/// # Examples
+/// ```
+/// use pii_radar::validate_spain_naf;
+///
+/// assert!(validate_spain_naf("281234567840")); // Valid NAF
+/// assert!(validate_spain_naf("28/12345678/40")); // With separators
+/// assert!(!validate_spain_naf("281234567841")); // Wrong check digits
+/// ```
+pub fn validate_spain_naf(naf: &str) -> bool {
+    let clean = naf.replace([' ', '-', '/'], "");
+
+    if clean.len() != 12 || !clean.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let (Ok(province), Ok(serial), Ok(check)) = (
+        clean[..2].parse::<u64>(),
+        clean[2..10].parse::<u64>(),
+        clean[10..].parse::<u64>(),
+    ) else {
+        return false;
+    };
+
+    if !(1..=53).contains(&province) {
+        return false;
+    }
+
+    let number = if serial < 10_000_000 {
+        province * 10_000_000 + serial
+    } else {
+        province * 100_000_000 + serial
+    };
+
+    number % 97 == check
+}
+
 /// Validates Belgian RRN (Rijksregisternummer) using modulus 97 algorithm
 ///
 /// Format: YYMMDD-SSS-CC (11 digits)
@@ -487,6 +700,524 @@ pub fn validate_portugal_nif(nif: &str) -> bool {
     check_digit == digits[8]
 }
 
+/// Validates a Dutch health insurance number (verzekerdennummer)
+///
+/// Insurer-issued member numbers are 8 to 10 digits and most insurers use
+/// an 11-proof: each digit is multiplied by its position counted from the
+/// right (n..1) and the sum must be divisible by 11. All-zero numbers are
+/// rejected.
+///
+/// # Examples
+/// ```
+/// use pii_radar::utils::checksum::validate_nl_health_insurance;
+///
+/// assert!(validate_nl_health_insurance("734190204"));
+/// assert!(!validate_nl_health_insurance("734190205"));
+/// ```
+pub fn validate_nl_health_insurance(number: &str) -> bool {
+    let digits: Vec<u32> = number
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .filter_map(|c| c.to_digit(10))
+        .collect();
+
+    if !(8..=10).contains(&digits.len()) || digits.iter().all(|&d| d == 0) {
+        return false;
+    }
+
+    let len = digits.len() as u32;
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(i, d)| d * (len - i as u32))
+        .sum();
+
+    sum.is_multiple_of(11)
+}
+
+/// Validates the national account checksum embedded in an IBAN
+///
+/// Several countries embed their own check digits in the BBAN on top of the
+/// IBAN modulo-97 check. This runs the national check for the supported
+/// countries:
+/// - BE: first 10 BBAN digits modulo 97 (97 when the remainder is 0)
+/// - NL: 10-digit account number, 11-proof (weights 10..1)
+/// - NO: modulo 11 with weights 5,4,3,2,7,6,5,4,3,2
+/// - FI: Luhn over the 14-digit BBAN
+/// - SE: modulo 11 for type 1 accounts (SEB, Danske Bank, Nordea,
+///   Länsförsäkringar, ICA), with the clearing number range checked
+///   against the bank code
+/// - IT: CIN control letter (see [`validate_it_cin`])
+/// - ES: two "dígitos de control" (see [`validate_es_dc`])
+/// - FR: RIB key (see [`validate_fr_rib`])
+///
+/// Returns `None` when the country (or Swedish bank) has no supported
+/// national check, so callers can tell "not checked" from "failed".
+///
+/// # Examples
+/// ```
+/// use pii_radar::utils::checksum::validate_iban_national;
+///
+/// assert_eq!(validate_iban_national("BE68539007547034"), Some(true));
+/// assert_eq!(validate_iban_national("BE41539007547035"), Some(false));
+/// assert_eq!(validate_iban_national("DE89370400440532013000"), None);
+/// ```
+pub fn validate_iban_national(iban: &str) -> Option<bool> {
+    let clean: String = iban
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+
+    if clean.len() < 5 || !clean.is_ascii() {
+        return None;
+    }
+
+    let country = &clean[..2];
+    let bban = &clean[4..];
+
+    match country {
+        "BE" => Some(national_check_be(bban)),
+        "NL" => national_check_nl(bban),
+        "NO" => Some(national_check_no(bban)),
+        "FI" => Some(national_check_fi(bban)),
+        "SE" => national_check_se(bban),
+        "IT" => Some(validate_it_cin(bban)),
+        "ES" => Some(validate_es_dc(bban)),
+        "FR" => Some(validate_fr_rib(bban)),
+        _ => None,
+    }
+}
+
+/// Parse an all-digit string into digits
+fn bban_digits(value: &str) -> Option<Vec<u32>> {
+    value.chars().map(|c| c.to_digit(10)).collect()
+}
+
+fn national_check_be(bban: &str) -> bool {
+    let Some(digits) = bban_digits(bban) else {
+        return false;
+    };
+    if digits.len() != 12 {
+        return false;
+    }
+
+    let base = digits[..10]
+        .iter()
+        .fold(0u64, |acc, &d| acc * 10 + d as u64);
+    let check = (digits[10] * 10 + digits[11]) as u64;
+    let expected = match base % 97 {
+        0 => 97,
+        r => r,
+    };
+
+    expected == check
+}
+
+fn national_check_nl(bban: &str) -> Option<bool> {
+    if bban.len() != 14 {
+        return Some(false);
+    }
+
+    // Former Postbank accounts (ING) predate the 11-proof
+    if &bban[..4] == "INGB" {
+        return None;
+    }
+
+    let Some(digits) = bban_digits(&bban[4..]) else {
+        return Some(false);
+    };
+
+    let sum: u32 = digits.iter().zip((1..=10).rev()).map(|(d, w)| d * w).sum();
+
+    Some(sum.is_multiple_of(11))
+}
+
+fn national_check_no(bban: &str) -> bool {
+    let Some(digits) = bban_digits(bban) else {
+        return false;
+    };
+    if digits.len() != 11 {
+        return false;
+    }
+
+    let weights = [5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+    let sum: u32 = digits[..10]
+        .iter()
+        .zip(weights.iter())
+        .map(|(d, w)| d * w)
+        .sum();
+
+    match 11 - sum % 11 {
+        11 => digits[10] == 0,
+        10 => false,
+        check => digits[10] == check,
+    }
+}
+
+fn national_check_fi(bban: &str) -> bool {
+    let Some(digits) = bban_digits(bban) else {
+        return false;
+    };
+    if digits.len() != 14 {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+/// Swedish type 1 (comment 1) banks: IBAN bank code and clearing number ranges
+const SE_TYPE1_BANKS: &[(&str, &[(u32, u32)])] = &[
+    ("120", &[(1200, 1399)]),
+    (
+        "300",
+        &[
+            (1100, 1199),
+            (1400, 2099),
+            (3000, 3299),
+            (3301, 3399),
+            (3410, 3781),
+            (3783, 3999),
+        ],
+    ),
+    ("500", &[(5000, 5999)]),
+    ("902", &[(3400, 3409), (9060, 9069)]),
+    ("927", &[(9270, 9279)]),
+];
+
+fn national_check_se(bban: &str) -> Option<bool> {
+    let digits = bban_digits(bban)?;
+    if digits.len() != 20 {
+        return Some(false);
+    }
+
+    let bank_code = &bban[..3];
+    let (_, ranges) = SE_TYPE1_BANKS.iter().find(|(code, _)| *code == bank_code)?;
+
+    // Type 1 accounts: 4-digit clearing number + 7-digit account, zero-padded
+    let account = &digits[9..];
+    let clearing = account[..4].iter().fold(0, |acc, &d| acc * 10 + d);
+
+    if !ranges
+        .iter()
+        .any(|&(low, high)| (low..=high).contains(&clearing))
+    {
+        return Some(false);
+    }
+
+    // Modulo 11 over the clearing number (minus its first digit) and account
+    let sum: u32 = account[1..].iter().rev().zip(1..).map(|(d, w)| d * w).sum();
+
+    Some(sum.is_multiple_of(11))
+}
+
+/// CIN values for characters in odd positions (indexed by digit or letter value)
+const IT_CIN_ODD: [u32; 26] = [
+    1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23,
+];
+
+/// Validates the CIN control letter of an Italian BBAN
+///
+/// Format: CIN letter + ABI (5 digits) + CAB (5 digits) + account number
+/// (12 alphanumerics). The CIN is computed over the 22 characters after it:
+/// odd positions are mapped through the CIN conversion table, even positions
+/// use their plain value (digits 0-9, letters A=0..Z=25), and the sum
+/// modulo 26 gives the expected letter.
+///
+/// # Examples
+/// ```
+/// use pii_radar::utils::checksum::validate_it_cin;
+///
+/// assert!(validate_it_cin("X0542811101000000123456"));
+/// assert!(!validate_it_cin("Y0542811101000000123456"));
+/// ```
+pub fn validate_it_cin(bban: &str) -> bool {
+    let bban = bban.to_uppercase();
+    if bban.len() != 23 || !bban.is_ascii() {
+        return false;
+    }
+
+    let mut chars = bban.chars();
+    let Some(cin) = chars.next().filter(|c| c.is_ascii_uppercase()) else {
+        return false;
+    };
+
+    let mut sum = 0;
+    for (index, c) in chars.enumerate() {
+        let value = match c {
+            '0'..='9' => c as u32 - '0' as u32,
+            'A'..='Z' => c as u32 - 'A' as u32,
+            _ => return false,
+        };
+        sum += if index % 2 == 0 {
+            IT_CIN_ODD[value as usize]
+        } else {
+            value
+        };
+    }
+
+    (b'A' + (sum % 26) as u8) as char == cin
+}
+
+/// Validates the two control digits of a Spanish BBAN
+///
+/// Format: entity (4 digits) + office (4 digits) + DC (2 digits) + account
+/// (10 digits). The first DC digit covers `00` + entity + office, the second
+/// covers the account, both modulo 11 with weights 1,2,4,8,5,10,9,7,3,6
+/// (11 - remainder, where 11 maps to 0 and 10 maps to 1).
+///
+/// # Examples
+/// ```
+/// use pii_radar::utils::checksum::validate_es_dc;
+///
+/// assert!(validate_es_dc("21000418450200051332"));
+/// assert!(!validate_es_dc("21000418460200051332"));
+/// ```
+pub fn validate_es_dc(bban: &str) -> bool {
+    let Some(digits) = bban_digits(bban) else {
+        return false;
+    };
+    if digits.len() != 20 {
+        return false;
+    }
+
+    fn control_digit(digits: &[u32]) -> u32 {
+        let weights = [1, 2, 4, 8, 5, 10, 9, 7, 3, 6];
+        let sum: u32 = digits.iter().zip(weights.iter()).map(|(d, w)| d * w).sum();
+        match 11 - sum % 11 {
+            11 => 0,
+            10 => 1,
+            check => check,
+        }
+    }
+
+    let mut bank = vec![0, 0];
+    bank.extend_from_slice(&digits[..8]);
+
+    control_digit(&bank) == digits[8] && control_digit(&digits[10..]) == digits[9]
+}
+
+/// Validates the RIB key of a French BBAN
+///
+/// Format: bank code (5 digits) + branch code (5 digits) + account number
+/// (11 alphanumerics) + RIB key (2 digits). Letters in the account number
+/// are converted to digits (A/J=1, B/K/S=2, ... I/R/Z=9) and the key must
+/// equal `97 - ((89 * bank + 15 * branch + 3 * account) mod 97)`.
+///
+/// # Examples
+/// ```
+/// use pii_radar::utils::checksum::validate_fr_rib;
+///
+/// assert!(validate_fr_rib("20041010050500013M02606"));
+/// assert!(!validate_fr_rib("20041010050500013M02607"));
+/// ```
+pub fn validate_fr_rib(bban: &str) -> bool {
+    let bban = bban.to_uppercase();
+    if bban.len() != 23 || !bban.is_ascii() {
+        return false;
+    }
+
+    let to_number = |part: &str| -> Option<u64> {
+        part.chars().try_fold(0u64, |acc, c| {
+            let digit = match c {
+                '0'..='9' => c as u64 - '0' as u64,
+                'A'..='I' => c as u64 - 'A' as u64 + 1,
+                'J'..='R' => c as u64 - 'J' as u64 + 1,
+                'S'..='Z' => c as u64 - 'S' as u64 + 2,
+                _ => return None,
+            };
+            Some(acc * 10 + digit)
+        })
+    };
+
+    // Bank code, branch code and key are always numeric
+    if !bban[..10].chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let (Some(bank), Some(branch), Some(account)) = (
+        to_number(&bban[..5]),
+        to_number(&bban[5..10]),
+        to_number(&bban[10..21]),
+    ) else {
+        return false;
+    };
+    let Ok(key) = bban[21..].parse::<u64>() else {
+        return false;
+    };
+
+    97 - (89 * bank + 15 * branch + 3 * account) % 97 == key
+}
+
+/// Validates the check digits of an EU VAT number (with country prefix)
+///
+/// Supported national formats:
+/// - DE: 9 digits, ISO 7064 MOD 11,10
+/// - NL: 9 digits + `B` + 2 digits, 11-proof or (sole proprietors) MOD 97
+/// - BE: 10 digits starting with 0 or 1, MOD 97
+/// - FR: 2-digit key + 9-digit SIREN, key = (12 + 3 × (SIREN mod 97)) mod 97
+/// - IT: 11 digits, Luhn
+/// - PT: 9 digits, NIF modulus 11
+///
+/// Returns `None` when the country is unsupported or the body does not have
+/// the national VAT format, so callers can tell "not a VAT number" from
+/// "failed".
+///
+/// # Examples
+/// ```
+/// use pii_radar::utils::checksum::validate_vat;
+///
+/// assert_eq!(validate_vat("DE136695976"), Some(true));
+/// assert_eq!(validate_vat("DE136695977"), Some(false));
+/// assert_eq!(validate_vat("DE12345"), None);
+/// ```
+pub fn validate_vat(vat: &str) -> Option<bool> {
+    let clean: String = vat
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_uppercase();
+
+    if clean.len() < 3 {
+        return None;
+    }
+
+    let (country, body) = clean.split_at(2);
+    let digits = bban_digits(body);
+
+    match (country, digits) {
+        ("DE", Some(digits)) if digits.len() == 9 => Some(vat_check_de(&digits)),
+        ("NL", _) => vat_check_nl(body),
+        ("BE", Some(digits)) if digits.len() == 10 && digits[0] <= 1 => {
+            let base = digits[..8].iter().fold(0, |acc, &d| acc * 10 + d);
+            Some(97 - base % 97 == digits[8] * 10 + digits[9])
+        }
+        ("FR", Some(digits)) if digits.len() == 11 => {
+            let key = digits[0] * 10 + digits[1];
+            let siren = digits[2..].iter().fold(0, |acc, &d| acc * 10 + d);
+            Some(key == (12 + 3 * (siren % 97)) % 97)
+        }
+        ("IT", Some(digits)) if digits.len() == 11 => Some(luhn_checksum(body)),
+        ("PT", Some(digits)) if digits.len() == 9 => Some(validate_portugal_nif(body)),
+        _ => None,
+    }
+}
+
+/// German VAT (USt-IdNr.) check digit, ISO 7064 MOD 11,10
+fn vat_check_de(digits: &[u32]) -> bool {
+    let mut product = 10;
+    for &digit in &digits[..8] {
+        let sum = match (digit + product) % 10 {
+            0 => 10,
+            sum => sum,
+        };
+        product = (2 * sum) % 11;
+    }
+
+    (11 - product) % 10 == digits[8]
+}
+
+/// Dutch VAT: `NNNNNNNNNBNN`
+fn vat_check_nl(body: &str) -> Option<bool> {
+    if body.len() != 12 || body.as_bytes()[9] != b'B' {
+        return None;
+    }
+    bban_digits(&body[..9])?;
+    bban_digits(&body[10..])?;
+
+    if validate_bsn_11_proef(&body[..9]) {
+        return Some(true);
+    }
+
+    Some(vat_mod97_nl(body))
+}
+
+/// MOD 97 check of a Dutch VAT body (`NNNNNNNNNBNN`), used by sole
+/// proprietor numbers issued since 2020: "NL" + body, letters as 10..35
+pub(crate) fn vat_mod97_nl(body: &str) -> bool {
+    let remainder = format!("NL{}", body)
+        .chars()
+        .map(|c| c.to_digit(36).unwrap_or(0))
+        .fold(0u64, |acc, d| {
+            if d >= 10 {
+                (acc * 100 + d as u64) % 97
+            } else {
+                (acc * 10 + d as u64) % 97
+            }
+        });
+
+    remainder == 1
+}
+
+/// Schemes accepted by [`validate`], named after the matching detector IDs
+pub const VALIDATION_SCHEMES: &[&str] = &[
+    "be_rrn",
+    "creditcard",
+    "de_steuer_id",
+    "es_dni",
+    "es_naf",
+    "es_nie",
+    "gb_nhs",
+    "gb_utr",
+    "iban",
+    "luhn",
+    "nl_bsn",
+    "nl_health_insurance",
+    "nl_rsin",
+    "pt_nif",
+    "vat",
+];
+
+/// Validates `value` against a named scheme
+///
+/// Scheme names follow the detector IDs (`nl_bsn`, `iban`, `es_dni`, ...;
+/// see [`VALIDATION_SCHEMES`]) plus the generic `luhn`. Returns `None` for
+/// unknown schemes. `vat` fails for numbers whose country or format has no
+/// supported check.
+///
+/// # Examples
+/// ```
+/// assert_eq!(pii_radar::validate("nl_bsn", "111222333"), Some(true));
+/// assert_eq!(pii_radar::validate("iban", "NL91ABNA0417164301"), Some(false));
+/// assert_eq!(pii_radar::validate("us_ssn", "078-05-1120"), None);
+/// ```
+pub fn validate(scheme: &str, value: &str) -> Option<bool> {
+    let valid = match scheme.to_ascii_lowercase().as_str() {
+        "be_rrn" => validate_belgian_rrn(value),
+        "creditcard" | "luhn" => validate_luhn(value),
+        "de_steuer_id" => validate_steuer_id(value),
+        "es_dni" | "es_nie" => validate_spain_id(value),
+        "es_naf" => validate_spain_naf(value),
+        "gb_nhs" => validate_nhs_number(value),
+        "gb_utr" => validate_uk_utr(value),
+        "iban" => validate_iban(value),
+        "nl_bsn" | "nl_rsin" => validate_bsn_11_proef(value),
+        "nl_health_insurance" => validate_nl_health_insurance(value),
+        "pt_nif" => validate_portugal_nif(value),
+        "vat" => validate_vat(value) == Some(true),
+        _ => return None,
+    };
+
+    Some(valid)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -601,6 +1332,19 @@ mod tests {
         assert!(!validate_iban("A".repeat(35).as_str())); // Te lang
     }
 
+    #[test]
+    fn test_iban_country_length() {
+        assert_eq!(iban_length("DE"), Some(22));
+        assert_eq!(iban_length("nl"), Some(18));
+        assert_eq!(validate_iban_length("DE89370400440532013000"), Some(true));
+
+        // Valid modulo-97, but a German IBAN must be 22 characters
+        assert!(validate_iban("DE863704004405320130"));
+        assert_eq!(validate_iban_length("DE863704004405320130"), Some(false));
+
+        assert_eq!(validate_iban_length("XX00123456789012"), None);
+    }
+
     // ===== Modulo helper tests =====
 
     #[test]
@@ -636,6 +1380,22 @@ mod tests {
         assert!(!validate_nhs_number("12345678901")); // Too long
     }
 
+    // ===== UK UTR Tests =====
+
+    #[test]
+    fn test_uk_utr_valid() {
+        assert!(validate_uk_utr("1955839661"));
+        assert!(validate_uk_utr("19558 39661"));
+        assert!(validate_uk_utr("1955839661K"));
+    }
+
+    #[test]
+    fn test_uk_utr_invalid() {
+        assert!(!validate_uk_utr("2955839661")); // Wrong check digit
+        assert!(!validate_uk_utr("195583966")); // Too short
+        assert!(!validate_uk_utr("19558-39661")); // Not a digit
+    }
+
     // ===== Spain DNI/NIE Tests =====
 
     #[test]
@@ -663,6 +1423,27 @@ mod tests {
         assert!(!validate_spain_id("W1234567L")); // Invalid prefix (not X/Y/Z)
     }
 
+    // ===== Spain NAF Tests =====
+
+    #[test]
+    fn test_spain_naf_valid() {
+        assert!(validate_spain_naf("281234567840")); // Valid NAF
+        assert!(validate_spain_naf("46 87654321 20")); // With spaces
+        assert!(validate_spain_naf("080123456774")); // 7-digit serial
+    }
+
+    #[test]
+    fn test_spain_naf_invalid_check_digits() {
+        assert!(!validate_spain_naf("281234567841"));
+        assert!(!validate_spain_naf("991234567840")); // Unknown province
+    }
+
+    #[test]
+    fn test_spain_naf_wrong_length() {
+        assert!(!validate_spain_naf("2812345678")); // Too short
+        assert!(!validate_spain_naf("2812345678400")); // Too long
+    }
+
     // ===== Belgian RRN Tests =====
 
     #[test]
@@ -774,4 +1555,175 @@ mod tests {
         assert!(!validate_portugal_nif("abcdefghi"));
         assert!(!validate_portugal_nif("12345678X"));
     }
+
+    // ===== IBAN National Checksum Tests =====
+
+    #[test]
+    fn test_iban_national_belgium() {
+        assert_eq!(validate_iban_national("BE68539007547034"), Some(true));
+        // Mod-97 valid, national check digits wrong
+        assert!(validate_iban("BE41539007547035"));
+        assert_eq!(validate_iban_national("BE41539007547035"), Some(false));
+    }
+
+    #[test]
+    fn test_iban_national_netherlands() {
+        assert_eq!(validate_iban_national("NL91ABNA0417164300"), Some(true));
+        assert!(validate_iban("NL64ABNA0417164301"));
+        assert_eq!(validate_iban_national("NL64ABNA0417164301"), Some(false));
+        // Former Postbank accounts are not 11-proof
+        assert_eq!(validate_iban_national("NL69INGB0123456789"), None);
+    }
+
+    #[test]
+    fn test_iban_national_norway() {
+        assert_eq!(validate_iban_national("NO9386011117947"), Some(true));
+        assert!(validate_iban("NO6686011117948"));
+        assert_eq!(validate_iban_national("NO6686011117948"), Some(false));
+    }
+
+    #[test]
+    fn test_iban_national_finland() {
+        assert_eq!(validate_iban_national("FI2112345600000785"), Some(true));
+        assert!(validate_iban("FI9112345600000786"));
+        assert_eq!(validate_iban_national("FI9112345600000786"), Some(false));
+    }
+
+    #[test]
+    fn test_iban_national_sweden() {
+        assert_eq!(
+            validate_iban_national("SE4550000000058398257466"),
+            Some(true)
+        );
+        assert!(validate_iban("SE1850000000058398257467"));
+        assert_eq!(
+            validate_iban_national("SE1850000000058398257467"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_validate_it_cin() {
+        assert!(validate_it_cin("X0542811101000000123456"));
+        assert!(!validate_it_cin("Y0542811101000000123456"));
+        assert!(!validate_it_cin("X054281110100000012345"));
+        assert_eq!(
+            validate_iban_national("IT60X0542811101000000123456"),
+            Some(true)
+        );
+        // Mod-97 valid, CIN wrong
+        assert!(validate_iban("IT64Y0542811101000000123456"));
+        assert_eq!(
+            validate_iban_national("IT64Y0542811101000000123456"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_validate_es_dc() {
+        assert!(validate_es_dc("21000418450200051332"));
+        assert!(!validate_es_dc("21000418460200051332"));
+        assert!(!validate_es_dc("2100041845020005133A"));
+        assert_eq!(
+            validate_iban_national("ES9121000418450200051332"),
+            Some(true)
+        );
+        assert!(validate_iban("ES2921000418460200051332"));
+        assert_eq!(
+            validate_iban_national("ES2921000418460200051332"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_validate_fr_rib() {
+        assert!(validate_fr_rib("20041010050500013M02606"));
+        assert!(!validate_fr_rib("20041010050500013M02607"));
+        assert!(!validate_fr_rib("2004101005050001302606"));
+        assert_eq!(
+            validate_iban_national("FR1420041010050500013M02606"),
+            Some(true)
+        );
+        assert!(validate_iban("FR8420041010050500013M02607"));
+        assert_eq!(
+            validate_iban_national("FR8420041010050500013M02607"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_iban_national_unsupported_country() {
+        assert_eq!(validate_iban_national("DE89370400440532013000"), None);
+        assert_eq!(validate_iban_national("GB82WEST12345698765432"), None);
+    }
+
+    // ===== NL Health Insurance Number Tests =====
+
+    #[test]
+    fn test_nl_health_insurance_valid() {
+        assert!(validate_nl_health_insurance("734190204"));
+        assert!(validate_nl_health_insurance("7341 902 04"));
+    }
+
+    #[test]
+    fn test_nl_health_insurance_invalid() {
+        assert!(!validate_nl_health_insurance("734190205"));
+        assert!(!validate_nl_health_insurance("0000000000"));
+        assert!(!validate_nl_health_insurance("1234567")); // too short
+        assert!(!validate_nl_health_insurance("12345678901")); // too long
+    }
+
+    // ===== VAT Tests =====
+
+    #[test]
+    fn test_vat_valid() {
+        assert_eq!(validate_vat("DE136695976"), Some(true));
+        assert_eq!(validate_vat("DE 811 569 869"), Some(true));
+        assert_eq!(validate_vat("BE0403170701"), Some(true));
+        assert_eq!(validate_vat("FR83404833048"), Some(true));
+        assert_eq!(validate_vat("NL111222333B01"), Some(true));
+        assert_eq!(validate_vat("PT123456789"), Some(true));
+    }
+
+    #[test]
+    fn test_vat_invalid() {
+        assert_eq!(validate_vat("DE136695977"), Some(false));
+        assert_eq!(validate_vat("BE0403170702"), Some(false));
+        assert_eq!(validate_vat("FR84404833048"), Some(false));
+        assert_eq!(validate_vat("NL111222334B01"), Some(false));
+    }
+
+    #[test]
+    fn test_vat_unknown_format() {
+        assert_eq!(validate_vat("DE12345"), None);
+        assert_eq!(validate_vat("NL111222333"), None);
+        assert_eq!(validate_vat("GB123456789"), None);
+    }
+
+    // ===== Scheme dispatch Tests =====
+
+    #[test]
+    fn test_validate_by_scheme() {
+        assert_eq!(validate("nl_bsn", "111222333"), Some(true));
+        assert_eq!(validate("nl_bsn", "123456789"), Some(false));
+        assert_eq!(validate("IBAN", "NL91ABNA0417164300"), Some(true));
+        assert_eq!(validate("luhn", "4532015112830366"), Some(true));
+        assert_eq!(validate("es_dni", "12345678Z"), Some(true));
+        assert_eq!(validate("gb_nhs", "9434765910"), Some(false));
+        assert_eq!(validate("vat", "DE136695976"), Some(true));
+        assert_eq!(validate("vat", "US123456789"), Some(false));
+    }
+
+    #[test]
+    fn test_validate_unknown_scheme() {
+        assert_eq!(validate("us_ssn", "078-05-1120"), None);
+        assert_eq!(validate("", "111222333"), None);
+    }
+
+    #[test]
+    fn test_validation_schemes_all_dispatch() {
+        for scheme in VALIDATION_SCHEMES {
+            assert!(validate(scheme, "0").is_some(), "{} not dispatched", scheme);
+        }
+    }
 }
@@ -0,0 +1,114 @@
+//! Keyword gates shared by detectors whose pattern alone is too broad
+//!
+//! Detectors such as the passport, IMEI and tax reference detectors only
+//! report a candidate when one of their keywords appears shortly before it
+//! on the same line. Paranoid scans also report candidates without a
+//! keyword, at Low confidence.
+
+use crate::core::{Confidence, Strictness};
+
+/// Number of bytes before a candidate searched for a keyword
+pub const KEYWORD_WINDOW: usize = 30;
+
+/// Check whether one of `keywords` (lowercase) appears shortly before `start`
+///
+/// The window is the [`KEYWORD_WINDOW`] bytes of `line` before `start`,
+/// widened to a character boundary, and is compared case-insensitively.
+pub fn has_keyword_before(line: &str, start: usize, keywords: &[&str]) -> bool {
+    let window = window_before(line, start);
+    keywords.iter().any(|kw| window.contains(kw))
+}
+
+/// Like [`has_keyword_before`], but keywords only count as whole words
+///
+/// `bv` is found in `Acme BV, RSIN` but not in `obviously`.
+pub fn has_word_before(line: &str, start: usize, keywords: &[&str]) -> bool {
+    let window = window_before(line, start);
+    keywords.iter().any(|kw| {
+        window.match_indices(kw).any(|(at, _)| {
+            let before = window[..at].chars().next_back();
+            let after = window[at + kw.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+    })
+}
+
+/// The lowercased [`KEYWORD_WINDOW`] bytes of `line` before `start`
+fn window_before(line: &str, start: usize) -> String {
+    let mut window_start = start.saturating_sub(KEYWORD_WINDOW);
+    while !line.is_char_boundary(window_start) {
+        window_start -= 1;
+    }
+
+    line[window_start..start].to_lowercase()
+}
+
+/// Confidence of a keyword-gated candidate, or `None` when it is not reported
+///
+/// A candidate with a keyword before it gets `confidence`; one without is
+/// reported as Low under [`Strictness::Paranoid`] and dropped otherwise.
+pub fn keyword_gate(
+    line: &str,
+    start: usize,
+    keywords: &[&str],
+    confidence: Confidence,
+    strictness: Strictness,
+) -> Option<Confidence> {
+    if has_keyword_before(line, start, keywords) {
+        Some(confidence)
+    } else {
+        strictness.reports_pattern_only().then_some(Confidence::Low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_keyword_before() {
+        let line = "Passport No: NX1234567";
+        assert!(has_keyword_before(line, 13, &["passport"]));
+        assert!(!has_keyword_before(line, 13, &["imei"]));
+
+        // Keyword too far before the candidate
+        let line = format!("passport{}NX1234567", " ".repeat(KEYWORD_WINDOW));
+        assert!(!has_keyword_before(&line, line.len() - 9, &["passport"]));
+
+        // Multi-byte characters at the window edge
+        let line = format!("{}Reisepass: C01X00T47", "ü".repeat(20));
+        let start = line.find("C01").unwrap();
+        assert!(has_keyword_before(&line, start, &["reisepass"]));
+    }
+
+    #[test]
+    fn test_has_word_before() {
+        assert!(has_word_before("Acme B.V., RSIN 111222333", 16, &["b.v."]));
+        assert!(has_word_before("Acme BV 111222333", 8, &["bv"]));
+        assert!(!has_word_before("obviously 111222333", 10, &["bv"]));
+        assert!(!has_word_before("permission 12345", 11, &["permis"]));
+    }
+
+    #[test]
+    fn test_keyword_gate_strictness() {
+        let gate = |line: &str, strictness| {
+            keyword_gate(
+                line,
+                line.len() - 9,
+                &["passport"],
+                Confidence::Medium,
+                strictness,
+            )
+        };
+
+        assert_eq!(
+            gate("Passport: NX1234567", Strictness::Lenient),
+            Some(Confidence::Medium)
+        );
+        assert_eq!(gate("Order ref NX1234567", Strictness::Balanced), None);
+        assert_eq!(
+            gate("Order ref NX1234567", Strictness::Paranoid),
+            Some(Confidence::Low)
+        );
+    }
+}
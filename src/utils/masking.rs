@@ -151,6 +151,104 @@ pub fn mask_api_key(key: &str) -> String {
     format!("{}{}", &key[..show_chars], "*".repeat(mask_len))
 }
 
+/// Kind of value being masked, used by [`mask_preserving`] to pick a
+/// format-preserving strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaskKind {
+    /// Payment card number (keeps BIN prefix and last 4 digits)
+    CreditCard,
+    /// IBAN (keeps country code, check digits and last 4 characters)
+    Iban,
+    /// Email address (keeps first character of each part and the TLD)
+    Email,
+    /// Anything else, falls back to [`mask_value`]
+    Generic,
+}
+
+impl MaskKind {
+    /// Pick the mask kind for a detector id
+    pub fn for_detector(detector_id: &str) -> Self {
+        match detector_id {
+            "creditcard" | "card_track_data" => MaskKind::CreditCard,
+            "iban" => MaskKind::Iban,
+            "email" => MaskKind::Email,
+            _ => MaskKind::Generic,
+        }
+    }
+}
+
+/// Mask a value while preserving its original layout
+///
+/// Separators (spaces, dashes, dots) and total length are kept, so masked
+/// values of different types remain visually distinguishable in reports.
+///
+/// Examples:
+/// - ("4532 0151 1283 0366", CreditCard) → "4532 **** **** 0366"
+/// - ("NL91 ABNA 0417 1643 00", Iban) → "NL91 **** **** **43 00"
+/// - ("john@example.com", Email) → "j***@e******.com"
+pub fn mask_preserving(value: &str, kind: MaskKind) -> String {
+    match kind {
+        MaskKind::CreditCard => mask_keep_ends(value, 4, 4),
+        MaskKind::Iban => mask_keep_ends(value, 4, 4),
+        MaskKind::Email => mask_email_preserving(value),
+        MaskKind::Generic => mask_value(value),
+    }
+}
+
+/// Mask all alphanumeric characters except the first `keep_start` and the
+/// last `keep_end`, leaving separators in place
+fn mask_keep_ends(value: &str, keep_start: usize, keep_end: usize) -> String {
+    let total = value.chars().filter(|c| c.is_ascii_alphanumeric()).count();
+
+    if total <= keep_start + keep_end {
+        return value
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { '*' } else { c })
+            .collect();
+    }
+
+    let mut seen = 0;
+    value
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_alphanumeric() {
+                return c;
+            }
+            seen += 1;
+            if seen <= keep_start || seen > total - keep_end {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Mask one part of an email, keeping only its first character
+fn mask_label(label: &str) -> String {
+    let mut chars = label.chars();
+    match chars.next() {
+        Some(first) => std::iter::once(first).chain(chars.map(|_| '*')).collect(),
+        None => String::new(),
+    }
+}
+
+fn mask_email_preserving(email: &str) -> String {
+    let Some((local, domain)) = email.split_once('@') else {
+        return "*".repeat(email.chars().count());
+    };
+
+    let masked_domain = match domain.rsplit_once('.') {
+        Some((host, tld)) => {
+            let host: Vec<String> = host.split('.').map(mask_label).collect();
+            format!("{}.{}", host.join("."), tld)
+        }
+        None => mask_label(domain),
+    };
+
+    format!("{}@{}", mask_label(local), masked_domain)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,4 +311,62 @@ mod tests {
             "xox************************"
         );
     }
+
+    #[test]
+    fn test_mask_preserving_credit_card() {
+        let cases = [
+            ("4532 0151 1283 0366", "4532 **** **** 0366"),
+            ("4532-0151-1283-0366", "4532-****-****-0366"),
+            ("4532015112830366", "4532********0366"),
+            ("3782 822463 10005", "3782 ****** *0005"),
+        ];
+        for (input, expected) in cases {
+            let masked = mask_preserving(input, MaskKind::CreditCard);
+            assert_eq!(masked, expected);
+            assert_eq!(masked.len(), input.len());
+        }
+    }
+
+    #[test]
+    fn test_mask_preserving_iban() {
+        let input = "NL91 ABNA 0417 1643 00";
+        let masked = mask_preserving(input, MaskKind::Iban);
+        assert_eq!(masked, "NL91 **** **** **43 00");
+        assert_eq!(masked.len(), input.len());
+
+        let compact = mask_preserving("NL91ABNA0417164300", MaskKind::Iban);
+        assert_eq!(compact, "NL91**********4300");
+    }
+
+    #[test]
+    fn test_mask_preserving_email() {
+        let masked = mask_preserving("john@example.com", MaskKind::Email);
+        assert_eq!(masked, "j***@e******.com");
+        assert_eq!(masked.len(), "john@example.com".len());
+
+        assert_eq!(
+            mask_preserving("admin@mail.company.co.uk", MaskKind::Email),
+            "a****@m***.c******.c*.uk"
+        );
+        assert_eq!(
+            mask_preserving("not-an-email", MaskKind::Email),
+            "************"
+        );
+    }
+
+    #[test]
+    fn test_mask_preserving_generic_and_dispatch() {
+        assert_eq!(
+            mask_preserving("123456789", MaskKind::Generic),
+            mask_value("123456789")
+        );
+        assert_eq!(MaskKind::for_detector("creditcard"), MaskKind::CreditCard);
+        assert_eq!(
+            MaskKind::for_detector("card_track_data"),
+            MaskKind::CreditCard
+        );
+        assert_eq!(MaskKind::for_detector("iban"), MaskKind::Iban);
+        assert_eq!(MaskKind::for_detector("email"), MaskKind::Email);
+        assert_eq!(MaskKind::for_detector("nl_bsn"), MaskKind::Generic);
+    }
 }
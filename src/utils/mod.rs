@@ -1,8 +1,12 @@
 /// Utility modules for PII-Radar
 pub mod checksum;
 pub mod entropy;
+pub mod keywords;
 pub mod masking;
+pub mod normalize;
 
 pub use checksum::*;
 pub use entropy::*;
+pub use keywords::{has_keyword_before, has_word_before, keyword_gate};
 pub use masking::*;
+pub use normalize::{normalize_text, NormalizedText};
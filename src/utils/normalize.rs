@@ -0,0 +1,176 @@
+//! Text normalization against zero-width and homoglyph obfuscation
+//!
+//! Strips invisible characters and maps common Unicode homoglyphs
+//! (Cyrillic/Greek look-alikes, fullwidth forms) to ASCII, keeping a byte
+//! mapping so positions in the normalized text can be translated back to
+//! the original text.
+
+/// Normalized text plus the mapping back to the original
+#[derive(Debug, Clone)]
+pub struct NormalizedText {
+    /// Normalized text
+    pub text: String,
+
+    /// For every normalized byte, the original byte range of its source character
+    spans: Vec<(usize, usize)>,
+
+    /// Length of the original text
+    original_len: usize,
+}
+
+impl NormalizedText {
+    /// Map a normalized start offset to the original text
+    pub fn original_start(&self, offset: usize) -> usize {
+        self.spans
+            .get(offset)
+            .map(|&(start, _)| start)
+            .unwrap_or(self.original_len)
+    }
+
+    /// Map a normalized (exclusive) end offset to the original text
+    pub fn original_end(&self, offset: usize) -> usize {
+        match offset.checked_sub(1) {
+            Some(last) => self
+                .spans
+                .get(last)
+                .map(|&(_, end)| end)
+                .unwrap_or(self.original_len),
+            None => 0,
+        }
+    }
+}
+
+/// Characters that render as nothing and are removed
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}' // soft hyphen
+            | '\u{180E}' // Mongolian vowel separator
+            | '\u{200B}'..='\u{200F}' // zero-width space/joiners, direction marks
+            | '\u{202A}'..='\u{202E}' // bidi embedding/override
+            | '\u{2060}'..='\u{2064}' // word joiner, invisible operators
+            | '\u{FEFF}' // zero-width no-break space / BOM
+    )
+}
+
+/// ASCII equivalent of a homoglyph, if any
+fn homoglyph(c: char) -> Option<char> {
+    let ascii = match c {
+        // Cyrillic lowercase
+        'а' => 'a',
+        'е' => 'e',
+        'о' => 'o',
+        'р' => 'p',
+        'с' => 'c',
+        'у' => 'y',
+        'х' => 'x',
+        'і' => 'i',
+        'ј' => 'j',
+        'ѕ' => 's',
+        'һ' => 'h',
+        'ԁ' => 'd',
+        // Cyrillic uppercase
+        'А' => 'A',
+        'В' => 'B',
+        'Е' => 'E',
+        'К' => 'K',
+        'М' => 'M',
+        'Н' => 'H',
+        'О' => 'O',
+        'Р' => 'P',
+        'С' => 'C',
+        'Т' => 'T',
+        'Х' => 'X',
+        'І' => 'I',
+        'Ј' => 'J',
+        'Ѕ' => 'S',
+        // Greek
+        'α' => 'a',
+        'ο' => 'o',
+        'ν' => 'v',
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Ζ' => 'Z',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Χ' => 'X',
+        'Υ' => 'Y',
+        // Fullwidth ASCII block
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFF01 + 0x21)?,
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Normalize `text`, returning `None` when nothing needed to change
+pub fn normalize_text(text: &str) -> Option<NormalizedText> {
+    if !text
+        .chars()
+        .any(|c| !c.is_ascii() && (is_invisible(c) || homoglyph(c).is_some()))
+    {
+        return None;
+    }
+
+    let mut normalized = String::with_capacity(text.len());
+    let mut spans = Vec::with_capacity(text.len());
+
+    for (start, c) in text.char_indices() {
+        if is_invisible(c) {
+            continue;
+        }
+
+        let end = start + c.len_utf8();
+        let mapped = homoglyph(c).unwrap_or(c);
+
+        normalized.push(mapped);
+        spans.extend(std::iter::repeat_n((start, end), mapped.len_utf8()));
+    }
+
+    Some(NormalizedText {
+        text: normalized,
+        spans,
+        original_len: text.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unchanged_text() {
+        assert!(normalize_text("plain ascii text").is_none());
+        assert!(normalize_text("café").is_none());
+    }
+
+    #[test]
+    fn test_strip_zero_width() {
+        let original = "a\u{200B}dmin@evil.com";
+        let normalized = normalize_text(original).unwrap();
+
+        assert_eq!(normalized.text, "admin@evil.com");
+        assert_eq!(normalized.original_start(0), 0);
+        assert_eq!(normalized.original_start(1), 4); // 'd' after the 3-byte ZWSP
+        assert_eq!(
+            normalized.original_end(normalized.text.len()),
+            original.len()
+        );
+    }
+
+    #[test]
+    fn test_homoglyphs() {
+        let original = "admin@\u{0435}xample.com"; // Cyrillic 'е'
+        let normalized = normalize_text(original).unwrap();
+
+        assert_eq!(normalized.text, "admin@example.com");
+        assert_eq!(normalized.original_start(7), 8);
+        assert_eq!(normalize_text("ｔｅｓｔ").unwrap().text, "test");
+    }
+}